@@ -0,0 +1,295 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Derive macros for [assertor](https://docs.rs/assertor), re-exported by its `derive` feature.
+#![warn(missing_docs)]
+
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
+use syn::{
+    parse_macro_input, parse_quote, Data, DataEnum, DeriveInput, Fields, GenericParam, Visibility,
+};
+
+/// Generates an extension trait `<Type>Assertion` for subjects of a struct or an enum.
+///
+/// For a struct, the trait has a method `field_<name>()` returning a derived subject for every
+/// public field. Fields of tuple structs are named by their index, e.g. `field_0()`.
+///
+/// For an enum, the trait has a method `is_<variant>()` checking the variant of the subject, and
+/// for variants with a single field, a method `as_<variant>()` returning a derived subject for
+/// the field. On mismatch, both fail with the name of the actual variant. Variant names are
+/// converted to snake case, e.g. `is_not_found()` for `NotFound`.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// #[derive(Debug, Assertable)]
+/// struct User {
+///     pub name: String,
+///     pub age: u32,
+/// }
+///
+/// let user = User { name: "Alice".to_string(), age: 30 };
+/// assert_that!(user).field_name().starts_with("A");
+/// assert_that!(user).field_age().is_at_least(18);
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// #[derive(Debug, Assertable)]
+/// struct User {
+///     pub name: String,
+/// }
+///
+/// let user = User { name: "Bob".to_string() };
+/// assert_that!(user).field_name().starts_with("A");
+/// // value of                          : user.name
+/// // expected a string that starts with: A
+/// // but was                           : Bob
+/// ```
+/// ```
+/// use assertor::*;
+///
+/// #[derive(Debug, Assertable)]
+/// enum State {
+///     Idle,
+///     Running { pid: u32 },
+///     Failed(String),
+/// }
+///
+/// let state = State::Failed("timeout".to_string());
+/// assert_that!(state).is_failed();
+/// assert_that!(state).as_failed().contains("time");
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// #[derive(Debug, Assertable)]
+/// enum State {
+///     Idle,
+///     Running { pid: u32 },
+/// }
+///
+/// let state = State::Idle;
+/// assert_that!(state).is_running();
+/// // expected to match: State::Running { .. }
+/// // but was variant  : Idle
+/// // ---
+/// // actual           : Idle
+/// ```
+#[proc_macro_derive(Assertable)]
+pub fn derive_assertable(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_assertable(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_assertable(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let name = &input.ident;
+    let vis = &input.vis;
+    let trait_name = format_ident!("{}Assertion", name);
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    // The trait takes the generic parameters of the type, without defaults, and the return type
+    // of the assertions.
+    let mut generics = input.generics.clone();
+    for param in generics.params.iter_mut() {
+        match param {
+            GenericParam::Type(param) => param.default = None,
+            GenericParam::Const(param) => param.default = None,
+            GenericParam::Lifetime(_) => {}
+        }
+    }
+    generics.params.push(parse_quote!(__R));
+    let mut impl_where_clause = generics.make_where_clause().clone();
+    let (impl_generics, trait_generics, where_clause) = generics.split_for_impl();
+
+    let methods = match &input.data {
+        Data::Struct(data) => field_methods(&data.fields),
+        Data::Enum(data) => {
+            // Checks of the variant fail through `GenericAssertion`, which renders the value.
+            impl_where_clause.predicates.push(parse_quote!(
+                #name #ty_generics: ::std::fmt::Debug
+            ));
+            impl_where_clause.predicates.push(parse_quote!(
+                ::assertor::AssertionResult: ::assertor::AssertionStrategy<__R>
+            ));
+            variant_methods(name, data)
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "Assertable can only be derived for structs and enums",
+            ))
+        }
+    };
+    let declarations = methods.iter().map(|method| &method.declaration);
+    let definitions = methods.iter().map(|method| &method.definition);
+
+    let trait_doc = match &input.data {
+        Data::Enum(_) => format!("Assertions on the variants of [`{}`].", name),
+        _ => format!("Assertions on the public fields of [`{}`].", name),
+    };
+    Ok(quote! {
+        #[doc = #trait_doc]
+        #vis trait #trait_name #impl_generics #where_clause {
+            #(#declarations)*
+        }
+
+        impl #impl_generics #trait_name #trait_generics
+            for ::assertor::Subject<'_, #name #ty_generics, (), __R>
+        #impl_where_clause
+        {
+            #(#definitions)*
+        }
+    })
+}
+
+/// A method of the generated trait.
+struct Method {
+    declaration: TokenStream,
+    definition: TokenStream,
+}
+
+/// Returns `field_<name>()` methods for the public fields of a struct.
+fn field_methods(fields: &Fields) -> Vec<Method> {
+    let mut methods = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        if !matches!(field.vis, Visibility::Public(_)) {
+            continue;
+        }
+        let (label, access) = match &field.ident {
+            Some(ident) => (ident.unraw().to_string(), quote!(#ident)),
+            None => {
+                let index = syn::Index::from(index);
+                (index.index.to_string(), quote!(#index))
+            }
+        };
+        let method = format_ident!("field_{}", label);
+        let ty = &field.ty;
+        let doc = format!("Returns a new subject which is the `{}` field.", label);
+        methods.push(Method {
+            declaration: quote! {
+                #[doc = #doc]
+                fn #method(&self) -> ::assertor::Subject<'_, #ty, (), __R>;
+            },
+            definition: quote! {
+                fn #method(&self) -> ::assertor::Subject<'_, #ty, (), __R> {
+                    self.field_subject(#label, |actual| &actual.#access)
+                }
+            },
+        });
+    }
+    methods
+}
+
+/// Returns `is_<variant>()` methods for the variants of an enum, and `as_<variant>()` methods for
+/// the variants with a single field.
+fn variant_methods(name: &Ident, data: &DataEnum) -> Vec<Method> {
+    let mut methods = Vec::new();
+    for variant in &data.variants {
+        let ident = &variant.ident;
+        let snake_case = to_snake_case(&ident.unraw().to_string());
+        let (pattern, shown) = match &variant.fields {
+            Fields::Named(_) => (
+                quote!(#name::#ident { .. }),
+                format!("{}::{} {{ .. }}", name, ident.unraw()),
+            ),
+            Fields::Unnamed(_) => (
+                quote!(#name::#ident(..)),
+                format!("{}::{}(..)", name, ident.unraw()),
+            ),
+            Fields::Unit => (
+                quote!(#name::#ident),
+                format!("{}::{}", name, ident.unraw()),
+            ),
+        };
+
+        let method = format_ident!("is_{}", snake_case);
+        let doc = format!("Checks that the subject is [`{}::{}`].", name, ident);
+        methods.push(Method {
+            declaration: quote! {
+                #[doc = #doc]
+                #[track_caller]
+                fn #method(&self) -> __R;
+            },
+            definition: quote! {
+                fn #method(&self) -> __R {
+                    ::assertor::GenericAssertion::matches_pattern(self, #shown, |actual| {
+                        matches!(actual, #pattern)
+                    })
+                }
+            },
+        });
+
+        if variant.fields.len() != 1 {
+            continue;
+        }
+        let field = variant.fields.iter().next().unwrap();
+        let binding = match &field.ident {
+            Some(field_ident) => quote!(#name::#ident { #field_ident: value }),
+            None => quote!(#name::#ident(value)),
+        };
+        let ty = &field.ty;
+        let method = format_ident!("as_{}", snake_case);
+        let label = format!("{}()", method);
+        let doc = format!(
+            "Returns a new subject which is the value of [`{}::{}`]. Fails if the subject is \
+             another variant.",
+            name, ident
+        );
+        methods.push(Method {
+            declaration: quote! {
+                #[doc = #doc]
+                #[track_caller]
+                fn #method(&self) -> ::assertor::Subject<'_, #ty, (), __R>;
+            },
+            definition: quote! {
+                fn #method(&self) -> ::assertor::Subject<'_, #ty, (), __R> {
+                    self.variant_subject(#label, #shown, |actual| match actual {
+                        #binding => Some(value),
+                        #[allow(unreachable_patterns)]
+                        _ => None,
+                    })
+                }
+            },
+        });
+    }
+    methods
+}
+
+/// Converts a variant name to snake case, e.g. `NotFound` to `not_found` and `HTTPError` to
+/// `http_error`.
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake_case = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lower = matches!(chars.get(i + 1), Some(next) if next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lower)
+            {
+                snake_case.push('_');
+            }
+        }
+        snake_case.extend(c.to_lowercase());
+    }
+    snake_case
+}
@@ -0,0 +1,125 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use assertor::testing::*;
+use assertor::*;
+
+#[derive(Debug, Assertable)]
+struct User {
+    pub name: String,
+    pub r#type: &'static str,
+    pub tags: Vec<&'static str>,
+    #[allow(dead_code)]
+    password: String,
+}
+
+#[derive(Debug, Assertable)]
+struct Point(pub i32, pub i32);
+
+#[derive(Debug, Assertable)]
+struct Wrapper<'a, T: std::fmt::Debug> {
+    pub value: &'a T,
+}
+
+fn user() -> User {
+    User {
+        name: "Alice".to_string(),
+        r#type: "admin",
+        tags: vec!["a", "b"],
+        password: "s3cr3t".to_string(),
+    }
+}
+
+#[test]
+fn named_fields() {
+    let user = user();
+    assert_that!(user).field_name().starts_with("A");
+    assert_that!(user).field_type().is_equal_to("admin");
+    assert_that!(user)
+        .field_tags()
+        .contains_exactly(vec!["b", "a"]);
+
+    // Failures
+    assert_that!(check_that!(user)
+        .field_name()
+        .is_equal_to("Bob".to_string()))
+    .facts_are(vec![
+        Fact::new("value of", "user.name"),
+        Fact::new("expected", r#""Bob""#),
+        Fact::new("actual", r#""Alice""#),
+    ]);
+}
+
+#[test]
+fn tuple_fields() {
+    let point = Point(1, 2);
+    assert_that!(point).field_0().is_equal_to(1);
+    assert_that!(point).field_1().is_equal_to(2);
+
+    // Failures
+    assert_that!(check_that!(point).field_1().is_equal_to(3)).facts_are(vec![
+        Fact::new("value of", "point.1"),
+        Fact::new("expected", "3"),
+        Fact::new("actual", "2"),
+    ]);
+}
+
+#[test]
+fn generic_struct() {
+    let value = 3;
+    let wrapper = Wrapper { value: &value };
+    assert_that!(wrapper).field_value().is_equal_to(&3);
+}
+
+#[derive(Debug, Assertable)]
+enum State {
+    Idle,
+    Running {
+        pid: u32,
+    },
+    Failed(String),
+    #[allow(dead_code)]
+    HTTPError(u16, String),
+}
+
+#[test]
+fn enum_variants() {
+    let state = State::Running { pid: 7 };
+    assert_that!(state).is_running();
+    assert_that!(state).as_running().is_equal_to(7);
+    assert_that!(State::Idle).is_idle();
+    assert_that!(State::Failed("timeout".to_string()))
+        .as_failed()
+        .contains("time");
+    assert_that!(State::HTTPError(404, "not found".to_string())).is_http_error();
+
+    // Failures
+    assert_that!(check_that!(state).is_failed()).facts_are(vec![
+        Fact::new("expected to match", "State::Failed(..)"),
+        Fact::new("but was variant", "Running"),
+        Fact::new_splitter(),
+        Fact::new("actual", "Running { pid: 7 }"),
+    ]);
+    assert_that!(check_that!(state).as_running().is_equal_to(8)).facts_are(vec![
+        Fact::new("value of", "state.as_running()"),
+        Fact::new("expected", "8"),
+        Fact::new("actual", "7"),
+    ]);
+}
+
+#[test]
+#[should_panic(expected = "expected to match: State::Running { .. }")]
+fn enum_variant_subject_of_other_variant() {
+    assert_that!(State::Idle).as_running();
+}
@@ -0,0 +1,219 @@
+// Copyright 2021 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+
+/// Values of several expressions of the same type, each labeled with its expression. Created by
+/// [`assert_that_all`](crate::assert_that_all).
+#[derive(Debug, Clone)]
+pub struct LabeledValues<'a, T> {
+    values: Vec<(String, &'a T)>,
+}
+
+impl<'a, T> LabeledValues<'a, T> {
+    /// Creates labeled values from pairs of an expression and its value.
+    pub fn new(values: Vec<(String, &'a T)>) -> Self {
+        LabeledValues { values }
+    }
+}
+
+/// Trait for assertions on the values of several expressions at once, e.g. results of several
+/// implementations of the same algorithm.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let (a, b, c) = (1 + 2, 3, 6 / 2);
+/// assert_that_all!([a, b, c]).are_equal();
+/// assert_that_all!([a, b, c]).each(|value| value.is_at_least(1));
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// let (a, b, c) = (1 + 2, 3, 2);
+/// assert_that_all!([a, b, c]).are_equal();
+/// // expected all values to be equal: but c differs from a
+/// // ---
+/// // value of                       : a
+/// // value                          : 3
+/// // ---
+/// // value of                       : c
+/// // value                          : 2
+/// ```
+pub trait AllValuesAssertion<T, R> {
+    /// Checks that all values are equal to each other.
+    #[track_caller]
+    fn are_equal(&self) -> R
+    where
+        T: PartialEq + Debug;
+
+    /// Checks that each value passes `assertion`, which is given a subject for the value labeled
+    /// with its expression.
+    #[track_caller]
+    fn each<F>(&self, assertion: F) -> R
+    where
+        F: Fn(Subject<'_, T, (), Result<(), AssertionResult>>) -> Result<(), AssertionResult>;
+}
+
+impl<T, R> AllValuesAssertion<T, R> for Subject<'_, LabeledValues<'_, T>, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn are_equal(&self) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        let values = &self.actual().values;
+        let (first_expr, first) = match values.first() {
+            Some(first) => first,
+            None => return self.new_result().do_ok(),
+        };
+        let (other_expr, other) = match values.iter().find(|(_, value)| value.ne(first)) {
+            Some(other) => other,
+            None => return self.new_result().do_ok(),
+        };
+        self.new_result()
+            .begin_group()
+            .add_fact(
+                "expected all values to be equal",
+                format!("but {} differs from {}", other_expr, first_expr),
+            )
+            .end_group()
+            .begin_group()
+            .add_fact(FactKey::ValueOf, first_expr)
+            .add_formatted_fact("value", first)
+            .end_group()
+            .begin_group()
+            .add_fact(FactKey::ValueOf, other_expr)
+            .add_formatted_fact("value", other)
+            .end_group()
+            .do_fail()
+    }
+
+    fn each<F>(&self, assertion: F) -> R
+    where
+        F: Fn(Subject<'_, T, (), Result<(), AssertionResult>>) -> Result<(), AssertionResult>,
+    {
+        pass_if_skipped!(self);
+        let values = &self.actual().values;
+        let failures: Vec<AssertionResult> = values
+            .iter()
+            .filter_map(|(expr, value)| {
+                assertion(Subject::new(
+                    *value,
+                    expr.clone(),
+                    Some(expr.clone()),
+                    (),
+                    self.location().clone(),
+                    PhantomData::<Result<(), AssertionResult>>,
+                ))
+                .err()
+            })
+            .collect();
+        if failures.is_empty() {
+            return self.new_result().do_ok();
+        }
+        let result = self
+            .new_result()
+            .begin_group()
+            .add_fact(
+                "expected each value to pass the assertion",
+                format!("but {} of {} did not", failures.len(), values.len()),
+            )
+            .end_group();
+        failures
+            .iter()
+            .fold(result, |result, failure| {
+                failure
+                    .facts()
+                    .iter()
+                    .fold(result.begin_group(), |group, fact| {
+                        group.push_fact(fact.clone())
+                    })
+                    .end_group()
+            })
+            .do_fail()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::{
+        assert_that, assert_that_all, check_that, ComparableAssertion, EqualityAssertion, Fact,
+    };
+
+    use super::*;
+
+    #[test]
+    fn are_equal() {
+        let (a, b, c) = (1, 1, 1);
+        assert_that_all!([a, b, c]).are_equal();
+        assert_that_all!([a]).are_equal();
+
+        // Failures
+        let (x, y) = (2, 3);
+        let values = LabeledValues::new(vec![
+            ("a".to_string(), &a),
+            ("x".to_string(), &x),
+            ("y".to_string(), &y),
+        ]);
+        assert_that!(check_that!(values).are_equal()).facts_are(vec![
+            Fact::new("expected all values to be equal", "but x differs from a"),
+            Fact::new_splitter(),
+            Fact::new("value of", "a"),
+            Fact::new("value", "1"),
+            Fact::new_splitter(),
+            Fact::new("value of", "x"),
+            Fact::new("value", "2"),
+        ]);
+    }
+
+    #[test]
+    // The closures return the `Result<(), AssertionResult>` that `each` takes from each value.
+    #[allow(clippy::result_large_err)]
+    fn each() {
+        let (a, b) = (1, 5);
+        assert_that_all!([a, b]).each(|value| value.is_at_least(1));
+
+        // Failures
+        let c = 7;
+        let values = LabeledValues::new(vec![
+            ("a".to_string(), &a),
+            ("b".to_string(), &b),
+            ("c".to_string(), &c),
+        ]);
+        assert_that!(check_that!(values).each(|value| value.is_equal_to(1))).facts_are(vec![
+            Fact::new(
+                "expected each value to pass the assertion",
+                "but 2 of 3 did not",
+            ),
+            Fact::new_splitter(),
+            Fact::new("value of", "b"),
+            Fact::new("expected", "1"),
+            Fact::new("actual", "5"),
+            Fact::new_splitter(),
+            Fact::new("value of", "c"),
+            Fact::new("expected", "1"),
+            Fact::new("actual", "7"),
+        ]);
+    }
+}
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+use crate::base::{pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, Subject};
 use crate::StringAssertion;
 
 /// Trait for anyhow error assertion.
@@ -82,6 +82,7 @@ impl<R> AnyhowErrorAssertion<R> for Subject<'_, anyhow::Error, (), R>
     }
 
     fn has_message<E: Into<String>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
         self.as_string().is_same_string_to(expected)
     }
 }
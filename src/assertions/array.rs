@@ -0,0 +1,380 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::fmt::Debug;
+
+use crate::assertions::iterator::{
+    check_contains_any_of, check_has_length, check_is_empty, check_is_not_empty, check_len_bound,
+    IteratorAssertion, LengthBound,
+};
+use crate::base::{pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, Subject};
+
+/// Trait for fixed-size array assertion.
+///
+/// Same as [`crate::VecAssertion`], but for arrays. Expected values can be arrays, vectors or
+/// slices. Ranges are iterators, so they can be asserted with [`crate::IteratorAssertion`]
+/// directly.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// assert_that!([1, 2, 3]).has_length(3);
+/// assert_that!([1, 2, 3]).contains(2);
+/// assert_that!([1, 2, 3]).contains_exactly([3, 2, 1]);
+/// assert_that!([1, 2, 3]).contains_exactly_in_order(vec![1, 2, 3]);
+///
+/// assert_that!(1..4).contains_exactly_in_order([1, 2, 3].into_iter());
+/// ```
+pub trait ArrayAssertion<'a, S, T, R>
+where
+    AssertionResult: AssertionStrategy<R>,
+    Self: Sized,
+{
+    /// Checks that the subject contains the element `expected`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 2, 3]).contains(2);
+    /// ```
+    #[track_caller]
+    fn contains<B>(&self, element: B) -> R
+    where
+        B: Borrow<T>,
+        T: PartialEq + Debug;
+
+    /// Checks that the subject does not contains the `element`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 2, 3]).does_not_contain(5);
+    /// ```
+    #[track_caller]
+    fn does_not_contain<B>(&self, element: B) -> R
+    where
+        B: Borrow<T>,
+        T: PartialEq + Debug;
+
+    /// Checks that the subject exactly contains elements of `expected`.
+    ///
+    /// This method doesn't take care of the order. Use
+    /// [contains_exactly_in_order](`ArrayAssertion::contains_exactly_in_order`) to check
+    /// elements are in the same order.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 2, 3]).contains_exactly([3, 2, 1]);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!([1]).contains_exactly([1, 2]);
+    /// ```
+    #[track_caller]
+    fn contains_exactly<B: Borrow<[T]>>(&self, expected: B) -> R
+    where
+        T: PartialEq + Debug;
+
+    /// Checks that the subject exactly contains `expected` in the same order.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 2, 3]).contains_exactly_in_order([1, 2, 3]);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!([1, 2]).contains_exactly_in_order([2, 1]);
+    /// ```
+    #[track_caller]
+    fn contains_exactly_in_order<B: Borrow<[T]>>(&self, expected: B) -> R
+    where
+        T: PartialEq + Debug;
+
+    /// Checks that the subject does not contain any element of `elements`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 2, 3]).does_not_contain_any([0, -5]);
+    /// ```
+    #[track_caller]
+    fn does_not_contain_any<B: Borrow<[T]>>(&self, elements: B) -> R
+    where
+        T: PartialEq + Debug;
+
+    /// Checks that the subject contains at least one element of `elements`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 2, 3]).contains_any_of([0, 3]);
+    /// ```
+    #[track_caller]
+    fn contains_any_of<B: Borrow<[T]>>(&self, elements: B) -> R
+    where
+        T: PartialEq + Debug;
+
+    /// Checks that the subject is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([0usize; 0]).is_empty();
+    /// ```
+    #[track_caller]
+    fn is_empty(&self) -> R
+    where
+        T: Debug;
+
+    /// Checks that the subject is not empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1]).is_not_empty();
+    /// ```
+    #[track_caller]
+    fn is_not_empty(&self) -> R
+    where
+        T: Debug;
+
+    /// Checks that the subject is the given length.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 2, 3]).has_length(3);
+    /// ```
+    #[track_caller]
+    fn has_length(&self, length: usize) -> R;
+
+    /// Checks that the subject has at least `length` elements.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 2, 3]).has_length_at_least(2);
+    /// ```
+    #[track_caller]
+    fn has_length_at_least(&self, length: usize) -> R;
+
+    /// Checks that the subject has at most `length` elements.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 2, 3]).has_length_at_most(3);
+    /// ```
+    #[track_caller]
+    fn has_length_at_most(&self, length: usize) -> R;
+}
+
+impl<'a, T, R, const N: usize> ArrayAssertion<'a, [T; N], T, R> for Subject<'a, [T; N], (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn contains<B>(&self, element: B) -> R
+    where
+        B: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        self.new_owned_subject(self.actual().iter(), None, ())
+            .contains(element.borrow())
+    }
+
+    fn does_not_contain<B>(&self, element: B) -> R
+    where
+        B: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        self.new_owned_subject(self.actual().iter(), None, ())
+            .does_not_contain(element.borrow())
+    }
+
+    fn contains_exactly<B: Borrow<[T]>>(&self, expected: B) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        self.new_owned_subject(self.actual().iter(), None, ())
+            .contains_exactly(expected.borrow().iter())
+    }
+
+    fn contains_exactly_in_order<B: Borrow<[T]>>(&self, expected: B) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        self.new_owned_subject(self.actual().iter(), None, ())
+            .contains_exactly_in_order(expected.borrow().iter())
+    }
+
+    fn does_not_contain_any<B: Borrow<[T]>>(&self, elements: B) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        self.new_owned_subject(self.actual().iter(), None, ())
+            .does_not_contain_any(elements.borrow().iter())
+    }
+
+    fn contains_any_of<B: Borrow<[T]>>(&self, elements: B) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        check_contains_any_of(
+            self.new_result(),
+            self.actual().iter(),
+            elements.borrow().iter(),
+        )
+    }
+
+    fn is_empty(&self) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_is_empty(self.new_result(), self.actual().iter())
+    }
+
+    fn is_not_empty(&self) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_is_not_empty(self.new_result(), self.actual().iter())
+    }
+
+    fn has_length(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_has_length(
+            self.new_result(),
+            self.actual().iter(),
+            self.description_or_expr(),
+            length,
+        )
+    }
+
+    fn has_length_at_least(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().iter().count(),
+            format!("{}.size()", self.description_or_expr()),
+            LengthBound::AtLeast(length),
+        )
+    }
+
+    fn has_length_at_most(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().iter().count(),
+            format!("{}.size()", self.description_or_expr()),
+            LengthBound::AtMost(length),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+
+    use super::*;
+
+    #[test]
+    fn contains() {
+        assert_that!([1, 2, 3]).contains(3);
+        assert_that!([1, 2, 3]).does_not_contain(4);
+
+        // Failures
+        assert_that!(check_that!([1, 2, 3]).contains(10)).facts_are(vec![
+            Fact::new("expected to contain", "10"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1", "2", "3"]),
+        ]);
+    }
+
+    #[test]
+    fn contains_exactly() {
+        assert_that!([1, 2, 3]).contains_exactly([3, 1, 2]);
+        assert_that!([1, 2, 3]).contains_exactly(vec![3, 1, 2]);
+        assert_that!(["a", "b"]).contains_exactly(&["b", "a"][..]);
+        assert_that!([1, 2, 3]).contains_exactly_in_order([1, 2, 3]);
+        assert_that!([1, 2, 3]).does_not_contain_any([4, 5]);
+        assert_that!([1, 2, 3]).contains_any_of(vec![4, 3]);
+
+        // Failures
+        assert_that!(check_that!([2, 1, 3]).contains_exactly_in_order([1, 2, 3])).facts_are(vec![
+            Fact::new_simple_fact("contents match, but order was wrong"),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("expected", vec!["1", "2", "3"]),
+            Fact::new_multi_value_fact("actual", vec!["2", "1", "3"]),
+            Fact::new("help", "use contains_exactly if the order does not matter"),
+        ])
+    }
+
+    #[test]
+    fn length() {
+        assert_that!([0usize; 0]).is_empty();
+        assert_that!([1]).is_not_empty();
+        assert_that!([1, 2, 3]).has_length(3);
+        assert_that!([1, 2, 3]).has_length_at_least(2);
+        assert_that!([1, 2, 3]).has_length_at_most(3);
+
+        // Failures
+        assert_that!(check_that!([1]).is_empty()).facts_are(vec![
+            Fact::new_simple_fact("expected to be empty"),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("actual", vec!["1"]),
+        ]);
+        assert_that!(check_that!([1, 2]).has_length(3)).facts_are(vec![
+            Fact::new("value of", "[1, 2].size()"),
+            Fact::new("expected", "3"),
+            Fact::new("actual", "2"),
+        ]);
+        assert_that!(check_that!([1, 2]).has_length_at_least(3)).facts_are(vec![
+            Fact::new("value of", "[1, 2].size()"),
+            Fact::new("expected", "at least 3"),
+            Fact::new("actual", "2"),
+        ]);
+        assert_that!(check_that!([1, 2]).has_length_at_most(1)).facts_are(vec![
+            Fact::new("value of", "[1, 2].size()"),
+            Fact::new("expected", "at most 1"),
+            Fact::new("actual", "2"),
+        ]);
+    }
+
+    #[test]
+    fn ranges() {
+        assert_that!(1..4).contains_exactly_in_order([1, 2, 3].into_iter());
+        assert_that!(1..=3).contains(3);
+        assert_that!(1..10).has_length(9);
+        assert_that!(0..0).is_empty();
+
+        // Failures
+        assert_that!(check_that!(1..3).contains(5)).facts_are(vec![
+            Fact::new("expected to contain", "5"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1", "2"]),
+        ]);
+    }
+}
@@ -12,12 +12,26 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::any::type_name;
 use std::borrow::Borrow;
 use std::fmt::Debug;
+use std::time::Duration;
 
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+use crate::assertions::time::scale_durations;
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, DebugCache, FactKey, Subject,
+};
+use crate::diff::debug::{
+    debug_differences, list_difference, set_difference, string_difference, DebugValue,
+};
 
 /// Trait for equality assertion.
+///
+/// When `is_equal_to` fails on structured values such as structs, collections or maps, the paths
+/// where their `Debug` representations differ are reported as `differs at` facts, e.g.
+/// `differs at: .address.zip (expected: 94043, actual: 94044)`. For strings, the position of the
+/// first differing character is reported, e.g. `differs at: char 5`.
+///
 /// # Example
 /// ```
 /// use assertor::*;
@@ -39,16 +53,25 @@ where
     AssertionResult: AssertionStrategy<R>,
 {
     fn is_equal_to<B: Borrow<S>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
         if self.actual().eq(expected.borrow()) {
             self.new_result().do_ok()
         } else {
-            self.new_result()
-                .add_fact("expected", format!("{:?}", expected.borrow()))
-                .add_fact("actual", format!("{:?}", self.actual()))
+            let mut result = self.new_result();
+            let mut cache = DebugCache::default();
+            // Differences are computed from `{:?}`, so they would leak redacted values or disagree
+            // with a custom formatter.
+            if self.formatter().is_none() && !self.is_redacted() {
+                result = add_difference_facts(result, &mut cache, expected.borrow(), self.actual());
+            }
+            let result =
+                self.add_value_fact(result, FactKey::Expected, expected.borrow(), &mut cache);
+            self.add_value_fact(result, FactKey::Actual, self.actual(), &mut cache)
                 .do_fail()
         }
     }
     fn is_not_equal_to<B: Borrow<S>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
         if !self.actual().ne(expected.borrow()) {
             self.new_result().do_fail()
         } else {
@@ -57,6 +80,80 @@ where
     }
 }
 
+/// Adds `differs at` facts followed by a splitter if the `Debug` representations of `expected`
+/// and `actual` differ in nested values or in the middle of a string, or `missing` and
+/// `unexpected` facts if both are sets.
+pub(crate) fn add_difference_facts<'v, T: Debug + ?Sized>(
+    mut result: AssertionResult,
+    cache: &mut DebugCache<'v>,
+    expected: &'v T,
+    actual: &'v T,
+) -> AssertionResult {
+    let (expected, actual) = (cache.debug_of(expected), cache.debug_of(actual));
+    // Both representations are parsed once for all kinds of differences.
+    let parsed = DebugValue::parse(&expected).zip(DebugValue::parse(&actual));
+    if let Some((expected, actual)) = &parsed {
+        if let Some((missing, unexpected)) = set_difference(expected, actual) {
+            return add_missing_and_unexpected_facts(result, &missing, &unexpected).add_splitter();
+        }
+        if let Some(difference) = list_difference(expected, actual) {
+            result = result.add_fact(
+                "first mismatch at index",
+                difference.first_mismatch.to_string(),
+            );
+            if difference.missing.is_empty() && difference.unexpected.is_empty() {
+                result = result.add_simple_fact("elements are the same, but in a different order");
+            }
+            return add_missing_and_unexpected_facts(
+                result,
+                &difference.missing,
+                &difference.unexpected,
+            )
+            .add_splitter();
+        }
+    }
+    let mut differences: Vec<String> = match &parsed {
+        Some((expected, actual)) => debug_differences(expected, actual)
+            .iter()
+            .map(|difference| difference.to_string())
+            .collect(),
+        None => vec![],
+    };
+    if differences.is_empty() {
+        differences.extend(string_difference(&expected, &actual));
+    }
+    if differences.is_empty() {
+        return result;
+    }
+    differences
+        .into_iter()
+        .fold(result, |result, difference| {
+            result.add_fact(FactKey::DiffersAt, difference)
+        })
+        .add_splitter()
+}
+
+fn add_missing_and_unexpected_facts(
+    mut result: AssertionResult,
+    missing: &[String],
+    unexpected: &[String],
+) -> AssertionResult {
+    if !missing.is_empty() {
+        result = result.add_fact(FactKey::MissingN(missing.len()), bracketed(missing));
+    }
+    if !unexpected.is_empty() {
+        result = result.add_fact(
+            FactKey::UnexpectedN(unexpected.len()),
+            bracketed(unexpected),
+        );
+    }
+    result
+}
+
+fn bracketed(elements: &[String]) -> String {
+    format!("[{}]", elements.join(", "))
+}
+
 /// Trait for comparison assertions.
 pub trait ComparableAssertion<S, R> {
     /// Checks that the subject is greater than or equal to `expected`.
@@ -81,42 +178,253 @@ where
     AssertionResult: AssertionStrategy<R>,
 {
     fn is_at_least<B: Borrow<S>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
         if self.actual().ge(expected.borrow()) {
             self.new_result().do_ok()
         } else {
-            // TODO: write error message
-            self.new_result().do_fail()
+            comparison_failure(self, "at least", expected.borrow())
         }
     }
 
     fn is_at_most<B: Borrow<S>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
         if self.actual().le(expected.borrow()) {
             self.new_result().do_ok()
         } else {
-            // TODO: write error message
-            self.new_result().do_fail()
+            comparison_failure(self, "at most", expected.borrow())
         }
     }
 
     fn is_greater_than<B: Borrow<S>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
         if self.actual().gt(expected.borrow()) {
             self.new_result().do_ok()
         } else {
-            // TODO: write error message
-            self.new_result().do_fail()
+            comparison_failure(self, "more than", expected.borrow())
         }
     }
 
     fn is_less_than<B: Borrow<S>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
         if self.actual().lt(expected.borrow()) {
             self.new_result().do_ok()
         } else {
-            // TODO: write error message
-            self.new_result().do_fail()
+            comparison_failure(self, "less than", expected.borrow())
+        }
+    }
+}
+
+/// Fails with the bound and the subject. [`Duration`]s are shown in the same unit, together with
+/// their difference, rather than e.g. as `999.5ms` and `1.25s`.
+fn comparison_failure<S: Debug, R>(subject: &Subject<S, (), R>, bound: &str, expected: &S) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    let result = subject.new_result();
+    if type_name::<S>() == type_name::<Duration>()
+        && subject.formatter().is_none()
+        && !subject.is_redacted()
+    {
+        let (expected_repr, actual_repr) =
+            (format!("{:?}", expected), format!("{:?}", subject.actual()));
+        if let Some([expected, actual, delta]) = scale_durations(&expected_repr, &actual_repr) {
+            return result
+                .add_fact(FactKey::Expected, format!("{} {}", bound, expected))
+                .add_fact(FactKey::ButWas, actual)
+                .add_fact("delta", delta)
+                .do_fail();
+        }
+    }
+    result
+        .add_fact(
+            FactKey::Expected,
+            format!("{} {}", bound, subject.format_value(expected)),
+        )
+        .add_fact(FactKey::ButWas, subject.format_value(subject.actual()))
+        .do_fail()
+}
+
+/// Trait for assertions available on every subject implementing [`Debug`], so that types without
+/// dedicated assertions still have a minimal API.
+///
+/// The equality check is named `is_debug_equal_to` rather than `is_equal_to`, as the latter would
+/// be ambiguous with [`EqualityAssertion::is_equal_to`] for types implementing both traits.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// #[derive(Debug)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// assert_that!(Point { x: 1, y: 2 }).is_debug_equal_to(Point { x: 1, y: 2 });
+/// assert_that!(Point { x: 1, y: 2 }).satisfies(|p| p.x < p.y);
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// assert_that!(vec![1, 2]).satisfies(|v| v.len() > 2);
+/// // expected to satisfy the predicate
+/// // but was                          : [1, 2]
+/// ```
+pub trait GenericAssertion<S, R> {
+    /// Checks that the [`Debug`] representation of the subject is the same as that of `expected`.
+    #[track_caller]
+    fn is_debug_equal_to<B: Borrow<S>>(&self, expected: B) -> R;
+
+    /// Checks that the subject satisfies `predicate`.
+    #[track_caller]
+    fn satisfies<F: FnOnce(&S) -> bool>(&self, predicate: F) -> R;
+
+    /// Checks that the [`Debug`] representation of the subject contains `expected`, e.g. the
+    /// variant name of an error enum without [`Display`](std::fmt::Display).
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// #[derive(Debug)]
+    /// enum Error {
+    ///     NotFound { id: u32 },
+    /// }
+    ///
+    /// let result: Result<(), Error> = Err(Error::NotFound { id: 7 });
+    /// assert_that!(result).err().has_debug_containing("NotFound");
+    /// ```
+    #[track_caller]
+    fn has_debug_containing(&self, expected: &str) -> R;
+
+    /// Checks that the subject matches a pattern, for which `matches` is the result of
+    /// `matches!`. Usually called by [`is_variant`](crate::is_variant), which passes the pattern
+    /// as written in the test.
+    #[track_caller]
+    fn matches_pattern<F: FnOnce(&S) -> bool>(&self, pattern: &str, matches: F) -> R;
+}
+
+/// Checks that a subject, such as the error of a result, matches an enum variant pattern. On
+/// failure, the facts show the pattern and the name of the actual variant, parsed from its
+/// [`Debug`] representation.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// #[derive(Debug)]
+/// enum Error {
+///     NotFound { id: u32 },
+///     PermissionDenied,
+/// }
+///
+/// let result: Result<(), Error> = Err(Error::NotFound { id: 7 });
+/// is_variant!(assert_that!(result).err(), Error::NotFound { .. });
+/// is_variant!(assert_that!(result).err(), Error::NotFound { id: 1..=9 });
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// #[derive(Debug)]
+/// enum Error {
+///     NotFound { id: u32 },
+///     PermissionDenied,
+/// }
+///
+/// let result: Result<(), Error> = Err(Error::PermissionDenied);
+/// is_variant!(assert_that!(result).err(), Error::NotFound { .. });
+/// // expected to match: Error::NotFound { .. }
+/// // but was variant  : PermissionDenied
+/// // ---
+/// // actual           : PermissionDenied
+/// ```
+#[macro_export]
+macro_rules! is_variant {
+    ($subject:expr, $pattern:pat $(,)?) => {
+        $crate::GenericAssertion::matches_pattern(&$subject, stringify!($pattern), |actual| {
+            matches!(actual, $pattern)
+        })
+    };
+}
+
+impl<S: Debug, R> GenericAssertion<S, R> for Subject<'_, S, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn is_debug_equal_to<B: Borrow<S>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
+        let mut result = self.new_result();
+        let mut cache = DebugCache::default();
+        if cache.debug_of(self.actual()) == cache.debug_of(expected.borrow()) {
+            result.do_ok()
+        } else {
+            if self.formatter().is_none() && !self.is_redacted() {
+                result = add_difference_facts(result, &mut cache, expected.borrow(), self.actual());
+            }
+            let result =
+                self.add_value_fact(result, FactKey::Expected, expected.borrow(), &mut cache);
+            self.add_value_fact(result, FactKey::Actual, self.actual(), &mut cache)
+                .do_fail()
+        }
+    }
+
+    fn satisfies<F: FnOnce(&S) -> bool>(&self, predicate: F) -> R {
+        pass_if_skipped!(self);
+        if predicate(self.actual()) {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_simple_fact("expected to satisfy the predicate")
+                .add_fact(FactKey::ButWas, self.format_value(self.actual()))
+                .do_fail()
+        }
+    }
+
+    fn has_debug_containing(&self, expected: &str) -> R {
+        pass_if_skipped!(self);
+        if format!("{:?}", self.actual()).contains(expected) {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_formatted_fact("expected debug representation to contain", expected)
+                .add_fact(FactKey::ButWas, self.format_value(self.actual()))
+                .do_fail()
+        }
+    }
+
+    fn matches_pattern<F: FnOnce(&S) -> bool>(&self, pattern: &str, matches: F) -> R {
+        pass_if_skipped!(self);
+        if matches(self.actual()) {
+            return self.new_result().do_ok();
         }
+        pattern_mismatch(self, pattern).do_fail()
     }
 }
 
+/// Returns the facts of a subject not matching an enum variant pattern, with the name of the
+/// actual variant.
+pub(crate) fn pattern_mismatch<S: Debug, O, R>(
+    subject: &Subject<S, O, R>,
+    pattern: &str,
+) -> AssertionResult {
+    let debug = format!("{:?}", subject.actual());
+    subject
+        .new_result()
+        .add_fact("expected to match", pattern.to_string())
+        .add_fact("but was variant", variant_name(&debug).to_string())
+        .add_splitter()
+        .add_fact(FactKey::Actual, subject.format_value(subject.actual()))
+}
+
+/// Returns the leading identifier of a [`Debug`] representation, which is the variant name for
+/// derived implementations of enums, e.g. `NotFound` for `NotFound { id: 7 }`.
+fn variant_name(debug: &str) -> &str {
+    let end = debug
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(debug.len());
+    &debug[..end]
+}
+
 #[cfg(test)]
 mod tests {
     use crate::testing::*;
@@ -139,6 +447,175 @@ mod tests {
         assert_that!(result).facts_are(vec![Fact::new("expected", "3"), Fact::new("actual", "1")])
     }
 
+    #[test]
+    fn is_equal_to_formats_values_once() {
+        use std::cell::Cell;
+
+        struct Counted<'a>(&'a Cell<usize>, u32);
+        impl PartialEq for Counted<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                self.1 == other.1
+            }
+        }
+        impl Debug for Counted<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.set(self.0.get() + 1);
+                write!(f, "Counted([{}])", self.1)
+            }
+        }
+
+        let count = Cell::new(0);
+        let result = check_that!(Counted(&count, 1)).is_equal_to(Counted(&count, 2));
+        assert_that!(count.get()).is_equal_to(2);
+        assert_that!(result).facts_are(vec![
+            Fact::new("differs at", ".0[0] (expected: 2, actual: 1)"),
+            Fact::new_splitter(),
+            Fact::new("expected", "Counted([2])"),
+            Fact::new("actual", "Counted([1])"),
+        ]);
+    }
+
+    #[test]
+    fn is_equal_to_differs_at() {
+        use crate::SetAssertion;
+
+        #[derive(Debug, PartialEq)]
+        struct Address {
+            city: &'static str,
+            zip: u32,
+        }
+        #[derive(Debug, PartialEq)]
+        struct Person {
+            name: &'static str,
+            address: Address,
+        }
+        let person = |zip| Person {
+            name: "Alice",
+            address: Address { city: "Tokyo", zip },
+        };
+
+        assert_that!(check_that!(person(1)).is_equal_to(person(2))).facts_are(vec![
+            Fact::new("differs at", ".address.zip (expected: 2, actual: 1)"),
+            Fact::new_splitter(),
+            Fact::new(
+                "expected",
+                r#"Person { name: "Alice", address: Address { city: "Tokyo", zip: 2 } }"#,
+            ),
+            Fact::new(
+                "actual",
+                r#"Person { name: "Alice", address: Address { city: "Tokyo", zip: 1 } }"#,
+            ),
+        ]);
+        assert_that!(check_that!(vec![1, 2]).is_equal_to(vec![1])).facts_are(vec![
+            Fact::new("first mismatch at index", "1"),
+            Fact::new("unexpected (1)", "[2]"),
+            Fact::new_splitter(),
+            Fact::new("expected", "[1]"),
+            Fact::new("actual", "[1, 2]"),
+        ]);
+        // Redacted subjects do not reveal the differing values.
+        assert_that!(check_that!(person(1)).redacted().is_equal_to(person(2)))
+            .fact_keys()
+            .does_not_contain(&"differs at".to_string());
+    }
+
+    #[test]
+    fn is_equal_to_deeply_nested() {
+        use crate::SetAssertion;
+
+        #[derive(PartialEq)]
+        struct Deep(u32);
+        impl Debug for Deep {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                let depth = 50_000;
+                write!(f, "{}{}{}", "[".repeat(depth), self.0, "]".repeat(depth))
+            }
+        }
+
+        // The representations are too deep to look for differences in.
+        assert_that!(check_that!(Deep(1)).is_equal_to(Deep(2)))
+            .fact_keys()
+            .does_not_contain(&"differs at".to_string());
+    }
+
+    #[test]
+    fn is_equal_to_nested_wrappers() {
+        let actual: Option<Result<Option<i32>, ()>> = Some(Ok(Some(3)));
+        assert_that!(check_that!(actual).is_equal_to(Some(Ok(Some(4))))).facts_are(vec![
+            Fact::new("differs at", ".0.0.0 (expected: 4, actual: 3)"),
+            Fact::new_splitter(),
+            Fact::new("expected", "Some→Ok→Some: 4"),
+            Fact::new("actual", "Some→Ok→Some: 3"),
+        ]);
+    }
+
+    #[test]
+    fn is_equal_to_vec() {
+        assert_that!(check_that!(vec![1, 2, 3, 4]).is_equal_to(vec![1, 2, 5, 3])).facts_are(vec![
+            Fact::new("first mismatch at index", "2"),
+            Fact::new("missing (1)", "[5]"),
+            Fact::new("unexpected (1)", "[4]"),
+            Fact::new_splitter(),
+            Fact::new("expected", "[1, 2, 5, 3]"),
+            Fact::new("actual", "[1, 2, 3, 4]"),
+        ]);
+        assert_that!(check_that!(vec!["b", "a"]).is_equal_to(vec!["a", "b"])).facts_are(vec![
+            Fact::new("first mismatch at index", "0"),
+            Fact::new_simple_fact("elements are the same, but in a different order"),
+            Fact::new_splitter(),
+            Fact::new("expected", r#"["a", "b"]"#),
+            Fact::new("actual", r#"["b", "a"]"#),
+        ]);
+        assert_that!(check_that!([1, 2]).is_equal_to([1, 3])).facts_are(vec![
+            Fact::new("first mismatch at index", "1"),
+            Fact::new("missing (1)", "[3]"),
+            Fact::new("unexpected (1)", "[2]"),
+            Fact::new_splitter(),
+            Fact::new("expected", "[1, 3]"),
+            Fact::new("actual", "[1, 2]"),
+        ]);
+    }
+
+    #[test]
+    fn is_equal_to_sets() {
+        use std::collections::{BTreeSet, HashSet};
+
+        assert_that!(HashSet::from([1, 2])).is_equal_to(HashSet::from([2, 1]));
+
+        // Failures
+        assert_that!(check_that!(HashSet::from(["a"])).is_equal_to(HashSet::from(["b"])))
+            .facts_are(vec![
+                Fact::new("missing (1)", r#"["b"]"#),
+                Fact::new("unexpected (1)", r#"["a"]"#),
+                Fact::new_splitter(),
+                Fact::new("expected", r#"{"b"}"#),
+                Fact::new("actual", r#"{"a"}"#),
+            ]);
+        assert_that!(check_that!(BTreeSet::from([1, 2, 3])).is_equal_to(BTreeSet::from([1, 2])))
+            .facts_are(vec![
+                Fact::new("unexpected (1)", "[3]"),
+                Fact::new_splitter(),
+                Fact::new("expected", "{1, 2}"),
+                Fact::new("actual", "{1, 2, 3}"),
+            ]);
+    }
+
+    #[test]
+    fn is_equal_to_with_formatter() {
+        assert_that!(255)
+            .with_formatter(|v| format!("{:#x}", v))
+            .is_equal_to(255);
+
+        // Failures
+        let result = check_that!(255)
+            .with_formatter(|v| format!("{:#x}", v))
+            .is_equal_to(256);
+        assert_that!(result).facts_are(vec![
+            Fact::new("expected", "0x100"),
+            Fact::new("actual", "0xff"),
+        ]);
+    }
+
     #[test]
     fn is_not_equal_to() {
         assert_that!(1).is_not_equal_to(2);
@@ -152,5 +629,109 @@ mod tests {
         assert_that!(2).is_at_least(1);
         assert_that!(2).is_at_least(2);
         assert_that!(2_f32).is_at_least(1.);
+
+        // Failures
+        assert_that!(check_that!(1).is_at_least(2)).facts_are(vec![
+            Fact::new("expected", "at least 2"),
+            Fact::new("but was", "1"),
+        ]);
+    }
+
+    #[test]
+    fn comparison_of_durations() {
+        let ms = Duration::from_millis;
+        assert_that!(ms(900)).is_less_than(ms(1000));
+
+        // Failures
+        assert_that!(check_that!(ms(1250)).is_at_most(ms(900))).facts_are(vec![
+            Fact::new("expected", "at most 900ms"),
+            Fact::new("but was", "1250ms"),
+            Fact::new("delta", "+350ms"),
+        ]);
+        assert_that!(check_that!(Duration::from_micros(1500)).is_greater_than(ms(2))).facts_are(
+            vec![
+                Fact::new("expected", "more than 2000µs"),
+                Fact::new("but was", "1500µs"),
+                Fact::new("delta", "-500µs"),
+            ],
+        );
+        assert_that!(check_that!(ms(2))
+            .with_formatter(|d| format!("{}", d.as_millis()))
+            .is_less_than(ms(1)))
+        .facts_are(vec![
+            Fact::new("expected", "less than 1"),
+            Fact::new("but was", "2"),
+        ]);
+    }
+
+    #[derive(Debug)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[test]
+    fn is_debug_equal_to() {
+        assert_that!(Point { x: 1, y: 2 }).is_debug_equal_to(Point { x: 1, y: 2 });
+
+        // Failures
+        assert_that!(check_that!(Point { x: 1, y: 2 }).is_debug_equal_to(Point { x: 1, y: 3 }))
+            .facts_are(vec![
+                Fact::new("differs at", ".y (expected: 3, actual: 2)"),
+                Fact::new_splitter(),
+                Fact::new("expected", "Point { x: 1, y: 3 }"),
+                Fact::new("actual", "Point { x: 1, y: 2 }"),
+            ]);
+    }
+
+    #[test]
+    fn satisfies() {
+        assert_that!(Point { x: 1, y: 2 }).satisfies(|p| p.x < p.y);
+
+        // Failures
+        assert_that!(check_that!(Point { x: 3, y: 2 }).satisfies(|p| p.x < p.y)).facts_are(vec![
+            Fact::new_simple_fact("expected to satisfy the predicate"),
+            Fact::new("but was", "Point { x: 3, y: 2 }"),
+        ]);
+    }
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    enum Error {
+        NotFound { id: u32 },
+        Io(String),
+        PermissionDenied,
+    }
+
+    #[test]
+    fn is_variant() {
+        let not_found = Error::NotFound { id: 7 };
+        is_variant!(assert_that!(not_found), Error::NotFound { .. });
+        is_variant!(
+            assert_that!(not_found),
+            Error::NotFound { id: 7 } | Error::Io(_)
+        );
+        let result: Result<(), Error> = Err(Error::PermissionDenied);
+        is_variant!(assert_that!(result), Err(Error::PermissionDenied));
+
+        // Failures
+        let io = Error::Io("closed".to_string());
+        assert_that!(is_variant!(check_that!(io), Error::NotFound { .. })).facts_are(vec![
+            Fact::new("expected to match", "Error::NotFound { .. }"),
+            Fact::new("but was variant", "Io"),
+            Fact::new_splitter(),
+            Fact::new("actual", r#"Io("closed")"#),
+        ]);
+    }
+
+    #[test]
+    fn has_debug_containing() {
+        assert_that!(Point { x: 1, y: 2 }).has_debug_containing("x: 1");
+
+        // Failures
+        assert_that!(check_that!(Point { x: 1, y: 2 }).has_debug_containing("z")).facts_are(vec![
+            Fact::new("expected debug representation to contain", r#""z""#),
+            Fact::new("but was", "Point { x: 1, y: 2 }"),
+        ]);
     }
 }
@@ -12,7 +12,9 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, Fact, Subject,
+};
 
 /// Trait for boolean assertion.
 ///
@@ -22,6 +24,10 @@ use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
 ///
 /// assert_that!(true).is_true();
 /// assert_that!(false).is_false();
+///
+/// let attempts = vec![1, 2];
+/// assert_that!(attempts.len() < 3)
+///     .is_true_with_context(|| vec![Fact::new("attempts", format!("{:?}", attempts))]);
 /// ```
 pub trait BooleanAssertion<R> {
     /// Checks that the subject is equal to `true`.
@@ -31,6 +37,17 @@ pub trait BooleanAssertion<R> {
     /// Checks that the subject is equal to `false`.
     #[track_caller]
     fn is_false(&self) -> R;
+
+    /// Checks that the subject is equal to `true`, like [`BooleanAssertion::is_true`]. On
+    /// failure, the facts returned by `context` are added after the usual ones, so that the state
+    /// behind the condition is shown. `context` is only called on failure.
+    #[track_caller]
+    fn is_true_with_context<F: FnOnce() -> Vec<Fact>>(&self, context: F) -> R;
+
+    /// Checks that the subject is equal to `false`, with extra facts on failure. See
+    /// [`BooleanAssertion::is_true_with_context`].
+    #[track_caller]
+    fn is_false_with_context<F: FnOnce() -> Vec<Fact>>(&self, context: F) -> R;
 }
 
 impl<R> BooleanAssertion<R> for Subject<'_, bool, (), R>
@@ -38,26 +55,45 @@ where
     AssertionResult: AssertionStrategy<R>,
 {
     fn is_true(&self) -> R {
-        if *self.actual() {
-            self.new_result().do_ok()
-        } else {
-            self.new_result()
-                .add_simple_fact("expected true")
-                .add_simple_fact("but actual was false")
-                .do_fail()
-        }
+        pass_if_skipped!(self);
+        self.is_true_with_context(Vec::new)
     }
 
     fn is_false(&self) -> R {
-        if !self.actual() {
-            self.new_result().do_ok()
-        } else {
-            self.new_result()
-                .add_simple_fact("expected false")
-                .add_simple_fact("but actual was true")
-                .do_fail()
+        pass_if_skipped!(self);
+        self.is_false_with_context(Vec::new)
+    }
+
+    fn is_true_with_context<F: FnOnce() -> Vec<Fact>>(&self, context: F) -> R {
+        pass_if_skipped!(self);
+        check_bool(self.new_result(), *self.actual(), true, context)
+    }
+
+    fn is_false_with_context<F: FnOnce() -> Vec<Fact>>(&self, context: F) -> R {
+        pass_if_skipped!(self);
+        check_bool(self.new_result(), *self.actual(), false, context)
+    }
+}
+
+fn check_bool<F, R>(result: AssertionResult, actual: bool, expected: bool, context: F) -> R
+where
+    F: FnOnce() -> Vec<Fact>,
+    AssertionResult: AssertionStrategy<R>,
+{
+    if actual == expected {
+        return result.do_ok();
+    }
+    let mut result = result
+        .add_simple_fact(format!("expected {}", expected))
+        .add_simple_fact(format!("but actual was {}", actual));
+    let context = context();
+    if !context.is_empty() {
+        result = result.add_splitter();
+        for fact in context {
+            result = result.push_fact(fact);
         }
     }
+    result.do_fail()
 }
 
 #[cfg(test)]
@@ -85,4 +121,24 @@ mod tests {
             Fact::new_simple_fact("but actual was true"),
         ])
     }
+
+    #[test]
+    fn with_context() {
+        assert_that!(true).is_true_with_context(|| panic!("context of a passing assertion"));
+        assert_that!(false).is_false_with_context(|| panic!("context of a passing assertion"));
+
+        let state = "idle";
+        assert_that!(check_that!(state == "running")
+            .is_true_with_context(|| vec![Fact::new("state", state)]))
+        .facts_are(vec![
+            Fact::new_simple_fact("expected true"),
+            Fact::new_simple_fact("but actual was false"),
+            Fact::new_splitter(),
+            Fact::new("state", "idle"),
+        ]);
+        assert_that!(check_that!(true).is_false_with_context(Vec::new)).facts_are(vec![
+            Fact::new_simple_fact("expected false"),
+            Fact::new_simple_fact("but actual was true"),
+        ]);
+    }
 }
@@ -0,0 +1,356 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::path::Path;
+
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+use crate::golden::check_matches_contents_of;
+#[cfg(feature = "bytes")]
+use crate::SequenceLike;
+
+/// Trait for byte sequence assertion. Implemented for every subject viewable as `&[u8]`, such as
+/// `Vec<u8>`, `[u8; N]`, `bytes::Bytes` and `bytes::BytesMut`.
+///
+/// Bytes are rendered in hex in failure messages.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let packet: Vec<u8> = vec![0xca, 0xfe, 0x00, 0x01];
+/// assert_that!(packet).starts_with_bytes([0xca, 0xfe]);
+/// assert_that!(packet).contains_bytes([0xfe, 0x00]);
+/// assert_that!(packet).is_equal_to_bytes(b"\xca\xfe\x00\x01");
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// let packet: Vec<u8> = vec![0xca, 0xfe, 0x00, 0x01];
+/// assert_that!(packet).is_equal_to_bytes([0xca, 0xfe, 0x00, 0x02]);
+/// // expected  : ca fe 00 02
+/// // actual    : ca fe 00 01
+/// // differs at: byte 3
+/// ```
+pub trait BytesAssertion<R> {
+    /// Checks that the subject is equal to `expected`, reporting the first differing byte.
+    #[track_caller]
+    fn is_equal_to_bytes<E: AsRef<[u8]>>(&self, expected: E) -> R;
+
+    /// Checks that the subject contains `expected` as a contiguous subsequence.
+    #[track_caller]
+    fn contains_bytes<E: AsRef<[u8]>>(&self, expected: E) -> R;
+
+    /// Checks that the subject starts with `expected`.
+    #[track_caller]
+    fn starts_with_bytes<E: AsRef<[u8]>>(&self, expected: E) -> R;
+
+    /// Checks that the subject ends with `expected`.
+    #[track_caller]
+    fn ends_with_bytes<E: AsRef<[u8]>>(&self, expected: E) -> R;
+
+    /// Checks that the subject is equal to the contents of the golden file at `path`, with a
+    /// unified diff of the lines in the failure message when both are text. Relative paths are
+    /// relative to the package root when run by `cargo test`.
+    ///
+    /// When the environment variable `ASSERTOR_UPDATE_GOLDEN` is `1`, the file is written with
+    /// the subject instead, to bless a deliberate change. When it is `new`, mismatching contents
+    /// are written to a pending golden file, with a `.new` extension, to be reviewed with
+    /// `cargo assertor-review`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// # let dir = std::env::temp_dir().join("assertor_matches_contents_of_doc");
+    /// # std::fs::create_dir_all(&dir).unwrap();
+    /// # std::fs::write(dir.join("report.txt"), "total: 3\nfailed: 0\n").unwrap();
+    /// # std::env::set_current_dir(&dir).unwrap();
+    ///
+    /// let report = "total: 3\nfailed: 0\n";
+    /// assert_that!(report).matches_contents_of("report.txt");
+    /// demo!(
+    ///     assert_that!("total: 3\nfailed: 1\n").matches_contents_of("report.txt"),
+    ///     r#"
+    ///     expected contents of                      : report.txt
+    ///     but differed at                           : line 2 (byte 17)
+    ///     to accept the new contents, run tests with: ASSERTOR_UPDATE_GOLDEN=1
+    ///     ---
+    ///     diff (-expected +actual)                  : @@ -1,2 +1,2 @@
+    ///      total: 3
+    ///     -failed: 0
+    ///     +failed: 1
+    ///     "#
+    /// );
+    /// ```
+    #[track_caller]
+    fn matches_contents_of<P: AsRef<Path>>(&self, path: P) -> R;
+}
+
+impl<S, R> BytesAssertion<R> for Subject<'_, S, (), R>
+where
+    S: AsRef<[u8]>,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn is_equal_to_bytes<E: AsRef<[u8]>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        let (actual, expected) = (self.actual().as_ref(), expected.as_ref());
+        if actual == expected {
+            return self.new_result().do_ok();
+        }
+        let differs_at = actual
+            .iter()
+            .zip(expected)
+            .position(|(a, e)| a != e)
+            .unwrap_or_else(|| actual.len().min(expected.len()));
+        self.new_result()
+            .add_fact(FactKey::Expected, hex(expected))
+            .add_fact(FactKey::Actual, hex(actual))
+            .add_fact(FactKey::DiffersAt, format!("byte {}", differs_at))
+            .do_fail()
+    }
+
+    fn contains_bytes<E: AsRef<[u8]>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        let (actual, expected) = (self.actual().as_ref(), expected.as_ref());
+        check_bytes(
+            self.new_result(),
+            expected.is_empty() || actual.windows(expected.len()).any(|w| w == expected),
+            "expected bytes that contain",
+            expected,
+            actual,
+        )
+    }
+
+    fn starts_with_bytes<E: AsRef<[u8]>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        let (actual, expected) = (self.actual().as_ref(), expected.as_ref());
+        check_bytes(
+            self.new_result(),
+            actual.starts_with(expected),
+            "expected bytes that start with",
+            expected,
+            actual,
+        )
+    }
+
+    fn ends_with_bytes<E: AsRef<[u8]>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        let (actual, expected) = (self.actual().as_ref(), expected.as_ref());
+        check_bytes(
+            self.new_result(),
+            actual.ends_with(expected),
+            "expected bytes that end with",
+            expected,
+            actual,
+        )
+    }
+
+    fn matches_contents_of<P: AsRef<Path>>(&self, path: P) -> R {
+        pass_if_skipped!(self);
+        check_matches_contents_of(self.new_result(), path.as_ref(), self.actual().as_ref())
+    }
+}
+
+fn check_bytes<R>(
+    assertion_result: AssertionResult,
+    ok: bool,
+    expectation: &str,
+    expected: &[u8],
+    actual: &[u8],
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    if ok {
+        assertion_result.do_ok()
+    } else {
+        assertion_result
+            .add_fact(expectation, hex(expected))
+            .add_fact(FactKey::ButWas, hex(actual))
+            .do_fail()
+    }
+}
+
+/// `bytes::Bytes` is also checked like a `Vec<u8>`, e.g. with
+/// [`VecAssertion::has_length`](crate::VecAssertion::has_length).
+#[cfg(feature = "bytes")]
+impl SequenceLike<u8> for bytes::Bytes {
+    type It<'a> = std::slice::Iter<'a, u8>;
+
+    fn elements_iter<'a>(&'a self) -> Self::It<'a> {
+        self.iter()
+    }
+}
+
+/// `bytes::BytesMut` is also checked like a `Vec<u8>`.
+#[cfg(feature = "bytes")]
+impl SequenceLike<u8> for bytes::BytesMut {
+    type It<'a> = std::slice::Iter<'a, u8>;
+
+    fn elements_iter<'a>(&'a self) -> Self::It<'a> {
+        self.iter()
+    }
+}
+
+/// Renders bytes as space separated lowercase hex, e.g. `ca fe 00`.
+fn hex(bytes: &[u8]) -> String {
+    if bytes.is_empty() {
+        return "<empty>".to_string();
+    }
+    bytes
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::EqualityAssertion;
+
+    use super::*;
+
+    #[test]
+    fn is_equal_to_bytes() {
+        assert_that!(vec![1_u8, 2, 3]).is_equal_to_bytes([1, 2, 3]);
+        assert_that!([0_u8; 0]).is_equal_to_bytes(b"");
+
+        // Failures
+        assert_that!(check_that!(vec![0xca_u8, 0xfe, 0x00]).is_equal_to_bytes([0xca, 0xff, 0x00]))
+            .facts_are(vec![
+                Fact::new("expected", "ca ff 00"),
+                Fact::new("actual", "ca fe 00"),
+                Fact::new("differs at", "byte 1"),
+            ]);
+        assert_that!(check_that!(vec![0xca_u8]).is_equal_to_bytes([0xca, 0xfe])).facts_are(vec![
+            Fact::new("expected", "ca fe"),
+            Fact::new("actual", "ca"),
+            Fact::new("differs at", "byte 1"),
+        ]);
+        assert_that!(check_that!(Vec::<u8>::new()).is_equal_to_bytes([0x01])).facts_are(vec![
+            Fact::new("expected", "01"),
+            Fact::new("actual", "<empty>"),
+            Fact::new("differs at", "byte 0"),
+        ]);
+    }
+
+    #[test]
+    fn contains_bytes() {
+        assert_that!(vec![1_u8, 2, 3]).contains_bytes([2, 3]);
+        assert_that!(vec![1_u8, 2, 3]).contains_bytes([]);
+        assert_that!("GET / HTTP/1.1").contains_bytes(b"HTTP");
+
+        // Failures
+        assert_that!(check_that!(vec![1_u8, 2, 3]).contains_bytes([3, 2])).facts_are(vec![
+            Fact::new("expected bytes that contain", "03 02"),
+            Fact::new("but was", "01 02 03"),
+        ]);
+        assert_that!(check_that!(vec![1_u8]).contains_bytes([1, 1])).facts_are(vec![
+            Fact::new("expected bytes that contain", "01 01"),
+            Fact::new("but was", "01"),
+        ]);
+    }
+
+    #[test]
+    fn starts_and_ends_with_bytes() {
+        assert_that!(vec![1_u8, 2, 3]).starts_with_bytes([1, 2]);
+        assert_that!(vec![1_u8, 2, 3]).ends_with_bytes([2, 3]);
+
+        // Failures
+        assert_that!(check_that!(vec![1_u8, 2, 3]).starts_with_bytes([2])).facts_are(vec![
+            Fact::new("expected bytes that start with", "02"),
+            Fact::new("but was", "01 02 03"),
+        ]);
+        assert_that!(check_that!(vec![1_u8, 2, 3]).ends_with_bytes([0xff])).facts_are(vec![
+            Fact::new("expected bytes that end with", "ff"),
+            Fact::new("but was", "01 02 03"),
+        ]);
+    }
+
+    #[cfg(feature = "bytes")]
+    #[test]
+    fn bytes_subjects() {
+        use crate::VecAssertion;
+        use bytes::{BufMut, Bytes, BytesMut};
+
+        let packet = Bytes::from_static(b"\xca\xfe\x00\x01");
+        assert_that!(packet).is_equal_to_bytes([0xca, 0xfe, 0x00, 0x01]);
+        assert_that!(packet).starts_with_bytes([0xca, 0xfe]);
+        assert_that!(packet).ends_with_bytes([0x01]);
+        assert_that!(packet).contains_bytes([0xfe, 0x00]);
+        assert_that!(packet).has_length(4);
+        assert_that!(packet).contains(0xfe);
+
+        let mut buffer = BytesMut::new();
+        buffer.put_slice(b"GET / HTTP/1.1");
+        assert_that!(buffer).contains_bytes(b"HTTP");
+        assert_that!(buffer).starts_with_bytes(b"GET");
+        assert_that!(buffer).has_length(14);
+
+        // Failures
+        assert_that!(check_that!(packet).is_equal_to_bytes([0xca, 0xfe, 0x00, 0x02])).facts_are(
+            vec![
+                Fact::new("expected", "ca fe 00 02"),
+                Fact::new("actual", "ca fe 00 01"),
+                Fact::new("differs at", "byte 3"),
+            ],
+        );
+        assert_that!(check_that!(buffer).ends_with_bytes(b"2")).facts_are(vec![
+            Fact::new("expected bytes that end with", "32"),
+            Fact::new("but was", "47 45 54 20 2f 20 48 54 54 50 2f 31 2e 31"),
+        ]);
+    }
+
+    #[test]
+    fn matches_contents_of() {
+        if update_golden() || write_pending_golden() {
+            return;
+        }
+        let path = std::env::temp_dir().join("assertor_matches_contents_of.txt");
+        std::fs::write(&path, "a\nb\n").unwrap();
+        assert_that!("a\nb\n").matches_contents_of(&path);
+        assert_that!(b"a\nb\n".to_vec()).matches_contents_of(&path);
+
+        // Failures
+        assert_that!(check_that!("a\nc\n").matches_contents_of(&path)).facts_are(vec![
+            Fact::new("expected contents of", path.display().to_string()),
+            Fact::new("but differed at", "line 2 (byte 2)"),
+            Fact::new(
+                "to accept the new contents, run tests with",
+                "ASSERTOR_UPDATE_GOLDEN=1",
+            ),
+            Fact::new_splitter(),
+            Fact::new("diff (-expected +actual)", "@@ -1,2 +1,2 @@\n a\n-b\n+c"),
+        ]);
+        assert_that!(check_that!([0xff_u8, 0x00]).matches_contents_of(&path)).facts_are(vec![
+            Fact::new("expected contents of", path.display().to_string()),
+            Fact::new("but differed at", "line 1 (byte 0)"),
+            Fact::new(
+                "to accept the new contents, run tests with",
+                "ASSERTOR_UPDATE_GOLDEN=1",
+            ),
+            Fact::new_splitter(),
+            Fact::new("expected length", "4 bytes"),
+            Fact::new("actual length", "2 bytes"),
+        ]);
+        std::fs::remove_file(&path).unwrap();
+
+        let missing = std::env::temp_dir().join("assertor_missing_contents.txt");
+        assert_that!(check_that!("a").matches_contents_of(&missing))
+            .fact_value_for_key("to create it, run tests with")
+            .is_equal_to("ASSERTOR_UPDATE_GOLDEN=1".to_string());
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright 2021 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::time::{Duration, Instant};
+
+use crate::base::{pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, Subject};
+
+/// Trait for assertions on closures computing a value, e.g. the state of a background task.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// use std::time::{Duration, Instant};
+///
+/// let start = Instant::now();
+/// assert_that_code!(|| start.elapsed())
+///     .satisfies_within(Duration::from_secs(1), |elapsed| *elapsed >= Duration::from_millis(20));
+/// ```
+/// ```should_panic
+/// use assertor::*;
+/// use std::time::Duration;
+///
+/// assert_that_code!(|| "starting").satisfies_within(Duration::from_millis(50), |s| *s == "ready");
+/// // expected to satisfy the predicate within: 50ms
+/// // but did not after                       : 6 attempts
+/// // observed values                         : [ "starting" (6 times) ]
+/// ```
+pub trait CodeAssertion<T, R> {
+    /// Checks that a value computed by the subject satisfies `predicate` before `timeout` elapses.
+    /// The subject is called repeatedly, every 10 milliseconds at most, until the predicate holds.
+    ///
+    /// On timeout, the observed values are listed in order, with consecutive duplicates merged
+    /// and only the latest values kept.
+    #[track_caller]
+    fn satisfies_within<P: Fn(&T) -> bool>(&self, timeout: Duration, predicate: P) -> R
+    where
+        T: Debug;
+}
+
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+const MAX_OBSERVED_VALUES: usize = 10;
+
+impl<F, T, R> CodeAssertion<T, R> for Subject<'_, F, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+    F: Fn() -> T,
+{
+    fn satisfies_within<P: Fn(&T) -> bool>(&self, timeout: Duration, predicate: P) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        let deadline = Instant::now() + timeout;
+        let mut observed = Observations::default();
+        loop {
+            let value = (self.actual())();
+            if predicate(&value) {
+                return self.new_result().do_ok();
+            }
+            observed.push(format!("{:?}", value));
+            let now = Instant::now();
+            if now >= deadline {
+                break;
+            }
+            std::thread::sleep(POLL_INTERVAL.min(deadline - now));
+        }
+        self.new_result()
+            .add_fact(
+                "expected to satisfy the predicate within",
+                format!("{:?}", timeout),
+            )
+            .add_fact("but did not after", pluralize_attempts(observed.attempts))
+            .add_formatted_values_fact("observed values", observed.describe())
+            .do_fail()
+    }
+}
+
+/// Values observed while polling, with consecutive duplicates merged into counts.
+#[derive(Default)]
+struct Observations {
+    values: VecDeque<(String, usize)>,
+    dropped: usize,
+    attempts: usize,
+}
+
+impl Observations {
+    fn push(&mut self, value: String) {
+        self.attempts += 1;
+        match self.values.back_mut() {
+            Some((last, count)) if *last == value => *count += 1,
+            _ => {
+                if self.values.len() == MAX_OBSERVED_VALUES {
+                    self.values.pop_front();
+                    self.dropped += 1;
+                }
+                self.values.push_back((value, 1));
+            }
+        }
+    }
+
+    fn describe(&self) -> Vec<Described> {
+        let earlier = (self.dropped > 0).then(|| format!("... ({} earlier values)", self.dropped));
+        earlier
+            .into_iter()
+            .chain(self.values.iter().map(|(value, count)| match count {
+                1 => value.clone(),
+                _ => format!("{} ({} times)", value, count),
+            }))
+            .map(Described)
+            .collect()
+    }
+}
+
+/// Shows an already formatted value as is in `Debug` facts.
+struct Described(String);
+
+impl Debug for Described {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+fn pluralize_attempts(attempts: usize) -> String {
+    match attempts {
+        1 => "1 attempt".to_string(),
+        _ => format!("{} attempts", attempts),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use crate::testing::*;
+    use crate::{assert_that, assert_that_code, check_that, EqualityAssertion, Fact};
+
+    use super::*;
+
+    #[test]
+    fn satisfies_within() {
+        let calls = Cell::new(0);
+        assert_that_code!(|| {
+            calls.set(calls.get() + 1);
+            calls.get()
+        })
+        .satisfies_within(Duration::from_secs(10), |n| *n == 3);
+        assert_that!(calls.get()).is_equal_to(3);
+
+        // Failures
+        let result = check_that!(|| 1).satisfies_within(Duration::ZERO, |n| *n == 2);
+        assert_that!(result).facts_are(vec![
+            Fact::new("expected to satisfy the predicate within", "0ns"),
+            Fact::new("but did not after", "1 attempt"),
+            Fact::new_multi_value_fact("observed values", vec!["1"]),
+        ]);
+    }
+
+    #[test]
+    fn observed_values() {
+        let mut observed = Observations::default();
+        for value in [0, 0, 1, 2, 2, 2] {
+            observed.push(value.to_string());
+        }
+        assert_that!(format!("{:?}", observed.describe()))
+            .is_equal_to("[0 (2 times), 1, 2 (3 times)]".to_string());
+
+        for value in 3..15 {
+            observed.push(value.to_string());
+        }
+        let described = observed.describe();
+        assert_that!(described.len()).is_equal_to(MAX_OBSERVED_VALUES + 1);
+        assert_that!(format!("{:?}", described[0]))
+            .is_equal_to("... (5 earlier values)".to_string());
+        assert_that!(format!("{:?}", described[MAX_OBSERVED_VALUES])).is_equal_to("14".to_string());
+        assert_that!(observed.attempts).is_equal_to(18);
+    }
+}
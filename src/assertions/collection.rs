@@ -0,0 +1,208 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+
+use crate::assertions::iterator::{
+    check_has_length, check_is_empty, check_is_not_empty, check_len_bound, LengthBound,
+};
+use crate::base::{pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, Subject};
+
+/// Marker for user-defined collections whose references can be iterated, enabling
+/// [`CollectionAssertion`] for them.
+///
+/// Opting in is required, as standard collections already have assertions with the same method
+/// names (e.g. [`VecAssertion`](crate::VecAssertion)), which a blanket implementation would make
+/// ambiguous.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// struct Bag(Vec<u32>);
+///
+/// impl<'a> IntoIterator for &'a Bag {
+///     type Item = &'a u32;
+///     type IntoIter = std::slice::Iter<'a, u32>;
+///     fn into_iter(self) -> Self::IntoIter {
+///         self.0.iter()
+///     }
+/// }
+///
+/// impl CollectionLike for Bag {}
+///
+/// assert_that!(Bag(vec![1, 2])).has_length(2);
+/// assert_that!(Bag(vec![])).is_empty();
+/// ```
+pub trait CollectionLike {}
+
+/// Trait for assertions on any [`CollectionLike`] collection.
+pub trait CollectionAssertion<R> {
+    /// Checks that the subject is empty.
+    #[track_caller]
+    fn is_empty(&self) -> R;
+
+    /// Checks that the subject is not empty.
+    #[track_caller]
+    fn is_not_empty(&self) -> R;
+
+    /// Checks that the subject has the given length.
+    #[track_caller]
+    fn has_length(&self, length: usize) -> R;
+
+    /// Checks that the subject has at least `length` elements.
+    #[track_caller]
+    fn has_length_at_least(&self, length: usize) -> R;
+
+    /// Checks that the subject has at most `length` elements.
+    #[track_caller]
+    fn has_length_at_most(&self, length: usize) -> R;
+}
+
+impl<C, R> CollectionAssertion<R> for Subject<'_, C, (), R>
+where
+    C: CollectionLike,
+    for<'b> &'b C: IntoIterator,
+    for<'b> <&'b C as IntoIterator>::Item: Debug + Clone,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn is_empty(&self) -> R {
+        pass_if_skipped!(self);
+        check_is_empty(self.new_result(), elements(self.actual()))
+    }
+
+    fn is_not_empty(&self) -> R {
+        pass_if_skipped!(self);
+        check_is_not_empty(self.new_result(), elements(self.actual()))
+    }
+
+    fn has_length(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_has_length(
+            self.new_result(),
+            elements(self.actual()),
+            self.description_or_expr(),
+            length,
+        )
+    }
+
+    fn has_length_at_least(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().into_iter().count(),
+            format!("{}.size()", self.description_or_expr()),
+            LengthBound::AtLeast(length),
+        )
+    }
+
+    fn has_length_at_most(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().into_iter().count(),
+            format!("{}.size()", self.description_or_expr()),
+            LengthBound::AtMost(length),
+        )
+    }
+}
+
+/// Collects the elements, so that they can be iterated more than once.
+fn elements<'b, C>(collection: &'b C) -> std::vec::IntoIter<<&'b C as IntoIterator>::Item>
+where
+    &'b C: IntoIterator,
+{
+    collection.into_iter().collect::<Vec<_>>().into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use crate::testing::*;
+
+    use super::*;
+
+    #[derive(Debug)]
+    struct Ring(VecDeque<&'static str>);
+
+    impl<'a> IntoIterator for &'a Ring {
+        type Item = &'a &'static str;
+        type IntoIter = std::collections::vec_deque::Iter<'a, &'static str>;
+
+        fn into_iter(self) -> Self::IntoIter {
+            self.0.iter()
+        }
+    }
+
+    impl CollectionLike for Ring {}
+
+    fn ring(elements: &[&'static str]) -> Ring {
+        Ring(elements.iter().copied().collect())
+    }
+
+    #[test]
+    fn is_empty() {
+        assert_that!(ring(&[])).is_empty();
+
+        // Failures
+        assert_that!(check_that!(ring(&["a"])).is_empty()).facts_are(vec![
+            Fact::new_simple_fact("expected to be empty"),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("actual", vec![r#""a""#]),
+        ]);
+    }
+
+    #[test]
+    fn is_not_empty() {
+        assert_that!(ring(&["a"])).is_not_empty();
+
+        // Failures
+        assert_that!(check_that!(ring(&[])).is_not_empty()).facts_are(vec![
+            Fact::new_simple_fact("expected to be non-empty"),
+            Fact::new_splitter(),
+            Fact::new("actual", "[]"),
+        ]);
+    }
+
+    #[test]
+    fn has_length() {
+        assert_that!(ring(&["a", "b"])).has_length(2);
+
+        // Failures
+        assert_that!(check_that!(ring(&["a", "b"])).has_length(3)).facts_are(vec![
+            Fact::new("value of", r#"ring(&["a", "b"]).size()"#),
+            Fact::new("expected", "3"),
+            Fact::new("actual", "2"),
+        ]);
+    }
+
+    #[test]
+    fn has_length_bounds() {
+        assert_that!(ring(&["a", "b"])).has_length_at_least(2);
+        assert_that!(ring(&["a", "b"])).has_length_at_most(2);
+
+        // Failures
+        assert_that!(check_that!(ring(&["a", "b"])).has_length_at_least(3)).facts_are(vec![
+            Fact::new("value of", r#"ring(&["a", "b"]).size()"#),
+            Fact::new("expected", "at least 3"),
+            Fact::new("actual", "2"),
+        ]);
+        assert_that!(check_that!(ring(&["a", "b"])).has_length_at_most(1)).facts_are(vec![
+            Fact::new("value of", r#"ring(&["a", "b"]).size()"#),
+            Fact::new("expected", "at most 1"),
+            Fact::new("actual", "2"),
+        ]);
+    }
+}
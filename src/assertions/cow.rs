@@ -14,7 +14,7 @@
 
 use std::borrow::Cow;
 
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+use crate::base::{pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, Subject};
 
 /// Trait for Cow assertion.
 ///
@@ -27,6 +27,16 @@ use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
 /// assert_that!(Cow::<str>::Owned("foobar".to_string())).is_owned();
 /// assert_that!(Cow::<str>::Owned("foobar".to_string())).deref().is_same_string_to("foobar");
 /// ```
+///
+/// Subjects of `Cow<[T]>` also implement [`crate::VecAssertion`].
+/// ```
+/// use std::borrow::Cow;
+/// use assertor::*;
+///
+/// let ids: Cow<[u32]> = Cow::Borrowed(&[1, 2, 3]);
+/// assert_that!(ids).is_borrowed();
+/// assert_that!(ids).contains_exactly_in_order(vec![1, 2, 3]);
+/// ```
 pub trait CowAssertion<T: ?Sized, Y, R>
 {
     /// Checks that the subject is [`Cow::Borrowed(_)`](`std::borrow::Cow::Borrowed`).
@@ -60,6 +70,7 @@ where
     AssertionResult: AssertionStrategy<R>,
 {
     fn is_borrowed(&self) -> R {
+        pass_if_skipped!(self);
         if matches!(self.actual(), Cow::Borrowed(_)) {
             self.new_result().do_ok()
         } else {
@@ -68,6 +79,7 @@ where
     }
 
     fn is_owned(&self) -> R {
+        pass_if_skipped!(self);
         if matches!(self.actual(), Cow::Owned(_)) {
             self.new_result().do_ok()
         } else {
@@ -0,0 +1,99 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::ops::Deref;
+
+use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+
+/// Trait for subjects behind smart pointers, such as [`Box`], [`Rc`](std::rc::Rc) and
+/// [`Arc`](std::sync::Arc).
+///
+/// Vectors, sets and maps behind smart pointers already have the assertions of the collections
+/// themselves, e.g. `assert_that!(Arc::new(vec![1])).contains(1)`. [`DerefAssertion::derefed`]
+/// gives access to the assertions of any other target.
+///
+/// # Example
+/// ```
+/// use std::rc::Rc;
+/// use assertor::*;
+///
+/// let name: Box<String> = Box::new("assertor".to_string());
+/// assert_that!(name).derefed().starts_with("assert");
+/// demo!(
+///     assert_that!(Rc::new(Some(1))).derefed().is_none(),
+///     r#"
+///     value of: *Rc::new(Some(1))
+///     expected: None
+///     actual  : Some(1)
+///     "#
+/// );
+/// ```
+pub trait DerefAssertion<S: Deref, R> {
+    /// Returns a new subject which is the target of the subject, described as `*<subject>`.
+    fn derefed(&self) -> Subject<'_, S::Target, (), R>
+    where
+        S::Target: Sized;
+}
+
+impl<S: Deref, R> DerefAssertion<S, R> for Subject<'_, S, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn derefed(&self) -> Subject<'_, S::Target, (), R>
+    where
+        S::Target: Sized,
+    {
+        self.new_subject(
+            self.actual().deref(),
+            Some(format!("*{}", self.description_or_expr())),
+            (),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::testing::*;
+    use crate::{EqualityAssertion, OptionAssertion, StringAssertion};
+
+    use super::*;
+
+    #[test]
+    fn derefed() {
+        assert_that!(Box::new("a".to_string()))
+            .derefed()
+            .contains("a");
+        assert_that!(Arc::new(Box::new(3)))
+            .derefed()
+            .derefed()
+            .is_equal_to(3);
+
+        // Failures
+        assert_that!(check_that!(Box::new("a".to_string()))
+            .derefed()
+            .contains("b"))
+        .facts_are(vec![
+            Fact::new("value of", "*Box::new(\"a\".to_string())"),
+            Fact::new("expected a string that contains", "b"),
+            Fact::new("but was", "a"),
+        ]);
+        assert_that!(check_that!(Arc::new(Some(1))).derefed().is_none()).facts_are(vec![
+            Fact::new("value of", "*Arc::new(Some(1))"),
+            Fact::new("expected", "None"),
+            Fact::new("actual", "Some(1)"),
+        ]);
+    }
+}
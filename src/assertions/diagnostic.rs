@@ -0,0 +1,414 @@
+// Copyright 2021 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error;
+
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+
+/// Errors carrying diagnostic metadata, whose parts can be inspected by
+/// [`DiagnosticAssertion`].
+///
+/// Mirrors the metadata of `miette::Diagnostic`. Implement this trait for diagnostic errors by
+/// forwarding to their `Diagnostic` implementation:
+///
+/// ```ignore
+/// impl assertor::DiagnosticLike for ConfigError {
+///     fn code(&self) -> Option<String> {
+///         miette::Diagnostic::code(self).map(|code| code.to_string())
+///     }
+///     fn severity(&self) -> Option<String> {
+///         miette::Diagnostic::severity(self).map(|severity| format!("{:?}", severity))
+///     }
+///     fn help(&self) -> Option<String> {
+///         miette::Diagnostic::help(self).map(|help| help.to_string())
+///     }
+/// }
+/// ```
+pub trait DiagnosticLike {
+    /// Returns the unique code of the diagnostic, e.g. `config::missing_key`.
+    fn code(&self) -> Option<String>;
+
+    /// Returns the severity of the diagnostic, e.g. `Error`, `Warning` or `Advice`.
+    fn severity(&self) -> Option<String>;
+
+    /// Returns the help text of the diagnostic.
+    fn help(&self) -> Option<String>;
+}
+
+/// Trait for assertions on the metadata of diagnostic errors.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// #[derive(Debug)]
+/// struct MissingKey;
+///
+/// impl DiagnosticLike for MissingKey {
+///     fn code(&self) -> Option<String> {
+///         Some("config::missing_key".to_string())
+///     }
+///     fn severity(&self) -> Option<String> {
+///         Some("Error".to_string())
+///     }
+///     fn help(&self) -> Option<String> {
+///         Some("add `port` to the config file".to_string())
+///     }
+/// }
+///
+/// let result: Result<(), MissingKey> = Err(MissingKey);
+/// assert_that!(result).err().has_code("config::missing_key");
+/// assert_that!(result).err().has_severity("error");
+/// assert_that!(result).err().has_help_containing("port");
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// struct MissingKey;
+///
+/// impl DiagnosticLike for MissingKey {
+///     fn code(&self) -> Option<String> {
+///         Some("config::missing_key".to_string())
+///     }
+///     fn severity(&self) -> Option<String> {
+///         None
+///     }
+///     fn help(&self) -> Option<String> {
+///         None
+///     }
+/// }
+///
+/// assert_that!(MissingKey).has_code("config::invalid_value");
+/// // expected code: config::invalid_value
+/// // but was      : config::missing_key
+/// // ---
+/// // code         : config::missing_key
+/// // severity     : <none>
+/// // help         : <none>
+/// ```
+pub trait DiagnosticAssertion<R> {
+    /// Checks that the subject has the diagnostic code `code`.
+    #[track_caller]
+    fn has_code<C: AsRef<str>>(&self, code: C) -> R;
+
+    /// Checks that the subject has the severity `severity`, ignoring case.
+    #[track_caller]
+    fn has_severity<V: AsRef<str>>(&self, severity: V) -> R;
+
+    /// Checks that the help text of the subject contains `expected`.
+    #[track_caller]
+    fn has_help_containing<E: AsRef<str>>(&self, expected: E) -> R;
+}
+
+impl<S, R> DiagnosticAssertion<R> for Subject<'_, S, (), R>
+where
+    S: DiagnosticLike,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn has_code<C: AsRef<str>>(&self, code: C) -> R {
+        pass_if_skipped!(self);
+        let actual = self.actual().code();
+        if actual.as_deref() == Some(code.as_ref()) {
+            self.new_result().do_ok()
+        } else {
+            fail(self, "expected code", code.as_ref(), actual)
+        }
+    }
+
+    fn has_severity<V: AsRef<str>>(&self, severity: V) -> R {
+        pass_if_skipped!(self);
+        let actual = self.actual().severity();
+        match &actual {
+            Some(actual) if actual.eq_ignore_ascii_case(severity.as_ref()) => {
+                self.new_result().do_ok()
+            }
+            _ => fail(self, "expected severity", severity.as_ref(), actual),
+        }
+    }
+
+    fn has_help_containing<E: AsRef<str>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        let actual = self.actual().help();
+        match &actual {
+            Some(help) if help.contains(expected.as_ref()) => self.new_result().do_ok(),
+            _ => fail(self, "expected help containing", expected.as_ref(), actual),
+        }
+    }
+}
+
+fn fail<S: DiagnosticLike, R>(
+    subject: &Subject<S, (), R>,
+    key: &str,
+    expected: &str,
+    actual: Option<String>,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    let diagnostic = subject.actual();
+    subject
+        .new_result()
+        .add_fact(key, expected)
+        .add_fact(FactKey::ButWas, or_none(actual))
+        .add_splitter()
+        .add_fact("code", or_none(diagnostic.code()))
+        .add_fact("severity", or_none(diagnostic.severity()))
+        .add_fact("help", or_none(diagnostic.help()))
+        .do_fail()
+}
+
+fn or_none(part: Option<String>) -> String {
+    part.unwrap_or_else(|| "<none>".to_string())
+}
+
+/// Trait for assertions on the chain of sources of errors, e.g. errors deriving
+/// `thiserror::Error` with `#[source]` or `#[from]` fields.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// use std::fmt;
+///
+/// #[derive(Debug)]
+/// struct ReadError(std::io::Error);
+///
+/// impl fmt::Display for ReadError {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         f.write_str("failed to read config")
+///     }
+/// }
+///
+/// impl std::error::Error for ReadError {
+///     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+///         Some(&self.0)
+///     }
+/// }
+///
+/// let error = ReadError(std::io::Error::new(std::io::ErrorKind::NotFound, "no such file"));
+/// assert_that!(error).has_source_containing("no such file");
+/// assert_that!(error)
+///     .source_chain()
+///     .contains_exactly_in_order(["failed to read config", "no such file"].map(String::from));
+/// ```
+pub trait ErrorSourceAssertion<'a, R> {
+    /// Returns a new subject which is the messages of the subject and its sources, outermost
+    /// first.
+    #[track_caller]
+    fn source_chain(&self) -> Subject<'a, Vec<String>, (), R>;
+
+    /// Checks that the message of one of the sources of the subject, excluding the subject
+    /// itself, contains `expected`.
+    #[track_caller]
+    fn has_source_containing<E: AsRef<str>>(&self, expected: E) -> R;
+}
+
+impl<'a, S, R> ErrorSourceAssertion<'a, R> for Subject<'a, S, (), R>
+where
+    S: Error,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn source_chain(&self) -> Subject<'a, Vec<String>, (), R> {
+        self.new_owned_subject(
+            messages(self.actual()),
+            Some(format!("{}.source_chain()", self.description_or_expr())),
+            (),
+        )
+    }
+
+    fn has_source_containing<E: AsRef<str>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        let chain = messages(self.actual());
+        if chain
+            .iter()
+            .skip(1)
+            .any(|message| message.contains(expected.as_ref()))
+        {
+            return self.new_result().do_ok();
+        }
+        let result = self
+            .new_result()
+            .add_fact("expected a source containing", expected.as_ref());
+        match chain.len() {
+            1 => result.add_simple_fact("but the error had no source"),
+            _ => result.add_simple_fact("but no source did"),
+        }
+        .add_splitter()
+        .add_formatted_values_fact("source chain", chain)
+        .do_fail()
+    }
+}
+
+/// Returns the messages of `error` and its sources, outermost first.
+fn messages<E: Error>(error: &E) -> Vec<String> {
+    let mut messages = vec![error.to_string()];
+    let mut source = error.source();
+    while let Some(error) = source {
+        messages.push(error.to_string());
+        source = error.source();
+    }
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt;
+
+    use crate::testing::*;
+    use crate::VecAssertion;
+
+    use super::*;
+
+    struct Diagnostic {
+        code: Option<&'static str>,
+        help: Option<&'static str>,
+    }
+
+    impl DiagnosticLike for Diagnostic {
+        fn code(&self) -> Option<String> {
+            self.code.map(str::to_string)
+        }
+
+        fn severity(&self) -> Option<String> {
+            Some("Warning".to_string())
+        }
+
+        fn help(&self) -> Option<String> {
+            self.help.map(str::to_string)
+        }
+    }
+
+    const MISSING_KEY: Diagnostic = Diagnostic {
+        code: Some("config::missing_key"),
+        help: Some("add `port` to the config file"),
+    };
+
+    #[test]
+    fn has_code() {
+        assert_that!(MISSING_KEY).has_code("config::missing_key");
+
+        // Failures
+        let no_code = Diagnostic {
+            code: None,
+            help: None,
+        };
+        assert_that!(check_that!(no_code).has_code("config::missing_key")).facts_are(vec![
+            Fact::new("expected code", "config::missing_key"),
+            Fact::new("but was", "<none>"),
+            Fact::new_splitter(),
+            Fact::new("code", "<none>"),
+            Fact::new("severity", "Warning"),
+            Fact::new("help", "<none>"),
+        ]);
+    }
+
+    #[test]
+    fn has_severity() {
+        assert_that!(MISSING_KEY).has_severity("Warning");
+        assert_that!(MISSING_KEY).has_severity("warning");
+
+        // Failures
+        assert_that!(check_that!(MISSING_KEY).has_severity("error")).facts_are(vec![
+            Fact::new("expected severity", "error"),
+            Fact::new("but was", "Warning"),
+            Fact::new_splitter(),
+            Fact::new("code", "config::missing_key"),
+            Fact::new("severity", "Warning"),
+            Fact::new("help", "add `port` to the config file"),
+        ]);
+    }
+
+    #[test]
+    fn has_help_containing() {
+        assert_that!(MISSING_KEY).has_help_containing("`port`");
+
+        // Failures
+        assert_that!(check_that!(MISSING_KEY).has_help_containing("host")).facts_are(vec![
+            Fact::new("expected help containing", "host"),
+            Fact::new("but was", "add `port` to the config file"),
+            Fact::new_splitter(),
+            Fact::new("code", "config::missing_key"),
+            Fact::new("severity", "Warning"),
+            Fact::new("help", "add `port` to the config file"),
+        ]);
+    }
+
+    #[derive(Debug)]
+    struct ChainedError {
+        message: &'static str,
+        source: Option<Box<ChainedError>>,
+    }
+
+    impl fmt::Display for ChainedError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.message)
+        }
+    }
+
+    impl Error for ChainedError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.source
+                .as_deref()
+                .map(|source| source as &(dyn Error + 'static))
+        }
+    }
+
+    fn chained(messages: &[&'static str]) -> ChainedError {
+        let (message, sources) = messages.split_first().unwrap();
+        ChainedError {
+            message,
+            source: (!sources.is_empty()).then(|| Box::new(chained(sources))),
+        }
+    }
+
+    #[test]
+    fn source_chain() {
+        let error = chained(&["request failed", "connection reset"]);
+        assert_that!(error)
+            .source_chain()
+            .contains_exactly_in_order(["request failed", "connection reset"].map(String::from));
+        assert_that!(chained(&["request failed"]))
+            .source_chain()
+            .has_length(1);
+    }
+
+    #[test]
+    fn has_source_containing() {
+        let error = chained(&["request failed", "io error", "connection reset"]);
+        assert_that!(error).has_source_containing("reset");
+
+        // Failures
+        assert_that!(check_that!(error).has_source_containing("request")).facts_are(vec![
+            Fact::new("expected a source containing", "request"),
+            Fact::new_simple_fact("but no source did"),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact(
+                "source chain",
+                vec![
+                    r#""request failed""#,
+                    r#""io error""#,
+                    r#""connection reset""#,
+                ],
+            ),
+        ]);
+        let root = chained(&["request failed"]);
+        assert_that!(check_that!(root).has_source_containing("reset")).facts_are(vec![
+            Fact::new("expected a source containing", "reset"),
+            Fact::new_simple_fact("but the error had no source"),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("source chain", vec![r#""request failed""#]),
+        ]);
+    }
+}
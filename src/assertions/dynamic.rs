@@ -0,0 +1,121 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Debug, Formatter};
+
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+
+/// A value seen only through its [`Debug`] representation, such as a trait object. Made by
+/// [`assert_that_dyn`](crate::assert_that_dyn).
+///
+/// As the value can not be compared or inspected otherwise, only [`DynDebugAssertion`] and the
+/// [`Debug`]-based assertions of [`GenericAssertion`](crate::GenericAssertion) apply to it.
+pub struct DynDebug<'a>(&'a dyn Debug);
+
+impl<'a> DynDebug<'a> {
+    /// Wraps `value`.
+    pub fn new(value: &'a dyn Debug) -> Self {
+        DynDebug(value)
+    }
+}
+
+impl Debug for DynDebug<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Trait for assertions on values seen through their [`Debug`] representation.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// trait Shape: std::fmt::Debug {}
+///
+/// #[derive(Debug)]
+/// struct Circle {
+///     radius: u32,
+/// }
+/// impl Shape for Circle {}
+///
+/// let shape: Box<dyn Shape> = Box::new(Circle { radius: 2 });
+/// assert_that_dyn!(shape).has_debug("Circle { radius: 2 }");
+/// assert_that_dyn!(shape).has_debug_containing("radius");
+/// demo!(
+///     assert_that_dyn!(shape).has_debug("Circle { radius: 3 }"),
+///     r#"
+///     expected debug representation: "Circle { radius: 3 }"
+///     but was                      : Circle { radius: 2 }
+///     "#
+/// );
+/// ```
+pub trait DynDebugAssertion<R> {
+    /// Checks that the [`Debug`] representation of the subject is `expected`.
+    #[track_caller]
+    fn has_debug(&self, expected: &str) -> R;
+}
+
+impl<R> DynDebugAssertion<R> for Subject<'_, DynDebug<'_>, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn has_debug(&self, expected: &str) -> R {
+        pass_if_skipped!(self);
+        if format!("{:?}", self.actual()) == expected {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_formatted_fact("expected debug representation", expected)
+                .add_fact(FactKey::ButWas, self.format_value(self.actual()))
+                .do_fail()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::{assert_that_dyn, GenericAssertion};
+
+    use super::*;
+
+    trait Named: Debug {}
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    struct Name(&'static str);
+
+    impl Named for Name {}
+
+    #[test]
+    fn has_debug() {
+        let named: &dyn Named = &Name("a");
+        assert_that_dyn!(named).has_debug("Name(\"a\")");
+        assert_that_dyn!(Box::new(Name("b")) as Box<dyn Named>).has_debug("Name(\"b\")");
+        assert_that_dyn!(named).has_debug_containing("Name");
+
+        // Failures
+        let result = DynDebugAssertion::<CheckThatResult>::has_debug(
+            &check_that!(DynDebug::new(&named)),
+            "Name(\"b\")",
+        );
+        assert_that!(result).facts_are(vec![
+            Fact::new("expected debug representation", "\"Name(\\\"b\\\")\""),
+            Fact::new("but was", "Name(\"a\")"),
+        ]);
+    }
+}
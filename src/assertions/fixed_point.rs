@@ -0,0 +1,225 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+
+/// Numbers with exact arithmetic, such as integers and fixed-point types, which can be compared
+/// with an absolute tolerance by [`FixedPointAssertion`].
+///
+/// Implement this trait for fixed-point types (e.g. from the `fixed` crate) to compare them
+/// without converting to `f64` and losing precision.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// /// Fixed-point number with 2 decimal digits.
+/// #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// struct Cents(i64);
+///
+/// impl FixedPoint for Cents {
+///     fn abs_diff(self, other: Self) -> Self {
+///         Cents((self.0 - other.0).abs())
+///     }
+/// }
+///
+/// assert_that!(Cents(1001)).with_abs_tol(Cents(1)).is_approx_equal_to(Cents(1000));
+/// ```
+pub trait FixedPoint: Copy + PartialOrd + Debug {
+    /// Returns `|self - other|`.
+    fn abs_diff(self, other: Self) -> Self;
+}
+
+macro_rules! impl_fixed_point_for_integers {
+    ($($t:ty),*) => {
+        $(
+            impl FixedPoint for $t {
+                fn abs_diff(self, other: Self) -> Self {
+                    // Saturates for signed integers, whose distance may exceed `<$t>::MAX`.
+                    <$t>::try_from(<$t>::abs_diff(self, other)).unwrap_or(<$t>::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_point_for_integers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Trait for fixed-point assertion.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// assert_that!(1000_u32).with_abs_tol(5).is_approx_equal_to(1005);
+/// assert_that!(-3_i64).is_approx_equal_to(-3);
+/// ```
+/// ```should_panic
+/// use assertor::*;
+/// assert_that!(1000_u32).with_abs_tol(5).is_approx_equal_to(1006);
+/// // expected         : 1006
+/// // but was          : 1000
+/// // outside tolerance: 5
+/// ```
+pub trait FixedPointAssertion<'a, S, R> {
+    /// Set the absolute tolerance. A negative tolerance fails the assertion.
+    fn with_abs_tol(self, abs_tol: S) -> Subject<'a, S, FixedPointTolerance<S>, R>;
+
+    /// Checks the subject is equal to `expected` with tolerance. Without a tolerance, the
+    /// subject must be exactly equal to `expected`.
+    ///
+    /// The equality with tolerance is defined as following:
+    /// ```math
+    /// abs(actual - expected) <= abs_tol
+    /// ```
+    #[track_caller]
+    fn is_approx_equal_to<B: Borrow<S>>(&self, expected: B) -> R;
+}
+
+pub struct FixedPointTolerance<S> {
+    /// absolute tolerance
+    abs_tol: S,
+}
+
+impl<'a, S, R> FixedPointAssertion<'a, S, R> for Subject<'a, S, FixedPointTolerance<S>, R>
+where
+    S: FixedPoint,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn with_abs_tol(mut self, abs_tol: S) -> Subject<'a, S, FixedPointTolerance<S>, R> {
+        self.option_mut().abs_tol = abs_tol;
+        self
+    }
+
+    fn is_approx_equal_to<B: Borrow<S>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
+        let expected = *expected.borrow();
+        let actual = *self.actual();
+        let abs_tol = self.option().abs_tol;
+        // `abs_tol.abs_diff(abs_tol)` is the zero of `S`.
+        if abs_tol < abs_tol.abs_diff(abs_tol) {
+            return self
+                .new_result()
+                .add_formatted_fact("invalid absolute tolerance", abs_tol)
+                .add_simple_fact("tolerance must be a non-negative number")
+                .do_fail();
+        }
+        if actual.abs_diff(expected) <= abs_tol {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_fact(FactKey::Expected, self.format_value(&expected))
+                .add_fact(FactKey::ButWas, self.format_value(&actual))
+                .add_formatted_fact(FactKey::OutsideTolerance, abs_tol)
+                .do_fail()
+        }
+    }
+}
+
+impl<'a, S, R: 'a> FixedPointAssertion<'a, S, R> for Subject<'a, S, (), R>
+where
+    S: FixedPoint,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn with_abs_tol(self, abs_tol: S) -> Subject<'a, S, FixedPointTolerance<S>, R> {
+        let subject = self.new_owned_subject(
+            *self.actual(),
+            self.description().clone(),
+            FixedPointTolerance { abs_tol },
+        );
+        match self.formatter() {
+            None => subject,
+            Some(formatter) => subject.with_formatter(formatter),
+        }
+    }
+
+    fn is_approx_equal_to<B: Borrow<S>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
+        let expected = *expected.borrow();
+        if self.actual().partial_cmp(&expected) == Some(Ordering::Equal) {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_fact(FactKey::Expected, self.format_value(&expected))
+                .add_fact(FactKey::ButWas, self.format_value(self.actual()))
+                .do_fail()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Cents(i64);
+
+    impl FixedPoint for Cents {
+        fn abs_diff(self, other: Self) -> Self {
+            Cents((self.0 - other.0).abs())
+        }
+    }
+
+    #[test]
+    fn is_approx_equal_to() {
+        assert_that!(1000_u32)
+            .with_abs_tol(5)
+            .is_approx_equal_to(1005);
+        assert_that!(1005_u32)
+            .with_abs_tol(5)
+            .is_approx_equal_to(1000);
+        assert_that!(-3_i8).with_abs_tol(0).is_approx_equal_to(-3);
+        assert_that!(i32::MIN)
+            .with_abs_tol(i32::MAX)
+            .is_approx_equal_to(-1);
+        assert_that!(7_usize).is_approx_equal_to(7);
+        assert_that!(Cents(1001))
+            .with_abs_tol(Cents(1))
+            .is_approx_equal_to(Cents(1000));
+
+        // Failures
+        assert_that!(check_that!(1000_u32)
+            .with_abs_tol(5)
+            .is_approx_equal_to(1006))
+        .facts_are(vec![
+            Fact::new("expected", "1006"),
+            Fact::new("but was", "1000"),
+            Fact::new("outside tolerance", "5"),
+        ]);
+        assert_that!(check_that!(i32::MIN)
+            .with_abs_tol(10)
+            .is_approx_equal_to(i32::MAX))
+        .facts_are(vec![
+            Fact::new("expected", "2147483647"),
+            Fact::new("but was", "-2147483648"),
+            Fact::new("outside tolerance", "10"),
+        ]);
+        assert_that!(check_that!(7_u8).is_approx_equal_to(8))
+            .facts_are(vec![Fact::new("expected", "8"), Fact::new("but was", "7")]);
+        assert_that!(check_that!(Cents(1000))
+            .with_abs_tol(Cents(-1))
+            .is_approx_equal_to(Cents(1000)))
+        .facts_are(vec![
+            Fact::new("invalid absolute tolerance", "Cents(-1)"),
+            Fact::new_simple_fact("tolerance must be a non-negative number"),
+        ]);
+    }
+}
@@ -17,7 +17,9 @@ use std::fmt::Debug;
 
 use num_traits::{Float, Zero};
 
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
 
 /// Trait for float assertion.
 ///
@@ -31,12 +33,52 @@ use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
 /// assert_that!(0.1_f64)
 ///     .with_rel_tol(0.2)
 ///     .is_approx_equal_to(0.12); // 0.1 ± 0.12 * 0.2
+/// assert_that!(f64::NAN).is_nan();
+/// assert_that!(f64::NAN).treating_nan_as_equal().is_approx_equal_to(f64::NAN);
 /// ```
 pub trait FloatAssertion<'a, S, R> {
-    /// Set the relative tolerance.
+    /// Set the relative tolerance. A negative or NaN tolerance fails the assertion.
     fn with_rel_tol(self, rel_tol: S) -> Subject<'a, S, FloatTolerance<S>, R>;
-    /// Set the absolute tolerance.
+    /// Set the absolute tolerance. A negative or NaN tolerance fails the assertion.
     fn with_abs_tol(self, abs_tol: S) -> Subject<'a, S, FloatTolerance<S>, R>;
+    /// Treats NaN as approximately equal to NaN in
+    /// [`is_approx_equal_to`](FloatAssertion::is_approx_equal_to). By default, NaN is not equal to
+    /// any value, including NaN.
+    fn treating_nan_as_equal(self) -> Subject<'a, S, FloatTolerance<S>, R>
+    where
+        FloatTolerance<S>: Default;
+
+    /// Checks that the subject is NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(f32::NAN).is_nan();
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(0.1_f32).is_nan();
+    /// // expected to be NaN
+    /// // but was: 0.1
+    /// ```
+    #[track_caller]
+    fn is_nan(&self) -> R;
+
+    /// Checks that the subject is not NaN.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(0.1_f32).is_not_nan();
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(f64::NAN).is_not_nan();
+    /// // expected to not be NaN
+    /// // but was: NaN
+    /// ```
+    #[track_caller]
+    fn is_not_nan(&self) -> R;
 
     /// Checks the subject is equal to `expected` with tolerance.
     ///
@@ -56,11 +98,17 @@ pub struct FloatTolerance<S> {
     rel_tol: S,
     /// absolute tolerance
     abs_tol: S,
+    /// whether NaN is approximately equal to NaN
+    nan_equal: bool,
 }
 
 impl<S> FloatTolerance<S> {
     fn new(rel_tol: S, abs_tol: S) -> Self {
-        FloatTolerance { rel_tol, abs_tol }
+        FloatTolerance {
+            rel_tol,
+            abs_tol,
+            nan_equal: false,
+        }
     }
     fn with_rel_tol(mut self, rel_tol: S) -> Self {
         self.rel_tol = rel_tol;
@@ -70,6 +118,10 @@ impl<S> FloatTolerance<S> {
         self.abs_tol = abs_tol;
         self
     }
+    fn with_nan_equal(mut self) -> Self {
+        self.nan_equal = true;
+        self
+    }
 }
 
 impl<S: Zero> FloatTolerance<S> {
@@ -107,16 +159,68 @@ where
         self
     }
 
+    fn treating_nan_as_equal(mut self) -> Subject<'a, S, FloatTolerance<S>, R> {
+        self.option_mut().nan_equal = true;
+        self
+    }
+
+    fn is_nan(&self) -> R {
+        pass_if_skipped!(self);
+        check_is_nan(
+            self.new_result(),
+            self.actual().is_nan(),
+            self.format_value(self.actual()),
+        )
+    }
+
+    fn is_not_nan(&self) -> R {
+        pass_if_skipped!(self);
+        check_is_not_nan(
+            self.new_result(),
+            self.actual().is_nan(),
+            self.format_value(self.actual()),
+        )
+    }
+
     fn is_approx_equal_to<B: Borrow<S>>(&self, expected: B) -> R {
-        let diff = (*self.actual() - *expected.borrow()).abs();
-        let tolerance: S = self.option().abs_tol + self.option().rel_tol * *expected.borrow();
-        if diff < tolerance {
+        pass_if_skipped!(self);
+        let expected = *expected.borrow();
+        let actual = *self.actual();
+        let option = self.option();
+        for (name, tol) in [("relative", option.rel_tol), ("absolute", option.abs_tol)] {
+            if tol.is_nan() || tol < S::zero() {
+                return self
+                    .new_result()
+                    .add_formatted_fact(format!("invalid {} tolerance", name), tol)
+                    .add_simple_fact("tolerance must be a non-negative number")
+                    .do_fail();
+            }
+        }
+        if expected.is_nan() || actual.is_nan() {
+            return if expected.is_nan() && actual.is_nan() && option.nan_equal {
+                self.new_result().do_ok()
+            } else {
+                let reason = if expected.is_nan() && actual.is_nan() {
+                    "NaN is not equal to NaN unless treating_nan_as_equal() is set"
+                } else {
+                    "NaN is not approximately equal to any number"
+                };
+                self.new_result()
+                    .add_fact(FactKey::Expected, self.format_value(&expected))
+                    .add_fact(FactKey::ButWas, self.format_value(&actual))
+                    .add_simple_fact(reason)
+                    .do_fail()
+            };
+        }
+        let diff = (actual - expected).abs();
+        let tolerance: S = option.abs_tol + option.rel_tol * expected.abs();
+        if diff <= tolerance {
             self.new_result().do_ok()
         } else {
             self.new_result()
-                .add_fact("expected", format!("{:?}", expected.borrow()))
-                .add_fact("but was", format!("{:?}", self.actual()))
-                .add_fact("outside tolerance", format!("{:?}", tolerance))
+                .add_fact(FactKey::Expected, self.format_value(&expected))
+                .add_fact(FactKey::ButWas, self.format_value(&actual))
+                .add_formatted_fact(FactKey::OutsideTolerance, tolerance)
                 .do_fail()
         }
     }
@@ -129,19 +233,51 @@ where
 {
     fn with_rel_tol(self, rel_tol: S) -> Subject<'a, S, FloatTolerance<S>, R> {
         // XXX: consider to remove clone.
-        self.new_owned_subject(
+        let subject = self.new_owned_subject(
             *self.actual(),
             self.description().clone(),
             FloatTolerance::zeros().with_rel_tol(rel_tol),
-        )
+        );
+        keep_formatter(&self, subject)
     }
 
     fn with_abs_tol(self, abs_tol: S) -> Subject<'a, S, FloatTolerance<S>, R> {
         // XXX: consider to remove clone.
-        self.new_owned_subject(
+        let subject = self.new_owned_subject(
             *self.actual(),
             self.description().clone(),
             FloatTolerance::zeros().with_abs_tol(abs_tol),
+        );
+        keep_formatter(&self, subject)
+    }
+
+    fn treating_nan_as_equal(self) -> Subject<'a, S, FloatTolerance<S>, R>
+    where
+        FloatTolerance<S>: Default,
+    {
+        let subject = self.new_owned_subject(
+            *self.actual(),
+            self.description().clone(),
+            FloatTolerance::default().with_nan_equal(),
+        );
+        keep_formatter(&self, subject)
+    }
+
+    fn is_nan(&self) -> R {
+        pass_if_skipped!(self);
+        check_is_nan(
+            self.new_result(),
+            self.actual().is_nan(),
+            self.format_value(self.actual()),
+        )
+    }
+
+    fn is_not_nan(&self) -> R {
+        pass_if_skipped!(self);
+        check_is_not_nan(
+            self.new_result(),
+            self.actual().is_nan(),
+            self.format_value(self.actual()),
         )
     }
 
@@ -149,8 +285,48 @@ where
     where
         FloatTolerance<S>: Default,
     {
-        self.new_subject(self.actual(), None, FloatTolerance::default())
-            .is_approx_equal_to(expected)
+        pass_if_skipped!(self);
+        let subject = self.new_subject(self.actual(), None, FloatTolerance::default());
+        keep_formatter(self, subject).is_approx_equal_to(expected)
+    }
+}
+
+fn check_is_nan<R>(assertion_result: AssertionResult, is_nan: bool, actual: String) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    if is_nan {
+        assertion_result.do_ok()
+    } else {
+        assertion_result
+            .add_simple_fact("expected to be NaN")
+            .add_fact(FactKey::ButWas, actual)
+            .do_fail()
+    }
+}
+
+fn check_is_not_nan<R>(assertion_result: AssertionResult, is_nan: bool, actual: String) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    if is_nan {
+        assertion_result
+            .add_simple_fact("expected to not be NaN")
+            .add_fact(FactKey::ButWas, actual)
+            .do_fail()
+    } else {
+        assertion_result.do_ok()
+    }
+}
+
+/// Copies the formatter of `from` to `to`.
+fn keep_formatter<'a, S, O1, O2, R>(
+    from: &Subject<S, O1, R>,
+    to: Subject<'a, S, O2, R>,
+) -> Subject<'a, S, O2, R> {
+    match from.formatter() {
+        None => to,
+        Some(formatter) => to.with_formatter(formatter),
     }
 }
 
@@ -179,6 +355,16 @@ mod tests {
         assert_that!(0.1_f64)
             .with_rel_tol(0.2)
             .is_approx_equal_to(0.12); // 0.1 ± 0.12 * 0.2
+        assert_that!(-0.1_f64)
+            .with_rel_tol(0.2)
+            .is_approx_equal_to(-0.12); // -0.1 ± 0.12 * 0.2
+        assert_that!(0.0_f64)
+            .with_abs_tol(0.5)
+            .is_approx_equal_to(0.5);
+        assert_that!(1.5_f64)
+            .with_abs_tol(0.0)
+            .with_rel_tol(0.0)
+            .is_approx_equal_to(1.5);
 
         // Failures
         assert_that!(check_that!(0.1).with_abs_tol(0.1).is_approx_equal_to(0.25)).facts_are(vec![
@@ -190,6 +376,78 @@ mod tests {
             Fact::new("expected", "0.3"),
             Fact::new("but was", "0.1"),
             Fact::new("outside tolerance", "3.01e-6"),
-        ])
+        ]);
+        assert_that!(check_that!(-0.1)
+            .with_rel_tol(0.1)
+            .is_approx_equal_to(-0.12))
+        .facts_are(vec![
+            Fact::new("expected", "-0.12"),
+            Fact::new("but was", "-0.1"),
+            Fact::new("outside tolerance", "0.012"),
+        ]);
+        assert_that!(check_that!(0.1)
+            .with_formatter(|v| format!("{:.2}", v))
+            .with_abs_tol(0.1)
+            .is_approx_equal_to(0.25))
+        .facts_are(vec![
+            Fact::new("expected", "0.25"),
+            Fact::new("but was", "0.10"),
+            Fact::new("outside tolerance", "0.1"),
+        ]);
+    }
+
+    #[test]
+    fn invalid_tolerance() {
+        assert_that!(check_that!(0.1).with_abs_tol(-1.0).is_approx_equal_to(0.1)).facts_are(vec![
+            Fact::new("invalid absolute tolerance", "-1.0"),
+            Fact::new_simple_fact("tolerance must be a non-negative number"),
+        ]);
+        assert_that!(check_that!(0.1_f32)
+            .with_rel_tol(f32::NAN)
+            .is_approx_equal_to(0.1))
+        .facts_are(vec![
+            Fact::new("invalid relative tolerance", "NaN"),
+            Fact::new_simple_fact("tolerance must be a non-negative number"),
+        ]);
+    }
+
+    #[test]
+    fn nan() {
+        assert_that!(f64::NAN).is_nan();
+        assert_that!(0.1_f64).is_not_nan();
+        assert_that!(f32::NAN).with_abs_tol(0.1).is_nan();
+        assert_that!(f64::NAN)
+            .treating_nan_as_equal()
+            .is_approx_equal_to(f64::NAN);
+        assert_that!(0.1_f64)
+            .treating_nan_as_equal()
+            .is_approx_equal_to(0.1);
+        assert_that!(f32::NAN)
+            .with_abs_tol(0.1)
+            .treating_nan_as_equal()
+            .is_approx_equal_to(f32::NAN);
+
+        // Failures
+        assert_that!(check_that!(0.1).is_nan()).facts_are(vec![
+            Fact::new_simple_fact("expected to be NaN"),
+            Fact::new("but was", "0.1"),
+        ]);
+        assert_that!(check_that!(f64::NAN).is_not_nan()).facts_are(vec![
+            Fact::new_simple_fact("expected to not be NaN"),
+            Fact::new("but was", "NaN"),
+        ]);
+        assert_that!(check_that!(f64::NAN).is_approx_equal_to(f64::NAN)).facts_are(vec![
+            Fact::new("expected", "NaN"),
+            Fact::new("but was", "NaN"),
+            Fact::new_simple_fact("NaN is not equal to NaN unless treating_nan_as_equal() is set"),
+        ]);
+        assert_that!(check_that!(0.1)
+            .treating_nan_as_equal()
+            .is_approx_equal_to(f64::NAN))
+        .facts_are(vec![
+            Fact::new("expected", "NaN"),
+            Fact::new("but was", "0.1"),
+            Fact::new_simple_fact("NaN is not approximately equal to any number"),
+        ]);
     }
 }
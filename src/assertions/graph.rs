@@ -0,0 +1,357 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+
+/// Maximum number of nodes or edges listed in failure messages.
+const MAX_LISTED: usize = 10;
+
+/// Directed graphs whose nodes and edges can be enumerated.
+///
+/// An adjacency list `BTreeMap<N, Vec<N>>` is a graph whose nodes are its keys and the nodes they
+/// point to. To use [`GraphAssertion`] with graph types of other crates (e.g. `petgraph::Graph`),
+/// implement this trait for a wrapper type, as assertor does not depend on them.
+pub trait GraphLike {
+    /// Node identifier.
+    type Node: PartialEq + Debug;
+
+    /// Returns all nodes, each exactly once.
+    fn nodes(&self) -> Vec<Self::Node>;
+
+    /// Returns all edges as `(source, target)` pairs.
+    fn edges(&self) -> Vec<(Self::Node, Self::Node)>;
+}
+
+impl<N: Ord + Clone + Debug> GraphLike for BTreeMap<N, Vec<N>> {
+    type Node = N;
+
+    fn nodes(&self) -> Vec<N> {
+        let mut nodes: Vec<N> = self.keys().cloned().collect();
+        for target in self.values().flatten() {
+            if !self.contains_key(target) && !nodes.contains(target) {
+                nodes.push(target.clone());
+            }
+        }
+        nodes
+    }
+
+    fn edges(&self) -> Vec<(N, N)> {
+        self.iter()
+            .flat_map(|(source, targets)| targets.iter().map(|t| (source.clone(), t.clone())))
+            .collect()
+    }
+}
+
+/// Trait for graph assertion.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use assertor::*;
+///
+/// let graph = BTreeMap::from([("a", vec!["b", "c"]), ("b", vec!["c"])]);
+/// assert_that!(graph).contains_node("c");
+/// assert_that!(graph).contains_edge("a", "b");
+/// assert_that!(graph).has_node_count(3);
+/// assert_that!(graph).is_acyclic();
+/// ```
+/// ```should_panic
+/// use std::collections::BTreeMap;
+/// use assertor::*;
+///
+/// let graph = BTreeMap::from([("a", vec!["b"]), ("b", vec!["a"])]);
+/// assert_that!(graph).is_acyclic();
+/// // expected to be acyclic
+/// // but found cycle: "a" -> "b" -> "a"
+/// ```
+pub trait GraphAssertion<N, R> {
+    /// Checks that the subject contains the node.
+    #[track_caller]
+    fn contains_node(&self, node: N) -> R;
+
+    /// Checks that the subject contains an edge from `source` to `target`.
+    #[track_caller]
+    fn contains_edge(&self, source: N, target: N) -> R;
+
+    /// Checks that the subject has exactly `count` nodes.
+    #[track_caller]
+    fn has_node_count(&self, count: usize) -> R;
+
+    /// Checks that the subject has no directed cycle, including self loops.
+    #[track_caller]
+    fn is_acyclic(&self) -> R;
+}
+
+impl<G, R> GraphAssertion<G::Node, R> for Subject<'_, G, (), R>
+where
+    G: GraphLike,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn contains_node(&self, node: G::Node) -> R {
+        pass_if_skipped!(self);
+        let nodes = self.actual().nodes();
+        if nodes.contains(&node) {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_formatted_fact("expected to contain node", node)
+                .add_simple_fact("but did not")
+                .add_formatted_values_fact("nodes", bounded(nodes))
+                .do_fail()
+        }
+    }
+
+    fn contains_edge(&self, source: G::Node, target: G::Node) -> R {
+        pass_if_skipped!(self);
+        let edges: Vec<Edge<G::Node>> = self
+            .actual()
+            .edges()
+            .into_iter()
+            .map(|(s, t)| Edge(s, t))
+            .collect();
+        if edges.iter().any(|e| e.0 == source && e.1 == target) {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_formatted_fact("expected to contain edge", Edge(source, target))
+                .add_simple_fact("but did not")
+                .add_formatted_values_fact("edges", bounded(edges))
+                .do_fail()
+        }
+    }
+
+    fn has_node_count(&self, count: usize) -> R {
+        pass_if_skipped!(self);
+        let nodes = self.actual().nodes();
+        if nodes.len() == count {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_fact(FactKey::Expected, count.to_string())
+                .add_fact(FactKey::Actual, nodes.len().to_string())
+                .add_formatted_values_fact("nodes", bounded(nodes))
+                .do_fail()
+        }
+    }
+
+    fn is_acyclic(&self) -> R {
+        pass_if_skipped!(self);
+        match find_cycle(self.actual()) {
+            None => self.new_result().do_ok(),
+            Some(cycle) => self
+                .new_result()
+                .add_simple_fact("expected to be acyclic")
+                .add_formatted_fact("but found cycle", Path(cycle))
+                .do_fail(),
+        }
+    }
+}
+
+/// Returns the nodes of a cycle in order, if any.
+fn find_cycle<G: GraphLike>(graph: &G) -> Option<Vec<G::Node>> {
+    let nodes = graph.nodes();
+    let index = |nodes: &[G::Node], node: &G::Node| nodes.iter().position(|n| n == node);
+    let mut successors: Vec<Vec<usize>> = vec![vec![]; nodes.len()];
+    for (source, target) in graph.edges() {
+        if let (Some(s), Some(t)) = (index(&nodes, &source), index(&nodes, &target)) {
+            successors[s].push(t);
+        }
+    }
+
+    // Depth first search, keeping the current path on a stack of (node, next successor).
+    let mut visited = vec![false; nodes.len()];
+    let mut on_path = vec![false; nodes.len()];
+    for root in 0..nodes.len() {
+        if visited[root] {
+            continue;
+        }
+        let mut stack = vec![(root, 0)];
+        visited[root] = true;
+        on_path[root] = true;
+        while let Some((node, next)) = stack.last_mut() {
+            let node = *node;
+            match successors[node].get(*next).copied() {
+                Some(successor) => {
+                    *next += 1;
+                    if on_path[successor] {
+                        let start = stack.iter().position(|(n, _)| *n == successor).unwrap();
+                        // Nodes on the path are distinct, so each can be moved out once.
+                        let mut slots: Vec<Option<G::Node>> = nodes.into_iter().map(Some).collect();
+                        return Some(
+                            stack[start..]
+                                .iter()
+                                .map(|(n, _)| slots[*n].take().unwrap())
+                                .collect(),
+                        );
+                    }
+                    if !visited[successor] {
+                        visited[successor] = true;
+                        on_path[successor] = true;
+                        stack.push((successor, 0));
+                    }
+                }
+                None => {
+                    on_path[node] = false;
+                    stack.pop();
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Renders at most [`MAX_LISTED`] values, followed by the number of omitted values.
+fn bounded<T: Debug>(values: Vec<T>) -> Vec<Listed<T>> {
+    let omitted = values.len().saturating_sub(MAX_LISTED);
+    let mut listed: Vec<Listed<T>> = values
+        .into_iter()
+        .take(MAX_LISTED)
+        .map(Listed::Value)
+        .collect();
+    if omitted > 0 {
+        listed.push(Listed::Omitted(omitted));
+    }
+    listed
+}
+
+enum Listed<T> {
+    Value(T),
+    Omitted(usize),
+}
+
+impl<T: Debug> Debug for Listed<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Listed::Value(value) => value.fmt(f),
+            Listed::Omitted(n) => write!(f, "... ({} more)", n),
+        }
+    }
+}
+
+struct Edge<N>(N, N);
+
+impl<N: Debug> Debug for Edge<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} -> {:?}", self.0, self.1)
+    }
+}
+
+/// A cycle, rendered as `a -> b -> a`.
+struct Path<N>(Vec<N>);
+
+impl<N: Debug> Debug for Path<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for node in &self.0 {
+            write!(f, "{:?} -> ", node)?;
+        }
+        write!(f, "{:?}", self.0[0])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+
+    use super::*;
+
+    fn diamond() -> BTreeMap<&'static str, Vec<&'static str>> {
+        BTreeMap::from([("a", vec!["b", "c"]), ("b", vec!["d"]), ("c", vec!["d"])])
+    }
+
+    #[test]
+    fn contains_node() {
+        assert_that!(diamond()).contains_node("a");
+        assert_that!(diamond()).contains_node("d");
+
+        // Failures
+        assert_that!(check_that!(diamond()).contains_node("e")).facts_are(vec![
+            Fact::new("expected to contain node", r#""e""#),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("nodes", vec![r#""a""#, r#""b""#, r#""c""#, r#""d""#]),
+        ]);
+    }
+
+    #[test]
+    fn contains_edge() {
+        assert_that!(diamond()).contains_edge("a", "b");
+        assert_that!(diamond()).contains_edge("c", "d");
+
+        // Failures
+        assert_that!(check_that!(diamond()).contains_edge("b", "a")).facts_are(vec![
+            Fact::new("expected to contain edge", r#""b" -> "a""#),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact(
+                "edges",
+                vec![
+                    r#""a" -> "b""#,
+                    r#""a" -> "c""#,
+                    r#""b" -> "d""#,
+                    r#""c" -> "d""#,
+                ],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn has_node_count() {
+        assert_that!(diamond()).has_node_count(4);
+        assert_that!(BTreeMap::<u32, Vec<u32>>::new()).has_node_count(0);
+
+        // Failures
+        let chain: BTreeMap<u32, Vec<u32>> = (0..12).map(|n| (n, vec![n + 1])).collect();
+        assert_that!(check_that!(chain).has_node_count(12)).facts_are(vec![
+            Fact::new("expected", "12"),
+            Fact::new("actual", "13"),
+            Fact::new_multi_value_fact(
+                "nodes",
+                vec![
+                    "0",
+                    "1",
+                    "2",
+                    "3",
+                    "4",
+                    "5",
+                    "6",
+                    "7",
+                    "8",
+                    "9",
+                    "... (3 more)",
+                ],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn is_acyclic() {
+        assert_that!(diamond()).is_acyclic();
+        assert_that!(BTreeMap::<u32, Vec<u32>>::new()).is_acyclic();
+
+        // Failures
+        let cyclic = BTreeMap::from([(1, vec![2]), (2, vec![3]), (3, vec![4, 2])]);
+        assert_that!(check_that!(cyclic).is_acyclic()).facts_are(vec![
+            Fact::new_simple_fact("expected to be acyclic"),
+            Fact::new("but found cycle", "2 -> 3 -> 2"),
+        ]);
+        let self_loop = BTreeMap::from([(1, vec![1])]);
+        assert_that!(check_that!(self_loop).is_acyclic()).facts_are(vec![
+            Fact::new_simple_fact("expected to be acyclic"),
+            Fact::new("but found cycle", "1 -> 1"),
+        ]);
+    }
+}
@@ -0,0 +1,220 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Debug, Formatter};
+
+use http::header::{HeaderMap, HeaderName, HeaderValue, Keys};
+
+use crate::assertions::iterator::check_contains_or_suggest;
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+use crate::diff::map::MapLike;
+
+/// Trait for [`http::HeaderMap`] assertions. Header names are compared ignoring case, as in HTTP.
+///
+/// `HeaderMap` also implements [`MapLike`], so [`crate::MapAssertion`] applies to it too. Since a
+/// header can have several values, map assertions see every value of a header as an entry, and
+/// look up the first value of a header.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// use http::header::{HeaderMap, HeaderValue, ACCEPT, CONTENT_TYPE};
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/html"));
+/// headers.append(ACCEPT, HeaderValue::from_static("text/html"));
+/// headers.append(ACCEPT, HeaderValue::from_static("application/json"));
+///
+/// assert_that!(headers).contains_header_key("Content-Type");
+/// assert_that!(headers).contains_header("accept", "application/json");
+/// demo!(
+///     assert_that!(headers).contains_header("Accept", "text/plain"),
+///     r#"
+///     expected to contain header: accept: "text/plain"
+///     but had values (2)        : [ "text/html", "application/json" ]
+///     "#
+/// );
+/// ```
+pub trait HeaderMapAssertion<R> {
+    /// Checks that the subject contains a header named `name`, ignoring case.
+    #[track_caller]
+    fn contains_header_key<N: AsRef<str>>(&self, name: N) -> R;
+
+    /// Checks that one of the values of the header named `name`, ignoring case, is `value`.
+    #[track_caller]
+    fn contains_header<N: AsRef<str>, V: AsRef<str>>(&self, name: N, value: V) -> R;
+}
+
+impl<R> HeaderMapAssertion<R> for Subject<'_, HeaderMap<HeaderValue>, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn contains_header_key<N: AsRef<str>>(&self, name: N) -> R {
+        pass_if_skipped!(self);
+        let name = name.as_ref().to_ascii_lowercase();
+        let names: Vec<&str> = self.actual().keys().map(HeaderName::as_str).collect();
+        check_contains_or_suggest(self.new_result(), names.into_iter(), &name.as_str())
+    }
+
+    fn contains_header<N: AsRef<str>, V: AsRef<str>>(&self, name: N, value: V) -> R {
+        pass_if_skipped!(self);
+        let name = name.as_ref().to_ascii_lowercase();
+        let value = value.as_ref();
+        let values: Vec<&HeaderValue> = self.actual().get_all(name.as_str()).iter().collect();
+        if values.iter().any(|v| v.as_bytes() == value.as_bytes()) {
+            return self.new_result().do_ok();
+        }
+        let result = self
+            .new_result()
+            .add_formatted_fact("expected to contain header", Header { name: &name, value });
+        if values.is_empty() {
+            result
+                .add_simple_fact("but header was not found")
+                .add_formatted_values_fact(
+                    FactKey::ThoughItDidContain,
+                    self.actual().keys().collect(),
+                )
+                .do_fail()
+        } else {
+            result
+                .add_formatted_values_fact(format!("but had values ({})", values.len()), values)
+                .do_fail()
+        }
+    }
+}
+
+impl<T> MapLike<HeaderName, T> for HeaderMap<T> {
+    type It<'a>
+        = Keys<'a, T>
+    where
+        T: 'a;
+
+    fn get(&self, k: &HeaderName) -> Option<&T> {
+        self.get(k)
+    }
+
+    fn keys_iter<'a>(&'a self) -> Self::It<'a>
+    where
+        T: 'a,
+    {
+        self.keys()
+    }
+
+    fn keys_ordered(&self) -> bool {
+        false
+    }
+
+    fn len(&self) -> usize {
+        self.keys_len()
+    }
+
+    fn entries(&self) -> Vec<(&HeaderName, &T)> {
+        self.iter().collect()
+    }
+}
+
+/// A header rendered as `name: "value"`.
+struct Header<'a> {
+    name: &'a str,
+    value: &'a str,
+}
+
+impl Debug for Header<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {:?}", self.name, self.value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::header::{ACCEPT, CONTENT_TYPE};
+
+    use crate::testing::*;
+    use crate::MapAssertion;
+
+    use super::*;
+
+    fn headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/html"));
+        headers.append(ACCEPT, HeaderValue::from_static("text/html"));
+        headers.append(ACCEPT, HeaderValue::from_static("application/json"));
+        headers
+    }
+
+    #[test]
+    fn contains_header_key() {
+        assert_that!(headers()).contains_header_key("content-type");
+        assert_that!(headers()).contains_header_key("Content-Type");
+
+        assert_that!(check_that!(headers()).contains_header_key("Content-Typ")).facts_are(vec![
+            Fact::new("expected to contain", r#""content-typ""#),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("did you mean", vec![r#""content-type""#]),
+            Fact::new_multi_value_fact(
+                "though it did contain",
+                vec![r#""content-type""#, r#""accept""#],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn contains_header() {
+        assert_that!(headers()).contains_header("ACCEPT", "text/html");
+        assert_that!(headers()).contains_header("accept", "application/json");
+
+        assert_that!(check_that!(headers()).contains_header("Accept", "text/plain")).facts_are(
+            vec![
+                Fact::new("expected to contain header", r#"accept: "text/plain""#),
+                Fact::new_multi_value_fact(
+                    "but had values (2)",
+                    vec![r#""text/html""#, r#""application/json""#],
+                ),
+            ],
+        );
+        assert_that!(check_that!(headers()).contains_header("Host", "example.com")).facts_are(
+            vec![
+                Fact::new("expected to contain header", r#"host: "example.com""#),
+                Fact::new_simple_fact("but header was not found"),
+                Fact::new_multi_value_fact(
+                    "though it did contain",
+                    vec![r#""content-type""#, r#""accept""#],
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    fn map_assertions() {
+        assert_that!(headers()).has_length(2);
+        assert_that!(headers()).contains_key(CONTENT_TYPE);
+        assert_that!(headers()).contains_entry(ACCEPT, HeaderValue::from_static("text/html"));
+
+        let mut expected = HeaderMap::new();
+        expected.insert(CONTENT_TYPE, HeaderValue::from_static("text/plain"));
+        assert_that!(check_that!(headers()).contains_at_least(expected)).facts_are(vec![
+            Fact::new(
+                "expected to contain the same entries",
+                "but found 1 entry that is different",
+            ),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact(
+                "key was mapped to unexpected value",
+                vec![r#"{ key: "content-type", expected: "text/html", actual: "text/plain" }"#],
+            ),
+        ]);
+    }
+}
@@ -0,0 +1,251 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+
+/// Containers whose values can be looked up by an index, like with [`std::ops::Index`], but
+/// without panicking when there is no value at the index.
+pub trait IndexLike {
+    /// Type of the indices: positions for sequences, keys for maps.
+    type Index;
+
+    /// Type of the values.
+    type Output;
+
+    /// Returns the value at `index`, if any.
+    fn get_at(&self, index: &Self::Index) -> Option<&Self::Output>;
+
+    /// Adds facts to `result` explaining that there is no value at `index`.
+    fn describe_missing(&self, index: &Self::Index, result: AssertionResult) -> AssertionResult;
+}
+
+/// Trait for assertions on [`IndexLike`] containers.
+///
+/// # Example
+/// ```
+/// use std::collections::BTreeMap;
+/// use assertor::*;
+///
+/// assert_that!(vec!["a", "b"]).at(1).is_equal_to("b");
+/// assert_that!(["a", "b"]).at(0).is_equal_to("a");
+/// assert_that!(BTreeMap::from([("x", 1)])).at("x").is_equal_to(1);
+/// demo!(
+///     assert_that!(BTreeMap::from([("x", 1), ("y", 2)])).at("z"),
+///     r#"
+///     expected an entry for key : "z"
+///     but key was not found
+///     ---
+///     though it did contain keys: ["x", "y"]
+///     "#
+/// );
+/// ```
+pub trait IndexAssertion<'a, S, R>
+where
+    S: IndexLike,
+{
+    /// Returns a new subject which is the value at `index`. Unlike `container[index]` inside the
+    /// macro, a missing index fails with facts about the subject.
+    #[track_caller]
+    fn at(&self, index: S::Index) -> Subject<'a, S::Output, (), R>
+    where
+        S::Index: Debug,
+        S::Output: Clone;
+}
+
+impl<'a, S, R> IndexAssertion<'a, S, R> for Subject<'a, S, (), R>
+where
+    S: IndexLike,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn at(&self, index: S::Index) -> Subject<'a, S::Output, (), R>
+    where
+        S::Index: Debug,
+        S::Output: Clone,
+    {
+        if let Some(value) = self.actual().get_at(&index) {
+            return self.new_owned_subject(
+                value.clone(),
+                Some(format!("{}[{:?}]", self.description_or_expr(), index)),
+                (),
+            );
+        }
+        // Like `VecAssertion::element_at`, a derived subject can not be returned, so panic.
+        let result = self.actual().describe_missing(&index, self.new_result());
+        result.fail_derivation()
+    }
+}
+
+/// Adds facts about an `index` beyond the `length` elements of a sequence, with the valid
+/// indices.
+pub(crate) fn index_out_of_bounds(
+    result: AssertionResult,
+    index: usize,
+    length: usize,
+) -> AssertionResult {
+    let result = result.add_fact("expected an element at index", index.to_string());
+    match length.checked_sub(1) {
+        Some(last) => result
+            .add_fact("but length was", length.to_string())
+            .add_fact("last valid index", last.to_string()),
+        None => result.add_simple_fact("but was empty"),
+    }
+}
+
+fn key_not_found<K: Debug>(result: AssertionResult, key: &K, keys: Vec<&K>) -> AssertionResult {
+    result
+        .add_formatted_fact("expected an entry for key", key)
+        .add_simple_fact("but key was not found")
+        .add_splitter()
+        .add_formatted_fact("though it did contain keys", keys)
+}
+
+impl<T> IndexLike for Vec<T> {
+    type Index = usize;
+    type Output = T;
+
+    fn get_at(&self, index: &usize) -> Option<&T> {
+        self.get(*index)
+    }
+
+    fn describe_missing(&self, index: &usize, result: AssertionResult) -> AssertionResult {
+        index_out_of_bounds(result, *index, self.len())
+    }
+}
+
+impl<T> IndexLike for VecDeque<T> {
+    type Index = usize;
+    type Output = T;
+
+    fn get_at(&self, index: &usize) -> Option<&T> {
+        self.get(*index)
+    }
+
+    fn describe_missing(&self, index: &usize, result: AssertionResult) -> AssertionResult {
+        index_out_of_bounds(result, *index, self.len())
+    }
+}
+
+impl<T, const N: usize> IndexLike for [T; N] {
+    type Index = usize;
+    type Output = T;
+
+    fn get_at(&self, index: &usize) -> Option<&T> {
+        self.get(*index)
+    }
+
+    fn describe_missing(&self, index: &usize, result: AssertionResult) -> AssertionResult {
+        index_out_of_bounds(result, *index, N)
+    }
+}
+
+impl<T> IndexLike for &[T] {
+    type Index = usize;
+    type Output = T;
+
+    fn get_at(&self, index: &usize) -> Option<&T> {
+        self.get(*index)
+    }
+
+    fn describe_missing(&self, index: &usize, result: AssertionResult) -> AssertionResult {
+        index_out_of_bounds(result, *index, self.len())
+    }
+}
+
+impl<K: Eq + Hash + Debug, V> IndexLike for HashMap<K, V> {
+    type Index = K;
+
+    type Output = V;
+
+    fn get_at(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn describe_missing(&self, key: &K, result: AssertionResult) -> AssertionResult {
+        key_not_found(result, key, self.keys().collect())
+    }
+}
+
+impl<K: Ord + Debug, V> IndexLike for BTreeMap<K, V> {
+    type Index = K;
+
+    type Output = V;
+
+    fn get_at(&self, key: &K) -> Option<&V> {
+        self.get(key)
+    }
+
+    fn describe_missing(&self, key: &K, result: AssertionResult) -> AssertionResult {
+        key_not_found(result, key, self.keys().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::EqualityAssertion;
+
+    use super::*;
+
+    #[test]
+    fn at() {
+        let v = vec!["a", "b", "c"];
+        assert_that!(v).at(2).is_equal_to("c");
+        assert_that!(VecDeque::from([1, 2])).at(0).is_equal_to(1);
+        assert_that!([1, 2, 3]).at(1).is_equal_to(2);
+        assert_that!(&v[1..]).at(0).is_equal_to("b");
+        assert_that!(HashMap::from([("k", 1)]))
+            .at("k")
+            .is_equal_to(1);
+
+        assert_that!(check_that!(v).at(1).is_equal_to("x")).facts_are(vec![
+            Fact::new("value of", "v[1]"),
+            Fact::new("expected", r#""x""#),
+            Fact::new("actual", r#""b""#),
+        ]);
+        let map = BTreeMap::from([("k", 1)]);
+        assert_that!(check_that!(map).at("k").is_equal_to(2)).facts_are(vec![
+            Fact::new("value of", r#"map["k"]"#),
+            Fact::new("expected", "2"),
+            Fact::new("actual", "1"),
+        ]);
+    }
+
+    #[test]
+    fn describe_missing() {
+        let result = [1, 2].describe_missing(&5, AssertionResult::new(&None));
+        assert_that!(AssertionStrategy::<CheckThatResult>::do_fail(result)).facts_are(vec![
+            Fact::new("expected an element at index", "5"),
+            Fact::new("but length was", "2"),
+            Fact::new("last valid index", "1"),
+        ]);
+        let result = BTreeMap::from([("a", 1), ("b", 2)])
+            .describe_missing(&"c", AssertionResult::new(&None));
+        assert_that!(AssertionStrategy::<CheckThatResult>::do_fail(result)).facts_are(vec![
+            Fact::new("expected an entry for key", r#""c""#),
+            Fact::new_simple_fact("but key was not found"),
+            Fact::new_splitter(),
+            Fact::new("though it did contain keys", r#"["a", "b"]"#),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "last valid index            : 2")]
+    fn at_out_of_bounds() {
+        assert_that!(vec![1, 2, 3]).at(3);
+    }
+}
@@ -0,0 +1,123 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::BufRead;
+
+use crate::base::{AssertionApi, AssertionResult, Subject};
+
+/// Trait for assertions on readers of text, such as [`std::io::Cursor`] and byte slices.
+///
+/// The subject only borrows the reader, so the lines are read from a clone of it, and the reader
+/// itself is left unread. Readers which can not be cloned, such as files, can be checked with
+/// [`assert_that_collected`](crate::assert_that_collected) and
+/// [`ResultsAssertion::all_ok`](crate::ResultsAssertion::all_ok) instead:
+/// `assert_that_collected!(reader.lines()).all_ok()`.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// use std::io::Cursor;
+///
+/// let log = Cursor::new("started\nlistening on :8080\n");
+/// assert_that!(log).lines().has_length(2);
+/// assert_that!(log).lines().contains("listening on :8080".to_string());
+/// ```
+pub trait BufReadAssertion<'a, R> {
+    /// Returns a new subject which is the lines of the subject, as read by
+    /// [`BufRead::lines`]. Fails with the line number and the error if a line can not be read.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// demo!(
+    ///     assert_that!(&b"ok\n\xff\n"[..]).lines(),
+    ///     "
+    ///     failed to read line: 2
+    ///     with error         : stream did not contain valid UTF-8
+    ///     error kind         : InvalidData
+    ///     "
+    /// );
+    /// ```
+    #[track_caller]
+    fn lines(&self) -> Subject<'a, Vec<String>, (), R>;
+}
+
+impl<'a, S, R> BufReadAssertion<'a, R> for Subject<'a, S, (), R>
+where
+    S: BufRead + Clone,
+{
+    fn lines(&self) -> Subject<'a, Vec<String>, (), R> {
+        let mut lines = vec![];
+        for line in self.actual().clone().lines() {
+            match line {
+                Ok(line) => lines.push(line),
+                Err(err) => {
+                    // Like `ResultsAssertion::all_ok`, a derived subject can not be returned, so
+                    // panic.
+                    let result: AssertionResult = self
+                        .new_result()
+                        .add_formatted_fact("failed to read line", lines.len() + 1)
+                        .add_fact("with error", err.to_string())
+                        .add_formatted_fact("error kind", err.kind());
+                    result.fail_derivation()
+                }
+            }
+        }
+        self.new_owned_subject(
+            lines,
+            Some(format!("{}.lines()", self.description_or_expr())),
+            (),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use crate::testing::*;
+    use crate::{EqualityAssertion, VecAssertion};
+
+    use super::*;
+
+    #[test]
+    fn lines() {
+        let log = Cursor::new("started\nlistening\n");
+        assert_that!(log)
+            .lines()
+            .contains_exactly_in_order(vec!["started".to_string(), "listening".to_string()]);
+        assert_that!(log)
+            .lines()
+            .any_satisfy(|line| line == "listening", "is listening");
+        assert_that!(log.position()).is_equal_to(0);
+        assert_that!(&b""[..]).lines().is_empty();
+        assert_that!(Cursor::new("no newline at end"))
+            .lines()
+            .has_length(1);
+
+        assert_that!(check_that!(Cursor::new("a\nb"))
+            .lines()
+            .contains("c".to_string()))
+        .facts_are_at_least(vec![Fact::new(
+            "value of",
+            r#"Cursor::new("a\nb").lines()"#,
+        )]);
+    }
+
+    #[test]
+    #[should_panic(expected = "stream did not contain valid UTF-8")]
+    fn lines_with_invalid_data() {
+        assert_that!(&b"ok\n\xff\n"[..]).lines();
+    }
+}
@@ -13,9 +13,19 @@
 // limitations under the License.
 
 use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
 use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::RangeBounds;
+use std::rc::Rc;
+use std::vec::IntoIter;
 
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, DuplicateKeys, FactKey,
+    Subject,
+};
+use crate::diff::debug::similar_strings;
 use crate::diff::iter::{SequenceComparison, SequenceOrderComparison};
 
 /// Trait for iterator assertion.
@@ -50,10 +60,14 @@ where
 {
     /// Checks that the subject iterator contains the element `expected`.
     ///
+    /// The element can be passed by value or by reference, whether the iterator yields values
+    /// or references. See [`ElementLike`](crate::ElementLike).
+    ///
     /// # Example
     /// ```
     /// use assertor::*;
     /// assert_that!(vec![1, 2, 3].iter()).contains(&2);
+    /// assert_that!(vec![1, 2, 3].iter()).contains(2);
     /// assert_that!("foobar".chars()).contains(&'a');
     /// ```
     /// ```should_panic
@@ -70,7 +84,7 @@ where
     #[track_caller]
     fn contains<B>(&self, element: B) -> R
     where
-        B: Borrow<T>,
+        B: ElementLike<T>,
         T: PartialEq + Debug;
 
     /// Checks that the subject iterator does not contains the element `expected`.
@@ -95,7 +109,7 @@ where
     #[track_caller]
     fn does_not_contain<B>(&self, element: B) -> R
     where
-        B: Borrow<T>,
+        B: ElementLike<T>,
         T: PartialEq + Debug;
 
     /// Checks that the subject exactly contains elements of `expected_iter`.
@@ -104,6 +118,9 @@ where
     /// [contains_exactly_in_order](`IteratorAssertion::contains_exactly_in_order`) to check
     /// elements are in the same order.
     ///
+    /// An empty `expected_iter` is the same as [`IteratorAssertion::is_empty`], which failures
+    /// point out, unless the subject [rejects it](crate::Subject::reject_empty_expected).
+    ///
     /// # Example
     /// ```
     /// use assertor::*;
@@ -119,12 +136,52 @@ where
     /// // actual        : ['f', 'o', 'o', 'b', 'a', 'r', 'b', 'a', 'z']
     /// ```
     #[track_caller]
-    fn contains_exactly<EI: Iterator<Item = T> + Clone>(self, expected_iter: EI) -> R
+    fn contains_exactly<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug;
+
+    /// Checks that the subject and `expected_iter` have the same elements, in any order. Each
+    /// element must occur as many times in both. Unlike [`IteratorAssertion::contains_exactly`],
+    /// failures summarize the elements found only on either side, which reads better when
+    /// comparing two large query results.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([3, 1, 2].iter()).has_same_elements_as([1, 2, 3].iter());
+    /// demo!(
+    ///     assert_that!([1, 2, 2, 4].iter()).has_same_elements_as([1, 2, 3].iter()),
+    ///     "
+    ///     expected the same elements, ignoring order
+    ///     only in actual (2)  : [ 2, 4 ]
+    ///     only in expected (1): [ 3 ]
+    ///     in both             : 2
+    ///     "
+    /// );
+    /// ```
+    #[track_caller]
+    fn has_same_elements_as<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug;
+
+    /// Like [`IteratorAssertion::has_same_elements_as`], but ignores how many times each element
+    /// occurs.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 1, 2].iter()).has_same_distinct_elements_as([2, 1].iter());
+    /// ```
+    #[track_caller]
+    fn has_same_distinct_elements_as<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
     where
         T: PartialEq + Debug;
 
     /// Checks that the subject exactly contains elements of `expected_iter` in the same order.
     ///
+    /// When both sequences have the same length but different contents, the failure lists the
+    /// first mismatched indexes with the expected and actual element at each.
+    ///
     /// # Example
     /// ```
     /// use assertor::*;
@@ -147,8 +204,40 @@ where
     /// // expected: ['b', 'a', 'z', 'b', 'a', 'r', 'f', 'o', 'o']
     /// // actual  : ['f', 'o', 'o', 'b', 'a', 'r', 'b', 'a', 'z']
     /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(vec![10, 20, 30].iter()).contains_exactly_in_order(vec![10, 25, 35].iter());
+    /// // mismatched indexes       : 2 of 3
+    /// // index | expected | actual: [ 1 | 25 | 20, 2 | 35 | 30 ]
+    /// // ---
+    /// // expected                 : [ 10, 25, 35 ]
+    /// // actual                   : [ 10, 20, 30 ]
+    /// ```
+    #[track_caller]
+    fn contains_exactly_in_order<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug;
+
+    /// Checks that the subject yields the same elements as `expected_iter`, in the same order.
+    ///
+    /// Unlike [contains_exactly_in_order](`IteratorAssertion::contains_exactly_in_order`), the
+    /// elements are compared pairwise in a single pass, and only the first mismatch is reported.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3].iter()).is_equal_to_iterator(vec![1, 2, 3].iter());
+    /// assert_that!("foo".chars()).is_equal_to_iterator("foo".chars());
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!("foobar".chars()).is_equal_to_iterator("foobaz".chars());
+    /// // first mismatch at index: 5
+    /// // expected               : 'z'
+    /// // but was                : 'r'
+    /// ```
     #[track_caller]
-    fn contains_exactly_in_order<EI: Iterator<Item = T> + Clone>(self, expected_iter: EI) -> R
+    fn is_equal_to_iterator<EI: Iterator<Item = T>>(&self, expected_iter: EI) -> R
     where
         T: PartialEq + Debug;
 
@@ -158,6 +247,9 @@ where
     /// [contains_all_of_in_order](`IteratorAssertion::contains_all_of_in_order`) to check
     /// elements are in the same order.
     ///
+    /// An empty `expected_iter` passes for any subject, unless the subject
+    /// [rejects it](crate::Subject::reject_empty_expected).
+    ///
     /// # Example
     /// ```
     /// use assertor::*;
@@ -165,7 +257,7 @@ where
     /// assert_that!("foobarbaz".chars()).contains_all_of("bazbar".chars());
     /// ```
     #[track_caller]
-    fn contains_all_of<EI: Iterator<Item = T> + Clone>(self, expected_iter: EI) -> R
+    fn contains_all_of<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
     where
         T: PartialEq + Debug;
 
@@ -190,6 +282,26 @@ where
     where
         T: PartialEq + Debug;
 
+    /// Checks that the subject contains at least one element of `elements`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3].iter()).contains_any_of(vec![0, 3].iter());
+    /// assert_that!("foobarbaz".chars()).contains_any_of("xyz".chars());
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!("foo".chars()).contains_any_of("ab".chars());
+    /// // expected to contain any of: ['a', 'b']
+    /// // but did not
+    /// // though it did contain     : ['f', 'o', 'o']
+    /// ```
+    #[track_caller]
+    fn contains_any_of<EI: Iterator<Item = T> + Clone>(&self, elements: EI) -> R
+    where
+        T: PartialEq + Debug;
+
     /// Checks that the subject contains at least all elements of `expected_iter` in the same order.
     ///
     /// # Example
@@ -199,7 +311,54 @@ where
     /// assert_that!("foobarbaz".chars()).contains_all_of_in_order("obarb".chars());
     /// ```
     #[track_caller]
-    fn contains_all_of_in_order<EI: Iterator<Item = T> + Clone>(self, expected_iter: EI) -> R
+    fn contains_all_of_in_order<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug;
+
+    /// Checks that all elements of the subject occur in `expected_iter` in the same order, not
+    /// necessarily next to each other. This is the reverse of
+    /// [`IteratorAssertion::contains_all_of_in_order`], for a subject which is a sample of a
+    /// larger container.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 3].iter()).is_subsequence_of(vec![1, 2, 3].iter());
+    /// demo!(
+    ///     assert_that!("bza".chars()).is_subsequence_of("foobarbaz".chars()),
+    ///     r#"
+    ///     not found in order (1)         : ['a']
+    ///     ---
+    ///     expected to be a subsequence of: [ 'f', 'o', 'o', 'b', 'a', 'r', 'b', 'a', 'z' ]
+    ///     but was                        : [ 'b', 'z', 'a' ]
+    ///     "#
+    /// );
+    /// ```
+    #[track_caller]
+    fn is_subsequence_of<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug;
+
+    /// Checks that the elements of the subject are the first elements of `expected_iter`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2].iter()).is_prefix_of(vec![1, 2, 3].iter());
+    /// demo!(
+    ///     assert_that!(vec![1, 4].iter()).is_prefix_of(vec![1, 2, 3].iter()),
+    ///     r#"
+    ///     first difference at       : index 1
+    ///     expected                  : 2
+    ///     actual                    : 4
+    ///     ---
+    ///     expected to be a prefix of: [ 1, 2, 3 ]
+    ///     but was                   : [ 1, 4 ]
+    ///     "#
+    /// );
+    /// ```
+    #[track_caller]
+    fn is_prefix_of<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
     where
         T: PartialEq + Debug;
 
@@ -254,7 +413,7 @@ where
     /// ```should_panic
     /// use assertor::*;
     /// assert_that!(vec![1,2,3]).has_length(2);
-    /// // value of: vec![1,2,3].size()
+    /// // value of: vec![1,2,3].iter().size()
     /// // expected: 2
     /// // actual  : 3
     /// ```
@@ -262,178 +421,1597 @@ where
     fn has_length(&self, length: usize) -> R
     where
         T: Debug;
-}
 
-impl<'a, S, T, R> IteratorAssertion<'a, S, T, R> for Subject<'a, S, (), R>
-where
-    S: Iterator<Item = T> + Clone,
-    AssertionResult: AssertionStrategy<R>,
-{
-    fn contains<B>(&self, element: B) -> R
-    where
-        B: Borrow<T>,
-        T: PartialEq + Debug,
-    {
-        check_contains(self.new_result(), self.actual().clone(), element.borrow())
-    }
+    /// Checks that the subject has at least `length` elements.
+    ///
+    /// At most `length + 1` elements are consumed, so this works with infinite iterators.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1,2,3].iter()).has_length_at_least(3);
+    /// assert_that!((0..)).has_length_at_least(100);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(vec![1,2,3].iter()).has_length_at_least(4);
+    /// // value of: vec![1,2,3].iter().size()
+    /// // expected: at least 4
+    /// // actual  : 3
+    /// ```
+    #[track_caller]
+    fn has_length_at_least(&self, length: usize) -> R;
 
-    fn does_not_contain<B>(&self, element: B) -> R
-    where
-        B: Borrow<T>,
-        T: PartialEq + Debug,
-    {
-        check_does_not_contain(self.new_result(), self.actual().clone(), element.borrow())
-    }
+    /// Checks that the subject has at most `length` elements.
+    ///
+    /// At most `length + 1` elements are consumed, so this works with infinite iterators.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1,2,3].iter()).has_length_at_most(3);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!((0..)).has_length_at_most(3);
+    /// // value of: (0..).size()
+    /// // expected: at most 3
+    /// // actual  : more than 3
+    /// ```
+    #[track_caller]
+    fn has_length_at_most(&self, length: usize) -> R;
+
+    /// Checks that the subject has more than `length` elements.
+    ///
+    /// At most `length + 1` elements are consumed, so this works with infinite iterators.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1,2,3].iter()).has_length_greater_than(2);
+    /// assert_that!((0..)).has_length_greater_than(100);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(vec![1,2,3].iter()).has_length_greater_than(3);
+    /// // value of: vec![1,2,3].iter().size()
+    /// // expected: more than 3
+    /// // actual  : 3
+    /// ```
+    #[track_caller]
+    fn has_length_greater_than(&self, length: usize) -> R;
+
+    /// Checks that the subject has less than `length` elements.
+    ///
+    /// At most `length + 1` elements are consumed, so this works with infinite iterators.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1,2,3].iter()).has_length_less_than(4);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!((0..)).has_length_less_than(3);
+    /// // value of: (0..).size()
+    /// // expected: less than 3
+    /// // actual  : more than 3
+    /// ```
+    #[track_caller]
+    fn has_length_less_than(&self, length: usize) -> R;
+
+    /// Checks that the subject has at least `min` and at most `max` elements.
+    ///
+    /// At most `max + 1` elements are consumed, so this works with infinite iterators. A `min`
+    /// greater than `max` fails the assertion.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1,2,3].iter()).has_length_between(1, 3);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!((0..)).has_length_between(1, 3);
+    /// // value of: (0..).size()
+    /// // expected: between 1 and 3
+    /// // actual  : more than 3
+    /// ```
+    #[track_caller]
+    fn has_length_between(&self, min: usize, max: usize) -> R;
 
-    fn contains_exactly<EI: Iterator<Item = T> + Clone>(self, expected_iter: EI) -> R
+    /// Returns a new subject which iterates over the unique elements, in the order of their first
+    /// occurrence.
+    ///
+    /// Elements are compared with [`PartialEq`], so types without [`Hash`](std::hash::Hash) or
+    /// [`Eq`] are supported, at a quadratic cost in the number of elements. Elements which are not
+    /// equal to themselves, such as `f64::NAN`, are never deduplicated.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 1, 3, 2].iter()).distinct().has_length(3);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!("hello".chars()).distinct().has_length(5);
+    /// // value of: "hello".chars().distinct()
+    /// // value of: "hello".chars().distinct().size()
+    /// // expected: 5
+    /// // actual  : 4
+    /// ```
+    fn distinct(&self) -> Subject<'a, std::vec::IntoIter<T>, (), R>
     where
-        T: PartialEq + Debug,
-    {
-        let comparison = SequenceComparison::from_iter(
-            self.actual().clone(),
-            expected_iter.clone(),
-            SequenceOrderComparison::Strict,
-        );
-        if comparison.contains_exactly() {
-            self.new_result().do_ok()
-        } else {
-            feed_facts_about_item_diff(
-                self.new_result(),
-                &comparison,
-                self.actual().clone(),
-                expected_iter,
-            )
-            .do_fail()
-        }
-    }
+        T: PartialEq;
 
-    fn contains_exactly_in_order<EI: Iterator<Item = T> + Clone>(self, expected_iter: EI) -> R
+    /// Returns a new subject which maps each distinct element to its number of occurrences.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// let words = "the cat saw the dog".split(' ');
+    /// assert_that!(words).counted().contains_entry("the", 2);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!("hello".chars()).counted().contains_entry('l', 1);
+    /// // value of                              : "hello".chars().counted()
+    /// // expected key to be mapped to value    : 'l' ⟶ 1
+    /// // but key was mapped to a different value: 2
+    /// // ---
+    /// // though it did contain keys            : ['e', 'h', 'l', 'o']
+    /// ```
+    fn counted(&self) -> Subject<'a, BTreeMap<T, usize>, (), R>
     where
-        T: PartialEq + Debug,
-    {
-        let comparison = SequenceComparison::from_iter(
-            self.actual().clone(),
-            expected_iter.clone(),
-            SequenceOrderComparison::Strict,
-        );
-        let (result, ok) = check_contains_exactly_in_order(
-            comparison,
-            self.actual().clone(),
-            expected_iter,
-            self.new_result(),
-        );
-        if ok {
-            result.do_ok()
-        } else {
-            result.do_fail()
-        }
-    }
+        T: Ord;
 
-    fn contains_all_of<EI: Iterator<Item = T> + Clone>(self, expected_iter: EI) -> R
+    /// Checks that no two elements have the same key, e.g. the same id, as computed by `key`.
+    /// Keys are compared with [`PartialEq`] like [`IteratorAssertion::distinct`] does. On failure,
+    /// all elements sharing each duplicate key are listed with their indices.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([(1, "a"), (2, "a")].iter()).does_not_contain_duplicates_by_key(|e| e.0);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// let events = [(1, "created"), (2, "created"), (1, "deleted")];
+    /// assert_that!(events.iter()).does_not_contain_duplicates_by_key(|e| e.0);
+    /// // expected keys to be unique: but 1 key was shared
+    /// // ---
+    /// // elements with key 1       : [ [0]: (1, "created"), [2]: (1, "deleted") ]
+    /// ```
+    #[track_caller]
+    fn does_not_contain_duplicates_by_key<K, F>(&self, key: F) -> R
     where
-        T: PartialEq + Debug,
-    {
-        let comparison = SequenceComparison::from_iter(
-            self.actual().clone(),
-            expected_iter.clone(),
-            SequenceOrderComparison::Relative,
-        );
-        if comparison.contains_all() {
-            self.new_result().do_ok()
-        } else {
-            let missing = comparison.missing;
-            self.new_result()
-                .add_fact(
-                    format!("missing ({})", missing.len()),
-                    format!("{:?}", missing),
-                )
-                .add_splitter()
-                .add_formatted_values_fact("expected to contain at least", expected_iter.collect())
-                .add_formatted_values_fact("but was", self.actual().clone().collect())
-                // Idea: implement near_miss_obj
-                // .add_fact("tough it did contain", format!("{:?}", near_miss_obj))
-                .do_fail()
-        }
-    }
+        K: PartialEq + Debug,
+        F: Fn(&T) -> K,
+        T: Debug;
 
-    fn does_not_contain_any<EI: Iterator<Item = T> + Clone>(&self, elements: EI) -> R
+    /// Returns a new subject which is the sum of the elements.
+    ///
+    /// The aggregation methods take elements by value; call `.copied()` on iterators of
+    /// references.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3].into_iter()).sum().is_equal_to(6);
+    /// ```
+    fn sum(&self) -> Subject<'a, T, (), R>
     where
-        T: PartialEq + Debug,
-    {
-        let els = elements.clone().collect::<Vec<T>>();
-        // set-like intersection satisfies containment requirement for this case
-        // TODO: move to sequence comparison API instead of in-place computation
-        let intersection: Vec<T> = self
-            .actual()
-            .clone()
-            .filter(|el| els.contains(el))
-            .collect();
-        // handle empty iterables
-        if intersection.is_empty()
-            || self.actual().clone().next().is_none()
-            || elements.clone().next().is_none()
-        {
-            self.new_result().do_ok()
-        } else {
-            self.new_result()
-                .add_fact(
-                    format!("found ({})", intersection.len()),
-                    format!("{:?}", intersection),
-                )
-                .add_splitter()
-                .add_formatted_values_fact("expected to contain none of", elements.collect())
-                .add_formatted_values_fact("but was", self.actual().clone().collect())
-                .do_fail()
-        }
-    }
+        T: Sum;
 
-    fn contains_all_of_in_order<EI: Iterator<Item = T> + Clone>(self, expected_iter: EI) -> R
+    /// Returns a new subject which is the smallest element. Incomparable elements, such as
+    /// `f64::NAN`, are skipped unless first. Panics if the subject is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([3.0, 1.5, 2.0].iter().copied()).min().is_equal_to(1.5);
+    /// ```
+    #[track_caller]
+    fn min(&self) -> Subject<'a, T, (), R>
+    where
+        T: PartialOrd;
+
+    /// Returns a new subject which is the largest element. Incomparable elements, such as
+    /// `f64::NAN`, are skipped unless first. Panics if the subject is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([3.0, 1.5, 2.0].iter().copied()).max().is_equal_to(3.0);
+    /// ```
+    #[track_caller]
+    fn max(&self) -> Subject<'a, T, (), R>
+    where
+        T: PartialOrd;
+
+    /// Returns a new subject which is the arithmetic mean of the elements. Panics if the subject
+    /// is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 2, 6].iter().copied()).mean().is_less_than(5.0);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(Vec::<f64>::new().into_iter()).mean();
+    /// // expected to be non-empty to compute mean()
+    /// // but was empty
+    /// ```
+    #[track_caller]
+    fn mean(&self) -> Subject<'a, f64, (), R>
+    where
+        T: Into<f64>;
+
+    /// Returns a new subject which is the `p`-th percentile of the elements, interpolating
+    /// linearly between the closest ranks. Panics if the subject is empty or if `p` is not within
+    /// `0.0..=100.0`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// let latencies_ms = [12, 15, 11, 90, 14];
+    /// assert_that!(latencies_ms.into_iter()).percentile(50.0).is_equal_to(14.0);
+    /// assert_that!(latencies_ms.into_iter()).percentile(100.0).is_equal_to(90.0);
+    /// ```
+    #[track_caller]
+    fn percentile(&self, p: f64) -> Subject<'a, f64, (), R>
+    where
+        T: Into<f64>;
+
+    /// Returns a new subject which is the population standard deviation of the elements. Panics
+    /// if the subject is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([2, 4, 4, 4, 5, 5, 7, 9].into_iter()).std_dev().is_equal_to(2.0);
+    /// ```
+    #[track_caller]
+    fn std_dev(&self) -> Subject<'a, f64, (), R>
+    where
+        T: Into<f64>;
+
+    /// Checks that all elements are within `range`, listing the elements which are not.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([12, 15, 11].into_iter()).all_within(10..20);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!([12, 95, 11, 20].into_iter()).all_within(10..20);
+    /// // expected all elements within: 10..20
+    /// // found (2)                   : [95 (index 1), 20 (index 3)]
+    /// ```
+    #[track_caller]
+    fn all_within<B: RangeBounds<T> + Debug>(&self, range: B) -> R
+    where
+        T: PartialOrd + Debug;
+
+    /// Checks that all elements are `true`, listing the indices of those which are not.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([true, true].iter()).are_all_true();
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!([true, false, true, false].into_iter()).are_all_true();
+    /// // expected all elements to be: true
+    /// // but was false at indices    : [1, 3]
+    /// ```
+    #[track_caller]
+    fn are_all_true(&self) -> R
+    where
+        T: Borrow<bool>;
+
+    /// Checks that all elements are `false`, listing the indices of those which are not.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([false, false].into_iter()).are_all_false();
+    /// ```
+    #[track_caller]
+    fn are_all_false(&self) -> R
+    where
+        T: Borrow<bool>;
+
+    /// Checks that all elements satisfy `predicate`, listing the elements which do not with
+    /// their indices. `description` describes the predicate in failure messages.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([2, 4, 6].into_iter()).all_satisfy(|n| n % 2 == 0, "even");
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!([2, 3, 6, 7].into_iter()).all_satisfy(|n| n % 2 == 0, "even");
+    /// // expected all elements to satisfy: even
+    /// // found (2)                       : [3 (index 1), 7 (index 3)]
+    /// ```
+    #[track_caller]
+    fn all_satisfy<P: Fn(&T) -> bool>(&self, predicate: P, description: &str) -> R
+    where
+        T: Debug;
+
+    /// Checks that at least one element satisfies `predicate`, which is described as
+    /// `description` in the failure message.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([1, 4, 7].into_iter()).any_satisfy(|n| n % 2 == 0, "even");
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!([1, 3].into_iter()).any_satisfy(|n| n % 2 == 0, "even");
+    /// // expected any element to satisfy: even
+    /// // but none did
+    /// // though it did contain          : [ [0]: 1, [1]: 3 ]
+    /// ```
+    #[track_caller]
+    fn any_satisfy<P: Fn(&T) -> bool>(&self, predicate: P, description: &str) -> R
+    where
+        T: Debug;
+
+    /// Returns a new subject which yields the elements in chunks of `size` elements, as `Vec`s.
+    /// The last chunk is shorter if the number of elements is not a multiple of `size`. Panics
+    /// if `size` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// let batches = [vec![1, 2], vec![3]];
+    /// assert_that!(1..=250)
+    ///     .chunks(100)
+    ///     .all_satisfy(|chunk| chunk.len() <= 100, "batch size");
+    /// assert_that!(1..=3).chunks(2).contains_exactly_in_order(batches.into_iter());
+    /// ```
+    #[track_caller]
+    fn chunks(&self, size: usize) -> Subject<'a, IntoIter<Vec<T>>, (), R>;
+
+    /// Returns a new subject which yields all runs of `size` consecutive elements, as `Vec`s,
+    /// e.g. to detect patterns over neighboring elements. Windows are indexed by the position of
+    /// their first element in failure messages. Panics if `size` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!([3, 1, 2, 5].into_iter())
+    ///     .windows(3)
+    ///     .any_satisfy(|w| w[0] < w[1] && w[1] < w[2], "three increasing values");
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!([1, 2, 4, 3].into_iter())
+    ///     .windows(2)
+    ///     .all_satisfy(|w| w[0] <= w[1], "sorted pair");
+    /// // value of                        : [1, 2, 4, 3].into_iter().windows(2)
+    /// // expected all elements to satisfy: sorted pair
+    /// // found (1)                       : [[4, 3] (index 2)]
+    /// ```
+    #[track_caller]
+    fn windows(&self, size: usize) -> Subject<'a, IntoIter<Vec<T>>, (), R>
+    where
+        T: Clone;
+
+    /// Returns a new subject which yields the leading elements satisfying `predicate`, which is
+    /// described as `description` in failure messages, e.g. the events during startup.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(["boot", "boot", "ready", "boot"].into_iter())
+    ///     .taking_while(|e| *e == "boot", "startup")
+    ///     .has_length(2);
+    /// ```
+    #[track_caller]
+    fn taking_while<P: Fn(&T) -> bool>(
+        &self,
+        predicate: P,
+        description: &str,
+    ) -> Subject<'a, IntoIter<T>, (), R>;
+
+    /// Returns a new subject which yields the elements after the leading elements satisfying
+    /// `predicate`, which is described as `description` in failure messages, e.g. the events
+    /// after startup.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(["boot", "ready", "serve"].into_iter())
+    ///     .skipping_while(|e| *e == "boot", "startup")
+    ///     .contains_exactly_in_order(["ready", "serve"].into_iter());
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(["boot", "ready", "panic"].into_iter())
+    ///     .skipping_while(|e| *e == "boot", "startup")
+    ///     .does_not_contain("panic");
+    /// // value of               : ["boot", "ready", "panic"].into_iter().skipping_while(startup)
+    /// // expected to not contain: "panic"
+    /// // but element was found
+    /// // though it did contain  : ["ready", "panic"]
+    /// ```
+    #[track_caller]
+    fn skipping_while<P: Fn(&T) -> bool>(
+        &self,
+        predicate: P,
+        description: &str,
+    ) -> Subject<'a, IntoIter<T>, (), R>;
+
+    /// Returns a new subject which is the only element. Panics if the subject does not have
+    /// exactly one element.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// let results = vec![("job", 42)];
+    /// assert_that!(results.iter()).single().is_equal_to(&("job", 42));
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(1..=3).single();
+    /// // expected to have exactly one element
+    /// // but had              : 3 elements
+    /// // though it did contain: [ [0]: 1, [1]: 2, [2]: 3 ]
+    /// ```
+    #[track_caller]
+    fn single(&self) -> Subject<'a, T, (), R>
+    where
+        T: Debug;
+
+    /// Returns a new subject which is the first element. Panics if the subject is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("foobar".chars()).first().is_equal_to('f');
+    /// ```
+    #[track_caller]
+    fn first(&self) -> Subject<'a, T, (), R>;
+
+    /// Returns a new subject which is the last element. Panics if the subject is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("foobar".chars()).last().is_equal_to('r');
+    /// ```
+    #[track_caller]
+    fn last(&self) -> Subject<'a, T, (), R>;
+
+    /// Returns a new subject which is the index of the first element equal to `element`, as
+    /// `Some(index)`. Panics if no element is equal to `element`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(["a", "b", "a"].into_iter()).position_of("a").has_value(0);
+    /// assert_that!("foobar".chars()).position_of('b').has_value(3);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(1..=3).position_of(4);
+    /// // expected to contain  : 4
+    /// // but did not
+    /// // though it did contain: [ [0]: 1, [1]: 2, [2]: 3 ]
+    /// ```
+    #[track_caller]
+    fn position_of<B>(&self, element: B) -> Subject<'a, Option<usize>, (), R>
+    where
+        B: ElementLike<T>,
+        T: PartialEq + Debug;
+}
+
+impl<'a, S, T, R> IteratorAssertion<'a, S, T, R> for Subject<'a, S, (), R>
+where
+    S: Iterator<Item = T> + Clone,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn contains<B>(&self, element: B) -> R
+    where
+        B: ElementLike<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        check_contains(
+            self.new_result(),
+            actual_elements(self),
+            elements_ordered(self),
+            &element,
+        )
+    }
+
+    fn does_not_contain<B>(&self, element: B) -> R
+    where
+        B: ElementLike<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        check_does_not_contain(self.new_result(), actual_elements(self), &element)
+    }
+
+    fn contains_exactly<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
     where
         T: PartialEq + Debug,
     {
+        pass_if_skipped!(self);
+        if let Some(result) =
+            rejected_empty_expected(self, &expected_iter, "contains_exactly", false)
+        {
+            return result.do_fail();
+        }
+        if self.is_fail_fast() {
+            return match first_difference(self.new_result(), actual_elements(self), expected_iter) {
+                Some(result) => result.do_fail(),
+                None => self.new_result().do_ok(),
+            };
+        }
         let comparison = SequenceComparison::from_iter(
-            self.actual().clone(),
+            actual_elements(self),
             expected_iter.clone(),
-            SequenceOrderComparison::Relative,
+            SequenceOrderComparison::Strict,
         );
-        let (result, ok) = check_contains_all_of_in_order(
+        if comparison.contains_exactly() {
+            self.new_result().do_ok()
+        } else {
+            let result = feed_facts_about_item_diff(
+                self.new_result(),
+                &comparison,
+                actual_elements(self),
+                expected_iter.clone(),
+            );
+            if expected_iter.clone().next().is_none() {
+                result
+                    .add_note(EMPTY_EXPECTED_NOTE)
+                    .add_help(EMPTY_EXPECTED_HELP)
+                    .do_fail()
+            } else if comparison.contains_all() {
+                result
+                    .add_note("all expected elements were found")
+                    .add_help("use contains_all_of if other elements are allowed")
+                    .do_fail()
+            } else {
+                result.do_fail()
+            }
+        }
+    }
+
+    fn has_same_elements_as<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        check_has_same_elements(
+            self.new_result(),
+            actual_elements(self).collect(),
+            expected_iter.collect(),
+            false,
+        )
+    }
+
+    fn has_same_distinct_elements_as<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        check_has_same_elements(
+            self.new_result(),
+            actual_elements(self).collect(),
+            expected_iter.collect(),
+            true,
+        )
+    }
+
+    fn contains_exactly_in_order<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        if let Some(result) =
+            rejected_empty_expected(self, &expected_iter, "contains_exactly_in_order", false)
+        {
+            return result.do_fail();
+        }
+        if self.is_fail_fast() {
+            return match first_difference_in_order(
+                self.new_result(),
+                actual_elements(self),
+                expected_iter,
+            ) {
+                Some(result) => result.do_fail(),
+                None => self.new_result().do_ok(),
+            };
+        }
+        let comparison = SequenceComparison::from_iter(
+            actual_elements(self),
+            expected_iter.clone(),
+            SequenceOrderComparison::Strict,
+        );
+        let (result, ok) = check_contains_exactly_in_order(
             comparison,
-            self.actual().clone(),
-            expected_iter,
+            actual_elements(self),
+            expected_iter.clone(),
             self.new_result(),
         );
         if ok {
             result.do_ok()
+        } else if expected_iter.clone().next().is_none() {
+            result
+                .add_note(EMPTY_EXPECTED_NOTE)
+                .add_help(EMPTY_EXPECTED_HELP)
+                .do_fail()
         } else {
             result.do_fail()
         }
     }
 
-    fn is_empty(&self) -> R
+    fn is_equal_to_iterator<EI: Iterator<Item = T>>(&self, expected_iter: EI) -> R
     where
-        T: Debug,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        check_is_equal_to_iterator(self.new_result(), actual_elements(self), expected_iter)
+    }
+
+    fn contains_all_of<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug,
     {
-        check_is_empty(self.new_result(), self.actual().clone())
+        pass_if_skipped!(self);
+        if let Some(result) = rejected_empty_expected(self, &expected_iter, "contains_all_of", true)
+        {
+            return result.do_fail();
+        }
+        let comparison = SequenceComparison::from_iter(
+            actual_elements(self),
+            expected_iter.clone(),
+            SequenceOrderComparison::Relative,
+        );
+        if comparison.contains_all() {
+            self.new_result().do_ok()
+        } else {
+            let missing = comparison.missing;
+            self.new_result()
+                .add_formatted_fact(FactKey::MissingN(missing.len()), &missing)
+                .add_splitter()
+                .add_formatted_values_fact(
+                    FactKey::ExpectedToContainAtLeast,
+                    expected_iter.collect(),
+                )
+                .add_formatted_values_fact(FactKey::ButWas, actual_elements(self).collect())
+                // Idea: implement near_miss_obj
+                // .add_fact("tough it did contain", format!("{:?}", near_miss_obj))
+                .do_fail()
+        }
+    }
+
+    fn does_not_contain_any<EI: Iterator<Item = T> + Clone>(&self, elements: EI) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        if let Some(result) = rejected_empty_expected(self, &elements, "does_not_contain_any", true)
+        {
+            return result.do_fail();
+        }
+        let els = elements.clone().collect::<Vec<T>>();
+        // set-like intersection satisfies containment requirement for this case
+        // TODO: move to sequence comparison API instead of in-place computation
+        let intersection: Vec<T> = self
+            .actual()
+            .clone()
+            .filter(|el| els.contains(el))
+            .collect();
+        // handle empty iterables
+        if intersection.is_empty()
+            || actual_elements(self).next().is_none()
+            || elements.clone().next().is_none()
+        {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_formatted_fact(FactKey::FoundN(intersection.len()), &intersection)
+                .add_splitter()
+                .add_formatted_values_fact(FactKey::ExpectedToContainNoneOf, elements.collect())
+                .add_formatted_values_fact(FactKey::ButWas, actual_elements(self).collect())
+                .do_fail()
+        }
+    }
+
+    fn contains_any_of<EI: Iterator<Item = T> + Clone>(&self, elements: EI) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        check_contains_any_of(self.new_result(), actual_elements(self), elements)
+    }
+
+    fn contains_all_of_in_order<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        if let Some(result) =
+            rejected_empty_expected(self, &expected_iter, "contains_all_of_in_order", true)
+        {
+            return result.do_fail();
+        }
+        let comparison = SequenceComparison::from_iter(
+            actual_elements(self),
+            expected_iter.clone(),
+            SequenceOrderComparison::Relative,
+        );
+        let (result, ok) = check_contains_all_of_in_order(
+            comparison,
+            actual_elements(self),
+            expected_iter,
+            self.new_result(),
+        );
+        if ok {
+            result.do_ok()
+        } else {
+            result.do_fail()
+        }
+    }
+
+    fn is_subsequence_of<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        check_is_subsequence_of(self.new_result(), actual_elements(self), expected_iter)
+    }
+
+    fn is_prefix_of<EI: Iterator<Item = T> + Clone>(&self, expected_iter: EI) -> R
+    where
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        check_is_prefix_of(self.new_result(), actual_elements(self), expected_iter)
+    }
+
+    fn is_empty(&self) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_is_empty(self.new_result(), actual_elements(self))
+    }
+
+    fn is_not_empty(&self) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_is_not_empty(self.new_result(), actual_elements(self))
+    }
+
+    fn has_length(&self, length: usize) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_has_length(
+            self.new_result(),
+            actual_elements(self),
+            self.description_or_expr(),
+            length,
+        )
+    }
+
+    fn has_length_at_least(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_length_bound(
+            self.new_result(),
+            actual_elements(self),
+            self.description_or_expr(),
+            LengthBound::AtLeast(length),
+        )
+    }
+
+    fn has_length_at_most(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_length_bound(
+            self.new_result(),
+            actual_elements(self),
+            self.description_or_expr(),
+            LengthBound::AtMost(length),
+        )
+    }
+
+    fn has_length_greater_than(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_length_bound(
+            self.new_result(),
+            actual_elements(self),
+            self.description_or_expr(),
+            LengthBound::GreaterThan(length),
+        )
+    }
+
+    fn has_length_less_than(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_length_bound(
+            self.new_result(),
+            actual_elements(self),
+            self.description_or_expr(),
+            LengthBound::LessThan(length),
+        )
+    }
+
+    fn has_length_between(&self, min: usize, max: usize) -> R {
+        pass_if_skipped!(self);
+        check_length_bound(
+            self.new_result(),
+            actual_elements(self),
+            self.description_or_expr(),
+            LengthBound::Between(min, max),
+        )
+    }
+
+    fn distinct(&self) -> Subject<'a, std::vec::IntoIter<T>, (), R>
+    where
+        T: PartialEq,
+    {
+        self.new_owned_subject(
+            distinct(actual_elements(self)).into_iter(),
+            Some(format!("{}.distinct()", self.description_or_expr())),
+            (),
+        )
+    }
+
+    fn counted(&self) -> Subject<'a, BTreeMap<T, usize>, (), R>
+    where
+        T: Ord,
+    {
+        self.new_owned_subject(
+            counted(actual_elements(self)),
+            Some(format!("{}.counted()", self.description_or_expr())),
+            (),
+        )
+    }
+
+    fn does_not_contain_duplicates_by_key<K, F>(&self, key: F) -> R
+    where
+        K: PartialEq + Debug,
+        F: Fn(&T) -> K,
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        // Indices of the elements of each key, in the order of first occurrence.
+        let mut groups: Vec<(K, Vec<(usize, T)>)> = vec![];
+        for (index, element) in actual_elements(self).enumerate() {
+            let element_key = key(&element);
+            match groups.iter_mut().find(|(k, _)| k.eq(&element_key)) {
+                Some((_, elements)) => elements.push((index, element)),
+                None => groups.push((element_key, vec![(index, element)])),
+            }
+        }
+        let duplicates: Vec<_> = groups
+            .into_iter()
+            .filter(|(_, elements)| elements.len() > 1)
+            .collect();
+        if duplicates.is_empty() {
+            return self.new_result().do_ok();
+        }
+        let count = duplicates.len();
+        let result = self
+            .new_result()
+            .add_fact(
+                "expected keys to be unique",
+                format!(
+                    "but {} {} shared",
+                    count,
+                    if count == 1 { "key was" } else { "keys were" }
+                ),
+            )
+            .add_splitter();
+        duplicates
+            .into_iter()
+            .fold(result, |result, (key, elements)| {
+                result.add_formatted_values_fact(
+                    format!("elements with key {:?}", key),
+                    elements
+                        .iter()
+                        .map(|(index, element)| Described(format!("[{}]: {:?}", index, element)))
+                        .collect(),
+                )
+            })
+            .do_fail()
+    }
+
+    fn sum(&self) -> Subject<'a, T, (), R>
+    where
+        T: Sum,
+    {
+        aggregated(self, Some(actual_elements(self).sum()), "sum()")
+    }
+
+    fn min(&self) -> Subject<'a, T, (), R>
+    where
+        T: PartialOrd,
+    {
+        aggregated(
+            self,
+            extreme(actual_elements(self), Ordering::Less),
+            "min()",
+        )
+    }
+
+    fn max(&self) -> Subject<'a, T, (), R>
+    where
+        T: PartialOrd,
+    {
+        aggregated(
+            self,
+            extreme(actual_elements(self), Ordering::Greater),
+            "max()",
+        )
+    }
+
+    fn mean(&self) -> Subject<'a, f64, (), R>
+    where
+        T: Into<f64>,
+    {
+        aggregated(self, mean(actual_elements(self)), "mean()")
+    }
+
+    fn percentile(&self, p: f64) -> Subject<'a, f64, (), R>
+    where
+        T: Into<f64>,
+    {
+        let value = percentile(self, actual_elements(self), p);
+        aggregated(self, value, &format!("percentile({:?})", p))
+    }
+
+    fn std_dev(&self) -> Subject<'a, f64, (), R>
+    where
+        T: Into<f64>,
+    {
+        aggregated(self, std_dev(actual_elements(self)), "std_dev()")
+    }
+
+    fn all_within<B: RangeBounds<T> + Debug>(&self, range: B) -> R
+    where
+        T: PartialOrd + Debug,
+    {
+        pass_if_skipped!(self);
+        check_all_within::<_, _, T, _, _>(self.new_result(), actual_elements(self), &range)
+    }
+
+    fn are_all_true(&self) -> R
+    where
+        T: Borrow<bool>,
+    {
+        pass_if_skipped!(self);
+        check_all_equal_to(self.new_result(), actual_elements(self), true)
+    }
+
+    fn are_all_false(&self) -> R
+    where
+        T: Borrow<bool>,
+    {
+        pass_if_skipped!(self);
+        check_all_equal_to(self.new_result(), actual_elements(self), false)
+    }
+
+    fn all_satisfy<P: Fn(&T) -> bool>(&self, predicate: P, description: &str) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_all_satisfy(
+            self.new_result(),
+            actual_elements(self),
+            predicate,
+            description,
+        )
+    }
+
+    fn any_satisfy<P: Fn(&T) -> bool>(&self, predicate: P, description: &str) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_any_satisfy(
+            self.new_result(),
+            actual_elements(self),
+            predicate,
+            description,
+        )
+    }
+
+    fn chunks(&self, size: usize) -> Subject<'a, IntoIter<Vec<T>>, (), R> {
+        derived_chunks(self, actual_elements(self), size)
+    }
+
+    fn windows(&self, size: usize) -> Subject<'a, IntoIter<Vec<T>>, (), R>
+    where
+        T: Clone,
+    {
+        derived_windows(self, actual_elements(self), size)
+    }
+
+    fn taking_while<P: Fn(&T) -> bool>(
+        &self,
+        predicate: P,
+        description: &str,
+    ) -> Subject<'a, IntoIter<T>, (), R> {
+        let elements = actual_elements(self).take_while(|el| predicate(el));
+        derived_region(self, elements, "taking_while", description)
+    }
+
+    fn skipping_while<P: Fn(&T) -> bool>(
+        &self,
+        predicate: P,
+        description: &str,
+    ) -> Subject<'a, IntoIter<T>, (), R> {
+        let elements = actual_elements(self).skip_while(|el| predicate(el));
+        derived_region(self, elements, "skipping_while", description)
+    }
+
+    fn single(&self) -> Subject<'a, T, (), R>
+    where
+        T: Debug,
+    {
+        let mut elements: Vec<T> = actual_elements(self).collect();
+        if elements.len() == 1 {
+            return self.new_owned_subject(
+                elements.remove(0),
+                Some(format!("{}.single()", self.description_or_expr())),
+                (),
+            );
+        }
+        // Like `aggregated`, a derived subject can not be returned, so panic.
+        let mut result: AssertionResult = self
+            .new_result()
+            .add_simple_fact("expected to have exactly one element")
+            .add_fact("but had", format!("{} elements", elements.len()));
+        if !elements.is_empty() {
+            result = result.add_formatted_values_fact(FactKey::ThoughItDidContain, elements);
+        }
+        result.fail_derivation()
+    }
+
+    fn first(&self) -> Subject<'a, T, (), R> {
+        aggregated(self, actual_elements(self).next(), "first()")
+    }
+
+    fn last(&self) -> Subject<'a, T, (), R> {
+        aggregated(self, actual_elements(self).last(), "last()")
+    }
+
+    fn position_of<B>(&self, element: B) -> Subject<'a, Option<usize>, (), R>
+    where
+        B: ElementLike<T>,
+        T: PartialEq + Debug,
+    {
+        let position = actual_elements(self).position(|x| element.matches(&x));
+        if position.is_some() {
+            return self.new_owned_subject(
+                position,
+                Some(format!(
+                    "{}.position_of({:?})",
+                    self.description_or_expr(),
+                    element
+                )),
+                (),
+            );
+        }
+        // Like `single`, a derived subject can not be returned, so panic.
+        check_contains::<_, _, _, ()>(
+            self.new_result().unskippable(),
+            actual_elements(self),
+            elements_ordered(self),
+            &element,
+        );
+        unreachable!()
+    }
+}
+
+/// How many elements of an iterator subject are evaluated, unless set by
+/// [`Subject::max_evaluated_elements`].
+const MAX_EVALUATED_ELEMENTS: usize = 1_000_000;
+
+/// Returns the elements of an iterator subject, which fail the assertion once more elements are
+/// evaluated than [`Subject::max_evaluated_elements`]. Iterators with an upper bound on their
+/// length, such as those of collections, are not capped.
+/// Whether the elements of an iterator subject are listed in their own order in failures. Iterators
+/// of the hash-based containers of `std` and `hashbrown` are recognized by their type names, which
+/// only decide the order of listed elements.
+fn elements_ordered<S, R>(subject: &Subject<S, (), R>) -> bool {
+    let type_name = std::any::type_name::<S>();
+    let hashed =
+        type_name.starts_with("std::collections::hash::") || type_name.starts_with("hashbrown::");
+    !hashed && !subject.has_unordered_elements()
+}
+
+fn actual_elements<S: Iterator + Clone, R>(subject: &Subject<S, (), R>) -> Capped<S> {
+    let inner = subject.actual().clone();
+    let cap = match inner.size_hint() {
+        (_, Some(_)) => None,
+        (_, None) => Some(subject.max_evaluated().unwrap_or(MAX_EVALUATED_ELEMENTS)),
+    };
+    Capped {
+        inner,
+        evaluated: 0,
+        cap,
+        result: Rc::new(subject.new_result()),
+    }
+}
+
+/// An iterator failing the assertion with `result` when it yields more than `cap` elements.
+#[derive(Clone)]
+struct Capped<I> {
+    inner: I,
+    evaluated: usize,
+    cap: Option<usize>,
+    result: Rc<AssertionResult>,
+}
+
+impl<I: Iterator> Iterator for Capped<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.inner.next()?;
+        let cap = match self.cap {
+            None => return Some(item),
+            Some(cap) => cap,
+        };
+        self.evaluated += 1;
+        if self.evaluated > cap {
+            let result = AssertionResult::clone(&self.result)
+                .add_simple_fact("expected the iterator to end")
+                .add_fact("but it yielded more than", format!("{} elements", cap))
+                .add_help(
+                    "bound the subject with take(), or raise the cap with max_evaluated_elements()",
+                );
+            AssertionStrategy::<()>::do_fail(result);
+            // Failures of skipped subjects are ignored, so the remaining elements are cut off.
+            return None;
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.cap {
+            None => self.inner.size_hint(),
+            // Capped iterators may end early, and have no upper bound.
+            Some(_) => (0, None),
+        }
+    }
+}
+
+/// Returns the element which compares as `ordering` to all elements before it, keeping the
+/// earlier element when incomparable.
+pub(crate) fn extreme<I, T>(actual_iter: I, ordering: Ordering) -> Option<T>
+where
+    I: Iterator<Item = T>,
+    T: PartialOrd,
+{
+    actual_iter.reduce(|acc, el| {
+        if el.partial_cmp(&acc) == Some(ordering) {
+            el
+        } else {
+            acc
+        }
+    })
+}
+
+/// Returns the arithmetic mean of the elements, or `None` if there are none.
+pub(crate) fn mean<I, T>(actual_iter: I) -> Option<f64>
+where
+    I: Iterator<Item = T>,
+    T: Into<f64>,
+{
+    let (sum, count) = actual_iter.fold((0.0, 0_usize), |(sum, count), el| {
+        (sum + el.into(), count + 1)
+    });
+    if count == 0 {
+        None
+    } else {
+        Some(sum / count as f64)
+    }
+}
+
+/// Returns the `p`-th percentile of the elements, or `None` if there are none. Fails if `p` is not
+/// within `0.0..=100.0`.
+#[track_caller]
+pub(crate) fn percentile<S, I, T, R>(
+    subject: &Subject<S, (), R>,
+    actual_iter: I,
+    p: f64,
+) -> Option<f64>
+where
+    I: Iterator<Item = T>,
+    T: Into<f64>,
+{
+    if !(0.0..=100.0).contains(&p) {
+        // Like `ResultAssertion::ok`, a derived subject can not be returned, so panic.
+        let result: AssertionResult = subject
+            .new_result()
+            .add_fact("invalid percentile", format!("{:?}", p))
+            .add_simple_fact("percentile must be within 0.0..=100.0");
+        result.fail_derivation()
+    }
+    let mut values: Vec<f64> = actual_iter.map(Into::into).collect();
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(f64::total_cmp);
+    let rank = p / 100.0 * (values.len() - 1) as f64;
+    let (lower, upper) = (values[rank.floor() as usize], values[rank.ceil() as usize]);
+    Some(lower + (upper - lower) * rank.fract())
+}
+
+/// Returns the population standard deviation of the elements, or `None` if there are none.
+pub(crate) fn std_dev<I, T>(actual_iter: I) -> Option<f64>
+where
+    I: Iterator<Item = T>,
+    T: Into<f64>,
+{
+    let values: Vec<f64> = actual_iter.map(Into::into).collect();
+    let mean = mean(values.iter().copied())?;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64;
+    Some(variance.sqrt())
+}
+
+pub(crate) fn check_all_within<I, T, E, B, R>(
+    assertion_result: AssertionResult,
+    actual_iter: I,
+    range: &B,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    I: Iterator<Item = T>,
+    T: Borrow<E> + Debug,
+    E: PartialOrd,
+    B: RangeBounds<E> + Debug,
+{
+    let outside: Vec<Indexed<T>> = actual_iter
+        .enumerate()
+        .filter(|(_, el)| !range.contains(el.borrow()))
+        .map(|(index, value)| Indexed { index, value })
+        .collect();
+    if outside.is_empty() {
+        assertion_result.do_ok()
+    } else {
+        assertion_result
+            .add_formatted_fact("expected all elements within", range)
+            .add_formatted_values_fact(FactKey::FoundN(outside.len()), outside)
+            .do_fail()
+    }
+}
+
+pub(crate) fn check_all_equal_to<I, T, R>(
+    assertion_result: AssertionResult,
+    actual_iter: I,
+    expected: bool,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    I: Iterator<Item = T>,
+    T: Borrow<bool>,
+{
+    let violations: Vec<usize> = actual_iter
+        .enumerate()
+        .filter(|(_, el)| *el.borrow() != expected)
+        .map(|(index, _)| index)
+        .collect();
+    if violations.is_empty() {
+        assertion_result.do_ok()
+    } else {
+        assertion_result
+            .add_fact("expected all elements to be", expected.to_string())
+            .add_formatted_fact(format!("but was {} at indices", !expected), violations)
+            .do_fail()
+    }
+}
+
+pub(crate) fn check_all_satisfy<I, T, P, R>(
+    assertion_result: AssertionResult,
+    actual_iter: I,
+    predicate: P,
+    description: &str,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    I: Iterator<Item = T>,
+    T: Debug,
+    P: Fn(&T) -> bool,
+{
+    let violations: Vec<Indexed<T>> = actual_iter
+        .enumerate()
+        .filter(|(_, el)| !predicate(el))
+        .map(|(index, value)| Indexed { index, value })
+        .collect();
+    if violations.is_empty() {
+        assertion_result.do_ok()
+    } else {
+        assertion_result
+            .add_fact("expected all elements to satisfy", description)
+            .add_formatted_values_fact(FactKey::FoundN(violations.len()), violations)
+            .do_fail()
+    }
+}
+
+/// Checks that `actual` and `expected` have the same elements in any order, counting duplicates
+/// unless `distinct`.
+pub(crate) fn check_has_same_elements<T, R>(
+    assertion_result: AssertionResult,
+    mut actual: Vec<T>,
+    mut expected: Vec<T>,
+    distinct: bool,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    T: PartialEq + Debug,
+{
+    if distinct {
+        dedup_unordered(&mut actual);
+        dedup_unordered(&mut expected);
+    }
+    let mut matched = vec![false; expected.len()];
+    let mut only_in_actual = vec![];
+    for element in actual {
+        match (0..expected.len()).find(|&i| !matched[i] && expected[i] == element) {
+            Some(i) => matched[i] = true,
+            None => only_in_actual.push(element),
+        }
+    }
+    let in_both = matched.iter().filter(|&&m| m).count();
+    let only_in_expected: Vec<T> = expected
+        .into_iter()
+        .zip(matched)
+        .filter(|(_, matched)| !matched)
+        .map(|(element, _)| element)
+        .collect();
+    if only_in_actual.is_empty() && only_in_expected.is_empty() {
+        return assertion_result.do_ok();
+    }
+
+    let mut result = assertion_result.add_simple_fact(if distinct {
+        "expected the same distinct elements, ignoring order"
+    } else {
+        "expected the same elements, ignoring order"
+    });
+    if !only_in_actual.is_empty() {
+        result = result.add_capped_values_fact(
+            format!("only in actual ({})", only_in_actual.len()),
+            only_in_actual,
+        );
+    }
+    if !only_in_expected.is_empty() {
+        result = result.add_capped_values_fact(
+            format!("only in expected ({})", only_in_expected.len()),
+            only_in_expected,
+        );
+    }
+    result.add_formatted_fact("in both", in_both).do_fail()
+}
+
+/// Removes the elements equal to an earlier one, keeping the first occurrences in order.
+fn dedup_unordered<T: PartialEq>(elements: &mut Vec<T>) {
+    let mut index = 0;
+    while index < elements.len() {
+        if elements[..index].contains(&elements[index]) {
+            elements.remove(index);
+        } else {
+            index += 1;
+        }
+    }
+}
+
+pub(crate) fn check_any_satisfy<I, T, P, R>(
+    assertion_result: AssertionResult,
+    mut actual_iter: I,
+    predicate: P,
+    description: &str,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    I: Iterator<Item = T> + Clone,
+    T: Debug,
+    P: Fn(&T) -> bool,
+{
+    if actual_iter.clone().any(|el| predicate(&el)) {
+        return assertion_result.do_ok();
+    }
+    let result = assertion_result
+        .add_fact("expected any element to satisfy", description)
+        .add_simple_fact("but none did");
+    match actual_iter.next() {
+        Some(first) => result
+            .add_formatted_values_fact(
+                FactKey::ThoughItDidContain,
+                std::iter::once(first).chain(actual_iter).collect(),
+            )
+            .do_fail(),
+        None => result.do_fail(),
+    }
+}
+
+/// Derives a subject yielding all windows of `size` consecutive elements of `actual_iter`.
+#[track_caller]
+pub(crate) fn derived_windows<'a, S, I, T, R>(
+    subject: &Subject<'a, S, (), R>,
+    actual_iter: I,
+    size: usize,
+) -> Subject<'a, IntoIter<Vec<T>>, (), R>
+where
+    I: Iterator<Item = T>,
+    T: Clone,
+{
+    assert!(size != 0, "window size must be non-zero");
+    let elements: Vec<T> = actual_iter.collect();
+    let windows: Vec<Vec<T>> = elements.windows(size).map(<[T]>::to_vec).collect();
+    subject.new_owned_subject(
+        windows.into_iter(),
+        Some(format!(
+            "{}.windows({})",
+            subject.description_or_expr(),
+            size
+        )),
+        (),
+    )
+}
+
+/// Derives a subject yielding the elements of `actual_iter` in chunks of `size` elements.
+#[track_caller]
+pub(crate) fn derived_chunks<'a, S, I, T, R>(
+    subject: &Subject<'a, S, (), R>,
+    actual_iter: I,
+    size: usize,
+) -> Subject<'a, IntoIter<Vec<T>>, (), R>
+where
+    I: Iterator<Item = T>,
+{
+    assert!(size != 0, "chunk size must be non-zero");
+    let mut chunks: Vec<Vec<T>> = vec![];
+    for element in actual_iter {
+        match chunks.last_mut() {
+            Some(chunk) if chunk.len() < size => chunk.push(element),
+            _ => chunks.push(vec![element]),
+        }
+    }
+    subject.new_owned_subject(
+        chunks.into_iter(),
+        Some(format!(
+            "{}.chunks({})",
+            subject.description_or_expr(),
+            size
+        )),
+        (),
+    )
+}
+
+/// Derives a subject yielding `elements`, a region of the subject described as
+/// `<subject>.<method>(<description>)`.
+pub(crate) fn derived_region<'a, S, I, T, R>(
+    subject: &Subject<'a, S, (), R>,
+    elements: I,
+    method: &str,
+    description: &str,
+) -> Subject<'a, IntoIter<T>, (), R>
+where
+    I: Iterator<Item = T>,
+{
+    let elements: Vec<T> = elements.collect();
+    subject.new_owned_subject(
+        elements.into_iter(),
+        Some(format!(
+            "{}.{}({})",
+            subject.description_or_expr(),
+            method,
+            description
+        )),
+        (),
+    )
+}
+
+/// An element rendered with its position, as `value (index i)`.
+struct Indexed<T> {
+    index: usize,
+    value: T,
+}
+
+impl<T: Debug> Debug for Indexed<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?} (index {})", self.value, self.index)
+    }
+}
+
+const MAX_LISTED_MISMATCHES: usize = 10;
+
+/// A position where two sequences differ, rendered as `index | expected | actual`.
+struct Mismatch<T> {
+    index: usize,
+    expected: T,
+    actual: T,
+}
+
+impl<T: Debug> Debug for Mismatch<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} | {:?} | {:?}",
+            self.index, self.expected, self.actual
+        )
+    }
+}
+
+/// Shows an already formatted value as is in `Debug` facts.
+pub(crate) struct Described(pub(crate) String);
+
+impl Debug for Described {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Derives a subject described as `<subject>.<method>`, or fails if the aggregated subject was
+/// empty.
+#[track_caller]
+pub(crate) fn aggregated<'a, S, V, R>(
+    subject: &Subject<'a, S, (), R>,
+    value: Option<V>,
+    method: &str,
+) -> Subject<'a, V, (), R> {
+    match value {
+        Some(value) => subject.new_owned_subject(
+            value,
+            Some(format!("{}.{}", subject.description_or_expr(), method)),
+            (),
+        ),
+        None => {
+            // Like `ResultAssertion::ok`, a derived subject can not be returned, so panic.
+            let result: AssertionResult = subject
+                .new_result()
+                .add_simple_fact(format!("expected to be non-empty to compute {}", method))
+                .add_simple_fact("but was empty");
+            result.fail_derivation()
+        }
     }
+}
 
-    fn is_not_empty(&self) -> R
-    where
-        T: Debug,
-    {
-        check_is_not_empty(self.new_result(), self.actual().clone())
+/// Collects the unique elements, in the order of their first occurrence.
+pub(crate) fn distinct<I, T>(actual_iter: I) -> Vec<T>
+where
+    I: Iterator<Item = T>,
+    T: PartialEq,
+{
+    let mut unique: Vec<T> = vec![];
+    for element in actual_iter {
+        if !unique.contains(&element) {
+            unique.push(element);
+        }
     }
+    unique
+}
 
-    fn has_length(&self, length: usize) -> R
-    where
-        T: Debug,
-    {
-        check_has_length(
-            self.new_result(),
-            self.actual().clone(),
-            self.expr(),
-            length,
-        )
+/// Counts the occurrences of each element.
+pub(crate) fn counted<I, T>(actual_iter: I) -> BTreeMap<T, usize>
+where
+    I: Iterator<Item = T>,
+    T: Ord,
+{
+    let mut counts = BTreeMap::new();
+    for element in actual_iter {
+        *counts.entry(element).or_insert(0) += 1;
     }
+    counts
 }
 
 pub(crate) fn check_is_empty<I, T, R>(assertion_result: AssertionResult, actual_iter: I) -> R
@@ -448,7 +2026,7 @@ where
         assertion_result
             .add_simple_fact("expected to be empty")
             .add_splitter()
-            .add_formatted_values_fact("actual", actual_iter.collect())
+            .add_formatted_values_fact(FactKey::Actual, actual_iter.collect())
             .do_fail()
     }
 }
@@ -463,35 +2041,81 @@ where
         assertion_result
             .add_simple_fact("expected to be non-empty")
             .add_splitter()
-            .add_fact("actual", format!("{:?}", actual_iter.collect::<Vec<_>>()))
+            .add_formatted_fact(FactKey::Actual, actual_iter.collect::<Vec<_>>())
             .do_fail()
     } else {
         assertion_result.do_ok()
     }
 }
 
-pub(crate) fn check_contains<I, T, R>(
+/// Values which can be compared with elements of type `T`: an element, a reference to an
+/// element, or the value referenced by an element. This lets e.g. both `contains(2)` and
+/// `contains(&2)` check iterators of `i32` as well as iterators of `&i32`.
+pub trait ElementLike<T>: Debug {
+    /// Returns whether `element` is equal to this value.
+    fn matches(&self, element: &T) -> bool;
+}
+
+impl<T: PartialEq + Debug> ElementLike<T> for T {
+    fn matches(&self, element: &T) -> bool {
+        element.eq(self)
+    }
+}
+
+impl<T: PartialEq + Debug> ElementLike<T> for &T {
+    fn matches(&self, element: &T) -> bool {
+        element.eq(*self)
+    }
+}
+
+impl<'a, T: PartialEq + Debug> ElementLike<&'a T> for T {
+    fn matches(&self, element: &&'a T) -> bool {
+        (*element).eq(self)
+    }
+}
+
+/// Collects `elements` to be listed in a failure fact. Unless `ordered`, they are sorted by their
+/// `Debug` representation, so that messages on hash-based containers are stable across runs.
+pub(crate) fn listed_in_stable_order<T: Debug>(
+    elements: impl Iterator<Item = T>,
+    ordered: bool,
+) -> Vec<T> {
+    let mut elements: Vec<T> = elements.collect();
+    if !ordered {
+        elements.sort_by_cached_key(|element| format!("{:?}", element));
+    }
+    elements
+}
+
+pub(crate) fn check_contains<I, T, B, R>(
     assertion_result: AssertionResult,
     actual_iter: I,
-    element: &T,
+    ordered: bool,
+    element: &B,
 ) -> R
 where
     AssertionResult: AssertionStrategy<R>,
     I: Iterator<Item = T> + Clone,
     T: PartialEq + Debug,
+    B: ElementLike<T>,
 {
-    if actual_iter.clone().any(|x| x.eq(element.borrow())) {
+    if actual_iter.clone().any(|x| element.matches(&x)) {
         assertion_result.do_ok()
     } else {
         assertion_result
-            .add_fact("expected to contain", format!("{:?}", element))
+            .add_formatted_fact(FactKey::ExpectedToContain, element)
             .add_simple_fact("but did not")
-            .add_formatted_values_fact("though it did contain", actual_iter.clone().collect())
+            .add_formatted_values_fact(
+                FactKey::ThoughItDidContain,
+                listed_in_stable_order(actual_iter.clone(), ordered),
+            )
             .do_fail()
     }
 }
 
-pub(crate) fn check_does_not_contain<I, T, R>(
+/// Same as [`check_contains`], but also suggests elements similar to a missing string-like
+/// `element` in a `did you mean` fact. Used for keys of maps and elements of sets.
+pub(crate) fn check_contains_or_suggest<I, T, R>(
     assertion_result: AssertionResult,
     actual_iter: I,
     element: &T,
@@ -502,16 +2126,106 @@ where
     T: PartialEq + Debug,
 {
     if actual_iter.clone().any(|x| x.eq(element.borrow())) {
+        return assertion_result.do_ok();
+    }
+    let actual: Vec<T> = actual_iter.collect();
+    let similar: Vec<&T> = similar_strings(element, &actual)
+        .into_iter()
+        .map(|index| &actual[index])
+        .collect();
+    let result = assertion_result
+        .add_formatted_fact(FactKey::ExpectedToContain, element)
+        .add_simple_fact("but did not");
+    let result = if similar.is_empty() {
+        result
+    } else {
+        result.add_formatted_values_fact(FactKey::DidYouMean, similar)
+    };
+    result
+        .add_formatted_values_fact(FactKey::ThoughItDidContain, actual)
+        .do_fail()
+}
+
+pub(crate) fn check_does_not_contain<I, T, B, R>(
+    assertion_result: AssertionResult,
+    actual_iter: I,
+    element: &B,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    I: Iterator<Item = T> + Clone,
+    T: PartialEq + Debug,
+    B: ElementLike<T>,
+{
+    if actual_iter.clone().any(|x| element.matches(&x)) {
         assertion_result
-            .add_fact("expected to not contain", format!("{:?}", element))
+            .add_formatted_fact(FactKey::ExpectedToNotContain, element)
             .add_simple_fact("but element was found")
-            .add_formatted_values_fact("though it did contain", actual_iter.clone().collect())
+            .add_formatted_values_fact(FactKey::ThoughItDidContain, actual_iter.clone().collect())
             .do_fail()
     } else {
         assertion_result.do_ok()
     }
 }
 
+pub(crate) fn check_is_equal_to_iterator<I, EI, T, R>(
+    assertion_result: AssertionResult,
+    mut actual_iter: I,
+    mut expected_iter: EI,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    I: Iterator<Item = T>,
+    EI: Iterator<Item = T>,
+    T: PartialEq + Debug,
+{
+    let mut index = 0;
+    let (expected, actual) = loop {
+        match (expected_iter.next(), actual_iter.next()) {
+            (None, None) => return assertion_result.do_ok(),
+            (Some(expected), Some(actual)) if expected.eq(&actual) => index += 1,
+            mismatch => break mismatch,
+        }
+    };
+    let result = assertion_result.add_fact("first mismatch at index", index.to_string());
+    let result = match expected {
+        Some(expected) => result.add_formatted_fact(FactKey::Expected, expected),
+        None => result.add_simple_fact("expected no more elements"),
+    };
+    match actual {
+        Some(actual) => result.add_formatted_fact(FactKey::ButWas, actual),
+        None => result.add_simple_fact("but the subject had no more elements"),
+    }
+    .do_fail()
+}
+
+pub(crate) fn check_contains_any_of<I, EI, T, R>(
+    assertion_result: AssertionResult,
+    actual_iter: I,
+    elements: EI,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    I: Iterator<Item = T> + Clone,
+    EI: Iterator<Item = T> + Clone,
+    T: PartialEq + Debug,
+{
+    let candidates: Vec<T> = elements.collect();
+    if actual_iter.clone().any(|el| candidates.contains(&el)) {
+        return assertion_result.do_ok();
+    }
+    let no_candidates = candidates.is_empty();
+    let result = assertion_result
+        .add_formatted_values_fact(FactKey::ExpectedToContainAnyOf, candidates)
+        .add_simple_fact("but did not")
+        .add_formatted_values_fact(FactKey::ThoughItDidContain, actual_iter.collect());
+    if no_candidates {
+        result.add_note(EMPTY_EXPECTED_NOTE).do_fail()
+    } else {
+        result.do_fail()
+    }
+}
+
 pub(crate) fn check_contains_exactly_in_order<T, I, EI, R>(
     comparison: SequenceComparison<T>,
     actual: I,
@@ -531,8 +2245,14 @@ where
             assertion_result
                 .add_simple_fact("contents match, but order was wrong")
                 .add_splitter()
-                .add_formatted_values_fact("expected", expected_iter.collect())
-                .add_formatted_values_fact("actual", actual.collect()),
+                .add_formatted_values_fact(FactKey::Expected, expected_iter.collect())
+                .add_formatted_values_fact(FactKey::Actual, actual.collect())
+                .add_help("use contains_exactly if the order does not matter"),
+            false,
+        )
+    } else if actual.clone().count() == expected_iter.clone().count() {
+        (
+            feed_facts_about_index_mismatches(assertion_result, actual, expected_iter),
             false,
         )
     } else {
@@ -543,6 +2263,110 @@ where
     }
 }
 
+/// The note of failures comparing with an empty expected collection.
+pub(crate) const EMPTY_EXPECTED_NOTE: &str = "expected collection is empty";
+
+/// The help of failures of `contains_exactly` with an empty expected collection.
+pub(crate) const EMPTY_EXPECTED_HELP: &str =
+    "use is_empty to check that the subject has no elements";
+
+/// Returns the failure of `method` with an empty `expected` collection on a subject which
+/// [rejects them](Subject::reject_empty_expected), or `None` otherwise. `vacuous` tells whether
+/// the assertion passes for any subject with an empty expected collection, like
+/// `contains_all_of`, rather than being the same as `is_empty`.
+pub(crate) fn rejected_empty_expected<S, R, EI: Iterator + Clone>(
+    subject: &Subject<S, (), R>,
+    expected: &EI,
+    method: &str,
+    vacuous: bool,
+) -> Option<AssertionResult> {
+    if !subject.is_rejecting_empty_expected() || expected.clone().next().is_some() {
+        return None;
+    }
+    let result = subject.new_result().add_simple_fact(EMPTY_EXPECTED_NOTE);
+    Some(if vacuous {
+        result.add_note(format!(
+            "rejected by reject_empty_expected(), as {} passes for any subject",
+            method
+        ))
+    } else {
+        result
+            .add_note("rejected by reject_empty_expected()")
+            .add_help(EMPTY_EXPECTED_HELP)
+    })
+}
+
+/// The note of failures which stopped at the first difference. See [`Subject::fail_fast`].
+pub(crate) const FAIL_FAST_NOTE: &str =
+    "stopped after first difference; rerun with full_diff() for details";
+
+/// Reports the first element missing from `actual`, or else the first unexpected one, without
+/// comparing further. Returns `None` if `actual` contains exactly the `expected` elements.
+pub(crate) fn first_difference<T, I, EI>(
+    result: AssertionResult,
+    actual: I,
+    expected: EI,
+) -> Option<AssertionResult>
+where
+    T: PartialEq + Debug,
+    I: Iterator<Item = T>,
+    EI: Iterator<Item = T>,
+{
+    let mut remaining: Vec<T> = actual.collect();
+    for element in expected {
+        match remaining.iter().position(|el| el == &element) {
+            Some(index) => {
+                remaining.remove(index);
+            }
+            None => {
+                return Some(
+                    result
+                        .add_formatted_fact("first missing", element)
+                        .add_note(FAIL_FAST_NOTE),
+                )
+            }
+        }
+    }
+    remaining.into_iter().next().map(|element| {
+        result
+            .add_formatted_fact("first unexpected", element)
+            .add_note(FAIL_FAST_NOTE)
+    })
+}
+
+/// Reports the first index at which `actual` and `expected` differ, without comparing further.
+/// Returns `None` if they are equal.
+pub(crate) fn first_difference_in_order<T, I, EI>(
+    result: AssertionResult,
+    mut actual: I,
+    mut expected: EI,
+) -> Option<AssertionResult>
+where
+    T: PartialEq + Debug,
+    I: Iterator<Item = T>,
+    EI: Iterator<Item = T>,
+{
+    let mut index = 0;
+    loop {
+        match (expected.next(), actual.next()) {
+            (None, None) => return None,
+            (Some(expected), Some(actual)) if expected == actual => index += 1,
+            (expected, actual) => {
+                let result = result.add_fact("first difference at", format!("index {}", index));
+                let result = match expected {
+                    Some(expected) => result.add_formatted_fact(FactKey::Expected, expected),
+                    None => result.add_fact(FactKey::Expected, "no element"),
+                };
+                let result = match actual {
+                    Some(actual) => result.add_formatted_fact(FactKey::Actual, actual),
+                    None => result.add_fact(FactKey::Actual, "no element"),
+                };
+                return Some(result.add_note(FAIL_FAST_NOTE));
+            }
+        }
+    }
+}
+
 pub(crate) fn check_contains_all_of_in_order<T, I, EI, R>(
     comparison: SequenceComparison<T>,
     actual: I,
@@ -565,27 +2389,98 @@ where
                     "expected order for required elements",
                     expected_iter.clone().collect(),
                 )
-                .add_formatted_values_fact("but was", actual.collect()),
+                .add_formatted_values_fact(FactKey::ButWas, actual.collect()),
             false,
         )
     } else {
         let missing = comparison.missing;
         (
             assertion_result
-                .add_fact(
-                    format!("missing ({})", missing.len()),
-                    format!("{:?}", missing),
-                )
+                .add_formatted_fact(FactKey::MissingN(missing.len()), &missing)
                 // Idea: implement near_miss_obj
                 // .add_fact("tough it did contain", format!("{:?}", near_miss_obj))
                 .add_splitter()
-                .add_formatted_values_fact("expected to contain at least", expected_iter.collect())
-                .add_formatted_values_fact("but was", actual.collect()),
+                .add_formatted_values_fact(
+                    FactKey::ExpectedToContainAtLeast,
+                    expected_iter.collect(),
+                )
+                .add_formatted_values_fact(FactKey::ButWas, actual.collect()),
             false,
         )
     }
 }
 
+pub(crate) fn check_is_subsequence_of<T, I, EI, R>(
+    assertion_result: AssertionResult,
+    actual_iter: I,
+    expected_iter: EI,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    T: PartialEq + Debug,
+    I: Iterator<Item = T> + Clone,
+    EI: Iterator<Item = T> + Clone,
+{
+    let mut remaining = expected_iter.clone();
+    let mut not_found = vec![];
+    for element in actual_iter.clone() {
+        // Elements which are not found do not consume the expected elements, so that a single
+        // unexpected element is reported as such.
+        let mut lookahead = remaining.clone();
+        if lookahead.any(|expected| expected == element) {
+            remaining = lookahead;
+        } else {
+            not_found.push(element);
+        }
+    }
+    if not_found.is_empty() {
+        return assertion_result.do_ok();
+    }
+    assertion_result
+        .add_formatted_fact(
+            format!("not found in order ({})", not_found.len()),
+            &not_found,
+        )
+        .add_splitter()
+        .add_formatted_values_fact("expected to be a subsequence of", expected_iter.collect())
+        .add_formatted_values_fact(FactKey::ButWas, actual_iter.collect())
+        .do_fail()
+}
+
+pub(crate) fn check_is_prefix_of<T, I, EI, R>(
+    assertion_result: AssertionResult,
+    actual_iter: I,
+    expected_iter: EI,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    T: PartialEq + Debug,
+    I: Iterator<Item = T> + Clone,
+    EI: Iterator<Item = T> + Clone,
+{
+    let mut expected = expected_iter.clone();
+    let mismatch = actual_iter
+        .clone()
+        .enumerate()
+        .map(|(index, actual)| (index, expected.next(), actual))
+        .find(|(_, expected, actual)| expected.as_ref() != Some(actual));
+    let (index, expected, actual) = match mismatch {
+        None => return assertion_result.do_ok(),
+        Some(mismatch) => mismatch,
+    };
+    let result = assertion_result.add_fact("first difference at", format!("index {}", index));
+    let result = match expected {
+        Some(expected) => result.add_formatted_fact(FactKey::Expected, expected),
+        None => result.add_fact(FactKey::Expected, "no element"),
+    };
+    result
+        .add_formatted_fact(FactKey::Actual, actual)
+        .add_splitter()
+        .add_formatted_values_fact("expected to be a prefix of", expected_iter.collect())
+        .add_formatted_values_fact(FactKey::ButWas, actual_iter.collect())
+        .do_fail()
+}
+
 pub(crate) fn feed_facts_about_item_diff<
     T: Debug + PartialEq,
     A: Debug,
@@ -593,23 +2488,24 @@ pub(crate) fn feed_facts_about_item_diff<
     IA: Iterator<Item = A> + Clone,
     IE: Iterator<Item = E> + Clone,
 >(
-    mut result: AssertionResult,
+    result: AssertionResult,
     comparison: &SequenceComparison<T>,
     actual_iter: IA,
     expected_iter: IE,
 ) -> AssertionResult {
+    let mut result = result.with_duplicate_keys(DuplicateKeys::Index);
     let mut splitter = false;
     if !comparison.missing.is_empty() {
-        result = result.add_fact(
-            format!("missing ({})", comparison.missing.len()),
-            format!("{:?}", comparison.missing),
+        result = result.add_formatted_fact(
+            FactKey::MissingN(comparison.missing.len()),
+            &comparison.missing,
         );
         splitter = true;
     }
     if !comparison.extra.is_empty() {
-        result = result.add_fact(
-            format!("unexpected ({})", comparison.extra.len()),
-            format!("{:?}", comparison.extra),
+        result = result.add_formatted_fact(
+            FactKey::UnexpectedN(comparison.extra.len()),
+            &comparison.extra,
         );
         splitter = true;
     }
@@ -617,8 +2513,53 @@ pub(crate) fn feed_facts_about_item_diff<
         result = result.add_splitter();
     }
     result
-        .add_formatted_values_fact("expected", expected_iter.clone().collect())
-        .add_formatted_values_fact("actual", actual_iter.clone().collect())
+        .add_formatted_values_fact(FactKey::Expected, expected_iter.clone().collect())
+        .add_formatted_values_fact(FactKey::Actual, actual_iter.clone().collect())
+}
+
+/// Lists the differing positions of two sequences of the same length, which tells more about
+/// positional data, e.g. time series, than the missing and unexpected elements.
+fn feed_facts_about_index_mismatches<T: Debug + PartialEq, I, EI>(
+    result: AssertionResult,
+    actual_iter: I,
+    expected_iter: EI,
+) -> AssertionResult
+where
+    I: Iterator<Item = T> + Clone,
+    EI: Iterator<Item = T> + Clone,
+{
+    let mismatches: Vec<Mismatch<T>> = expected_iter
+        .clone()
+        .zip(actual_iter.clone())
+        .enumerate()
+        .filter(|(_, (expected, actual))| expected.ne(actual))
+        .map(|(index, (expected, actual))| Mismatch {
+            index,
+            expected,
+            actual,
+        })
+        .collect();
+    let count = mismatches.len();
+    let mut rows: Vec<String> = mismatches
+        .iter()
+        .take(MAX_LISTED_MISMATCHES)
+        .map(|mismatch| format!("{:?}", mismatch))
+        .collect();
+    if count > MAX_LISTED_MISMATCHES {
+        rows.push(format!("... ({} more)", count - MAX_LISTED_MISMATCHES));
+    }
+    result
+        .add_fact(
+            "mismatched indexes",
+            format!("{} of {}", count, actual_iter.clone().count()),
+        )
+        .add_formatted_values_fact(
+            "index | expected | actual",
+            rows.into_iter().map(Described).collect(),
+        )
+        .add_splitter()
+        .add_formatted_values_fact(FactKey::Expected, expected_iter.collect())
+        .add_formatted_values_fact(FactKey::Actual, actual_iter.collect())
 }
 
 pub(crate) fn check_has_length<I, T, R>(
@@ -636,50 +2577,306 @@ where
         assertion_result.do_ok()
     } else {
         assertion_result
-            .add_fact("value of", format!("{}.size()", actual_expr))
-            .add_fact("expected", format!("{}", length))
-            .add_fact("actual", format!("{}", actual))
+            .add_fact(FactKey::ValueOf, format!("{}.size()", actual_expr))
+            .add_fact(FactKey::Expected, format!("{}", length))
+            .add_fact(FactKey::Actual, format!("{}", actual))
+            .do_fail()
+    }
+}
+
+/// Bound on the number of elements, checked by [`check_length_bound`].
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum LengthBound {
+    AtLeast(usize),
+    AtMost(usize),
+    GreaterThan(usize),
+    LessThan(usize),
+    /// Inclusive band of lengths, from the first to the second.
+    Between(usize, usize),
+}
+
+impl LengthBound {
+    fn threshold(&self) -> usize {
+        match self {
+            LengthBound::AtLeast(n)
+            | LengthBound::AtMost(n)
+            | LengthBound::GreaterThan(n)
+            | LengthBound::LessThan(n)
+            | LengthBound::Between(_, n) => *n,
+        }
+    }
+
+    fn is_satisfied_by(&self, length: usize) -> bool {
+        match self {
+            LengthBound::AtLeast(n) => length >= *n,
+            LengthBound::AtMost(n) => length <= *n,
+            LengthBound::GreaterThan(n) => length > *n,
+            LengthBound::LessThan(n) => length < *n,
+            LengthBound::Between(min, max) => (*min..=*max).contains(&length),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            LengthBound::AtLeast(n) => format!("at least {}", n),
+            LengthBound::AtMost(n) => format!("at most {}", n),
+            LengthBound::GreaterThan(n) => format!("more than {}", n),
+            LengthBound::LessThan(n) => format!("less than {}", n),
+            LengthBound::Between(min, max) => format!("between {} and {}", min, max),
+        }
+    }
+
+    /// Whether some length satisfies the bound, i.e. it is not a band whose `min` exceeds `max`.
+    fn is_valid(&self) -> bool {
+        match self {
+            LengthBound::Between(min, max) => min <= max,
+            _ => true,
+        }
+    }
+}
+
+/// Fails with the invalid `bound`, e.g. `between 3 and 1`, instead of reporting a length which
+/// can never satisfy it.
+fn invalid_length_bound<R>(assertion_result: AssertionResult, bound: &LengthBound) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    assertion_result
+        .add_fact("invalid length range", bound.describe())
+        .add_simple_fact("min must not exceed max")
+        .do_fail()
+}
+
+/// Counts at most `bound.threshold() + 1` elements of `actual_iter`, so that infinite iterators
+/// can be checked.
+pub(crate) fn check_length_bound<I, T, R>(
+    assertion_result: AssertionResult,
+    actual_iter: I,
+    actual_expr: &str,
+    bound: LengthBound,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    I: Iterator<Item = T>,
+{
+    if !bound.is_valid() {
+        return invalid_length_bound(assertion_result, &bound);
+    }
+    let limit = bound.threshold().saturating_add(1);
+    let counted = actual_iter.take(limit).count();
+    if bound.is_satisfied_by(counted) {
+        assertion_result.do_ok()
+    } else {
+        let actual = if counted == limit {
+            format!("more than {}", bound.threshold())
+        } else {
+            format!("{}", counted)
+        };
+        assertion_result
+            .add_fact(FactKey::ValueOf, format!("{}.size()", actual_expr))
+            .add_fact(FactKey::Expected, bound.describe())
+            .add_fact(FactKey::Actual, actual)
+            .do_fail()
+    }
+}
+
+/// Checks the length of collections whose length is known, which is reported exactly.
+pub(crate) fn check_len_bound<R>(
+    assertion_result: AssertionResult,
+    len: usize,
+    value_of: String,
+    bound: LengthBound,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    if !bound.is_valid() {
+        return invalid_length_bound(assertion_result, &bound);
+    }
+    if bound.is_satisfied_by(len) {
+        assertion_result.do_ok()
+    } else {
+        assertion_result
+            .add_fact(FactKey::ValueOf, value_of)
+            .add_fact(FactKey::Expected, bound.describe())
+            .add_fact(FactKey::Actual, len.to_string())
             .do_fail()
     }
-}
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::testing::*;
+    use crate::{EqualityAssertion, MapAssertion, OptionAssertion};
+
+    use super::*;
+
+    #[test]
+    #[allow(clippy::needless_borrows_for_generic_args)]
+    fn contains() {
+        assert_that!(vec![1, 2, 3].iter()).contains(&3);
+        assert_that!([1, 2, 3].iter()).contains(3);
+        assert_that!([1, 2, 3].into_iter()).contains(3);
+        assert_that!([1, 2, 3].into_iter()).contains(&3);
+        assert_that!(["a", "b"].iter()).contains("b");
+        assert_that!(["a", "b"].iter()).contains(&"b");
+        assert_that!([1, 2, 3].iter()).does_not_contain(4);
+        assert_that!([1, 2, 3].into_iter()).does_not_contain(&4);
+
+        // Failures
+        assert_that!(check_that!(vec![1, 2, 3].iter()).contains(10)).facts_are(vec![
+            Fact::new("expected to contain", "10"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1", "2", "3"]),
+        ]);
+        // Elements of hash-based containers are listed in a stable order.
+        let set: HashSet<i32> = (1..=12).collect();
+        assert_that!(check_that!(set.iter()).contains(&13)).facts_are(vec![
+            Fact::new("expected to contain", "13"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact(
+                "though it did contain",
+                vec![
+                    "1", "10", "11", "12", "2", "3", "4", "5", "6", "7", "8", "9",
+                ],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn contains_exactly() {
+        assert_that!(vec![1, 2, 3].iter()).contains_exactly(vec![1, 2, 3].iter());
+        assert_that!(vec![2, 1, 3].iter()).contains_exactly(vec![1, 2, 3].iter());
+
+        assert_that!(check_that!("foobarbaz".chars()).contains_exactly("bazbar".chars()))
+            .facts_are(vec![
+                Fact::new("unexpected (3)", r#"['f', 'o', 'o']"#),
+                Fact::Splitter,
+                Fact::new_multi_value_fact(
+                    "expected",
+                    vec!["'b'", "'a'", "'z'", "'b'", "'a'", "'r'"],
+                ),
+                Fact::new_multi_value_fact(
+                    "actual",
+                    vec![
+                        "'f'", "'o'", "'o'", "'b'", "'a'", "'r'", "'b'", "'a'", "'z'",
+                    ],
+                ),
+                Fact::new("note", "all expected elements were found"),
+                Fact::new("help", "use contains_all_of if other elements are allowed"),
+            ]);
+    }
+
+    #[test]
+    fn empty_expected() {
+        let empty: Vec<i32> = vec![];
+        assert_that!(empty.iter()).contains_exactly(empty.iter());
+        assert_that!([1, 2].iter()).contains_all_of(empty.iter());
+        assert_that!([1, 2].iter()).contains_all_of_in_order(empty.iter());
+        assert_that!([1, 2].iter()).does_not_contain_any(empty.iter());
+
+        // Failures
+        assert_that!(check_that!([1, 2].iter()).contains_exactly(empty.iter())).facts_are(vec![
+            Fact::new("unexpected (2)", "[1, 2]"),
+            Fact::Splitter,
+            Fact::new_multi_value_fact("expected", Vec::<String>::new()),
+            Fact::new_multi_value_fact("actual", vec!["1", "2"]),
+            Fact::new("note", "expected collection is empty"),
+            Fact::new(
+                "help",
+                "use is_empty to check that the subject has no elements",
+            ),
+        ]);
+        assert_that!(check_that!([1].iter()).contains_exactly_in_order(empty.iter()))
+            .facts_are_at_least(vec![
+                Fact::new("note", "expected collection is empty"),
+                Fact::new(
+                    "help",
+                    "use is_empty to check that the subject has no elements",
+                ),
+            ]);
+        assert_that!(check_that!([1].iter()).contains_any_of(empty.iter())).facts_are(vec![
+            Fact::new_multi_value_fact("expected to contain any of", Vec::<String>::new()),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1"]),
+            Fact::new("note", "expected collection is empty"),
+        ]);
+    }
+
+    #[test]
+    fn reject_empty_expected() {
+        let empty: Vec<i32> = vec![];
+        assert_that!([1].iter())
+            .reject_empty_expected()
+            .contains_all_of([1].iter());
+
+        // Failures
+        assert_that!(check_that!(empty.iter())
+            .reject_empty_expected()
+            .contains_exactly(empty.iter()))
+        .facts_are(vec![
+            Fact::new_simple_fact("expected collection is empty"),
+            Fact::new("note", "rejected by reject_empty_expected()"),
+            Fact::new(
+                "help",
+                "use is_empty to check that the subject has no elements",
+            ),
+        ]);
+        assert_that!(check_that!([1, 2].iter())
+            .reject_empty_expected()
+            .does_not_contain_any(empty.iter()))
+        .facts_are(vec![
+            Fact::new_simple_fact("expected collection is empty"),
+            Fact::new(
+                "note",
+                "rejected by reject_empty_expected(), as does_not_contain_any passes for any subject",
+            ),
+        ]);
+    }
 
-#[cfg(test)]
-mod tests {
-    use crate::testing::*;
+    #[test]
+    fn has_same_elements_as() {
+        assert_that!([3, 1, 2].iter()).has_same_elements_as([1, 2, 3].iter());
+        assert_that!(Vec::<i32>::new().iter()).has_same_elements_as([].iter());
 
-    use super::*;
+        assert_that!(check_that!([1, 2, 2, 4].iter()).has_same_elements_as([1, 2, 3].iter()))
+            .facts_are(vec![
+                Fact::new_simple_fact("expected the same elements, ignoring order"),
+                Fact::new_multi_value_fact("only in actual (2)", vec!["2", "4"]),
+                Fact::new_multi_value_fact("only in expected (1)", vec!["3"]),
+                Fact::new("in both", "2"),
+            ]);
+        assert_that!(check_that!([1].iter()).has_same_elements_as([1, 2].iter())).facts_are(vec![
+            Fact::new_simple_fact("expected the same elements, ignoring order"),
+            Fact::new_multi_value_fact("only in expected (1)", vec!["2"]),
+            Fact::new("in both", "1"),
+        ]);
+    }
 
     #[test]
-    fn contains() {
-        assert_that!(vec![1, 2, 3].iter()).contains(&3);
-
-        // Failures
-        assert_that!(check_that!(vec![1, 2, 3].iter()).contains(&10)).facts_are(vec![
-            Fact::new("expected to contain", "10"),
-            Fact::new_simple_fact("but did not"),
-            Fact::new_multi_value_fact("though it did contain", vec!["1", "2", "3"]),
+    fn has_same_elements_as_caps_listed_elements() {
+        let actual: Vec<i32> = (0..40).collect();
+        let mut expected_values: Vec<String> = (0..32).map(|i| i.to_string()).collect();
+        expected_values.push("... (8 more)".to_string());
+        assert_that!(check_that!(actual.iter()).has_same_elements_as([].iter())).facts_are(vec![
+            Fact::new_simple_fact("expected the same elements, ignoring order"),
+            Fact::new_multi_value_fact("only in actual (40)", expected_values),
+            Fact::new("in both", "0"),
         ]);
     }
 
     #[test]
-    fn contains_exactly() {
-        assert_that!(vec![1, 2, 3].iter()).contains_exactly(vec![1, 2, 3].iter());
-        assert_that!(vec![2, 1, 3].iter()).contains_exactly(vec![1, 2, 3].iter());
+    fn has_same_distinct_elements_as() {
+        assert_that!([1, 1, 2].iter()).has_same_distinct_elements_as([2, 1, 2].iter());
 
-        assert_that!(check_that!("foobarbaz".chars()).contains_exactly("bazbar".chars()))
+        assert_that!(check_that!([1, 1, 2].iter()).has_same_distinct_elements_as([3, 1].iter()))
             .facts_are(vec![
-                Fact::new("unexpected (3)", r#"['f', 'o', 'o']"#),
-                Fact::Splitter,
-                Fact::new_multi_value_fact(
-                    "expected",
-                    vec!["'b'", "'a'", "'z'", "'b'", "'a'", "'r'"],
-                ),
-                Fact::new_multi_value_fact(
-                    "actual",
-                    vec![
-                        "'f'", "'o'", "'o'", "'b'", "'a'", "'r'", "'b'", "'a'", "'z'",
-                    ],
-                ),
+                Fact::new_simple_fact("expected the same distinct elements, ignoring order"),
+                Fact::new_multi_value_fact("only in actual (1)", vec!["2"]),
+                Fact::new_multi_value_fact("only in expected (1)", vec!["3"]),
+                Fact::new("in both", "1"),
             ]);
     }
 
@@ -703,8 +2900,11 @@ mod tests {
             ]);
         assert_that!(check_that!(vec![1, 2].iter()).contains_exactly_in_order(vec![2, 3].iter()))
             .facts_are(vec![
-                Fact::new("missing (1)", "[3]"),
-                Fact::new("unexpected (1)", "[1]"),
+                Fact::new("mismatched indexes", "2 of 2"),
+                Fact::new_multi_value_fact(
+                    "index | expected | actual",
+                    vec!["0 | 2 | 1", "1 | 3 | 2"],
+                ),
                 Fact::new_splitter(),
                 Fact::new_multi_value_fact("expected", vec!["2", "3"]),
                 Fact::new_multi_value_fact("actual", vec!["1", "2"]),
@@ -717,9 +2917,76 @@ mod tests {
             Fact::new_splitter(),
             Fact::new_multi_value_fact("expected", vec!["1", "2", "3"]),
             Fact::new_multi_value_fact("actual", vec!["2", "1", "3"]),
+            Fact::new("help", "use contains_exactly if the order does not matter"),
         ])
     }
 
+    #[test]
+    fn contains_exactly_in_order_mismatched_indexes() {
+        let actual: Vec<i32> = (0..15).collect();
+        let expected: Vec<i32> = (0..15).map(|i| if i < 2 { i } else { -i }).collect();
+        let rows: Vec<String> = (2..12)
+            .map(|i| format!("{} | {} | {}", i, -i, i))
+            .chain(std::iter::once("... (3 more)".to_string()))
+            .collect();
+        assert_that!(check_that!(actual.iter()).contains_exactly_in_order(expected.iter()))
+            .facts_are_at_least(vec![
+                Fact::new("mismatched indexes", "13 of 15"),
+                Fact::new_multi_value_fact("index | expected | actual", rows),
+            ]);
+    }
+
+    #[test]
+    fn contains_exactly_fail_fast() {
+        assert_that!([1, 2, 2].iter())
+            .fail_fast()
+            .contains_exactly([2, 1, 2].iter());
+        assert_that!([1, 2].iter())
+            .fail_fast()
+            .contains_exactly_in_order([1, 2].iter());
+
+        // Failures
+        let note = "stopped after first difference; rerun with full_diff() for details";
+        assert_that!(check_that!([1, 2, 3].iter())
+            .fail_fast()
+            .contains_exactly([4, 1, 5].iter()))
+        .facts_are(vec![
+            Fact::new("first missing", "4"),
+            Fact::new("note", note),
+        ]);
+        assert_that!(check_that!([1, 2, 3].iter())
+            .fail_fast()
+            .contains_exactly([3, 1].iter()))
+        .facts_are(vec![
+            Fact::new("first unexpected", "2"),
+            Fact::new("note", note),
+        ]);
+        assert_that!(check_that!([1, 2, 3].iter())
+            .fail_fast()
+            .contains_exactly_in_order([1, 3, 2].iter()))
+        .facts_are(vec![
+            Fact::new("first difference at", "index 1"),
+            Fact::new("expected", "3"),
+            Fact::new("actual", "2"),
+            Fact::new("note", note),
+        ]);
+        assert_that!(check_that!([1, 2].iter())
+            .fail_fast()
+            .contains_exactly_in_order([1, 2, 3].iter()))
+        .facts_are(vec![
+            Fact::new("first difference at", "index 2"),
+            Fact::new("expected", "3"),
+            Fact::new("actual", "no element"),
+            Fact::new("note", note),
+        ]);
+        // full_diff() restores the detailed report.
+        assert_that!(check_that!([1, 2].iter())
+            .fail_fast()
+            .full_diff()
+            .contains_exactly([1].iter()))
+        .facts_are_at_least(vec![Fact::new("unexpected (1)", "[2]")]);
+    }
+
     #[test]
     fn contains_at_least() {
         assert_that!(vec![1, 2, 3].iter()).contains_all_of(vec![].iter());
@@ -794,19 +3061,157 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn has_length_bounds() {
+        assert_that!([1, 2, 3].iter()).has_length_at_least(3);
+        assert_that!([1, 2, 3].iter()).has_length_at_most(3);
+        assert_that!([1, 2, 3].iter()).has_length_greater_than(2);
+        assert_that!([1, 2, 3].iter()).has_length_less_than(4);
+        assert_that!(Vec::<usize>::new().iter()).has_length_at_least(0);
+        assert_that!((0..)).has_length_greater_than(10);
+        assert_that!([1, 2, 3].iter()).has_length_between(3, 3);
+
+        // Failures
+        assert_that!(check_that!([1, 2, 3].iter()).has_length_at_least(4)).facts_are(vec![
+            Fact::new("value of", "[1, 2, 3].iter().size()"),
+            Fact::new("expected", "at least 4"),
+            Fact::new("actual", "3"),
+        ]);
+        assert_that!(check_that!([1, 2, 3].iter()).has_length_at_most(2)).facts_are(vec![
+            Fact::new("value of", "[1, 2, 3].iter().size()"),
+            Fact::new("expected", "at most 2"),
+            Fact::new("actual", "more than 2"),
+        ]);
+        assert_that!(check_that!([1, 2, 3].iter()).has_length_greater_than(3)).facts_are(vec![
+            Fact::new("value of", "[1, 2, 3].iter().size()"),
+            Fact::new("expected", "more than 3"),
+            Fact::new("actual", "3"),
+        ]);
+        assert_that!(check_that!([1, 2, 3].iter()).has_length_less_than(3)).facts_are(vec![
+            Fact::new("value of", "[1, 2, 3].iter().size()"),
+            Fact::new("expected", "less than 3"),
+            Fact::new("actual", "3"),
+        ]);
+        assert_that!(check_that!((0..)).has_length_less_than(3)).facts_are(vec![
+            Fact::new("value of", "(0..).size()"),
+            Fact::new("expected", "less than 3"),
+            Fact::new("actual", "more than 3"),
+        ]);
+        assert_that!(check_that!((0..)).has_length_between(1, 3)).facts_are(vec![
+            Fact::new("value of", "(0..).size()"),
+            Fact::new("expected", "between 1 and 3"),
+            Fact::new("actual", "more than 3"),
+        ]);
+        assert_that!(check_that!([1].iter()).has_length_between(2, 3)).facts_are(vec![
+            Fact::new("value of", "[1].iter().size()"),
+            Fact::new("expected", "between 2 and 3"),
+            Fact::new("actual", "1"),
+        ]);
+        assert_that!(check_that!([1].iter()).has_length_between(3, 1)).facts_are(vec![
+            Fact::new("invalid length range", "between 3 and 1"),
+            Fact::new_simple_fact("min must not exceed max"),
+        ]);
+    }
+
     #[test]
     fn does_not_contain() {
-        assert_that!(vec![1, 2, 3].iter()).does_not_contain(&5);
-        assert_that!(Vec::<usize>::new().iter()).does_not_contain(&0);
+        assert_that!(vec![1, 2, 3].iter()).does_not_contain(5);
+        assert_that!(Vec::<usize>::new().iter()).does_not_contain(0);
 
         // Failures
-        assert_that!(check_that!(vec![1].iter()).does_not_contain(&1)).facts_are(vec![
+        assert_that!(check_that!(vec![1].iter()).does_not_contain(1)).facts_are(vec![
             Fact::new("expected to not contain", "1"),
             Fact::new_simple_fact("but element was found"),
             Fact::new_multi_value_fact("though it did contain", vec!["1"]),
         ]);
     }
 
+    #[test]
+    fn is_subsequence_of() {
+        assert_that!([1, 3].iter()).is_subsequence_of([1, 2, 3].iter());
+        assert_that!([1, 2, 3].iter()).is_subsequence_of([1, 2, 3].iter());
+        assert_that!([0; 0].iter()).is_subsequence_of([1].iter());
+
+        // Failures
+        assert_that!(check_that!([3, 1, 2].iter()).is_subsequence_of([1, 2, 3].iter())).facts_are(
+            vec![
+                Fact::new("not found in order (2)", "[1, 2]"),
+                Fact::new_splitter(),
+                Fact::new_multi_value_fact("expected to be a subsequence of", vec!["1", "2", "3"]),
+                Fact::new_multi_value_fact("but was", vec!["3", "1", "2"]),
+            ],
+        );
+        assert_that!(check_that!([1, 1].iter()).is_subsequence_of([1].iter()))
+            .facts_are_at_least(vec![Fact::new("not found in order (1)", "[1]")]);
+    }
+
+    #[test]
+    fn is_prefix_of() {
+        assert_that!([1, 2].iter()).is_prefix_of([1, 2, 3].iter());
+        assert_that!([1, 2].iter()).is_prefix_of([1, 2].iter());
+        assert_that!([0; 0].iter()).is_prefix_of([1].iter());
+
+        // Failures
+        assert_that!(check_that!([2].iter()).is_prefix_of([1, 2].iter())).facts_are(vec![
+            Fact::new("first difference at", "index 0"),
+            Fact::new("expected", "1"),
+            Fact::new("actual", "2"),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("expected to be a prefix of", vec!["1", "2"]),
+            Fact::new_multi_value_fact("but was", vec!["2"]),
+        ]);
+        assert_that!(check_that!([1, 2].iter()).is_prefix_of([1].iter())).facts_are_at_least(vec![
+            Fact::new("first difference at", "index 1"),
+            Fact::new("expected", "no element"),
+            Fact::new("actual", "2"),
+        ]);
+    }
+
+    #[test]
+    fn is_equal_to_iterator() {
+        assert_that!([1, 2, 3].iter()).is_equal_to_iterator([1, 2, 3].iter());
+        assert_that!("".chars()).is_equal_to_iterator("".chars());
+
+        // Failures
+        assert_that!(check_that!([1, 2, 3].iter()).is_equal_to_iterator([1, 3, 2].iter()))
+            .facts_are(vec![
+                Fact::new("first mismatch at index", "1"),
+                Fact::new("expected", "3"),
+                Fact::new("but was", "2"),
+            ]);
+        assert_that!(check_that!([1, 2].iter()).is_equal_to_iterator([1, 2, 3].iter())).facts_are(
+            vec![
+                Fact::new("first mismatch at index", "2"),
+                Fact::new("expected", "3"),
+                Fact::new_simple_fact("but the subject had no more elements"),
+            ],
+        );
+        assert_that!(check_that!((0..)).is_equal_to_iterator(0..2)).facts_are(vec![
+            Fact::new("first mismatch at index", "2"),
+            Fact::new_simple_fact("expected no more elements"),
+            Fact::new("but was", "2"),
+        ]);
+    }
+
+    #[test]
+    fn contains_any_of() {
+        assert_that!([1, 2, 3].iter()).contains_any_of([3, 4].iter());
+        assert_that!([1, 2, 3].iter()).contains_any_of([1].iter());
+
+        // Failures
+        assert_that!(check_that!([1, 2, 3].iter()).contains_any_of([4, 5].iter())).facts_are(vec![
+            Fact::new_multi_value_fact("expected to contain any of", vec!["4", "5"]),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1", "2", "3"]),
+        ]);
+        assert_that!(check_that!([1].iter()).contains_any_of([].iter())).facts_are(vec![
+            Fact::new_multi_value_fact("expected to contain any of", Vec::<String>::new()),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1"]),
+            Fact::new("note", "expected collection is empty"),
+        ]);
+    }
+
     #[test]
     fn does_not_contain_any() {
         assert_that!(vec![1, 2, 3].iter()).does_not_contain_any(vec![4, 5].iter());
@@ -823,4 +3228,406 @@ mod tests {
                 Fact::new_multi_value_fact("but was", vec!["1", "2", "3"]),
             ]);
     }
+
+    #[test]
+    fn counted() {
+        let words = "the cat saw the dog".split(' ');
+        assert_that!(words.clone())
+            .counted()
+            .contains_entry("the", 2);
+        assert_that!(words).counted().contains_entry("cat", 1);
+
+        // Failures
+        assert_that!(check_that!("hello".chars())
+            .counted()
+            .contains_entry('l', 1))
+        .facts_are(vec![
+            Fact::new("value of", r#""hello".chars().counted()"#),
+            Fact::new("expected key to be mapped to value", "'l' ⟶ 1"),
+            Fact::new("but key was mapped to a different value", "2"),
+            Fact::new_splitter(),
+            Fact::new("though it did contain keys", "['e', 'h', 'l', 'o']"),
+        ]);
+    }
+
+    #[test]
+    fn distinct() {
+        assert_that!([1, 2, 1, 3, 2].iter())
+            .distinct()
+            .contains_exactly_in_order([&1, &2, &3].into_iter());
+        assert_that!(Vec::<i32>::new().iter()).distinct().is_empty();
+        assert_that!([f64::NAN, f64::NAN].iter())
+            .distinct()
+            .has_length(2);
+
+        // Failures
+        assert_that!(check_that!("hello".chars()).distinct().contains('x')).facts_are(vec![
+            Fact::new("value of", r#""hello".chars().distinct()"#),
+            Fact::new("expected to contain", "'x'"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["'h'", "'e'", "'l'", "'o'"]),
+        ]);
+    }
+
+    #[test]
+    fn does_not_contain_duplicates_by_key() {
+        #[derive(Debug)]
+        struct Event {
+            id: u32,
+            kind: &'static str,
+        }
+        let event = |id, kind| Event { id, kind };
+        assert_that!([event(1, "a"), event(2, "a")].iter())
+            .does_not_contain_duplicates_by_key(|e| e.id);
+        assert_that!(Vec::<Event>::new().iter()).does_not_contain_duplicates_by_key(|e| e.id);
+
+        // Failures
+        let events = [
+            event(1, "a"),
+            event(2, "a"),
+            event(1, "b"),
+            event(3, "a"),
+            event(2, "c"),
+        ];
+        assert_that!(check_that!(events.iter()).does_not_contain_duplicates_by_key(|e| e.id))
+            .facts_are(vec![
+                Fact::new("expected keys to be unique", "but 2 keys were shared"),
+                Fact::new_splitter(),
+                Fact::new_multi_value_fact(
+                    "elements with key 1",
+                    vec![
+                        r#"[0]: Event { id: 1, kind: "a" }"#,
+                        r#"[2]: Event { id: 1, kind: "b" }"#,
+                    ],
+                ),
+                Fact::new_multi_value_fact(
+                    "elements with key 2",
+                    vec![
+                        r#"[1]: Event { id: 2, kind: "a" }"#,
+                        r#"[4]: Event { id: 2, kind: "c" }"#,
+                    ],
+                ),
+            ]);
+        assert_that!(check_that!(events.iter()).does_not_contain_duplicates_by_key(|e| e.kind))
+            .facts_are_at_least(vec![Fact::new(
+                "expected keys to be unique",
+                "but 1 key was shared",
+            )]);
+    }
+
+    #[test]
+    fn aggregations() {
+        assert_that!([1, 2, 3].into_iter()).sum().is_equal_to(6);
+        assert_that!(Vec::<i32>::new().into_iter())
+            .sum()
+            .is_equal_to(0);
+        assert_that!([3, 1, 2].into_iter()).min().is_equal_to(1);
+        assert_that!([3, 1, 2].into_iter()).max().is_equal_to(3);
+        assert_that!([1_u8, 2].into_iter()).mean().is_equal_to(1.5);
+        assert_that!([1.0, f64::NAN, 0.5].into_iter())
+            .min()
+            .is_equal_to(0.5);
+
+        // Failures
+        assert_that!(check_that!([1.0, 2.0].into_iter()).mean().is_equal_to(2.0)).facts_are(vec![
+            Fact::new("value of", "[1.0, 2.0].into_iter().mean()"),
+            Fact::new("expected", "2.0"),
+            Fact::new("actual", "1.5"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected to be non-empty to compute max()\nbut was empty")]
+    fn max_of_empty() {
+        assert_that!(Vec::<i32>::new().into_iter()).max();
+    }
+
+    #[test]
+    fn statistics() {
+        let latencies = [12, 15, 11, 90, 14];
+        assert_that!(latencies.into_iter())
+            .percentile(50.0)
+            .is_equal_to(14.0);
+        assert_that!(latencies.into_iter())
+            .percentile(0.0)
+            .is_equal_to(11.0);
+        assert_that!(latencies.into_iter())
+            .percentile(87.5)
+            .is_equal_to(52.5);
+        assert_that!([2, 4, 4, 4, 5, 5, 7, 9].into_iter())
+            .std_dev()
+            .is_equal_to(2.0);
+        assert_that!([3.5].into_iter()).std_dev().is_equal_to(0.0);
+
+        // Failures
+        assert_that!(check_that!(latencies.into_iter())
+            .percentile(99.0)
+            .is_equal_to(15.0))
+        .fact_value_for_key("value of")
+        .is_equal_to("latencies.into_iter().percentile(99.0)".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid percentile: 101.0")]
+    fn percentile_out_of_range() {
+        assert_that!([1, 2].into_iter()).percentile(101.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected to be non-empty to compute std_dev()")]
+    fn std_dev_of_empty() {
+        assert_that!(Vec::<f32>::new().into_iter()).std_dev();
+    }
+
+    #[test]
+    fn all_within() {
+        assert_that!([1, 2, 3].into_iter()).all_within(1..=3);
+        assert_that!(Vec::<i32>::new().into_iter()).all_within(..0);
+
+        // Failures
+        assert_that!(check_that!([12, 95, 11, 20].into_iter()).all_within(10..20)).facts_are(vec![
+            Fact::new("expected all elements within", "10..20"),
+            Fact::new_multi_value_fact("found (2)", vec!["95 (index 1)", "20 (index 3)"]),
+        ]);
+    }
+
+    #[test]
+    fn all_satisfy() {
+        assert_that!([2, 4, 6].into_iter()).all_satisfy(|n| n % 2 == 0, "even");
+        assert_that!(std::iter::empty::<i32>()).all_satisfy(|_| false, "nothing");
+
+        // Failures
+        assert_that!(check_that!([2, 3, 6, 7].into_iter()).all_satisfy(|n| n % 2 == 0, "even"))
+            .facts_are(vec![
+                Fact::new("expected all elements to satisfy", "even"),
+                Fact::new_multi_value_fact("found (2)", vec!["3 (index 1)", "7 (index 3)"]),
+            ]);
+    }
+
+    #[test]
+    fn chunks() {
+        assert_that!(1..=5)
+            .chunks(2)
+            .contains_exactly_in_order([vec![1, 2], vec![3, 4], vec![5]].into_iter());
+        assert_that!(1..=4)
+            .chunks(2)
+            .contains_exactly_in_order([vec![1, 2], vec![3, 4]].into_iter());
+        assert_that!(std::iter::empty::<i32>()).chunks(3).is_empty();
+
+        // Failures
+        assert_that!(check_that!(1..=5)
+            .chunks(2)
+            .all_satisfy(|chunk| chunk.len() == 2, "full batch"))
+        .facts_are(vec![
+            Fact::new("value of", "1..=5.chunks(2)"),
+            Fact::new("expected all elements to satisfy", "full batch"),
+            Fact::new_multi_value_fact("found (1)", vec!["[5] (index 2)"]),
+        ]);
+    }
+
+    #[test]
+    fn any_satisfy() {
+        assert_that!([1, 4, 7].into_iter()).any_satisfy(|n| n % 2 == 0, "even");
+
+        // Failures
+        assert_that!(check_that!([1, 3].into_iter()).any_satisfy(|n| n % 2 == 0, "even"))
+            .facts_are(vec![
+                Fact::new("expected any element to satisfy", "even"),
+                Fact::new_simple_fact("but none did"),
+                Fact::new_multi_value_fact("though it did contain", vec!["1", "3"]),
+            ]);
+        assert_that!(check_that!(std::iter::empty::<i32>()).any_satisfy(|_| true, "anything"))
+            .facts_are(vec![
+                Fact::new("expected any element to satisfy", "anything"),
+                Fact::new_simple_fact("but none did"),
+            ]);
+    }
+
+    #[test]
+    fn windows() {
+        assert_that!(1..=4)
+            .windows(3)
+            .contains_exactly_in_order([vec![1, 2, 3], vec![2, 3, 4]].into_iter());
+        assert_that!(1..=2).windows(3).is_empty();
+        assert_that!([3, 1, 2, 5].into_iter())
+            .windows(3)
+            .any_satisfy(|w| w[0] < w[1] && w[1] < w[2], "three increasing values");
+
+        // Failures
+        assert_that!(check_that!([5, 4, 6, 1].into_iter())
+            .windows(3)
+            .any_satisfy(|w| w[0] < w[1] && w[1] < w[2], "three increasing values"))
+        .facts_are(vec![
+            Fact::new("value of", "[5, 4, 6, 1].into_iter().windows(3)"),
+            Fact::new("expected any element to satisfy", "three increasing values"),
+            Fact::new_simple_fact("but none did"),
+            Fact::new_multi_value_fact("though it did contain", vec!["[5, 4, 6]", "[4, 6, 1]"]),
+        ]);
+        assert_that!(check_that!(1..=5)
+            .windows(2)
+            .all_satisfy(|w| w[1] - w[0] == 1 && w[0] != 3, "no 3"))
+        .facts_are(vec![
+            Fact::new("value of", "1..=5.windows(2)"),
+            Fact::new("expected all elements to satisfy", "no 3"),
+            Fact::new_multi_value_fact("found (1)", vec!["[3, 4] (index 2)"]),
+        ]);
+    }
+
+    #[test]
+    fn taking_and_skipping_while() {
+        let events = ["boot", "boot", "ready", "boot"];
+        assert_that!(events.into_iter())
+            .taking_while(|e| *e == "boot", "startup")
+            .contains_exactly_in_order(["boot", "boot"].into_iter());
+        assert_that!(events.into_iter())
+            .skipping_while(|e| *e == "boot", "startup")
+            .contains_exactly_in_order(["ready", "boot"].into_iter());
+        assert_that!(events.into_iter())
+            .taking_while(|e| *e == "ready", "ready")
+            .is_empty();
+
+        // Failures
+        assert_that!(check_that!(events.into_iter())
+            .skipping_while(|e| *e == "boot", "startup")
+            .does_not_contain("ready"))
+        .facts_are(vec![
+            Fact::new("value of", "events.into_iter().skipping_while(startup)"),
+            Fact::new("expected to not contain", r#""ready""#),
+            Fact::new_simple_fact("but element was found"),
+            Fact::new_multi_value_fact("though it did contain", vec![r#""ready""#, r#""boot""#]),
+        ]);
+    }
+
+    #[test]
+    fn max_evaluated_elements() {
+        assert_that!(0..).contains(5);
+        assert_that!((0..).step_by(2))
+            .max_evaluated_elements(10)
+            .contains(18);
+        // Iterators with an upper bound on their length are not capped.
+        assert_that!(0..20)
+            .max_evaluated_elements(10)
+            .has_length(20);
+        // Skipped subjects stop evaluating at the cap.
+        assert_that!(0..)
+            .max_evaluated_elements(10)
+            .assuming(false)
+            .contains(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected the iterator to end
+but it yielded more than: 10 elements")]
+    fn max_evaluated_elements_exceeded() {
+        assert_that!(0..).max_evaluated_elements(10).contains(-1);
+    }
+
+    #[test]
+    #[should_panic(expected = "but it yielded more than: 1000000 elements")]
+    fn max_evaluated_elements_by_default() {
+        assert_that!(std::iter::repeat(1)).has_length(3);
+    }
+
+    #[test]
+    #[should_panic(expected = "window size must be non-zero")]
+    fn windows_of_zero() {
+        assert_that!(1..=3).windows(0);
+    }
+
+    #[test]
+    fn single() {
+        let results = [("job", 42)];
+        assert_that!(results.iter())
+            .single()
+            .is_equal_to(&("job", 42));
+        assert_that!(check_that!([7].into_iter()).single().is_equal_to(8)).facts_are(vec![
+            Fact::new("value of", "[7].into_iter().single()"),
+            Fact::new("expected", "8"),
+            Fact::new("actual", "7"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected to have exactly one element\nbut had: 0 elements")]
+    fn single_of_empty() {
+        assert_that!(std::iter::empty::<i32>()).single();
+    }
+
+    #[test]
+    #[should_panic(expected = "but had              : 3 elements\n\
+                               though it did contain: [ [0]: 1, [1]: 2, [2]: 3 ]")]
+    fn single_of_many() {
+        assert_that!(1..=3).single();
+    }
+
+    #[test]
+    fn first_and_last() {
+        assert_that!([1, 2, 3].into_iter()).first().is_equal_to(1);
+        assert_that!([1, 2, 3].into_iter()).last().is_equal_to(3);
+        assert_that!([1].iter()).last().is_equal_to(&1);
+
+        // Failures
+        assert_that!(check_that!([1, 2, 3].into_iter()).last().is_equal_to(2)).facts_are(vec![
+            Fact::new("value of", "[1, 2, 3].into_iter().last()"),
+            Fact::new("expected", "2"),
+            Fact::new("actual", "3"),
+        ]);
+    }
+
+    #[test]
+    fn position_of() {
+        assert_that!(["a", "b", "a"].into_iter())
+            .position_of("a")
+            .has_value(0);
+        assert_that!([1, 2, 3].iter()).position_of(3).has_value(2);
+
+        // Failures
+        assert_that!(check_that!([1, 2, 1].into_iter())
+            .position_of(1)
+            .has_value(2))
+        .facts_are(vec![
+            Fact::new("value of", "[1, 2, 1].into_iter().position_of(1)"),
+            Fact::new("expected", "Some(2)"),
+            Fact::new("actual", "Some(0)"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected to contain  : 4\n\
+                               but did not\n\
+                               though it did contain: [ [0]: 1, [1]: 2, [2]: 3 ]")]
+    fn position_of_missing() {
+        assert_that!(1..=3).position_of(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected to be non-empty to compute first()\nbut was empty")]
+    fn first_of_empty() {
+        assert_that!(std::iter::empty::<i32>()).first();
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn chunks_of_zero() {
+        assert_that!([1].into_iter()).chunks(0);
+    }
+
+    #[test]
+    fn are_all_true_and_false() {
+        assert_that!([true, true].iter()).are_all_true();
+        assert_that!([false, false].into_iter()).are_all_false();
+        assert_that!(std::iter::empty::<bool>()).are_all_true();
+
+        // Failures
+        assert_that!(check_that!([true, false, true, false].into_iter()).are_all_true()).facts_are(
+            vec![
+                Fact::new("expected all elements to be", "true"),
+                Fact::new("but was false at indices", "[1, 3]"),
+            ],
+        );
+        assert_that!(check_that!([false, true].iter()).are_all_false()).facts_are(vec![
+            Fact::new("expected all elements to be", "false"),
+            Fact::new("but was true at indices", "[1]"),
+        ]);
+    }
 }
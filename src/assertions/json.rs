@@ -0,0 +1,114 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::vec::IntoIter;
+
+use serde_json::Value;
+
+use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+
+/// Trait for [`serde_json::Value`] assertions.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// use serde_json::json;
+///
+/// let body = json!({"tags": ["a", "b"]});
+/// assert_that!(body["tags"]).as_array().contains(json!("b"));
+/// assert_that!(r#"[3, 1, 2]"#)
+///     .as_json()
+///     .as_array()
+///     .contains_exactly(vec![json!(1), json!(2), json!(3)].into_iter());
+/// demo!(
+///     assert_that!(body["tags"]).as_array().contains(json!("c")),
+///     r#"
+///     value of             : body["tags"].as_array()
+///     expected to contain  : String("c")
+///     but did not
+///     though it did contain: [ [0]: String("a"), [1]: String("b") ]
+///     "#
+/// );
+/// ```
+pub trait JsonValueAssertion<R> {
+    /// Returns a new subject which is an iterator over the elements of the subject, so that all
+    /// [`IteratorAssertion`](crate::IteratorAssertion) assertions apply to a JSON array. Panics if
+    /// the subject is not an array.
+    #[track_caller]
+    fn as_array(&self) -> Subject<'_, IntoIter<Value>, (), R>;
+}
+
+impl<R> JsonValueAssertion<R> for Subject<'_, Value, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn as_array(&self) -> Subject<'_, IntoIter<Value>, (), R> {
+        if let Value::Array(elements) = self.actual() {
+            return self.new_owned_subject(
+                elements.clone().into_iter(),
+                Some(format!("{}.as_array()", self.description_or_expr())),
+                (),
+            );
+        }
+        // Like `StringAssertion::as_json`, a derived subject can not be returned, so panic.
+        let result: AssertionResult = self
+            .new_result()
+            .add_simple_fact("expected a JSON array")
+            .add_fact(
+                "but was",
+                format!("{} {}", kind(self.actual()), self.actual()),
+            );
+        result.fail_derivation()
+    }
+}
+
+fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::testing::*;
+    use crate::IteratorAssertion;
+
+    use super::*;
+
+    #[test]
+    fn as_array() {
+        let value = json!([1, "a", null]);
+        assert_that!(value).as_array().has_length(3);
+        assert_that!(value).as_array().contains(json!("a"));
+        assert_that!(json!([])).as_array().is_empty();
+
+        assert_that!(check_that!(value)
+            .as_array()
+            .contains_exactly_in_order(vec![json!(1), json!(null), json!("a")].into_iter()))
+        .facts_are_at_least(vec![Fact::new("value of", "value.as_array()")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a JSON array\nbut was: object {\"id\":7}")]
+    fn as_array_of_object() {
+        assert_that!(json!({"id": 7})).as_array();
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::Mutex;
+
+use crate::base::{pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, Subject};
+
+/// Severity of a [`CapturedRecord`], ordered from the most severe (`Error`) to the least
+/// (`Trace`) like `log::Level`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    /// Serious errors.
+    Error,
+    /// Hazardous situations.
+    Warn,
+    /// Useful information.
+    Info,
+    /// Lower priority information.
+    Debug,
+    /// Very low priority, often extremely verbose, information.
+    Trace,
+}
+
+impl Display for LogLevel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Trace => "TRACE",
+        })
+    }
+}
+
+/// A log record captured by [`CapturedLogs`].
+#[derive(Clone, PartialEq, Eq)]
+pub struct CapturedRecord {
+    /// Severity of the record.
+    pub level: LogLevel,
+    /// Formatted message of the record.
+    pub message: String,
+}
+
+impl Debug for CapturedRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {:?}", self.level, self.message)
+    }
+}
+
+/// Log records captured during a test, to be checked with [`LogAssertion`].
+///
+/// Records are added through a shared reference so that a test logger (e.g. a `log::Log`
+/// implementation) can forward every record it receives with [`CapturedLogs::record`].
+#[derive(Debug, Default)]
+pub struct CapturedLogs {
+    records: Mutex<Vec<CapturedRecord>>,
+}
+
+impl CapturedLogs {
+    /// Creates an empty capture.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Captures a record.
+    pub fn record<M: Into<String>>(&self, level: LogLevel, message: M) {
+        self.records.lock().unwrap().push(CapturedRecord {
+            level,
+            message: message.into(),
+        });
+    }
+
+    /// Returns the captured records, in the order they were captured.
+    pub fn records(&self) -> Vec<CapturedRecord> {
+        self.records.lock().unwrap().clone()
+    }
+}
+
+/// Trait for captured log assertion.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let logs = CapturedLogs::new();
+/// logs.record(LogLevel::Warn, "disk almost full");
+///
+/// assert_that!(logs).contains_log(LogLevel::Warn, "disk");
+/// assert_that!(logs).has_no_errors();
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// let logs = CapturedLogs::new();
+/// logs.record(LogLevel::Info, "started");
+/// logs.record(LogLevel::Error, "disk full");
+///
+/// assert_that!(logs).has_no_errors();
+/// // expected no records at level: ERROR
+/// // but found                    : [ ERROR: "disk full" ]
+/// // captured records             : [
+/// //   INFO: "started"
+/// //   ERROR: "disk full"
+/// // ]
+/// ```
+pub trait LogAssertion<R> {
+    /// Checks that a record at `level` whose message contains `substring` was captured.
+    #[track_caller]
+    fn contains_log<M: AsRef<str>>(&self, level: LogLevel, substring: M) -> R;
+
+    /// Checks that no record at [`LogLevel::Error`] was captured.
+    #[track_caller]
+    fn has_no_errors(&self) -> R;
+}
+
+impl<R> LogAssertion<R> for Subject<'_, CapturedLogs, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn contains_log<M: AsRef<str>>(&self, level: LogLevel, substring: M) -> R {
+        pass_if_skipped!(self);
+        let records = self.actual().records();
+        let substring = substring.as_ref();
+        if records
+            .iter()
+            .any(|record| record.level == level && record.message.contains(substring))
+        {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_fact("expected a record at level", level.to_string())
+                .add_formatted_fact("containing", substring)
+                .add_simple_fact("but did not")
+                .add_formatted_values_fact("captured records", records)
+                .do_fail()
+        }
+    }
+
+    fn has_no_errors(&self) -> R {
+        pass_if_skipped!(self);
+        let records = self.actual().records();
+        let errors: Vec<&CapturedRecord> = records
+            .iter()
+            .filter(|record| record.level == LogLevel::Error)
+            .collect();
+        if errors.is_empty() {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_fact("expected no records at level", LogLevel::Error.to_string())
+                .add_formatted_values_fact("but found", errors)
+                .add_formatted_values_fact("captured records", records.iter().collect())
+                .do_fail()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::VecAssertion;
+
+    use super::*;
+
+    #[test]
+    fn contains_log() {
+        let logs = CapturedLogs::new();
+        logs.record(LogLevel::Info, "started");
+        logs.record(LogLevel::Error, "disk full");
+
+        assert_that!(logs).contains_log(LogLevel::Error, "disk");
+        assert_that!(logs).contains_log(LogLevel::Info, "");
+
+        // Failures
+        assert_that!(check_that!(logs).contains_log(LogLevel::Warn, "disk")).facts_are(vec![
+            Fact::new("expected a record at level", "WARN"),
+            Fact::new("containing", r#""disk""#),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact(
+                "captured records",
+                vec![r#"INFO: "started""#, r#"ERROR: "disk full""#],
+            ),
+        ]);
+        assert_that!(check_that!(CapturedLogs::new()).contains_log(LogLevel::Info, "started"))
+            .facts_are(vec![
+                Fact::new("expected a record at level", "INFO"),
+                Fact::new("containing", r#""started""#),
+                Fact::new_simple_fact("but did not"),
+                Fact::new_multi_value_fact("captured records", Vec::<String>::new()),
+            ]);
+    }
+
+    #[test]
+    fn has_no_errors() {
+        let logs = CapturedLogs::new();
+        assert_that!(logs).has_no_errors();
+        logs.record(LogLevel::Warn, "disk almost full");
+        assert_that!(logs).has_no_errors();
+
+        // Failures
+        logs.record(LogLevel::Error, "disk full");
+        assert_that!(check_that!(logs).has_no_errors()).facts_are(vec![
+            Fact::new("expected no records at level", "ERROR"),
+            Fact::new_multi_value_fact("but found", vec![r#"ERROR: "disk full""#]),
+            Fact::new_multi_value_fact(
+                "captured records",
+                vec![r#"WARN: "disk almost full""#, r#"ERROR: "disk full""#],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn record_from_threads() {
+        let logs = CapturedLogs::new();
+        std::thread::scope(|scope| {
+            for i in 0..4 {
+                let logs = &logs;
+                scope.spawn(move || logs.record(LogLevel::Debug, format!("worker {}", i)));
+            }
+        });
+        assert_that!(logs.records()).has_length(4);
+        assert_that!(logs).contains_log(LogLevel::Debug, "worker 3");
+    }
+}
@@ -13,14 +13,20 @@
 // limitations under the License.
 
 use std::borrow::Borrow;
+use std::collections::BTreeMap;
 use std::fmt::{Debug, Formatter};
 use std::hash::Hash;
+use std::ops::RangeBounds;
 
 use crate::assertions::basic::EqualityAssertion;
 use crate::assertions::iterator::{
-    check_contains, check_does_not_contain, check_is_empty, check_is_not_empty,
+    check_contains_or_suggest, check_does_not_contain, check_is_empty, check_is_not_empty,
+    check_len_bound, listed_in_stable_order, LengthBound, FAIL_FAST_NOTE,
+};
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, DuplicateKeys, FactKey,
+    Subject,
 };
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
 use crate::diff::iter::SequenceOrderComparison;
 use crate::diff::map::{MapComparison, MapLike, MapValueDiff, OrderedMapLike};
 
@@ -51,6 +57,19 @@ where
     #[track_caller]
     fn has_length(&self, length: usize) -> R;
 
+    /// Checks that the subject has at least `length` entries.
+    #[track_caller]
+    fn has_length_at_least(&self, length: usize) -> R;
+
+    /// Checks that the subject has at most `length` entries.
+    #[track_caller]
+    fn has_length_at_most(&self, length: usize) -> R;
+
+    /// Checks that the subject has at least `min` and at most `max` entries. A `min` greater than
+    /// `max` fails the assertion.
+    #[track_caller]
+    fn has_length_between(&self, min: usize, max: usize) -> R;
+
     /// Checks that the subject is empty.
     #[track_caller]
     fn is_empty(&self) -> R
@@ -95,32 +114,95 @@ where
         K: Eq + Hash + Debug,
         V: Eq + Debug;
 
-    /// Checks that the subject contains all entries from `expected`.
+    /// Checks that the subject has none of the given `keys`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use assertor::*;
+    ///
+    /// let map = HashMap::from([("one", 1), ("two", 2)]);
+    /// assert_that!(map).does_not_contain_keys(["three", "four"]);
+    /// ```
+    /// ```should_panic
+    /// use std::collections::BTreeMap;
+    /// use assertor::*;
+    ///
+    /// let map = BTreeMap::from([("one", 1), ("two", 2)]);
+    /// assert_that!(map).does_not_contain_keys(["two", "three"]);
+    /// // expected to contain none of: [ "two", "three" ]
+    /// // found (1)                  : [ "two" ]
+    /// // ---
+    /// // though it did contain keys : ["one", "two"]
+    /// ```
     #[track_caller]
-    fn contains_at_least<BM: 'a, OML: 'a>(&self, expected: BM) -> R
+    fn does_not_contain_keys<E>(&self, keys: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<K>,
+        K: Eq + Hash + Debug;
+
+    /// Checks that the subject does not map any key to `value`.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use assertor::*;
+    ///
+    /// let map = HashMap::from([("one", 1), ("two", 2)]);
+    /// assert_that!(map).contains_no_value(3);
+    /// ```
+    /// ```should_panic
+    /// use std::collections::BTreeMap;
+    /// use assertor::*;
+    ///
+    /// let map = BTreeMap::from([("one", 1), ("two", 2), ("deux", 2)]);
+    /// assert_that!(map).contains_no_value(2);
+    /// // expected to not contain value: 2
+    /// // found (2)                    : [ "deux" ⟶ 2, "two" ⟶ 2 ]
+    /// ```
+    #[track_caller]
+    fn contains_no_value<BV>(&self, value: BV) -> R
+    where
+        BV: Borrow<V>,
+        K: Debug,
+        V: Eq + Debug;
+
+    /// Checks that the subject contains all entries from `expected`, which can also be a reference
+    /// or a smart pointer to a map.
+    #[track_caller]
+    fn contains_at_least<OML: 'a>(&self, expected: OML) -> R
     where
         K: Eq + Hash + Debug,
         V: Eq + Debug,
-        OML: MapLike<K, V> + 'a,
-        BM: Borrow<OML> + 'a;
+        OML: MapLike<K, V> + 'a;
 
     /// Checks that the subject does not contain any entries from `expected`.
     #[track_caller]
-    fn does_not_contain_any<BM: 'a, OML: 'a>(&self, expected: BM) -> R
+    fn does_not_contain_any<OML: 'a>(&self, expected: OML) -> R
     where
         K: Eq + Hash + Debug,
         V: Eq + Debug,
-        OML: MapLike<K, V> + 'a,
-        BM: Borrow<OML> + 'a;
+        OML: MapLike<K, V> + 'a;
 
-    /// Checks that the subject contains only entries from `expected`.
+    /// Checks that the subject does not contain any entries from `expected`. Same as
+    /// [`does_not_contain_any`](MapAssertion::does_not_contain_any), named after the entries for
+    /// symmetry with [`does_not_contain_keys`](MapAssertion::does_not_contain_keys).
     #[track_caller]
-    fn contains_exactly<BM, OML>(&self, expected: BM) -> R
+    fn does_not_contain_entries<OML>(&self, expected: OML) -> R
     where
         K: Eq + Hash + Debug,
         V: Eq + Debug,
-        OML: MapLike<K, V> + 'a,
-        BM: Borrow<OML> + 'a;
+        OML: MapLike<K, V> + 'a;
+
+    /// Checks that the subject contains only entries from `expected`, which can also be a reference
+    /// or a smart pointer to a map.
+    #[track_caller]
+    fn contains_exactly<OML>(&self, expected: OML) -> R
+    where
+        K: Eq + Hash + Debug,
+        V: Eq + Debug,
+        OML: MapLike<K, V> + 'a;
 
     /// Returns a new subject which is an key set of the subject and which implements
     /// [`crate::IteratorAssertion`].
@@ -143,6 +225,88 @@ where
     fn key_set<'b>(&'b self) -> Subject<ML::It<'b>, (), R>
     where
         K: 'b;
+
+    /// Returns a new subject which is the only entry of the subject, as a `(key, value)` tuple.
+    /// Panics if the subject does not have exactly one entry.
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use assertor::*;
+    ///
+    /// let totals = HashMap::from([("2024-01-01", 42)]);
+    /// assert_that!(totals).single_entry().is_equal_to((&"2024-01-01", &42));
+    /// ```
+    /// ```should_panic
+    /// use std::collections::BTreeMap;
+    /// use assertor::*;
+    ///
+    /// let totals = BTreeMap::from([("a", 1), ("b", 2)]);
+    /// assert_that!(totals).single_entry();
+    /// // expected to have exactly one entry
+    /// // but had              : 2 entries
+    /// // though it did contain: [ [0]: "a" ⟶ 1, [1]: "b" ⟶ 2 ]
+    /// ```
+    #[track_caller]
+    fn single_entry<'b>(&'b self) -> Subject<'b, (&'b K, &'b V), (), R>
+    where
+        K: Debug,
+        V: Debug;
+
+    /// Returns a new subject which applies `f` to the values of both the subject and the expected
+    /// maps before comparing them, and which implements [`MappedValuesAssertion`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use assertor::*;
+    ///
+    /// let latencies = HashMap::from([("p50", 10.04), ("p99", 41.98)]);
+    /// assert_that!(latencies)
+    ///     .mapping_values(|v: &f64| v.round() as i64)
+    ///     .contains_exactly(HashMap::from([("p50", 10.0), ("p99", 42.0)]));
+    /// demo!(
+    ///     assert_that!(HashMap::from([("a", "X ")]))
+    ///         .mapping_values(|v: &&str| v.trim().to_lowercase())
+    ///         .contains_entry("a", "y".to_string()),
+    ///     r#"
+    ///     value of                               : HashMap::from([("a", "X ")]).mapping_values(..)
+    ///     expected key to be mapped to value     : "a" ⟶ "y"
+    ///     but key was mapped to a different value: "x"
+    ///     ---
+    ///     though it did contain keys             : ["a"]
+    ///     "#
+    /// );
+    /// ```
+    fn mapping_values<'b, F, W>(&'b self, f: F) -> Subject<'b, &'b ML, MappedValues<F>, R>
+    where
+        F: Fn(&V) -> W;
+
+    /// Returns a new subject which compares string keys ignoring case, such as HTTP headers, and
+    /// which implements [`CaseInsensitiveKeysAssertion`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::HashMap;
+    /// use assertor::*;
+    ///
+    /// let headers = HashMap::from([("Content-Type", "text/html")]);
+    /// assert_that!(headers)
+    ///     .with_case_insensitive_keys()
+    ///     .contains_entry("content-type", "text/html");
+    /// demo!(
+    ///     assert_that!(headers).with_case_insensitive_keys().contains_key("content-length"),
+    ///     r#"
+    ///     value of             : headers.with_case_insensitive_keys()
+    ///     expected to contain  : "content-length"
+    ///     but did not
+    ///     though it did contain: [ [0]: "Content-Type" ]
+    ///     "#
+    /// );
+    /// ```
+    fn with_case_insensitive_keys(&self) -> Subject<'_, &ML, CaseInsensitiveKeys, R>
+    where
+        K: Borrow<str>;
 }
 
 /// Trait for ordered map assertion.
@@ -172,21 +336,62 @@ where
 {
     /// Checks that the subject exactly contains `expected` in the same order.
     #[track_caller]
-    fn contains_exactly_in_order<BM, OML>(&self, expected: BM) -> R
+    fn contains_exactly_in_order<OML>(&self, expected: OML) -> R
     where
         K: Eq + Ord + Debug,
         V: Eq + Debug,
-        OML: OrderedMapLike<K, V> + 'a,
-        BM: Borrow<OML> + 'a;
+        OML: OrderedMapLike<K, V> + 'a;
 
     /// Checks that the subject contains at least all elements of `expected` in the same order.
     #[track_caller]
-    fn contains_all_of_in_order<BM, OML>(&self, expected: BM) -> R
+    fn contains_all_of_in_order<OML>(&self, expected: OML) -> R
     where
         K: Eq + Ord + Debug,
         V: Eq + Debug,
-        OML: OrderedMapLike<K, V> + 'a,
-        BM: Borrow<OML> + 'a;
+        OML: OrderedMapLike<K, V> + 'a;
+
+    /// Returns a new subject which is the smallest key of the subject, or `None` if the subject
+    /// is empty, and which implements [`crate::OptionAssertion`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use assertor::*;
+    ///
+    /// let readings = BTreeMap::from([(10, "start"), (20, "peak"), (30, "end")]);
+    /// assert_that!(readings).first_key().has_value(&10);
+    /// assert_that!(readings).last_key().has_value(&30);
+    /// ```
+    #[track_caller]
+    fn first_key<'b>(&'b self) -> Subject<'b, Option<&'b K>, (), R>
+    where
+        K: 'b;
+
+    /// Returns a new subject which is the largest key of the subject, or `None` if the subject
+    /// is empty, and which implements [`crate::OptionAssertion`].
+    #[track_caller]
+    fn last_key<'b>(&'b self) -> Subject<'b, Option<&'b K>, (), R>
+    where
+        K: 'b;
+
+    /// Returns a new subject which is a map of the entries of the subject whose keys are within
+    /// `range`, and which implements [`crate::MapAssertion`].
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::BTreeMap;
+    /// use assertor::*;
+    ///
+    /// let readings = BTreeMap::from([(10, "start"), (20, "peak"), (30, "end")]);
+    /// assert_that!(readings).entries_in_range(15..).has_length(2);
+    /// assert_that!(readings).entries_in_range(15..25).contains_entry(&20, &"peak");
+    /// ```
+    #[track_caller]
+    fn entries_in_range<'b, RB>(&'b self, range: RB) -> Subject<'b, BTreeMap<&'b K, &'b V>, (), R>
+    where
+        K: 'b,
+        V: 'b,
+        RB: RangeBounds<K> + Debug;
 }
 
 impl<'a, K, V, ML, R> MapAssertion<'a, K, V, ML, R> for Subject<'a, ML, (), R>
@@ -196,6 +401,7 @@ where
     ML: MapLike<K, V>,
 {
     fn has_length(&self, length: usize) -> R {
+        pass_if_skipped!(self);
         self.new_subject(
             &self.actual().len(),
             Some(format!("{}.len()", self.description_or_expr())),
@@ -204,10 +410,41 @@ where
         .is_equal_to(length)
     }
 
+    fn has_length_at_least(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().len(),
+            format!("{}.len()", self.description_or_expr()),
+            LengthBound::AtLeast(length),
+        )
+    }
+
+    fn has_length_at_most(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().len(),
+            format!("{}.len()", self.description_or_expr()),
+            LengthBound::AtMost(length),
+        )
+    }
+
+    fn has_length_between(&self, min: usize, max: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().len(),
+            format!("{}.len()", self.description_or_expr()),
+            LengthBound::Between(min, max),
+        )
+    }
+
     fn is_empty(&self) -> R
     where
         K: Debug,
     {
+        pass_if_skipped!(self);
         check_is_empty(self.new_result(), self.actual().keys().into_iter())
     }
 
@@ -215,6 +452,7 @@ where
     where
         K: Debug,
     {
+        pass_if_skipped!(self);
         check_is_not_empty(self.new_result(), self.actual().keys().into_iter())
     }
 
@@ -223,9 +461,13 @@ where
         BK: Borrow<K>,
         K: Eq + Hash + Debug,
     {
-        check_contains(
+        pass_if_skipped!(self);
+        if self.actual().contains(key.borrow()) {
+            return self.new_result().do_ok();
+        }
+        check_contains_or_suggest(
             self.new_result(),
-            self.actual().keys().into_iter(),
+            listed_keys(self.actual()).into_iter(),
             &key.borrow(),
         )
     }
@@ -235,9 +477,13 @@ where
         BK: Borrow<K>,
         K: Eq + Hash + Debug,
     {
+        pass_if_skipped!(self);
+        if !self.actual().contains(key.borrow()) {
+            return self.new_result().do_ok();
+        }
         check_does_not_contain(
             self.new_result(),
-            self.actual().keys().into_iter(),
+            listed_keys(self.actual()).into_iter(),
             &key.borrow(),
         )
     }
@@ -249,39 +495,14 @@ where
         K: Eq + Hash + Debug,
         V: Eq + Debug,
     {
-        let actual_value = self.actual().get(key.borrow());
-        if Some(value.borrow()) == actual_value {
-            self.new_result().do_ok()
-        } else if actual_value.is_none() {
-            self.new_result()
-                .add_formatted_fact(
-                    "expected key to be mapped to value",
-                    MapEntry::new(key.borrow(), value.borrow()),
-                )
-                .add_fact("but key was not found", format!("{:?}", key.borrow()))
-                .add_splitter()
-                .add_fact(
-                    "though it did contain keys",
-                    format!("{:?}", self.actual().keys()),
-                )
-                .do_fail()
-        } else {
-            self.new_result()
-                .add_formatted_fact(
-                    "expected key to be mapped to value",
-                    MapEntry::new(key.borrow(), value.borrow()),
-                )
-                .add_fact(
-                    "but key was mapped to a different value",
-                    format!("{:?}", actual_value.unwrap().borrow()),
-                )
-                .add_splitter()
-                .add_fact(
-                    "though it did contain keys",
-                    format!("{:?}", self.actual().keys()),
-                )
-                .do_fail()
-        }
+        pass_if_skipped!(self);
+        check_contains_entry(
+            self.new_result(),
+            key.borrow(),
+            value.borrow(),
+            self.actual().get(key.borrow()),
+            self.actual(),
+        )
     }
 
     fn does_not_contain_entry<BK, BV>(&self, key: BK, value: BV) -> R
@@ -291,6 +512,7 @@ where
         K: Eq + Hash + Debug,
         V: Eq + Debug,
     {
+        pass_if_skipped!(self);
         let actual_value = self.actual().get(key.borrow());
         if Some(value.borrow()) == actual_value {
             self.new_result()
@@ -301,48 +523,88 @@ where
                 .add_simple_fact("but entry was found")
                 .add_splitter()
                 // TODO: add better representation of the map
-                .add_fact(
-                    "though it did contain",
-                    format!("{:?}", self.actual().keys()),
-                )
+                .add_formatted_fact("though it did contain", listed_keys(self.actual()))
                 .do_fail()
         } else {
             self.new_result().do_ok()
         }
     }
 
-    fn contains_at_least<BM, OML>(&self, expected: BM) -> R
+    fn does_not_contain_keys<E>(&self, keys: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<K>,
+        K: Eq + Hash + Debug,
+    {
+        pass_if_skipped!(self);
+        let keys: Vec<E::Item> = keys.into_iter().collect();
+        let found: Vec<&K> = keys
+            .iter()
+            .map(Borrow::borrow)
+            .filter(|key| self.actual().contains(key))
+            .collect();
+        if found.is_empty() {
+            return self.new_result().do_ok();
+        }
+        self.new_result()
+            .add_formatted_values_fact(
+                FactKey::ExpectedToContainNoneOf,
+                keys.iter().map(Borrow::borrow).collect(),
+            )
+            .add_formatted_values_fact(FactKey::FoundN(found.len()), found)
+            .add_splitter()
+            .add_formatted_fact("though it did contain keys", listed_keys(self.actual()))
+            .do_fail()
+    }
+
+    fn contains_no_value<BV>(&self, value: BV) -> R
+    where
+        BV: Borrow<V>,
+        K: Debug,
+        V: Eq + Debug,
+    {
+        pass_if_skipped!(self);
+        let found: Vec<MapEntry<K, V>> = self
+            .actual()
+            .entries()
+            .into_iter()
+            .filter(|(_, actual_value)| value.borrow().eq(actual_value))
+            .map(|(key, value)| MapEntry::new(key, value))
+            .collect();
+        if found.is_empty() {
+            return self.new_result().do_ok();
+        }
+        self.new_result()
+            .add_formatted_fact("expected to not contain value", value.borrow())
+            .add_formatted_values_fact(FactKey::FoundN(found.len()), found)
+            .do_fail()
+    }
+
+    fn contains_at_least<OML>(&self, expected: OML) -> R
     where
         K: Eq + Hash + Debug,
         V: Eq + Debug,
         OML: MapLike<K, V> + 'a,
-        BM: Borrow<OML> + 'a,
     {
-        let expected_map = expected.borrow();
+        pass_if_skipped!(self);
+        let expected_map = &expected;
         let diff = MapComparison::from_map_like(self.actual(), expected_map, None);
         if diff.common.len() == expected_map.len() {
             return self.new_result().do_ok();
         }
-        let (result, splitter) = feed_missing_entries_facts(
-            "at least",
-            self.new_result(),
-            &diff,
-            expected_map.len(),
-            false,
-        );
-        feed_different_values_facts(result, &diff, splitter)
-            .0
-            .do_fail()
+        let result =
+            feed_missing_entries_facts("at least", self.new_result(), &diff, expected_map.len());
+        feed_different_values_facts(result, &diff).do_fail()
     }
 
-    fn does_not_contain_any<BM: 'a, OML: 'a>(&self, expected: BM) -> R
+    fn does_not_contain_any<OML: 'a>(&self, expected: OML) -> R
     where
         K: Eq + Hash + Debug,
         V: Eq + Debug,
         OML: MapLike<K, V> + 'a,
-        BM: Borrow<OML> + 'a,
     {
-        let expected_map = expected.borrow();
+        pass_if_skipped!(self);
+        let expected_map = &expected;
         let diff = MapComparison::from_map_like(self.actual(), expected_map, None);
         if !diff.common.is_empty() {
             let mut result = self
@@ -357,40 +619,534 @@ where
         return self.new_result().do_ok();
     }
 
-    fn contains_exactly<BM, OML>(&self, expected: BM) -> R
+    fn does_not_contain_entries<OML>(&self, expected: OML) -> R
+    where
+        K: Eq + Hash + Debug,
+        V: Eq + Debug,
+        OML: MapLike<K, V> + 'a,
+    {
+        pass_if_skipped!(self);
+        self.does_not_contain_any(expected)
+    }
+
+    fn contains_exactly<OML>(&self, expected: OML) -> R
     where
         K: Eq + Hash + Debug,
         V: Eq + Debug,
         OML: MapLike<K, V> + 'a,
-        BM: Borrow<OML> + 'a,
     {
-        let expected_map = expected.borrow();
+        pass_if_skipped!(self);
+        let expected_map = &expected;
+        if self.is_fail_fast() {
+            return match first_entry_difference(self.new_result(), self.actual(), expected_map) {
+                Some(result) => result.do_fail(),
+                None => self.new_result().do_ok(),
+            };
+        }
         let diff = MapComparison::from_map_like(self.actual(), expected_map, None);
         if diff.extra.is_empty() && diff.missing.is_empty() && diff.different_values.is_empty() {
             return self.new_result().do_ok();
         }
-        let (result, splitter) = feed_missing_entries_facts(
-            "exactly",
+        let result =
+            feed_missing_entries_facts("exactly", self.new_result(), &diff, expected_map.len());
+        let result = feed_extra_entries_facts(result, &diff);
+        feed_different_values_facts(result, &diff).do_fail()
+    }
+
+    fn key_set<'b>(&'b self) -> Subject<ML::It<'b>, (), R>
+    where
+        K: 'b,
+    {
+        self.new_owned_subject(
+            self.actual().keys_iter(),
+            Some(format!("{}.keys()", self.description_or_expr())),
+            (),
+        )
+        .with_unordered_elements(!self.actual().keys_ordered())
+    }
+
+    fn single_entry<'b>(&'b self) -> Subject<'b, (&'b K, &'b V), (), R>
+    where
+        K: Debug,
+        V: Debug,
+    {
+        let mut entries = self.actual().entries();
+        if entries.len() == 1 {
+            return self.new_owned_subject(
+                entries.remove(0),
+                Some(format!("{}.single_entry()", self.description_or_expr())),
+                (),
+            );
+        }
+        // Like `IteratorAssertion::single`, a derived subject can not be returned, so panic.
+        let mut result: AssertionResult = self
+            .new_result()
+            .add_simple_fact("expected to have exactly one entry")
+            .add_fact("but had", format!("{} entries", entries.len()));
+        if !entries.is_empty() {
+            let found: Vec<MapEntry<K, V>> = entries
+                .into_iter()
+                .map(|(key, value)| MapEntry::new(key, value))
+                .collect();
+            result = result.add_formatted_values_fact(FactKey::ThoughItDidContain, found);
+        }
+        result.fail_derivation()
+    }
+
+    fn mapping_values<'b, F, W>(&'b self, f: F) -> Subject<'b, &'b ML, MappedValues<F>, R>
+    where
+        F: Fn(&V) -> W,
+    {
+        self.new_owned_subject(
+            self.actual(),
+            Some(format!("{}.mapping_values(..)", self.description_or_expr())),
+            MappedValues { f },
+        )
+    }
+
+    fn with_case_insensitive_keys(&self) -> Subject<'_, &ML, CaseInsensitiveKeys, R>
+    where
+        K: Borrow<str>,
+    {
+        self.new_owned_subject(
+            self.actual(),
+            Some(format!(
+                "{}.with_case_insensitive_keys()",
+                self.description_or_expr()
+            )),
+            CaseInsensitiveKeys,
+        )
+    }
+}
+
+/// Trait for map assertions on values transformed by [`MapAssertion::mapping_values`].
+pub trait MappedValuesAssertion<'a, K: 'a + Eq, V, W, ML, R>
+where
+    AssertionResult: AssertionStrategy<R>,
+    ML: MapLike<K, V>,
+{
+    /// Checks that the subject contains the entry, comparing the transformed value.
+    #[track_caller]
+    fn contains_entry<BK, BW>(&self, key: BK, value: BW) -> R
+    where
+        BK: Borrow<K>,
+        BW: Borrow<W>,
+        K: Debug,
+        W: Eq + Debug;
+
+    /// Checks that the subject contains all entries from `expected`, comparing the transformed
+    /// values.
+    #[track_caller]
+    fn contains_at_least<OML>(&self, expected: OML) -> R
+    where
+        K: Debug,
+        W: Eq + Debug,
+        OML: MapLike<K, V>;
+
+    /// Checks that the subject contains only entries from `expected`, comparing the transformed
+    /// values.
+    #[track_caller]
+    fn contains_exactly<OML>(&self, expected: OML) -> R
+    where
+        K: Debug,
+        W: Eq + Debug,
+        OML: MapLike<K, V>;
+}
+
+/// Value transformation set by [`MapAssertion::mapping_values`].
+pub struct MappedValues<F> {
+    f: F,
+}
+
+impl<'a, K, V, W, ML, F, R> MappedValuesAssertion<'a, K, V, W, ML, R>
+    for Subject<'a, &'a ML, MappedValues<F>, R>
+where
+    AssertionResult: AssertionStrategy<R>,
+    K: 'a + Eq,
+    ML: MapLike<K, V>,
+    F: Fn(&V) -> W,
+{
+    fn contains_entry<BK, BW>(&self, key: BK, value: BW) -> R
+    where
+        BK: Borrow<K>,
+        BW: Borrow<W>,
+        K: Debug,
+        W: Eq + Debug,
+    {
+        pass_if_skipped!(self);
+        let actual_value = self.actual().get(key.borrow()).map(&self.option().f);
+        check_contains_entry(
+            self.new_result(),
+            key.borrow(),
+            value.borrow(),
+            actual_value.as_ref(),
+            self.actual(),
+        )
+    }
+
+    fn contains_at_least<OML>(&self, expected: OML) -> R
+    where
+        K: Debug,
+        W: Eq + Debug,
+        OML: MapLike<K, V>,
+    {
+        pass_if_skipped!(self);
+        let actual_map = MappedMap::new(*self.actual(), |k, v| (k, (self.option().f)(v)));
+        let expected_map = MappedMap::new(&expected, |k, v| (k, (self.option().f)(v)));
+        let diff = MapComparison::from_map_like(&actual_map, &expected_map, None);
+        if diff.common.len() == expected_map.len() {
+            return self.new_result().do_ok();
+        }
+        let result =
+            feed_missing_entries_facts("at least", self.new_result(), &diff, expected_map.len());
+        feed_different_values_facts(result, &diff).do_fail()
+    }
+
+    fn contains_exactly<OML>(&self, expected: OML) -> R
+    where
+        K: Debug,
+        W: Eq + Debug,
+        OML: MapLike<K, V>,
+    {
+        pass_if_skipped!(self);
+        let actual_map = MappedMap::new(*self.actual(), |k, v| (k, (self.option().f)(v)));
+        let expected_map = MappedMap::new(&expected, |k, v| (k, (self.option().f)(v)));
+        let diff = MapComparison::from_map_like(&actual_map, &expected_map, None);
+        if diff.extra.is_empty() && diff.missing.is_empty() && diff.different_values.is_empty() {
+            return self.new_result().do_ok();
+        }
+        let result =
+            feed_missing_entries_facts("exactly", self.new_result(), &diff, expected_map.len());
+        let result = feed_extra_entries_facts(result, &diff);
+        feed_different_values_facts(result, &diff).do_fail()
+    }
+}
+
+/// Trait for multimap assertions, on maps whose values are `Vec`s.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use assertor::*;
+///
+/// let owners = HashMap::from([("src", vec!["alice", "bob"]), ("docs", vec![])]);
+/// assert_that!(owners).contains_key_with_value("src", "bob");
+/// assert_that!(owners).values_for_key("docs").is_empty();
+/// demo!(
+///     assert_that!(owners).contains_key_with_value("src", "carol"),
+///     r#"
+///     expected key to be mapped to a value including: "src" ⟶ "carol"
+///     but key was mapped to                         : [ "alice", "bob" ]
+///     "#
+/// );
+/// ```
+pub trait MultimapAssertion<'a, K: 'a + Eq, V, ML, R>
+where
+    AssertionResult: AssertionStrategy<R>,
+    ML: MapLike<K, Vec<V>>,
+{
+    /// Checks that the values of `key` include `value`.
+    #[track_caller]
+    fn contains_key_with_value<BK, BV>(&self, key: BK, value: BV) -> R
+    where
+        BK: Borrow<K>,
+        BV: Borrow<V>,
+        K: Debug,
+        V: PartialEq + Debug;
+
+    /// Returns a new subject which is an iterator over the values of `key`, and which implements
+    /// [`crate::IteratorAssertion`]. Panics if the subject does not contain `key`.
+    #[track_caller]
+    fn values_for_key<'b, BK>(&'b self, key: BK) -> Subject<'b, std::slice::Iter<'b, V>, (), R>
+    where
+        BK: Borrow<K>,
+        K: Debug;
+}
+
+impl<'a, K, V, ML, R> MultimapAssertion<'a, K, V, ML, R> for Subject<'a, ML, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+    K: 'a + Eq,
+    ML: MapLike<K, Vec<V>>,
+{
+    fn contains_key_with_value<BK, BV>(&self, key: BK, value: BV) -> R
+    where
+        BK: Borrow<K>,
+        BV: Borrow<V>,
+        K: Debug,
+        V: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        let result = match self.actual().get(key.borrow()) {
+            Some(values) if values.contains(value.borrow()) => return self.new_result().do_ok(),
+            Some(values) => new_multimap_result(self, key.borrow(), Some(value.borrow()))
+                .add_formatted_values_fact("but key was mapped to", values.iter().collect()),
+            None => new_multimap_result(self, key.borrow(), Some(value.borrow())),
+        };
+        result.do_fail()
+    }
+
+    fn values_for_key<'b, BK>(&'b self, key: BK) -> Subject<'b, std::slice::Iter<'b, V>, (), R>
+    where
+        BK: Borrow<K>,
+        K: Debug,
+    {
+        if let Some(values) = self.actual().get(key.borrow()) {
+            return self.new_owned_subject(
+                values.iter(),
+                Some(format!(
+                    "{}.values_for_key({:?})",
+                    self.description_or_expr(),
+                    key.borrow()
+                )),
+                (),
+            );
+        }
+        // Like `single_entry`, a derived subject can not be returned, so panic.
+        new_multimap_result(self, key.borrow(), None).fail_derivation()
+    }
+}
+
+/// Starts the failure of a multimap assertion, with the missing key facts if `key` is not found.
+fn new_multimap_result<K, V, ML, R>(
+    subject: &Subject<ML, (), R>,
+    key: &K,
+    value: Option<&dyn Debug>,
+) -> AssertionResult
+where
+    K: Eq + Debug,
+    ML: MapLike<K, Vec<V>>,
+{
+    let result = match value {
+        Some(value) => subject.new_result().add_fact(
+            "expected key to be mapped to a value including",
+            format!("{:?} ⟶ {:?}", key, value),
+        ),
+        None => subject
+            .new_result()
+            .add_formatted_fact("expected to contain key", key),
+    };
+    if subject.actual().contains(key) {
+        return result;
+    }
+    result
+        .add_formatted_fact("but key was not found", key)
+        .add_splitter()
+        .add_formatted_fact("though it did contain keys", listed_keys(subject.actual()))
+}
+
+/// Key lookup mode set by [`MapAssertion::with_case_insensitive_keys`].
+pub struct CaseInsensitiveKeys;
+
+/// Trait for assertions on string-keyed maps which ignore the case of keys, returned by
+/// [`MapAssertion::with_case_insensitive_keys`]. When keys of the subject only differ in case, the
+/// first one in the map's iteration order is used.
+pub trait CaseInsensitiveKeysAssertion<'a, K: 'a + Eq, V, ML, R>
+where
+    AssertionResult: AssertionStrategy<R>,
+    ML: MapLike<K, V>,
+{
+    /// Checks that the subject contains the key, ignoring case.
+    #[track_caller]
+    fn contains_key<BK: Borrow<str>>(&self, key: BK) -> R;
+
+    /// Checks that the subject does not contain the key, ignoring case.
+    #[track_caller]
+    fn does_not_contain_key<BK: Borrow<str>>(&self, key: BK) -> R;
+
+    /// Checks that the subject maps the key, ignoring case, to the value.
+    #[track_caller]
+    fn contains_entry<BK, BV>(&self, key: BK, value: BV) -> R
+    where
+        BK: Borrow<str>,
+        BV: Borrow<V>,
+        V: Eq + Debug;
+
+    /// Checks that the subject contains all entries from `expected`, ignoring the case of keys.
+    #[track_caller]
+    fn contains_at_least<OML>(&self, expected: OML) -> R
+    where
+        V: Eq + Debug,
+        OML: MapLike<K, V>;
+
+    /// Checks that the subject contains only entries from `expected`, ignoring the case of keys.
+    #[track_caller]
+    fn contains_exactly<OML>(&self, expected: OML) -> R
+    where
+        V: Eq + Debug,
+        OML: MapLike<K, V>;
+}
+
+impl<'a, K, V, ML, R> CaseInsensitiveKeysAssertion<'a, K, V, ML, R>
+    for Subject<'a, &'a ML, CaseInsensitiveKeys, R>
+where
+    AssertionResult: AssertionStrategy<R>,
+    K: 'a + Eq + Borrow<str>,
+    ML: MapLike<K, V>,
+{
+    fn contains_key<BK: Borrow<str>>(&self, key: BK) -> R {
+        pass_if_skipped!(self);
+        let actual_map = MappedMap::new(*self.actual(), |k, v| (CaseInsensitiveKey::new(k), v));
+        let key = CaseInsensitiveKey(key.borrow());
+        if actual_map.contains(&key) {
+            return self.new_result().do_ok();
+        }
+        check_contains_or_suggest(
             self.new_result(),
-            &diff,
-            expected_map.len(),
-            false,
-        );
-        let (result, splitter) = feed_extra_entries_facts(result, &diff, splitter);
-        feed_different_values_facts(result, &diff, splitter)
-            .0
-            .do_fail()
+            listed_keys(&actual_map).into_iter().copied(),
+            &key,
+        )
+    }
+
+    fn does_not_contain_key<BK: Borrow<str>>(&self, key: BK) -> R {
+        pass_if_skipped!(self);
+        let actual_map = MappedMap::new(*self.actual(), |k, v| (CaseInsensitiveKey::new(k), v));
+        let key = CaseInsensitiveKey(key.borrow());
+        if !actual_map.contains(&key) {
+            return self.new_result().do_ok();
+        }
+        check_does_not_contain(
+            self.new_result(),
+            listed_keys(&actual_map).into_iter().copied(),
+            &key,
+        )
+    }
+
+    fn contains_entry<BK, BV>(&self, key: BK, value: BV) -> R
+    where
+        BK: Borrow<str>,
+        BV: Borrow<V>,
+        V: Eq + Debug,
+    {
+        pass_if_skipped!(self);
+        let actual_map = MappedMap::new(*self.actual(), |k, v| (CaseInsensitiveKey::new(k), v));
+        let key = CaseInsensitiveKey(key.borrow());
+        check_contains_entry(
+            self.new_result(),
+            &key,
+            &value.borrow(),
+            actual_map.get(&key),
+            &actual_map,
+        )
+    }
+
+    fn contains_at_least<OML>(&self, expected: OML) -> R
+    where
+        V: Eq + Debug,
+        OML: MapLike<K, V>,
+    {
+        pass_if_skipped!(self);
+        let actual_map = MappedMap::new(*self.actual(), |k, v| (CaseInsensitiveKey::new(k), v));
+        let expected_map = MappedMap::new(&expected, |k, v| (CaseInsensitiveKey::new(k), v));
+        let diff = MapComparison::from_map_like(&actual_map, &expected_map, None);
+        if diff.common.len() == expected_map.len() {
+            return self.new_result().do_ok();
+        }
+        let result =
+            feed_missing_entries_facts("at least", self.new_result(), &diff, expected_map.len());
+        feed_different_values_facts(result, &diff).do_fail()
+    }
+
+    fn contains_exactly<OML>(&self, expected: OML) -> R
+    where
+        V: Eq + Debug,
+        OML: MapLike<K, V>,
+    {
+        pass_if_skipped!(self);
+        let actual_map = MappedMap::new(*self.actual(), |k, v| (CaseInsensitiveKey::new(k), v));
+        let expected_map = MappedMap::new(&expected, |k, v| (CaseInsensitiveKey::new(k), v));
+        let diff = MapComparison::from_map_like(&actual_map, &expected_map, None);
+        if diff.extra.is_empty() && diff.missing.is_empty() && diff.different_values.is_empty() {
+            return self.new_result().do_ok();
+        }
+        let result =
+            feed_missing_entries_facts("exactly", self.new_result(), &diff, expected_map.len());
+        let result = feed_extra_entries_facts(result, &diff);
+        feed_different_values_facts(result, &diff).do_fail()
+    }
+}
+
+/// A string key which equals keys differing only in case.
+#[derive(Clone, Copy)]
+struct CaseInsensitiveKey<'a>(&'a str);
+
+impl<'a> CaseInsensitiveKey<'a> {
+    fn new<K: Borrow<str>>(key: &'a K) -> Self {
+        CaseInsensitiveKey(key.borrow())
+    }
+}
+
+impl PartialEq for CaseInsensitiveKey<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_lowercase() == other.0.to_lowercase()
+    }
+}
+
+impl Eq for CaseInsensitiveKey<'_> {}
+
+impl Debug for CaseInsensitiveKey<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(self.0, f)
+    }
+}
+
+/// Entries of a map with transformed keys or values, compared by [`MappedValuesAssertion`] and
+/// [`CaseInsensitiveKeysAssertion`].
+struct MappedMap<K, W> {
+    entries: Vec<(K, W)>,
+    keys_ordered: bool,
+}
+
+impl<K: Eq, W> MappedMap<K, W> {
+    fn new<'a, SK, V, ML>(map: &'a ML, f: impl Fn(&'a SK, &'a V) -> (K, W)) -> Self
+    where
+        SK: 'a + Eq,
+        V: 'a,
+        ML: MapLike<SK, V>,
+    {
+        MappedMap {
+            entries: map
+                .entries()
+                .into_iter()
+                .map(|(key, value)| f(key, value))
+                .collect(),
+            keys_ordered: map.keys_ordered(),
+        }
+    }
+}
+
+impl<K: Eq, W> MapLike<K, W> for MappedMap<K, W> {
+    type It<'b>
+        = std::iter::Map<std::slice::Iter<'b, (K, W)>, fn(&'b (K, W)) -> &'b K>
+    where
+        K: 'b,
+        W: 'b,
+        Self: 'b;
+
+    fn get(&self, k: &K) -> Option<&W> {
+        self.entries
+            .iter()
+            .find(|(key, _)| key == k)
+            .map(|(_, value)| value)
     }
 
-    fn key_set<'b>(&'b self) -> Subject<ML::It<'b>, (), R>
+    fn keys_iter<'b>(&'b self) -> Self::It<'b>
     where
         K: 'b,
+        W: 'b,
     {
-        self.new_owned_subject(
-            self.actual().keys_iter(),
-            Some(format!("{}.keys()", self.description_or_expr())),
-            (),
-        )
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    fn keys_ordered(&self) -> bool {
+        self.keys_ordered
+    }
+
+    fn entries(&self) -> Vec<(&K, &W)> {
+        self.entries
+            .iter()
+            .map(|(key, value)| (key, value))
+            .collect()
     }
 }
 
@@ -400,25 +1156,35 @@ where
     K: 'a + Eq + Ord,
     ML: OrderedMapLike<K, V>,
 {
-    fn contains_exactly_in_order<BM, OML>(&self, expected: BM) -> R
+    fn contains_exactly_in_order<OML>(&self, expected: OML) -> R
     where
         K: Eq + Ord + Debug,
         V: Eq + Debug,
         OML: OrderedMapLike<K, V> + 'a,
-        BM: Borrow<OML> + 'a,
     {
+        pass_if_skipped!(self);
+        if self.is_fail_fast() {
+            return match first_entry_difference_in_order(
+                self.new_result(),
+                self.actual(),
+                &expected,
+            ) {
+                Some(result) => result.do_fail(),
+                None => self.new_result().do_ok(),
+            };
+        }
         let map_diff = MapComparison::from_map_like(
             self.actual(),
-            expected.borrow(),
+            &expected,
             Some(SequenceOrderComparison::Strict),
         );
-        let (values_assertion_result, values_different) =
-            feed_different_values_facts(self.new_result(), &map_diff, false);
+        let values_different = !map_diff.different_values.is_empty();
+        let values_assertion_result = feed_different_values_facts(self.new_result(), &map_diff);
         let key_order_comparison = map_diff.key_order_comparison.unwrap();
         let (order_assertion_result, order_ok) = super::iterator::check_contains_exactly_in_order(
             key_order_comparison,
             self.actual().keys().into_iter(),
-            expected.borrow().keys().into_iter(),
+            expected.keys().into_iter(),
             values_assertion_result,
         );
 
@@ -429,25 +1195,25 @@ where
         }
     }
 
-    fn contains_all_of_in_order<BM, OML>(&self, expected: BM) -> R
+    fn contains_all_of_in_order<OML>(&self, expected: OML) -> R
     where
         K: Eq + Ord + Debug,
         V: Eq + Debug,
         OML: OrderedMapLike<K, V> + 'a,
-        BM: Borrow<OML> + 'a,
     {
+        pass_if_skipped!(self);
         let map_diff = MapComparison::from_map_like(
             self.actual(),
-            expected.borrow(),
+            &expected,
             Some(SequenceOrderComparison::Relative),
         );
-        let (values_assertion_result, values_different) =
-            feed_different_values_facts(self.new_result(), &map_diff, false);
+        let values_different = !map_diff.different_values.is_empty();
+        let values_assertion_result = feed_different_values_facts(self.new_result(), &map_diff);
         let key_order_comparison = map_diff.key_order_comparison.unwrap();
         let (order_assertion_result, order_ok) = super::iterator::check_contains_all_of_in_order(
             key_order_comparison,
             self.actual().keys().into_iter(),
-            expected.borrow().keys().into_iter(),
+            expected.keys().into_iter(),
             values_assertion_result,
         );
 
@@ -457,6 +1223,83 @@ where
             order_assertion_result.do_fail()
         }
     }
+
+    fn first_key<'b>(&'b self) -> Subject<'b, Option<&'b K>, (), R>
+    where
+        K: 'b,
+    {
+        self.new_owned_subject(
+            self.actual().first_key(),
+            Some(format!("{}.first_key()", self.description_or_expr())),
+            (),
+        )
+    }
+
+    fn last_key<'b>(&'b self) -> Subject<'b, Option<&'b K>, (), R>
+    where
+        K: 'b,
+    {
+        self.new_owned_subject(
+            self.actual().last_key(),
+            Some(format!("{}.last_key()", self.description_or_expr())),
+            (),
+        )
+    }
+
+    fn entries_in_range<'b, RB>(&'b self, range: RB) -> Subject<'b, BTreeMap<&'b K, &'b V>, (), R>
+    where
+        K: 'b,
+        V: 'b,
+        RB: RangeBounds<K> + Debug,
+    {
+        let description = format!("{}.range({:?})", self.description_or_expr(), range);
+        self.new_owned_subject(
+            self.actual().entries_in_range(range).into_iter().collect(),
+            Some(description),
+            (),
+        )
+    }
+}
+
+fn check_contains_entry<K, V, MV, ML, R>(
+    result: AssertionResult,
+    key: &K,
+    value: &V,
+    actual_value: Option<&V>,
+    actual: &ML,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    K: Eq + Debug,
+    V: Eq + Debug,
+    ML: MapLike<K, MV>,
+{
+    if Some(value) == actual_value {
+        return result.do_ok();
+    }
+    let result = result.add_formatted_fact(
+        "expected key to be mapped to value",
+        MapEntry::new(key, value),
+    );
+    match actual_value {
+        None => result.add_formatted_fact("but key was not found", key),
+        Some(actual_value) => {
+            result.add_formatted_fact("but key was mapped to a different value", actual_value)
+        }
+    }
+    .add_splitter()
+    .add_formatted_fact("though it did contain keys", listed_keys(actual))
+    .do_fail()
+}
+
+/// Returns the keys of `map` to be listed in failure facts, in a stable order.
+fn listed_keys<'a, K, V, ML>(map: &'a ML) -> Vec<&'a K>
+where
+    K: 'a + Eq + Debug,
+    V: 'a,
+    ML: MapLike<K, V>,
+{
+    listed_in_stable_order(map.keys_iter(), map.keys_ordered())
 }
 
 fn pluralize<'a>(count: usize, single: &'a str, plural: &'a str) -> &'a str {
@@ -468,71 +1311,151 @@ fn pluralize<'a>(count: usize, single: &'a str, plural: &'a str) -> &'a str {
 }
 
 fn feed_different_values_facts<K: Eq + Debug, V: Eq + Debug>(
-    mut result: AssertionResult,
+    result: AssertionResult,
     diff: &MapComparison<&K, &V>,
-    splitter: bool,
-) -> (AssertionResult, bool) {
-    let has_diffs = !diff.different_values.is_empty();
-    if has_diffs {
-        if splitter {
-            result = result.add_splitter();
-        }
-        result = result
-            .add_fact(
-                "expected to contain the same entries",
-                format!(
-                    "but found {} {} different",
+) -> AssertionResult {
+    if diff.different_values.is_empty() {
+        return result;
+    }
+    let mut ordered_diffs: Vec<_> = diff.different_values.iter().collect();
+    ordered_diffs.sort_by(|d1, d2| format!("{:?}", d1.key).cmp(&format!("{:?}", d2.key)));
+    result
+        .with_duplicate_keys(DuplicateKeys::Merge)
+        .begin_group()
+        .add_fact(
+            "expected to contain the same entries",
+            format!(
+                "but found {} {} different",
+                diff.different_values.len(),
+                pluralize(
                     diff.different_values.len(),
-                    pluralize(
-                        diff.different_values.len(),
-                        "entry that is",
-                        "entries that are",
-                    )
-                ),
-            )
-            .add_splitter();
-        let mut ordered_diffs: Vec<_> = diff.different_values.iter().collect();
-        ordered_diffs.sort_by(|d1, d2| format!("{:?}", d1.key).cmp(&format!("{:?}", d2.key)));
-        result = result.add_formatted_values_fact(
+                    "entry that is",
+                    "entries that are",
+                )
+            ),
+        )
+        .add_splitter()
+        .add_formatted_values_fact(
             format!(
                 "{} mapped to unexpected {}",
                 pluralize(diff.different_values.len(), "key was", "keys were"),
                 pluralize(diff.different_values.len(), "value", "values")
             ),
             ordered_diffs,
-        );
+        )
+        .end_group()
+}
+
+/// Reports the first entry of `expected` which is missing from `actual` or has another value in
+/// it, or else the first unexpected entry, without comparing further. Returns `None` if the maps
+/// are equal.
+fn first_entry_difference<K, V, A, E>(
+    result: AssertionResult,
+    actual: &A,
+    expected: &E,
+) -> Option<AssertionResult>
+where
+    K: Eq + Debug,
+    V: Eq + Debug,
+    A: MapLike<K, V>,
+    E: MapLike<K, V>,
+{
+    for (key, expected_value) in expected.entries() {
+        match actual.get(key) {
+            None => {
+                return Some(
+                    result
+                        .add_formatted_fact("first missing", MapEntry::new(key, expected_value))
+                        .add_note(FAIL_FAST_NOTE),
+                )
+            }
+            Some(actual_value) if actual_value != expected_value => {
+                return Some(
+                    result
+                        .add_formatted_fact("first different value at", key)
+                        .add_formatted_fact(FactKey::Expected, expected_value)
+                        .add_formatted_fact(FactKey::Actual, actual_value)
+                        .add_note(FAIL_FAST_NOTE),
+                )
+            }
+            Some(_) => {}
+        }
+    }
+    actual
+        .entries()
+        .into_iter()
+        .find(|(key, _)| !expected.contains(key))
+        .map(|(key, value)| {
+            result
+                .add_formatted_fact("first unexpected", MapEntry::new(key, value))
+                .add_note(FAIL_FAST_NOTE)
+        })
+}
+
+/// Reports the first position at which the entries of `actual` and `expected` differ, without
+/// comparing further. Returns `None` if they are equal.
+fn first_entry_difference_in_order<K, V, A, E>(
+    result: AssertionResult,
+    actual: &A,
+    expected: &E,
+) -> Option<AssertionResult>
+where
+    K: Eq + Ord + Debug,
+    V: Eq + Debug,
+    A: OrderedMapLike<K, V>,
+    E: OrderedMapLike<K, V>,
+{
+    let actual = actual.entries();
+    let expected = expected.entries();
+    let index = actual
+        .iter()
+        .zip(&expected)
+        .position(|(actual, expected)| actual != expected)
+        .unwrap_or(actual.len().min(expected.len()));
+    if index == actual.len() && index == expected.len() {
+        return None;
     }
-    (result, has_diffs)
+    let result = result.add_fact("first difference at", format!("index {}", index));
+    let result = match expected.get(index) {
+        Some((key, value)) => {
+            result.add_formatted_fact(FactKey::Expected, MapEntry::new(key, value))
+        }
+        None => result.add_fact(FactKey::Expected, "no entry"),
+    };
+    let result = match actual.get(index) {
+        Some((key, value)) => result.add_formatted_fact(FactKey::Actual, MapEntry::new(key, value)),
+        None => result.add_fact(FactKey::Actual, "no entry"),
+    };
+    Some(result.add_note(FAIL_FAST_NOTE))
 }
 
 fn feed_missing_entries_facts<K: Eq + Debug, V: Eq + Debug>(
     containment_spec: &str,
-    mut result: AssertionResult,
+    result: AssertionResult,
     diff: &MapComparison<&K, &V>,
     expected_length: usize,
-    splitter: bool,
-) -> (AssertionResult, bool) {
-    let has_diffs = !diff.missing.is_empty();
-    if has_diffs {
-        if splitter {
-            result = result.add_splitter();
-        }
-        result = result
-            .add_fact(
-                format!(
-                    "expected to contain {} {} provided {}",
-                    containment_spec,
-                    expected_length,
-                    pluralize(expected_length, "entry", "entries")
-                ),
-                format!(
-                    "but {} {} not found",
-                    diff.missing.len(),
-                    pluralize(diff.missing.len(), "entry", "entries")
-                ),
-            )
-            .add_splitter();
-        result = result.add_formatted_values_fact(
+) -> AssertionResult {
+    if diff.missing.is_empty() {
+        return result;
+    }
+    result
+        .with_duplicate_keys(DuplicateKeys::Merge)
+        .begin_group()
+        .add_fact(
+            format!(
+                "expected to contain {} {} provided {}",
+                containment_spec,
+                expected_length,
+                pluralize(expected_length, "entry", "entries")
+            ),
+            format!(
+                "but {} {} not found",
+                diff.missing.len(),
+                pluralize(diff.missing.len(), "entry", "entries")
+            ),
+        )
+        .add_splitter()
+        .add_formatted_values_fact(
             format!(
                 "{} not found",
                 pluralize(diff.missing.len(), "entry was", "entries were")
@@ -541,32 +1464,30 @@ fn feed_missing_entries_facts<K: Eq + Debug, V: Eq + Debug>(
                 .into_iter()
                 .map(|(k, v)| MapEntry::new(k, v))
                 .collect(),
-        );
-    }
-    (result, has_diffs)
+        )
+        .end_group()
 }
 
 fn feed_extra_entries_facts<K: Eq + Debug, V: Eq + Debug>(
-    mut result: AssertionResult,
+    result: AssertionResult,
     diff: &MapComparison<&K, &V>,
-    splitter: bool,
-) -> (AssertionResult, bool) {
-    let has_diffs = !diff.extra.is_empty();
-    if has_diffs {
-        if splitter {
-            result = result.add_splitter();
-        }
-        result = result
-            .add_fact(
-                "expected to not contain additional entries".to_string(),
-                format!(
-                    "but {} additional {} found",
-                    diff.extra.len(),
-                    pluralize(diff.extra.len(), "entry was", "entries were")
-                ),
-            )
-            .add_splitter();
-        result = result.add_formatted_values_fact(
+) -> AssertionResult {
+    if diff.extra.is_empty() {
+        return result;
+    }
+    result
+        .with_duplicate_keys(DuplicateKeys::Merge)
+        .begin_group()
+        .add_fact(
+            "expected to not contain additional entries".to_string(),
+            format!(
+                "but {} additional {} found",
+                diff.extra.len(),
+                pluralize(diff.extra.len(), "entry was", "entries were")
+            ),
+        )
+        .add_splitter()
+        .add_formatted_values_fact(
             format!(
                 "unexpected {} found",
                 pluralize(diff.extra.len(), "entry was", "entries were")
@@ -575,9 +1496,8 @@ fn feed_extra_entries_facts<K: Eq + Debug, V: Eq + Debug>(
                 .into_iter()
                 .map(|(k, v)| MapEntry::new(k, v))
                 .collect(),
-        );
-    }
-    (result, has_diffs)
+        )
+        .end_group()
 }
 
 struct MapEntry<'a, K: Debug, V: Debug> {
@@ -612,7 +1532,9 @@ impl<K: Debug, V: PartialEq + Debug> Debug for MapValueDiff<&K, &V> {
 #[cfg(test)]
 mod tests {
     use crate::testing::*;
-    use crate::{assert_that, check_that, Fact, IteratorAssertion, SetAssertion};
+    use crate::{
+        assert_that, check_that, Fact, IteratorAssertion, OptionAssertion, ResultAssertion,
+    };
     use std::collections::{BTreeMap, HashMap};
 
     use super::*;
@@ -635,6 +1557,38 @@ mod tests {
         ])
     }
 
+    #[test]
+    fn has_length_bounds() {
+        let map = BTreeMap::from([("a", 1), ("b", 2)]);
+        assert_that!(map).has_length_at_least(2);
+        assert_that!(map).has_length_at_most(2);
+
+        // failures
+        assert_that!(check_that!(map).has_length_at_least(3)).facts_are(vec![
+            Fact::new("value of", "map.len()"),
+            Fact::new("expected", "at least 3"),
+            Fact::new("actual", "2"),
+        ]);
+        assert_that!(check_that!(map).has_length_at_most(1)).facts_are(vec![
+            Fact::new("value of", "map.len()"),
+            Fact::new("expected", "at most 1"),
+            Fact::new("actual", "2"),
+        ]);
+    }
+
+    #[test]
+    fn has_length_between() {
+        let map = BTreeMap::from([("a", 1), ("b", 2)]);
+        assert_that!(map).has_length_between(1, 2);
+
+        // failures
+        assert_that!(check_that!(map).has_length_between(3, 4)).facts_are(vec![
+            Fact::new("value of", "map.len()"),
+            Fact::new("expected", "between 3 and 4"),
+            Fact::new("actual", "2"),
+        ]);
+    }
+
     #[test]
     fn is_empty() {
         let map_empty: HashMap<&str, &str> = HashMap::new();
@@ -673,15 +1627,33 @@ mod tests {
         assert_that!(map_abc).contains_key("c");
 
         // failures
-        let result = check_that!(map_abc).contains_key("not exist");
-        assert_that!(result).facts_are_at_least(vec![
+        assert_that!(check_that!(map_abc).contains_key("not exist")).facts_are(vec![
             Fact::new("expected to contain", r#""not exist""#),
             Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec![r#""a""#, r#""b""#, r#""c""#]),
+        ]);
+    }
+
+    #[test]
+    fn contains_key_suggestions() {
+        let map = BTreeMap::from([("color", 1), ("colors", 2), ("size", 3)]);
+        assert_that!(check_that!(map).contains_key("colour")).facts_are(vec![
+            Fact::new("expected to contain", r#""colour""#),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("did you mean", vec![r#""color""#, r#""colors""#]),
+            Fact::new_multi_value_fact(
+                "though it did contain",
+                vec![r#""color""#, r#""colors""#, r#""size""#],
+            ),
+        ]);
+        assert_that!(check_that!(map).contains_key("weight")).facts_are(vec![
+            Fact::new("expected to contain", r#""weight""#),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact(
+                "though it did contain",
+                vec![r#""color""#, r#""colors""#, r#""size""#],
+            ),
         ]);
-        assert_that!(result)
-            .fact_keys()
-            .contains(&"though it did contain".to_string());
-        // Skip test for value because key order is not stable.
     }
 
     #[test]
@@ -694,15 +1666,38 @@ mod tests {
         assert_that!(map_abc).does_not_contain_key("y");
 
         // failures
-        let result = check_that!(map_abc).does_not_contain_key("a");
-        assert_that!(result).facts_are_at_least(vec![
+        assert_that!(check_that!(map_abc).does_not_contain_key("a")).facts_are(vec![
             Fact::new("expected to not contain", r#""a""#),
             Fact::new_simple_fact("but element was found"),
+            Fact::new_multi_value_fact("though it did contain", vec![r#""a""#, r#""b""#, r#""c""#]),
         ]);
+    }
+
+    #[test]
+    fn single_entry() {
+        let totals = HashMap::from([("2024-01-01", 42)]);
+        assert_that!(totals)
+            .single_entry()
+            .is_equal_to((&"2024-01-01", &42));
+
+        // failures
+        let result = check_that!(totals)
+            .single_entry()
+            .is_equal_to((&"2024-01-02", &42));
         assert_that!(result)
-            .fact_keys()
-            .contains(&"though it did contain".to_string());
-        // Skip test for value because key order is not stable.
+            .facts_are_at_least(vec![Fact::new("value of", "totals.single_entry()")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected to have exactly one entry\nbut had: 0 entries")]
+    fn single_entry_of_empty() {
+        assert_that!(HashMap::<&str, i32>::new()).single_entry();
+    }
+
+    #[test]
+    #[should_panic(expected = r#"though it did contain: [ [0]: "a" ⟶ 1, [1]: "b" ⟶ 2 ]"#)]
+    fn single_entry_of_many() {
+        assert_that!(BTreeMap::from([("a", 1), ("b", 2)])).single_entry();
     }
 
     #[test]
@@ -711,23 +1706,18 @@ mod tests {
         map_abc.insert("a", "1");
         map_abc.insert("b", "2");
         map_abc.insert("c", "3");
-        assert_that!(map_abc).key_set().contains(&"a");
-        assert_that!(map_abc).key_set().contains(&"b");
-        assert_that!(map_abc).key_set().contains(&"c");
+        assert_that!(map_abc).key_set().contains("a");
+        assert_that!(map_abc).key_set().contains("b");
+        assert_that!(map_abc).key_set().contains("c");
 
         // failures
-        let result = check_that!(map_abc).key_set().contains(&"not exist");
-        assert_that!(result).facts_are_at_least(vec![
+        let result = check_that!(map_abc).key_set().contains("not exist");
+        assert_that!(result).facts_are(vec![
             Fact::new("value of", "map_abc.keys()"),
             Fact::new("expected to contain", r#""not exist""#),
             Fact::new_simple_fact("but did not"),
-            /* TODO: fix unstable value order.
-             * Fact::new("though it did contain", r#"["c", "a", "b"]"#), */
+            Fact::new_multi_value_fact("though it did contain", vec![r#""a""#, r#""b""#, r#""c""#]),
         ]);
-        assert_that!(result)
-            .fact_keys()
-            .contains(&"though it did contain".to_string());
-        // Skip test for value because key order is not stable.
     }
 
     #[test]
@@ -741,28 +1731,20 @@ mod tests {
         assert_that!(map_abc).contains_entry("c", "3");
 
         // failures: missing key
-        let result = check_that!(map_abc).contains_entry("not exist", "1");
-        assert_that!(result).facts_are_at_least(vec![
+        assert_that!(check_that!(map_abc).contains_entry("not exist", "1")).facts_are(vec![
             Fact::new("expected key to be mapped to value", r#""not exist" ⟶ "1""#),
             Fact::new("but key was not found", r#""not exist""#),
             Fact::new_splitter(),
+            Fact::new("though it did contain keys", r#"["a", "b", "c"]"#),
         ]);
-        assert_that!(result)
-            .fact_keys()
-            .contains(&"though it did contain keys".to_string());
-        // Skip test for value because key order is not stable.
 
         // failures: not equal value
-        let result = check_that!(map_abc).contains_entry("a", "2");
-        assert_that!(result).facts_are_at_least(vec![
+        assert_that!(check_that!(map_abc).contains_entry("a", "2")).facts_are(vec![
             Fact::new("expected key to be mapped to value", r#""a" ⟶ "2""#),
             Fact::new("but key was mapped to a different value", r#""1""#),
             Fact::new_splitter(),
+            Fact::new("though it did contain keys", r#"["a", "b", "c"]"#),
         ]);
-        assert_that!(result)
-            .fact_keys()
-            .contains(&"though it did contain keys".to_string());
-        // Skip test for value because key order is not stable.
     }
 
     #[test]
@@ -780,15 +1762,12 @@ mod tests {
         assert_that!(map_abc).does_not_contain_entry("c", "4");
 
         // failure
-        let result = check_that!(map_abc).does_not_contain_entry("a", "1");
-        assert_that!(result).facts_are_at_least(vec![
+        assert_that!(check_that!(map_abc).does_not_contain_entry("a", "1")).facts_are(vec![
             Fact::new("expected to not contain entry", r#""a" ⟶ "1""#),
             Fact::new_simple_fact("but entry was found"),
             Fact::new_splitter(),
+            Fact::new("though it did contain", r#"["a", "b", "c"]"#),
         ]);
-        assert_that!(result)
-            .fact_keys()
-            .contains(&"though it did contain".to_string());
     }
 
     #[test]
@@ -958,6 +1937,51 @@ mod tests {
         assert_that!(result).facts_are_at_least(vec![Fact::new_simple_fact(r#""a" ⟶ "1""#)]);
     }
 
+    #[test]
+    fn does_not_contain_entries() {
+        let map = BTreeMap::from([("a", 1), ("b", 2)]);
+        assert_that!(map).does_not_contain_entries(BTreeMap::from([("a", 2), ("x", 1)]));
+
+        // Failures
+        let result = check_that!(map).does_not_contain_entries(BTreeMap::from([("a", 1)]));
+        assert_that!(result).facts_are(vec![
+            Fact::new_simple_fact("found 1 unexpected entries"),
+            Fact::new_splitter(),
+            Fact::new_simple_fact(r#""a" ⟶ 1"#),
+        ]);
+    }
+
+    #[test]
+    fn does_not_contain_keys() {
+        let map = BTreeMap::from([("a", 1), ("b", 2), ("c", 3)]);
+        assert_that!(map).does_not_contain_keys(["x", "y"]);
+        assert_that!(map).does_not_contain_keys(Vec::<&str>::new());
+
+        // Failures
+        assert_that!(check_that!(map).does_not_contain_keys(["c", "x", "a"])).facts_are(vec![
+            Fact::new_multi_value_fact(
+                "expected to contain none of",
+                vec![r#""c""#, r#""x""#, r#""a""#],
+            ),
+            Fact::new_multi_value_fact("found (2)", vec![r#""c""#, r#""a""#]),
+            Fact::new_splitter(),
+            Fact::new("though it did contain keys", r#"["a", "b", "c"]"#),
+        ]);
+    }
+
+    #[test]
+    fn contains_no_value() {
+        let map = BTreeMap::from([("a", 1), ("b", 2), ("c", 2)]);
+        assert_that!(map).contains_no_value(3);
+        assert_that!(BTreeMap::<&str, i32>::new()).contains_no_value(1);
+
+        // Failures
+        assert_that!(check_that!(map).contains_no_value(2)).facts_are(vec![
+            Fact::new("expected to not contain value", "2"),
+            Fact::new_multi_value_fact("found (2)", vec![r#""b" ⟶ 2"#, r#""c" ⟶ 2"#]),
+        ]);
+    }
+
     #[test]
     fn supports_any_map() {
         let empty: BTreeMap<String, String> = BTreeMap::new();
@@ -966,8 +1990,8 @@ mod tests {
         assert_that!(empty).is_empty();
         assert_that!(tree_map).is_not_empty();
         assert_that!(tree_map).contains_key("hello");
-        assert_that!(tree_map).does_not_contain_key(&"key");
-        assert_that!(tree_map).key_set().contains(&"hello");
+        assert_that!(tree_map).does_not_contain_key("key");
+        assert_that!(tree_map).key_set().contains("hello");
         assert_that!(tree_map).contains_entry("hello", "sorted_map");
         assert_that!(tree_map).does_not_contain_entry("hello", "other");
         assert_that!(tree_map).contains_at_least(BTreeMap::from([("world", "in")]));
@@ -980,6 +2004,234 @@ mod tests {
         assert_that!(tree_map).does_not_contain_any(HashMap::from([("world", "nope")]));
     }
 
+    #[test]
+    fn mapping_values() {
+        let actual = HashMap::from([("a", 1.04), ("b", 1.96)]);
+        let round = |v: &f64| v.round() as i64;
+        assert_that!(actual)
+            .mapping_values(round)
+            .contains_exactly(HashMap::from([("a", 1.0), ("b", 2.0)]));
+        assert_that!(actual)
+            .mapping_values(round)
+            .contains_at_least(HashMap::from([("b", 2.4)]));
+        assert_that!(actual)
+            .mapping_values(round)
+            .contains_entry("a", 1);
+
+        assert_that!(check_that!(actual)
+            .mapping_values(round)
+            .contains_exactly(HashMap::from([("a", 1.6), ("b", 2.0)])))
+        .facts_are(vec![
+            Fact::new("value of", "actual.mapping_values(..)"),
+            Fact::new(
+                "expected to contain the same entries",
+                "but found 1 entry that is different",
+            ),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact(
+                "key was mapped to unexpected value",
+                vec![r#"{ key: "a", expected: 1, actual: 2 }"#],
+            ),
+        ]);
+        assert_that!(check_that!(actual)
+            .mapping_values(round)
+            .contains_entry("c", 1))
+        .facts_are_at_least(vec![
+            Fact::new("value of", "actual.mapping_values(..)"),
+            Fact::new("expected key to be mapped to value", r#""c" ⟶ 1"#),
+            Fact::new("but key was not found", r#""c""#),
+            Fact::new_splitter(),
+        ]);
+    }
+
+    #[test]
+    fn mapping_values_of_ordered_map() {
+        let actual = BTreeMap::from([(1, " a"), (2, "B")]);
+        assert_that!(check_that!(actual)
+            .mapping_values(|v: &&str| v.trim().to_lowercase())
+            .contains_exactly(BTreeMap::from([(1, "a"), (3, "b")])))
+        .facts_are(vec![
+            Fact::new("value of", "actual.mapping_values(..)"),
+            Fact::new(
+                "expected to contain exactly 2 provided entries",
+                "but 1 entry not found",
+            ),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("entry was not found", vec![r#"3 ⟶ "b""#]),
+            Fact::new_splitter(),
+            Fact::new(
+                "expected to not contain additional entries",
+                "but 1 additional entry was found",
+            ),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("unexpected entry was found", vec![r#"2 ⟶ "b""#]),
+        ]);
+    }
+
+    #[test]
+    fn with_case_insensitive_keys() {
+        let headers = HashMap::from([("Content-Type", "text/html"), ("ETag", "abc")]);
+        assert_that!(headers)
+            .with_case_insensitive_keys()
+            .contains_key("content-type");
+        assert_that!(headers)
+            .with_case_insensitive_keys()
+            .does_not_contain_key("content-length");
+        assert_that!(headers)
+            .with_case_insensitive_keys()
+            .contains_entry("etag", "abc");
+        assert_that!(headers)
+            .with_case_insensitive_keys()
+            .contains_exactly(HashMap::from([
+                ("CONTENT-TYPE", "text/html"),
+                ("etag", "abc"),
+            ]));
+        assert_that!(headers)
+            .with_case_insensitive_keys()
+            .contains_at_least(HashMap::from([("etag", "abc")]));
+
+        assert_that!(check_that!(headers)
+            .with_case_insensitive_keys()
+            .does_not_contain_key("etag"))
+        .facts_are_at_least(vec![
+            Fact::new("value of", "headers.with_case_insensitive_keys()"),
+            Fact::new("expected to not contain", r#""etag""#),
+            Fact::new_simple_fact("but element was found"),
+        ]);
+        assert_that!(check_that!(headers)
+            .with_case_insensitive_keys()
+            .contains_entry("etag", "xyz"))
+        .facts_are_at_least(vec![
+            Fact::new("value of", "headers.with_case_insensitive_keys()"),
+            Fact::new("expected key to be mapped to value", r#""etag" ⟶ "xyz""#),
+            Fact::new("but key was mapped to a different value", r#""abc""#),
+        ]);
+    }
+
+    #[test]
+    fn with_case_insensitive_keys_diff() {
+        let headers = BTreeMap::from([("Accept".to_string(), "*/*"), ("Host".to_string(), "a")]);
+        assert_that!(check_that!(headers)
+            .with_case_insensitive_keys()
+            .contains_exactly(BTreeMap::from([
+                ("accept".to_string(), "*/*"),
+                ("host".to_string(), "b")
+            ])))
+        .facts_are(vec![
+            Fact::new("value of", "headers.with_case_insensitive_keys()"),
+            Fact::new(
+                "expected to contain the same entries",
+                "but found 1 entry that is different",
+            ),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact(
+                "key was mapped to unexpected value",
+                vec![r#"{ key: "Host", expected: "a", actual: "b" }"#],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn contains_key_with_value() {
+        let owners = BTreeMap::from([("docs", vec![]), ("src", vec!["alice", "bob"])]);
+        assert_that!(owners).contains_key_with_value("src", "alice");
+
+        assert_that!(check_that!(owners).contains_key_with_value("src", "carol")).facts_are(vec![
+            Fact::new(
+                "expected key to be mapped to a value including",
+                r#""src" ⟶ "carol""#,
+            ),
+            Fact::new_multi_value_fact("but key was mapped to", vec![r#""alice""#, r#""bob""#]),
+        ]);
+        assert_that!(check_that!(owners).contains_key_with_value("lib", "alice")).facts_are(vec![
+            Fact::new(
+                "expected key to be mapped to a value including",
+                r#""lib" ⟶ "alice""#,
+            ),
+            Fact::new("but key was not found", r#""lib""#),
+            Fact::new_splitter(),
+            Fact::new("though it did contain keys", r#"["docs", "src"]"#),
+        ]);
+    }
+
+    #[test]
+    fn values_for_key() {
+        let owners = HashMap::from([("docs", vec![]), ("src", vec!["alice", "bob"])]);
+        assert_that!(owners)
+            .values_for_key("src")
+            .contains_exactly(["bob", "alice"].iter());
+        assert_that!(owners).values_for_key("docs").is_empty();
+        assert_that!(check_that!(owners).values_for_key("src").contains("carol"))
+            .facts_are_at_least(vec![Fact::new(
+                "value of",
+                r#"owners.values_for_key("src")"#,
+            )]);
+    }
+
+    #[test]
+    #[should_panic(expected = "but key was not found     : \"lib\"")]
+    fn values_for_missing_key() {
+        let owners = HashMap::from([("src", vec!["alice"])]);
+        check_that!(owners).values_for_key("lib");
+    }
+
+    #[test]
+    fn contains_exactly_fail_fast() {
+        let note = "stopped after first difference; rerun with full_diff() for details";
+        let map = HashMap::from([("a", 1), ("b", 2)]);
+        assert_that!(map)
+            .fail_fast()
+            .contains_exactly(HashMap::from([("b", 2), ("a", 1)]));
+
+        // Failures
+        assert_that!(check_that!(map)
+            .fail_fast()
+            .contains_exactly(HashMap::from([("a", 1), ("c", 3)])))
+        .facts_are(vec![
+            Fact::new("first missing", r#""c" ⟶ 3"#),
+            Fact::new("note", note),
+        ]);
+        assert_that!(check_that!(map)
+            .fail_fast()
+            .contains_exactly(HashMap::from([("a", 1), ("b", 3)])))
+        .facts_are(vec![
+            Fact::new("first different value at", r#""b""#),
+            Fact::new("expected", "3"),
+            Fact::new("actual", "2"),
+            Fact::new("note", note),
+        ]);
+        assert_that!(check_that!(map)
+            .fail_fast()
+            .contains_exactly(HashMap::from([("b", 2)])))
+        .facts_are(vec![
+            Fact::new("first unexpected", r#""a" ⟶ 1"#),
+            Fact::new("note", note),
+        ]);
+
+        let tree_map = BTreeMap::from([("a", 1), ("b", 2)]);
+        assert_that!(tree_map)
+            .fail_fast()
+            .contains_exactly_in_order(BTreeMap::from([("a", 1), ("b", 2)]));
+        assert_that!(check_that!(tree_map)
+            .fail_fast()
+            .contains_exactly_in_order(BTreeMap::from([("a", 1), ("b", 3), ("c", 4)])))
+        .facts_are(vec![
+            Fact::new("first difference at", "index 1"),
+            Fact::new("expected", r#""b" ⟶ 3"#),
+            Fact::new("actual", r#""b" ⟶ 2"#),
+            Fact::new("note", note),
+        ]);
+        assert_that!(check_that!(tree_map)
+            .fail_fast()
+            .contains_exactly_in_order(BTreeMap::from([("a", 1)])))
+        .facts_are(vec![
+            Fact::new("first difference at", "index 1"),
+            Fact::new("expected", "no entry"),
+            Fact::new("actual", r#""b" ⟶ 2"#),
+            Fact::new("note", note),
+        ]);
+    }
+
     #[test]
     fn contains_exactly_in_order() {
         let tree_map = BTreeMap::from([("hello", "sorted_map"), ("world", "in")]);
@@ -1005,8 +2257,8 @@ mod tests {
         let result = check_that!(tree_map)
             .contains_exactly_in_order(BTreeMap::from([("hello", "sorted_map"), ("was", "at")]));
         assert_that!(result).facts_are(vec![
-            Fact::new("missing (1)", r#"["was"]"#),
-            Fact::new("unexpected (1)", r#"["world"]"#),
+            Fact::new("mismatched indexes", "1 of 2"),
+            Fact::new_multi_value_fact("index | expected | actual", vec![r#"1 | "was" | "world""#]),
             Fact::new_splitter(),
             Fact::new_multi_value_fact("expected", vec![r#""hello""#, r#""was""#]),
             Fact::new_multi_value_fact("actual", vec![r#""hello""#, r#""world""#]),
@@ -1025,8 +2277,8 @@ mod tests {
                 r#"key was mapped to unexpected value"#,
                 vec![r#"{ key: "hello", expected: "sorted_map", actual: "wrong" }"#],
             ),
-            Fact::new("missing (1)", r#"["was"]"#),
-            Fact::new("unexpected (1)", r#"["world"]"#),
+            Fact::new("mismatched indexes", "1 of 2"),
+            Fact::new_multi_value_fact("index | expected | actual", vec![r#"1 | "was" | "world""#]),
             Fact::new_splitter(),
             Fact::new_multi_value_fact("expected", vec![r#""hello""#, r#""was""#]),
             Fact::new_multi_value_fact("actual", vec![r#""hello""#, r#""world""#]),
@@ -1059,4 +2311,110 @@ mod tests {
             Fact::new_multi_value_fact("actual", vec![r#""hello""#, r#""lang""#, r#""world""#]),
         ]);
     }
+
+    #[test]
+    fn nested_in_options_and_results() {
+        let map = HashMap::from([("k", 1)]);
+        let borrowed: Option<&HashMap<&str, i32>> = Some(&map);
+        assert_that!(borrowed).some().contains_key("k");
+        assert_that!(borrowed).some().contains_entry("k", 1);
+        assert_that!(borrowed).some().has_length(1);
+        assert_that!(Some(map.clone())).some().contains_key("k");
+        let ordered: Result<&BTreeMap<&str, i32>, ()> = Ok(&BTreeMap::new());
+        assert_that!(ordered).ok().is_empty();
+        assert_that!(ordered).ok().first_key().is_none();
+
+        // Failures
+        assert_that!(check_that!(borrowed).some().contains_key("missing")).facts_are(vec![
+            Fact::new("expected to contain", "\"missing\""),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["\"k\""]),
+        ]);
+    }
+
+    #[test]
+    fn smart_pointers() {
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        assert_that!(Arc::new(HashMap::from([("a", 1)]))).contains_entry("a", 1);
+        assert_that!(Box::new(BTreeMap::from([("a", 1), ("b", 2)])))
+            .first_key()
+            .has_value(&"a");
+
+        // Failures
+        assert_that!(check_that!(Rc::new(BTreeMap::from([("a", 1)]))).has_length(2)).facts_are(
+            vec![
+                Fact::new("value of", "Rc::new(BTreeMap::from([(\"a\", 1)])).len()"),
+                Fact::new("expected", "2"),
+                Fact::new("actual", "1"),
+            ],
+        );
+    }
+
+    #[test]
+    fn expected_behind_references_and_smart_pointers() {
+        use std::rc::Rc;
+
+        let actual = HashMap::from([("a", 1), ("b", 2)]);
+        let expected = HashMap::from([("a", 1), ("b", 2)]);
+        assert_that!(actual).contains_exactly(&expected);
+        assert_that!(actual).contains_exactly(Box::new(expected.clone()));
+        assert_that!(actual).contains_exactly(Rc::new(expected.clone()));
+        assert_that!(actual).contains_at_least(&expected);
+        assert_that!(actual).contains_at_least(Box::new(expected.clone()));
+        assert_that!(actual).contains_at_least(Rc::new(expected.clone()));
+        assert_that!(actual).does_not_contain_any(&HashMap::from([("a", 2)]));
+        assert_that!(actual)
+            .mapping_values(|v: &i32| v % 2)
+            .contains_exactly(&HashMap::from([("a", 3), ("b", 4)]));
+
+        let ordered = BTreeMap::from([("a", 1), ("b", 2)]);
+        assert_that!(ordered).contains_exactly_in_order(&ordered);
+        assert_that!(ordered).contains_all_of_in_order(Rc::new(BTreeMap::from([("b", 2)])));
+
+        // Failures
+        assert_that!(check_that!(actual).contains_exactly(&HashMap::from([("a", 1)])))
+            .facts_are_at_least(vec![Fact::new(
+                "expected to not contain additional entries",
+                "but 1 additional entry was found",
+            )]);
+    }
+
+    #[test]
+    fn first_and_last_key() {
+        let readings = BTreeMap::from([(20, "peak"), (10, "start"), (30, "end")]);
+        assert_that!(readings).first_key().has_value(&10);
+        assert_that!(readings).last_key().has_value(&30);
+        assert_that!(BTreeMap::<i32, i32>::new())
+            .first_key()
+            .is_none();
+        assert_that!(BTreeMap::<i32, i32>::new())
+            .last_key()
+            .is_none();
+
+        // Failures
+        assert_that!(check_that!(readings).last_key().has_value(&20)).facts_are(vec![
+            Fact::new("value of", "readings.last_key()"),
+            Fact::new("expected", "Some(20)"),
+            Fact::new("actual", "Some(30)"),
+        ]);
+    }
+
+    #[test]
+    fn entries_in_range() {
+        let readings = BTreeMap::from([(10, "start"), (20, "peak"), (30, "end")]);
+        assert_that!(readings).entries_in_range(15..).has_length(2);
+        assert_that!(readings)
+            .entries_in_range(15..25)
+            .contains_entry(&20, &"peak");
+        assert_that!(readings).entries_in_range(..10).is_empty();
+
+        // Failures
+        assert_that!(check_that!(readings).entries_in_range(..=20).has_length(3)).facts_are(vec![
+            Fact::new("value of", "readings.range(..=20).len()"),
+            Fact::new("expected", "3"),
+            Fact::new("actual", "2"),
+        ]);
+    }
 }
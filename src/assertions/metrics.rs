@@ -0,0 +1,232 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Formatter};
+use std::sync::Mutex;
+
+use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+
+/// Counters and histograms recorded during a test, to be checked with [`MetricsAssertion`].
+///
+/// Metrics are recorded through a shared reference so that a test recorder (e.g. a
+/// `metrics::Recorder` implementation) can forward every update it receives.
+#[derive(Debug, Default)]
+pub struct CapturedMetrics {
+    counters: Mutex<BTreeMap<String, u64>>,
+    histograms: Mutex<BTreeMap<String, Vec<f64>>>,
+}
+
+impl CapturedMetrics {
+    /// Creates an empty capture.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Increments the counter `name` by `value`, registering it if needed.
+    pub fn increment_counter<N: Into<String>>(&self, name: N, value: u64) {
+        *self
+            .counters
+            .lock()
+            .unwrap()
+            .entry(name.into())
+            .or_default() += value;
+    }
+
+    /// Records `value` to the histogram `name`, registering it if needed.
+    pub fn record_histogram<N: Into<String>>(&self, name: N, value: f64) {
+        self.histograms
+            .lock()
+            .unwrap()
+            .entry(name.into())
+            .or_default()
+            .push(value);
+    }
+
+    /// Returns the value of the counter `name`, if recorded.
+    pub fn counter(&self, name: &str) -> Option<u64> {
+        self.counters.lock().unwrap().get(name).copied()
+    }
+
+    /// Returns the values recorded to the histogram `name`, if recorded.
+    pub fn histogram(&self, name: &str) -> Option<Vec<f64>> {
+        self.histograms.lock().unwrap().get(name).cloned()
+    }
+
+    fn recorded(&self) -> Vec<RecordedMetric> {
+        let counters = self.counters.lock().unwrap();
+        let histograms = self.histograms.lock().unwrap();
+        counters
+            .iter()
+            .map(|(name, value)| RecordedMetric::Counter(name.clone(), *value))
+            .chain(
+                histograms
+                    .iter()
+                    .map(|(name, values)| RecordedMetric::Histogram(name.clone(), values.clone())),
+            )
+            .collect()
+    }
+}
+
+/// A recorded metric, rendered in facts when a lookup fails.
+enum RecordedMetric {
+    Counter(String, u64),
+    Histogram(String, Vec<f64>),
+}
+
+impl Debug for RecordedMetric {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecordedMetric::Counter(name, value) => write!(f, "counter {}: {}", name, value),
+            RecordedMetric::Histogram(name, values) => {
+                write!(f, "histogram {}: {:?}", name, values)
+            }
+        }
+    }
+}
+
+/// Trait for captured metrics assertion.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let metrics = CapturedMetrics::new();
+/// metrics.increment_counter("requests_total", 3);
+/// metrics.record_histogram("latency_ms", 12.5);
+///
+/// assert_that!(metrics).counter("requests_total").is_at_least(1);
+/// assert_that!(metrics).histogram("latency_ms").contains(12.5);
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// let metrics = CapturedMetrics::new();
+/// metrics.increment_counter("requests_total", 3);
+///
+/// assert_that!(metrics).counter("errors_total").is_at_least(1);
+/// // expected a counter named: errors_total
+/// // but was not recorded
+/// // recorded metrics        : [ counter requests_total: 3 ]
+/// ```
+pub trait MetricsAssertion<R> {
+    /// Returns a new subject which is the value of the counter `name`. Panics if the counter
+    /// was not recorded.
+    #[track_caller]
+    fn counter(&self, name: &str) -> Subject<'_, u64, (), R>;
+
+    /// Returns a new subject which is the values recorded to the histogram `name`. Panics if the
+    /// histogram was not recorded.
+    #[track_caller]
+    fn histogram(&self, name: &str) -> Subject<'_, Vec<f64>, (), R>;
+}
+
+impl<R> MetricsAssertion<R> for Subject<'_, CapturedMetrics, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn counter(&self, name: &str) -> Subject<'_, u64, (), R> {
+        match self.actual().counter(name) {
+            Some(value) => self.new_owned_subject(
+                value,
+                Some(format!(
+                    "{}.counter({:?})",
+                    self.description_or_expr(),
+                    name
+                )),
+                (),
+            ),
+            None => fail_lookup(self, "counter", name),
+        }
+    }
+
+    fn histogram(&self, name: &str) -> Subject<'_, Vec<f64>, (), R> {
+        match self.actual().histogram(name) {
+            Some(values) => self.new_owned_subject(
+                values,
+                Some(format!(
+                    "{}.histogram({:?})",
+                    self.description_or_expr(),
+                    name
+                )),
+                (),
+            ),
+            None => fail_lookup(self, "histogram", name),
+        }
+    }
+}
+
+#[track_caller]
+fn fail_lookup<R>(subject: &Subject<CapturedMetrics, (), R>, kind: &str, name: &str) -> ! {
+    // Like `ResultAssertion::ok`, a derived subject can not be returned, so panic.
+    let result: AssertionResult = subject
+        .new_result()
+        .add_fact(format!("expected a {} named", kind), name)
+        .add_simple_fact("but was not recorded")
+        .add_formatted_values_fact("recorded metrics", subject.actual().recorded());
+    result.fail_derivation()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::{EqualityAssertion, VecAssertion};
+
+    use super::*;
+
+    fn metrics() -> CapturedMetrics {
+        let metrics = CapturedMetrics::new();
+        metrics.increment_counter("requests_total", 2);
+        metrics.increment_counter("requests_total", 1);
+        metrics.record_histogram("latency_ms", 12.5);
+        metrics.record_histogram("latency_ms", 3.0);
+        metrics
+    }
+
+    #[test]
+    fn counter() {
+        assert_that!(metrics())
+            .counter("requests_total")
+            .is_equal_to(3);
+
+        // Failures
+        assert_that!(check_that!(metrics())
+            .counter("requests_total")
+            .is_equal_to(4))
+        .facts_are(vec![
+            Fact::new("value of", r#"metrics().counter("requests_total")"#),
+            Fact::new("expected", "4"),
+            Fact::new("actual", "3"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a counter named: errors_total")]
+    fn counter_not_recorded() {
+        assert_that!(metrics()).counter("errors_total");
+    }
+
+    #[test]
+    fn histogram() {
+        assert_that!(metrics())
+            .histogram("latency_ms")
+            .contains_exactly_in_order(vec![12.5, 3.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "[ counter requests_total: 3, histogram latency_ms: [12.5, 3.0] ]")]
+    fn histogram_not_recorded() {
+        assert_that!(metrics()).histogram("size_bytes");
+    }
+}
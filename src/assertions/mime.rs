@@ -0,0 +1,200 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+
+/// Media types (MIME types) whose parts can be inspected by [`MimeAssertion`].
+///
+/// Implemented for strings such as a `Content-Type` header value, which are split following
+/// RFC 9110 section 8.3.1. To use [`MimeAssertion`] with media types of other crates (e.g.
+/// `mime::Mime`), implement this trait for a wrapper type, as assertor does not depend on them.
+pub trait MimeLike {
+    /// Returns the lowercase `type/subtype` without parameters, e.g. `application/json`.
+    fn essence(&self) -> String;
+
+    /// Returns the parameters with lowercase names and unquoted values, in order.
+    fn params(&self) -> Vec<(String, String)>;
+}
+
+impl MimeLike for &str {
+    fn essence(&self) -> String {
+        self.split(';')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase()
+    }
+
+    fn params(&self) -> Vec<(String, String)> {
+        self.split(';')
+            .skip(1)
+            .filter_map(|param| param.split_once('='))
+            .map(|(name, value)| {
+                let value = value.trim();
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|v| v.strip_suffix('"'))
+                    .unwrap_or(value);
+                (name.trim().to_ascii_lowercase(), value.to_string())
+            })
+            .collect()
+    }
+}
+
+impl MimeLike for String {
+    fn essence(&self) -> String {
+        self.as_str().essence()
+    }
+
+    fn params(&self) -> Vec<(String, String)> {
+        self.as_str().params()
+    }
+}
+
+/// Trait for media type assertion.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let content_type = "Application/JSON; charset=\"utf-8\"";
+/// assert_that!(content_type).has_essence("application/json");
+/// assert_that!(content_type).has_param("charset", "utf-8");
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// assert_that!("text/html; charset=utf-8").has_essence("application/json");
+/// // expected essence: application/json
+/// // but was         : text/html
+/// // ---
+/// // essence         : text/html
+/// // params          : charset=utf-8
+/// ```
+pub trait MimeAssertion<R> {
+    /// Checks that the subject has the given `type/subtype`, ignoring case and parameters.
+    #[track_caller]
+    fn has_essence<E: AsRef<str>>(&self, essence: E) -> R;
+
+    /// Checks that the subject has a parameter `name`, ignoring case, whose value is `value`.
+    #[track_caller]
+    fn has_param<N: AsRef<str>, V: AsRef<str>>(&self, name: N, value: V) -> R;
+}
+
+impl<S, R> MimeAssertion<R> for Subject<'_, S, (), R>
+where
+    S: MimeLike,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn has_essence<E: AsRef<str>>(&self, essence: E) -> R {
+        pass_if_skipped!(self);
+        let (expected, actual) = (essence.as_ref(), self.actual().essence());
+        if actual.eq_ignore_ascii_case(expected) {
+            self.new_result().do_ok()
+        } else {
+            fail(self, "essence", expected, actual)
+        }
+    }
+
+    fn has_param<N: AsRef<str>, V: AsRef<str>>(&self, name: N, value: V) -> R {
+        pass_if_skipped!(self);
+        let (name, value) = (name.as_ref(), value.as_ref());
+        let params = self.actual().params();
+        match params.iter().find(|(n, _)| n.eq_ignore_ascii_case(name)) {
+            Some((_, v)) if v == value => self.new_result().do_ok(),
+            Some((n, v)) => fail(self, "param", &render(name, value), render(n, v)),
+            None => fail(self, "param", &render(name, value), "<none>".to_string()),
+        }
+    }
+}
+
+fn fail<S: MimeLike, R>(
+    subject: &Subject<S, (), R>,
+    part: &str,
+    expected: &str,
+    actual: String,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    let params = subject
+        .actual()
+        .params()
+        .iter()
+        .map(|(n, v)| render(n, v))
+        .collect::<Vec<_>>()
+        .join("; ");
+    subject
+        .new_result()
+        .add_fact(format!("expected {}", part), expected)
+        .add_fact(FactKey::ButWas, actual)
+        .add_splitter()
+        .add_fact("essence", subject.actual().essence())
+        .add_fact("params", params)
+        .do_fail()
+}
+
+fn render(name: &str, value: &str) -> String {
+    format!("{}={}", name, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+
+    use super::*;
+
+    #[test]
+    fn has_essence() {
+        assert_that!("application/json").has_essence("application/json");
+        assert_that!(" Text/HTML ; charset=utf-8").has_essence("text/html");
+        assert_that!("text/plain".to_string()).has_essence("TEXT/plain");
+
+        // Failures
+        assert_that!(check_that!("text/html; charset=utf-8; q=1").has_essence("text/plain"))
+            .facts_are(vec![
+                Fact::new("expected essence", "text/plain"),
+                Fact::new("but was", "text/html"),
+                Fact::new_splitter(),
+                Fact::new("essence", "text/html"),
+                Fact::new("params", "charset=utf-8; q=1"),
+            ]);
+    }
+
+    #[test]
+    fn has_param() {
+        assert_that!("text/html; charset=utf-8").has_param("charset", "utf-8");
+        assert_that!("text/html;CharSet=\"utf-8\"").has_param("charset", "utf-8");
+        assert_that!("multipart/form-data; boundary=a=b").has_param("Boundary", "a=b");
+
+        // Failures
+        assert_that!(check_that!("text/html; charset=latin1").has_param("charset", "utf-8"))
+            .facts_are(vec![
+                Fact::new("expected param", "charset=utf-8"),
+                Fact::new("but was", "charset=latin1"),
+                Fact::new_splitter(),
+                Fact::new("essence", "text/html"),
+                Fact::new("params", "charset=latin1"),
+            ]);
+        assert_that!(check_that!("text/html").has_param("charset", "utf-8")).facts_are(vec![
+            Fact::new("expected param", "charset=utf-8"),
+            Fact::new("but was", "<none>"),
+            Fact::new_splitter(),
+            Fact::new("essence", "text/html"),
+            Fact::new("params", ""),
+        ]);
+    }
+}
@@ -12,15 +12,29 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub mod all;
+pub mod array;
 pub mod basic;
 pub mod boolean;
+pub mod bytes;
+pub mod code;
+pub mod collection;
 pub mod cow;
+pub mod deref;
+pub mod diagnostic;
+pub mod dynamic;
+pub mod fixed_point;
+pub mod index;
+pub mod io;
 pub mod iterator;
 pub mod map;
 pub mod option;
+pub mod pair;
+pub mod process;
 pub mod result;
 pub mod set;
 pub mod string;
+pub mod time;
 pub mod vec;
 
 #[cfg(feature = "float")]
@@ -29,5 +43,29 @@ pub mod float;
 #[cfg(feature = "anyhow")]
 pub mod anyhow;
 
+#[cfg(feature = "http")]
+pub mod http;
+
+#[cfg(feature = "serde_json")]
+pub mod json;
+
+#[cfg(feature = "captured-logs")]
+pub mod log;
+
+#[cfg(feature = "captured-events")]
+pub mod tracing;
+
+#[cfg(feature = "captured-metrics")]
+pub mod metrics;
+
+#[cfg(feature = "graphs")]
+pub mod graph;
+
+#[cfg(feature = "url-parts")]
+pub mod url;
+
+#[cfg(feature = "media-types")]
+pub mod mime;
+
 #[cfg(any(test, doc, feature = "testing"))]
 pub(crate) mod testing;
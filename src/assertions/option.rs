@@ -15,8 +15,8 @@
 use std::borrow::Borrow;
 use std::fmt::Debug;
 
-use crate::{AssertionResult, AssertionStrategy, Subject};
-use crate::base::AssertionApi;
+use crate::{AssertionResult, AssertionStrategy, FactKey, Subject};
+use crate::base::{pass_if_skipped, AssertionApi};
 
 /// Trait for option assertion.
 ///
@@ -52,6 +52,27 @@ where
         B: Borrow<T>,
         T: PartialEq + Debug;
 
+    /// Checks the subject is [`Option::Some(_)`](`Option::Some`) with a value which satisfies
+    /// `predicate`, which is described as `description` in the failure message.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// assert_that!(Option::Some(4)).is_some_and(|v| v % 2 == 0, "even");
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// assert_that!(Option::Some(3)).is_some_and(|v| v % 2 == 0, "even");
+    /// // expected Some(_) satisfying: even
+    /// // actual                     : Some(3)
+    /// ```
+    #[track_caller]
+    fn is_some_and<P: FnOnce(&T) -> bool>(&self, predicate: P, description: &str) -> R
+    where
+        T: Debug;
+
     /// Returns a new subject which is the value of the subject if the subject is [`Option::Some(_)`](`Option::Some`). Otherwise, it fails.
     ///
     /// # Example
@@ -76,12 +97,11 @@ where
     where
         T: PartialEq + Debug,
     {
+        pass_if_skipped!(self);
         match self.actual() {
             None => self.new_result().do_ok(),
-            Some(actual) => self
-                .new_result()
-                .add_fact("expected", "None")
-                .add_fact("actual", format!("Some({:?})", actual))
+            Some(_) => self
+                .add_expected_actual_facts(self.new_result(), None::<&T>)
                 .do_fail(),
         }
     }
@@ -90,11 +110,12 @@ where
     where
         T: PartialEq + Debug,
     {
+        pass_if_skipped!(self);
         match self.actual() {
             None => self
                 .new_result()
-                .add_fact("expected", "Some(_)")
-                .add_fact("actual", "None")
+                .add_fact(FactKey::Expected, "Some(_)")
+                .add_fact(FactKey::Actual, "None")
                 .do_fail(),
             Some(_) => self.new_result().do_ok(),
         }
@@ -105,17 +126,26 @@ where
         B: Borrow<T>,
         T: PartialEq + Debug,
     {
+        pass_if_skipped!(self);
         match self.actual() {
             Some(actual) if expected.borrow().eq(actual) => self.new_result().do_ok(),
-            Some(actual) => self
-                .new_result()
-                .add_fact("expected", format!("Some({:?})", expected.borrow()))
-                .add_fact("actual", format!("Some({:?})", actual))
+            _ => self
+                .add_expected_actual_facts(self.new_result(), Some(expected.borrow()))
                 .do_fail(),
-            None => self
+        }
+    }
+
+    fn is_some_and<P: FnOnce(&T) -> bool>(&self, predicate: P, description: &str) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        match self.actual() {
+            Some(value) if predicate(value) => self.new_result().do_ok(),
+            _ => self
                 .new_result()
-                .add_fact("expected", format!("Some({:?})", expected.borrow()))
-                .add_fact("actual", "None")
+                .add_fact("expected Some(_) satisfying", description.to_string())
+                .add_fact(FactKey::Actual, self.format_value(self.actual()))
                 .do_fail(),
         }
     }
@@ -161,6 +191,37 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn with_formatter() {
+        assert_that!(check_that!(Option::Some(255))
+            .with_formatter(|v| format!("{:x?}", v))
+            .has_value(254))
+        .facts_are(vec![
+            Fact::new("expected", "Some(254)"),
+            Fact::new("actual", "Some(ff)"),
+        ]);
+    }
+
+    #[test]
+    fn is_some_and() {
+        assert_that!(Option::Some(4)).is_some_and(|v| v % 2 == 0, "even");
+        assert_that!(Option::Some("foo")).is_some_and(|v| v.starts_with('f'), "starts with f");
+
+        // Failures
+        assert_that!(check_that!(Option::Some(3)).is_some_and(|v| v % 2 == 0, "even")).facts_are(
+            vec![
+                Fact::new("expected Some(_) satisfying", "even"),
+                Fact::new("actual", "Some(3)"),
+            ],
+        );
+        assert_that!(check_that!(Option::<i32>::None).is_some_and(|_| true, "anything")).facts_are(
+            vec![
+                Fact::new("expected Some(_) satisfying", "anything"),
+                Fact::new("actual", "None"),
+            ],
+        );
+    }
+
     #[test]
     fn has_value() {
         let none: Option<isize> = Option::None;
@@ -180,5 +241,10 @@ mod tests {
             Fact::new("expected", r#"Some("2")"#),
             Fact::new("actual", r#"Some("1")"#),
         ]);
+        // Both values are flattened alike.
+        assert_that!(check_that!(Some(Ok::<_, ()>(1))).has_value(Ok(2))).facts_are(vec![
+            Fact::new("expected", "Some→Ok: 2"),
+            Fact::new("actual", "Some→Ok: 1"),
+        ]);
     }
 }
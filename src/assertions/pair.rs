@@ -0,0 +1,235 @@
+// Copyright 2021 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+
+use crate::assertions::set::SetLike;
+use crate::base::{pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, Subject};
+
+/// Values of two expressions, each labeled with its expression. Created by
+/// [`assert_that_pair`](crate::assert_that_pair).
+#[derive(Debug, Clone)]
+pub struct Pair<'a, L, Rt> {
+    left_expr: String,
+    left: &'a L,
+    right_expr: String,
+    right: &'a Rt,
+}
+
+impl<'a, L, Rt> Pair<'a, L, Rt> {
+    /// Creates a pair from two expressions and their values.
+    pub fn new(left_expr: String, left: &'a L, right_expr: String, right: &'a Rt) -> Self {
+        Pair {
+            left_expr,
+            left,
+            right_expr,
+            right,
+        }
+    }
+}
+
+/// Trait for assertions on relations between two values, whose facts refer to both values by
+/// their expressions.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// use std::collections::HashSet;
+///
+/// let admins = HashSet::from(["alice"]);
+/// let users = HashSet::from(["alice", "bob"]);
+/// let guests = HashSet::from(["carol"]);
+/// assert_that_pair!(admins, users).left_is_subset_of_right();
+/// assert_that_pair!(users, guests).are_disjoint_sets();
+/// ```
+/// ```should_panic
+/// use assertor::*;
+/// use std::collections::HashSet;
+///
+/// let users = HashSet::from(["alice", "bob"]);
+/// let guests = HashSet::from(["bob"]);
+/// assert_that_pair!(users, guests).are_disjoint_sets();
+/// // expected users and guests to be disjoint
+/// // but both contained: [ "bob" ]
+/// // ---
+/// // users             : [ "alice", "bob" ]
+/// // guests            : [ "bob" ]
+/// ```
+pub trait PairAssertion<L, Rt, R> {
+    /// Checks that the left value is equal to the right value.
+    #[track_caller]
+    fn are_equal(&self) -> R
+    where
+        L: PartialEq<Rt> + Debug,
+        Rt: Debug;
+
+    /// Checks that the left and the right set have no element in common.
+    #[track_caller]
+    fn are_disjoint_sets<T>(&self) -> R
+    where
+        T: Eq + Debug,
+        L: SetLike<T>,
+        Rt: SetLike<T>;
+
+    /// Checks that all elements of the left set are contained in the right set.
+    #[track_caller]
+    fn left_is_subset_of_right<T>(&self) -> R
+    where
+        T: Eq + Debug,
+        L: SetLike<T>,
+        Rt: SetLike<T>;
+}
+
+impl<L, Rt, R> PairAssertion<L, Rt, R> for Subject<'_, Pair<'_, L, Rt>, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn are_equal(&self) -> R
+    where
+        L: PartialEq<Rt> + Debug,
+        Rt: Debug,
+    {
+        pass_if_skipped!(self);
+        let pair = self.actual();
+        if pair.left.eq(pair.right) {
+            return self.new_result().do_ok();
+        }
+        self.new_result()
+            .add_simple_fact(format!(
+                "expected {} to be equal to {}",
+                pair.left_expr, pair.right_expr
+            ))
+            .add_splitter()
+            .add_formatted_fact(&pair.left_expr, pair.left)
+            .add_formatted_fact(&pair.right_expr, pair.right)
+            .do_fail()
+    }
+
+    fn are_disjoint_sets<T>(&self) -> R
+    where
+        T: Eq + Debug,
+        L: SetLike<T>,
+        Rt: SetLike<T>,
+    {
+        pass_if_skipped!(self);
+        let pair = self.actual();
+        let common: Vec<&T> = pair
+            .left
+            .iter()
+            .filter(|element| pair.right.iter().any(|other| other.eq(element)))
+            .collect();
+        if common.is_empty() {
+            return self.new_result().do_ok();
+        }
+        self.new_result()
+            .add_simple_fact(format!(
+                "expected {} and {} to be disjoint",
+                pair.left_expr, pair.right_expr
+            ))
+            .add_formatted_values_fact("but both contained", common)
+            .add_splitter()
+            .add_formatted_values_fact(&pair.left_expr, pair.left.iter().collect())
+            .add_formatted_values_fact(&pair.right_expr, pair.right.iter().collect())
+            .do_fail()
+    }
+
+    fn left_is_subset_of_right<T>(&self) -> R
+    where
+        T: Eq + Debug,
+        L: SetLike<T>,
+        Rt: SetLike<T>,
+    {
+        pass_if_skipped!(self);
+        let pair = self.actual();
+        let missing: Vec<&T> = pair
+            .left
+            .iter()
+            .filter(|element| !pair.right.iter().any(|other| other.eq(element)))
+            .collect();
+        if missing.is_empty() {
+            return self.new_result().do_ok();
+        }
+        self.new_result()
+            .add_simple_fact(format!(
+                "expected {} to be a subset of {}",
+                pair.left_expr, pair.right_expr
+            ))
+            .add_formatted_values_fact(format!("but {} did not contain", pair.right_expr), missing)
+            .add_splitter()
+            .add_formatted_values_fact(&pair.left_expr, pair.left.iter().collect())
+            .add_formatted_values_fact(&pair.right_expr, pair.right.iter().collect())
+            .do_fail()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::testing::*;
+    use crate::{assert_that, assert_that_pair, check_that, Fact};
+
+    use super::*;
+
+    #[test]
+    fn are_equal() {
+        let (left, right) = (vec![1, 2], [1, 2]);
+        assert_that_pair!(left, right).are_equal();
+
+        // Failures
+        let right = [1, 3];
+        let pair = Pair::new("left".to_string(), &left, "right".to_string(), &right);
+        assert_that!(check_that!(pair).are_equal()).facts_are(vec![
+            Fact::new_simple_fact("expected left to be equal to right"),
+            Fact::new_splitter(),
+            Fact::new("left", "[1, 2]"),
+            Fact::new("right", "[1, 3]"),
+        ]);
+    }
+
+    #[test]
+    fn are_disjoint_sets() {
+        let (left, right) = (BTreeSet::from([1, 2]), BTreeSet::from([3]));
+        assert_that_pair!(left, right).are_disjoint_sets();
+        assert_that_pair!(BTreeSet::<i32>::new(), left).are_disjoint_sets();
+
+        // Failures
+        let right = BTreeSet::from([2, 3]);
+        let pair = Pair::new("left".to_string(), &left, "right".to_string(), &right);
+        assert_that!(check_that!(pair).are_disjoint_sets()).facts_are(vec![
+            Fact::new_simple_fact("expected left and right to be disjoint"),
+            Fact::new_multi_value_fact("but both contained", vec!["2"]),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("left", vec!["1", "2"]),
+            Fact::new_multi_value_fact("right", vec!["2", "3"]),
+        ]);
+    }
+
+    #[test]
+    fn left_is_subset_of_right() {
+        let (left, right) = (BTreeSet::from([1, 2]), BTreeSet::from([1, 2, 3]));
+        assert_that_pair!(left, right).left_is_subset_of_right();
+        assert_that_pair!(left, left).left_is_subset_of_right();
+
+        // Failures
+        let pair = Pair::new("right".to_string(), &right, "left".to_string(), &left);
+        assert_that!(check_that!(pair).left_is_subset_of_right()).facts_are(vec![
+            Fact::new_simple_fact("expected right to be a subset of left"),
+            Fact::new_multi_value_fact("but left did not contain", vec!["3"]),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("right", vec!["1", "2", "3"]),
+            Fact::new_multi_value_fact("left", vec!["1", "2"]),
+        ]);
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::process::Output;
+
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+
+/// Trait for the output of a finished process, as returned by
+/// [`std::process::Command::output`] and by `assert_cmd`.
+///
+/// # Example
+/// ```
+/// use std::process::Command;
+/// use assertor::*;
+///
+/// let output = Command::new("rustc").arg("--version").output().unwrap();
+/// assert_that!(output).succeeded();
+/// assert_that!(output).stdout().starts_with("rustc ");
+/// ```
+/// ```should_panic
+/// use std::process::Command;
+/// use assertor::*;
+///
+/// let output = Command::new("rustc").arg("--no-such-flag").output().unwrap();
+/// assert_that!(output).succeeded();
+/// // expected to succeed
+/// // but was: exit status: 1
+/// // ---
+/// // stdout : ""
+/// // stderr : "error: Unrecognized option: 'no-such-flag'\n..."
+/// ```
+pub trait OutputAssertion<R> {
+    /// Checks that the process exited successfully.
+    #[track_caller]
+    fn succeeded(&self) -> R;
+
+    /// Checks that the process exited with the given code.
+    #[track_caller]
+    fn failed_with_code(&self, code: i32) -> R;
+
+    /// Returns a new subject which is the standard output of the process, decoded as UTF-8 with
+    /// invalid sequences replaced.
+    fn stdout(&self) -> Subject<'_, String, (), R>;
+
+    /// Returns a new subject which is the standard error of the process, decoded as UTF-8 with
+    /// invalid sequences replaced.
+    fn stderr(&self) -> Subject<'_, String, (), R>;
+}
+
+impl<R> OutputAssertion<R> for Subject<'_, Output, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn succeeded(&self) -> R {
+        pass_if_skipped!(self);
+        if self.actual().status.success() {
+            self.new_result().do_ok()
+        } else {
+            add_output_facts(
+                self.new_result()
+                    .add_simple_fact("expected to succeed")
+                    .add_fact(FactKey::ButWas, self.actual().status.to_string()),
+                self.actual(),
+            )
+            .do_fail()
+        }
+    }
+
+    fn failed_with_code(&self, code: i32) -> R {
+        pass_if_skipped!(self);
+        let actual = self.actual().status.code();
+        if actual == Some(code) {
+            self.new_result().do_ok()
+        } else {
+            add_output_facts(
+                self.new_result()
+                    .add_fact("expected exit code", code.to_string())
+                    .add_fact(FactKey::ButWas, self.actual().status.to_string()),
+                self.actual(),
+            )
+            .do_fail()
+        }
+    }
+
+    fn stdout(&self) -> Subject<'_, String, (), R> {
+        let stdout = String::from_utf8_lossy(&self.actual().stdout).into_owned();
+        self.new_owned_subject(
+            stdout,
+            Some(format!("{}.stdout()", self.description_or_expr())),
+            (),
+        )
+    }
+
+    fn stderr(&self) -> Subject<'_, String, (), R> {
+        let stderr = String::from_utf8_lossy(&self.actual().stderr).into_owned();
+        self.new_owned_subject(
+            stderr,
+            Some(format!("{}.stderr()", self.description_or_expr())),
+            (),
+        )
+    }
+}
+
+fn add_output_facts(result: AssertionResult, output: &Output) -> AssertionResult {
+    result
+        .add_splitter()
+        .add_formatted_fact("stdout", String::from_utf8_lossy(&output.stdout))
+        .add_formatted_fact("stderr", String::from_utf8_lossy(&output.stderr))
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::ExitStatus;
+
+    use crate::testing::*;
+    use crate::StringAssertion;
+
+    use super::*;
+
+    fn output(code: i32, stdout: &str, stderr: &str) -> Output {
+        Output {
+            // The raw wait status holds the exit code in its second byte.
+            status: ExitStatus::from_raw(code << 8),
+            stdout: stdout.as_bytes().to_vec(),
+            stderr: stderr.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn succeeded() {
+        assert_that!(output(0, "ok\n", "")).succeeded();
+
+        // Failures
+        assert_that!(check_that!(output(2, "", "no such file\n")).succeeded()).facts_are(vec![
+            Fact::new_simple_fact("expected to succeed"),
+            Fact::new("but was", "exit status: 2"),
+            Fact::new_splitter(),
+            Fact::new("stdout", r#""""#),
+            Fact::new("stderr", r#""no such file\n""#),
+        ]);
+    }
+
+    #[test]
+    fn failed_with_code() {
+        assert_that!(output(2, "", "")).failed_with_code(2);
+
+        // Failures
+        assert_that!(check_that!(output(0, "done", "")).failed_with_code(1)).facts_are(vec![
+            Fact::new("expected exit code", "1"),
+            Fact::new("but was", "exit status: 0"),
+            Fact::new_splitter(),
+            Fact::new("stdout", r#""done""#),
+            Fact::new("stderr", r#""""#),
+        ]);
+        // Killed by a signal, without an exit code.
+        let killed = Output {
+            status: ExitStatus::from_raw(9),
+            stdout: vec![],
+            stderr: vec![],
+        };
+        assert_that!(check_that!(killed).failed_with_code(1))
+            .fact_value_for_key("but was")
+            .starts_with("signal: 9");
+    }
+
+    #[test]
+    fn stdout_and_stderr() {
+        assert_that!(output(0, "hello\nworld\n", "warning"))
+            .stdout()
+            .contains("world");
+        assert_that!(output(0, "", "warning"))
+            .stderr()
+            .ends_with("ing");
+
+        // Failures
+        assert_that!(check_that!(output(0, "hello", ""))
+            .stdout()
+            .contains("world"))
+        .facts_are(vec![
+            Fact::new("value of", r#"output(0, "hello", "").stdout()"#),
+            Fact::new("expected a string that contains", "world"),
+            Fact::new("but was", "hello"),
+        ]);
+    }
+}
@@ -12,11 +12,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::any::TypeId;
 use std::borrow::Borrow;
 use std::fmt::Debug;
 
 use crate::assert_that;
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+use crate::assertions::iterator::Described;
+use crate::assertions::vec::SequenceLike;
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
 
 /// Trait for result assertion.
 ///
@@ -33,14 +38,25 @@ use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
 /// assert_that!(err).has_err(1);
 /// ```
 pub trait ResultAssertion<R, OK, ERR> {
-    /// Checks that the subject is [`Result::Ok(_)`](`std::result::Result::Ok`).
+    /// Checks that the subject is [`Result::Ok(_)`](`std::result::Result::Ok`). Failures of
+    /// results without an ok value, such as `Result<(), E>`, expect `Ok(())`.
     #[track_caller]
-    fn is_ok(&self) -> R;
+    fn is_ok(&self) -> R
+        where
+            OK: 'static;
 
     /// Checks that the subject is [`Result::Err(_)`](`std::result::Result::Err`).
     #[track_caller]
     fn is_err(&self) -> R;
 
+    /// Checks that the subject is `Ok(())`. Same as [`ResultAssertion::is_ok`], for results of
+    /// operations without an ok value, such as `Result<(), E>`.
+    #[track_caller]
+    fn is_ok_and_unit(&self) -> R
+        where
+            (): Borrow<OK>,
+            OK: PartialEq;
+
     /// Checks that the subject is [`Result::Ok(expected)`](`std::result::Result::Err`).
     #[track_caller]
     fn has_ok<B: Borrow<OK>>(&self, expected: B) -> R
@@ -53,6 +69,27 @@ pub trait ResultAssertion<R, OK, ERR> {
         where
             ERR: PartialEq;
 
+    /// Checks that the subject is [`Result::Ok(_)`](`std::result::Result::Ok`) with a value which
+    /// satisfies `predicate`, which is described as `description` in the failure message.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// let result: Result<&str, String> = Ok("foobar");
+    /// assert_that!(result).is_ok_and(|v| v.len() > 3, "longer than 3");
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// let result: Result<&str, String> = Err("timeout".to_string());
+    /// assert_that!(result).is_ok_and(|v| v.len() > 3, "longer than 3");
+    /// // expected Ok(_) satisfying: longer than 3
+    /// // actual                   : Err("timeout")
+    /// ```
+    #[track_caller]
+    fn is_ok_and<P: FnOnce(&OK) -> bool>(&self, predicate: P, description: &str) -> R;
+
     /// Returns a new subject which is the ok value of the subject if the subject has ok value. Otherwise, it fails.
     #[track_caller]
     fn ok(&self) -> Subject<OK, (), R>;
@@ -66,47 +103,55 @@ impl<R, OK: Debug, ERR: Debug> ResultAssertion<R, OK, ERR> for Subject<'_, Resul
     where
         AssertionResult: AssertionStrategy<R>,
 {
-    fn is_ok(&self) -> R {
+    fn is_ok(&self) -> R
+        where
+            OK: 'static,
+    {
+        pass_if_skipped!(self);
         if self.actual().is_ok() {
             self.new_result().do_ok()
         } else {
-            self.new_result()
-                .add_fact("expected", "Result::Err")
-                .add_fact("actual", "Result::Ok")
-                .add_splitter()
-                .add_fact("actual", format!("{:?}", self.actual()))
-                .do_fail()
+            let expected = if TypeId::of::<OK>() == TypeId::of::<()>() {
+                "Ok(())"
+            } else {
+                "Result::Ok"
+            };
+            not_ok(self, expected).do_fail()
         }
     }
 
     fn is_err(&self) -> R {
+        pass_if_skipped!(self);
         if self.actual().is_err() {
             self.new_result().do_ok()
         } else {
             self.new_result()
-                .add_fact("expected", "Result::Err")
-                .add_fact("actual", "Result::Ok")
+                .add_fact(FactKey::Expected, "Result::Err")
+                .add_fact(FactKey::Actual, "Result::Ok")
                 .add_splitter()
-                .add_fact("actual", format!("{:?}", self.actual()))
+                .add_fact(FactKey::Actual, self.format_value(self.actual()))
                 .do_fail()
         }
     }
 
+    fn is_ok_and_unit(&self) -> R
+        where
+            (): Borrow<OK>,
+            OK: PartialEq,
+    {
+        pass_if_skipped!(self);
+        self.has_ok(())
+    }
+
     fn has_ok<B: Borrow<OK>>(&self, expected: B) -> R
         where
             OK: PartialEq,
     {
+        pass_if_skipped!(self);
         match self.actual() {
             Ok(actual) if actual.eq(expected.borrow()) => self.new_result().do_ok(),
-            Ok(actual) => self
-                .new_result()
-                .add_fact("expected", format!("Ok({:?})", expected.borrow()))
-                .add_fact("actual", format!("Ok({:?})", actual))
-                .do_fail(),
-            Err(actual) => self
-                .new_result()
-                .add_fact("expected", format!("Ok({:?})", expected.borrow()))
-                .add_fact("actual", format!("Err({:?})", actual))
+            _ => self
+                .add_expected_actual_facts(self.new_result(), Ok::<_, ()>(expected.borrow()))
                 .do_fail(),
         }
     }
@@ -115,23 +160,31 @@ impl<R, OK: Debug, ERR: Debug> ResultAssertion<R, OK, ERR> for Subject<'_, Resul
         where
             ERR: PartialEq,
     {
+        pass_if_skipped!(self);
         match self.actual() {
             Err(actual) if actual.eq(expected.borrow()) => self.new_result().do_ok(),
-            Err(actual) => self
-                .new_result()
-                .add_fact("expected", format!("Err({:?})", expected.borrow()))
-                .add_fact("actual", format!("Err({:?})", actual))
+            _ => self
+                .add_expected_actual_facts(self.new_result(), Err::<(), _>(expected.borrow()))
                 .do_fail(),
-            Ok(actual) => self
+        }
+    }
+
+    fn is_ok_and<P: FnOnce(&OK) -> bool>(&self, predicate: P, description: &str) -> R {
+        pass_if_skipped!(self);
+        match self.actual() {
+            Ok(value) if predicate(value) => self.new_result().do_ok(),
+            _ => self
                 .new_result()
-                .add_fact("expected", format!("Err({:?})", expected.borrow()))
-                .add_fact("actual", format!("Ok({:?})", actual))
+                .add_fact("expected Ok(_) satisfying", description.to_string())
+                .add_fact(FactKey::Actual, self.format_value(self.actual()))
                 .do_fail(),
         }
     }
 
     fn ok(&self) -> Subject<OK, (), R> {
-        assert_that!(*self.actual()).is_ok();
+        if self.actual().is_err() {
+            not_ok(self, "Result::Ok").fail_derivation();
+        }
         self.new_subject(self.actual().as_ref().ok().unwrap(), Some(format!("{}.ok", self.description_or_expr())), ())
     }
 
@@ -141,13 +194,118 @@ impl<R, OK: Debug, ERR: Debug> ResultAssertion<R, OK, ERR> for Subject<'_, Resul
     }
 }
 
+/// Facts for a subject which is not `Ok`, expecting `expected`.
+fn not_ok<OK: Debug, ERR: Debug, R>(
+    subject: &Subject<Result<OK, ERR>, (), R>,
+    expected: &str,
+) -> AssertionResult {
+    subject
+        .new_result()
+        .add_fact(FactKey::Expected, expected)
+        .add_fact(FactKey::Actual, "Result::Err")
+        .add_splitter()
+        .add_fact(FactKey::Actual, subject.format_value(subject.actual()))
+}
+
+/// Trait for assertions on sequences of results, such as the lines read from a `BufRead`.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let results: Vec<Result<u8, String>> = vec![Ok(1), Ok(2)];
+/// assert_that!(results).all_ok().contains_exactly_in_order(vec![1, 2]);
+/// ```
+pub trait ResultsAssertion<'a, OK, ERR, R> {
+    /// Returns a new subject which is the ok values of the subject, in order, if every element is
+    /// ok. Otherwise, it fails with the index and value of each error.
+    #[track_caller]
+    fn all_ok(&self) -> Subject<'a, Vec<OK>, (), R>;
+}
+
+impl<'a, S, OK, ERR, R> ResultsAssertion<'a, OK, ERR, R> for Subject<'a, S, (), R>
+where
+    S: SequenceLike<Result<OK, ERR>>,
+    OK: Clone,
+    ERR: Debug,
+{
+    fn all_ok(&self) -> Subject<'a, Vec<OK>, (), R> {
+        let errors: Vec<Described> = self
+            .actual()
+            .elements_iter()
+            .enumerate()
+            .filter_map(|(index, element)| {
+                element
+                    .as_ref()
+                    .err()
+                    .map(|err| Described(format!("[{}]: {:?}", index, err)))
+            })
+            .collect();
+        if !errors.is_empty() {
+            // Like `StringAssertion::parses_as`, a derived subject can not be returned, so panic.
+            let result: AssertionResult = self
+                .new_result()
+                .add_fact("expected every element to be", "Result::Ok")
+                .add_formatted_values_fact(
+                    format!("but found errors ({})", errors.len()),
+                    errors,
+                );
+            result.fail_derivation()
+        }
+        let values = self
+            .actual()
+            .elements_iter()
+            .filter_map(|element| element.as_ref().ok().cloned())
+            .collect();
+        self.new_owned_subject(
+            values,
+            Some(format!("{}.all_ok()", self.description_or_expr())),
+            (),
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::ComparableAssertion;
+    use crate::{assert_that_collected, ComparableAssertion, GenericAssertion, VecAssertion};
     use crate::testing::*;
 
     use super::*;
 
+    #[test]
+    fn all_ok() {
+        let results: Vec<Result<u8, &str>> = vec![Ok(1), Ok(2)];
+        assert_that!(results)
+            .all_ok()
+            .contains_exactly_in_order(vec![1, 2]);
+        assert_that!(Vec::<Result<u8, &str>>::new())
+            .all_ok()
+            .is_empty();
+
+        let results: Vec<Result<u8, &str>> = vec![Ok(1), Ok(2)];
+        assert_that!(check_that!(results).all_ok().contains(3))
+            .facts_are_at_least(vec![Fact::new("value of", "results.all_ok()")]);
+    }
+
+    #[test]
+    #[should_panic(expected = "[ [1]: \"bad\", [3]: \"worse\" ]")]
+    fn all_ok_panic() {
+        let results: Vec<Result<u8, &str>> = vec![Ok(1), Err("bad"), Ok(3), Err("worse")];
+        assert_that!(results).all_ok();
+    }
+
+    #[test]
+    fn collected() {
+        let mut queue = vec![1, 2, 3];
+        assert_that_collected!(queue.drain(..2)).contains_exactly_in_order(vec![1, 2]);
+        assert_that!(queue).contains_exactly(vec![3]);
+
+        let input = std::io::Cursor::new("a\nb\n");
+        assert_that_collected!(std::io::BufRead::lines(input))
+            .all_ok()
+            .contains_exactly_in_order(vec!["a".to_string(), "b".to_string()]);
+    }
+
     #[test]
     fn is_ok() {
         assert_that!(Result::<_, ()>::Ok(0)).is_ok();
@@ -155,6 +313,63 @@ mod tests {
         assert_that!(Result::<_, ()>::Ok(())).is_ok();
     }
 
+    #[test]
+    fn is_ok_failure() {
+        assert_that!(check_that!(Result::<(), &str>::Err("failure")).is_ok()).facts_are(vec![
+            Fact::new("expected", "Ok(())"),
+            Fact::new("actual", "Result::Err"),
+            Fact::new_splitter(),
+            Fact::new("actual", r#"Err("failure")"#),
+        ]);
+        assert_that!(check_that!(Result::<i32, &str>::Err("failure")).is_ok()).facts_are(vec![
+            Fact::new("expected", "Result::Ok"),
+            Fact::new("actual", "Result::Err"),
+            Fact::new_splitter(),
+            Fact::new("actual", r#"Err("failure")"#),
+        ]);
+    }
+
+    #[test]
+    fn is_ok_and_unit() {
+        assert_that!(Result::<(), &str>::Ok(())).is_ok_and_unit();
+
+        // Failures
+        assert_that!(check_that!(Result::<(), &str>::Err("failure")).is_ok_and_unit()).facts_are(
+            vec![
+                Fact::new("expected", "Ok(())"),
+                Fact::new("actual", r#"Err("failure")"#),
+            ],
+        );
+    }
+
+    #[test]
+    fn is_ok_and() {
+        assert_that!(Result::<_, ()>::Ok(4)).is_ok_and(|v| v % 2 == 0, "even");
+
+        // Failures
+        assert_that!(check_that!(Result::<_, ()>::Ok(3)).is_ok_and(|v| v % 2 == 0, "even"))
+            .facts_are(vec![
+                Fact::new("expected Ok(_) satisfying", "even"),
+                Fact::new("actual", "Ok(3)"),
+            ]);
+        assert_that!(check_that!(Result::<i32, &str>::Err("failure")).is_ok_and(|_| true, "any"))
+            .facts_are(vec![
+                Fact::new("expected Ok(_) satisfying", "any"),
+                Fact::new("actual", r#"Err("failure")"#),
+            ]);
+    }
+
+    #[test]
+    fn err_debug() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        enum Error {
+            NotFound { id: u32 },
+        }
+        let result: Result<(), Error> = Err(Error::NotFound { id: 7 });
+        assert_that!(result).err().has_debug_containing("NotFound");
+    }
+
     #[test]
     fn is_err() {
         assert_that!(Result::<(), _>::Err(0)).is_err();
@@ -16,9 +16,18 @@ use std::borrow::Borrow;
 use std::collections::{BTreeSet, HashSet};
 use std::fmt::Debug;
 use std::hash::Hash;
-
-use crate::assertions::iterator::{check_is_empty, IteratorAssertion};
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+use std::ops::RangeBounds;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::vec::IntoIter;
+
+use crate::assertions::iterator::{
+    check_contains_any_of, check_contains_or_suggest, check_does_not_contain, check_is_empty,
+    check_is_not_empty, check_len_bound, listed_in_stable_order, IteratorAssertion, LengthBound,
+};
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
 use crate::EqualityAssertion;
 
 /// Trait for set assertion.
@@ -54,12 +63,31 @@ pub trait SetAssertion<'a, S, T, R> {
     #[track_caller]
     fn has_length(&self, length: usize) -> R;
 
+    /// Checks that the subject has at least `length` elements.
+    #[track_caller]
+    fn has_length_at_least(&self, length: usize) -> R;
+
+    /// Checks that the subject has at most `length` elements.
+    #[track_caller]
+    fn has_length_at_most(&self, length: usize) -> R;
+
+    /// Checks that the subject has at least `min` and at most `max` elements. A `min` greater than
+    /// `max` fails the assertion.
+    #[track_caller]
+    fn has_length_between(&self, min: usize, max: usize) -> R;
+
     /// Checks that the subject is empty.
     #[track_caller]
     fn is_empty(&self) -> R
     where
         T: Debug;
 
+    /// Checks that the subject is not empty.
+    #[track_caller]
+    fn is_not_empty(&self) -> R
+    where
+        T: Debug;
+
     /// Checks that the subject has `expected`.
     #[track_caller]
     fn contains<B: Borrow<T>>(&self, expected: B) -> R
@@ -75,9 +103,47 @@ pub trait SetAssertion<'a, S, T, R> {
 
     /// Checks that the subject does not contain any element of `elements`.
     #[track_caller]
-    fn does_not_contain_any<B: Borrow<Vec<T>>>(&self, elements: B) -> R
+    fn does_not_contain_any<E>(&self, elements: E) -> R
     where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
         T: PartialEq + Debug;
+
+    /// Checks that the subject contains at least one element of `elements`.
+    #[track_caller]
+    fn contains_any_of<E>(&self, elements: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
+        T: PartialEq + Debug;
+
+    /// Checks that the subject has exactly the same elements as `expected`.
+    ///
+    /// Membership is looked up in the sets themselves, so this takes linear time for hash sets,
+    /// whereas comparing their iterators with [`IteratorAssertion::contains_exactly`] is quadratic.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// use std::collections::HashSet;
+    /// assert_that!(HashSet::from([1, 2, 3])).contains_exactly(HashSet::from([3, 2, 1]));
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// use std::collections::HashSet;
+    /// assert_that!(HashSet::from([1, 2])).contains_exactly(HashSet::from([2, 3]));
+    /// // only in actual (1)  : [1]
+    /// // only in expected (1): [3]
+    /// // ---
+    /// // expected            : [..]
+    /// // actual              : [..]
+    /// ```
+    #[track_caller]
+    fn contains_exactly<OSA, OS>(&self, expected: OSA) -> R
+    where
+        T: Eq + Debug,
+        OS: SetLike<T>,
+        OSA: Borrow<OS>;
 }
 
 impl<'a, T, R, ST> SetAssertion<'a, ST, T, R> for Subject<'a, ST, (), R>
@@ -87,6 +153,7 @@ where
     ST: SetLike<T>,
 {
     fn has_length(&self, length: usize) -> R {
+        pass_if_skipped!(self);
         self.new_subject(
             &self.actual().len(),
             Some(format!("{}.len()", self.description_or_expr())),
@@ -95,19 +162,65 @@ where
         .is_equal_to(length)
     }
 
+    fn has_length_at_least(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().len(),
+            format!("{}.len()", self.description_or_expr()),
+            LengthBound::AtLeast(length),
+        )
+    }
+
+    fn has_length_at_most(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().len(),
+            format!("{}.len()", self.description_or_expr()),
+            LengthBound::AtMost(length),
+        )
+    }
+
+    fn has_length_between(&self, min: usize, max: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().len(),
+            format!("{}.len()", self.description_or_expr()),
+            LengthBound::Between(min, max),
+        )
+    }
+
     fn is_empty(&self) -> R
     where
         T: Debug,
     {
+        pass_if_skipped!(self);
         check_is_empty(self.new_result(), self.actual().iter())
     }
 
+    fn is_not_empty(&self) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_is_not_empty(self.new_result(), self.actual().iter())
+    }
+
     fn contains<B: Borrow<T>>(&self, expected: B) -> R
     where
         T: PartialEq + Eq + Debug + Hash,
     {
-        self.new_owned_subject(self.actual().iter(), None, ())
-            .contains(expected.borrow())
+        pass_if_skipped!(self);
+        if self.actual().contains(expected.borrow()) {
+            return self.new_result().do_ok();
+        }
+        check_contains_or_suggest(
+            self.new_result(),
+            listed_elements(self.actual()).into_iter(),
+            &expected.borrow(),
+        )
     }
 
     fn does_not_contain<B>(&self, element: B) -> R
@@ -115,17 +228,86 @@ where
         B: Borrow<T>,
         T: PartialEq + Debug,
     {
-        self.new_owned_subject(self.actual().iter(), None, ())
-            .does_not_contain(element.borrow())
+        pass_if_skipped!(self);
+        if !self.actual().contains(element.borrow()) {
+            return self.new_result().do_ok();
+        }
+        check_does_not_contain(
+            self.new_result(),
+            listed_elements(self.actual()).into_iter(),
+            &element.borrow(),
+        )
     }
 
-    fn does_not_contain_any<B: Borrow<Vec<T>>>(&self, elements: B) -> R
+    fn does_not_contain_any<E>(&self, elements: E) -> R
     where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
         T: PartialEq + Debug,
     {
-        self.new_owned_subject(self.actual().iter(), None, ())
-            .does_not_contain_any(elements.borrow().iter())
+        pass_if_skipped!(self);
+        let elements: Vec<E::Item> = elements.into_iter().collect();
+        let subject = self.new_owned_subject(self.actual().iter(), None, ());
+        subject.does_not_contain_any(elements.iter().map(Borrow::borrow))
     }
+
+    fn contains_any_of<E>(&self, elements: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        let elements: Vec<E::Item> = elements.into_iter().collect();
+        check_contains_any_of(
+            self.new_result(),
+            self.actual().iter(),
+            elements.iter().map(Borrow::borrow),
+        )
+    }
+
+    fn contains_exactly<OSA, OS>(&self, expected: OSA) -> R
+    where
+        T: Eq + Debug,
+        OS: SetLike<T>,
+        OSA: Borrow<OS>,
+    {
+        pass_if_skipped!(self);
+        check_sets_contain_exactly(self.new_result(), self.actual(), expected.borrow())
+    }
+}
+
+/// Compares two sets by looking up each element of one set in the other.
+fn check_sets_contain_exactly<T, A, E, R>(result: AssertionResult, actual: &A, expected: &E) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+    T: Eq + Debug,
+    A: SetLike<T>,
+    E: SetLike<T>,
+{
+    let only_in_actual: Vec<&T> = actual.iter().filter(|v| !expected.contains(v)).collect();
+    let only_in_expected: Vec<&T> = expected.iter().filter(|v| !actual.contains(v)).collect();
+    if only_in_actual.is_empty() && only_in_expected.is_empty() {
+        return result.do_ok();
+    }
+    let mut result = result;
+    if !only_in_actual.is_empty() {
+        result = result.add_formatted_fact(
+            format!("only in actual ({})", only_in_actual.len()),
+            &only_in_actual,
+        );
+    }
+    if !only_in_expected.is_empty() {
+        result = result.add_formatted_fact(
+            format!("only in expected ({})", only_in_expected.len()),
+            &only_in_expected,
+        );
+    }
+    result
+        .add_splitter()
+        .add_formatted_values_fact(FactKey::Expected, expected.iter().collect())
+        .add_formatted_values_fact(FactKey::Actual, actual.iter().collect())
+        .do_fail()
 }
 
 /// Trait for sorted set assertions.
@@ -190,6 +372,52 @@ where
         T: PartialOrd + Eq + Debug,
         OS: OrderedSetLike<T>,
         OSA: Borrow<OS>;
+
+    /// Returns a new subject which is the smallest element of the subject, or `None` if the
+    /// subject is empty, and which implements [`crate::OptionAssertion`].
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// use std::collections::BTreeSet;
+    /// assert_that!(BTreeSet::from([3, 1, 2])).first().has_value(&1);
+    /// assert_that!(BTreeSet::<i32>::new()).first().is_none();
+    /// ```
+    #[track_caller]
+    fn first<'b>(&'b self) -> Subject<'b, Option<&'b T>, (), R>
+    where
+        T: 'b;
+
+    /// Returns a new subject which is the largest element of the subject, or `None` if the
+    /// subject is empty, and which implements [`crate::OptionAssertion`].
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// use std::collections::BTreeSet;
+    /// assert_that!(BTreeSet::from([3, 1, 2])).last().has_value(&3);
+    /// ```
+    #[track_caller]
+    fn last<'b>(&'b self) -> Subject<'b, Option<&'b T>, (), R>
+    where
+        T: 'b;
+
+    /// Returns a new subject which yields the elements of the subject within `range` in order,
+    /// and which implements [`crate::IteratorAssertion`].
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// use std::collections::BTreeSet;
+    /// let set = BTreeSet::from([1, 3, 5, 7, 9]);
+    /// assert_that!(set).elements_in_range(2..8).contains_exactly_in_order([3, 5, 7].iter());
+    /// assert_that!(set).elements_in_range(10..).is_empty();
+    /// ```
+    #[track_caller]
+    fn elements_in_range<'b, RB>(&'b self, range: RB) -> Subject<'b, IntoIter<&'b T>, (), R>
+    where
+        T: 'b,
+        RB: RangeBounds<T> + Debug;
 }
 
 impl<'a, T, R, ST> OrderedSetAssertion<'a, ST, T, R> for Subject<'a, ST, (), R>
@@ -204,6 +432,7 @@ where
         OS: OrderedSetLike<T>,
         OSA: Borrow<OS>,
     {
+        pass_if_skipped!(self);
         self.new_owned_subject(self.actual().iter(), None, ())
             .contains_all_of_in_order(expected.borrow().iter())
     }
@@ -214,9 +443,49 @@ where
         OS: OrderedSetLike<T>,
         OSA: Borrow<OS>,
     {
+        pass_if_skipped!(self);
         self.new_owned_subject(self.actual().iter(), None, ())
             .contains_exactly_in_order(expected.borrow().iter())
     }
+
+    fn first<'b>(&'b self) -> Subject<'b, Option<&'b T>, (), R>
+    where
+        T: 'b,
+    {
+        self.new_owned_subject(
+            self.actual().first(),
+            Some(format!("{}.first()", self.description_or_expr())),
+            (),
+        )
+    }
+
+    fn last<'b>(&'b self) -> Subject<'b, Option<&'b T>, (), R>
+    where
+        T: 'b,
+    {
+        self.new_owned_subject(
+            self.actual().last(),
+            Some(format!("{}.last()", self.description_or_expr())),
+            (),
+        )
+    }
+
+    fn elements_in_range<'b, RB>(&'b self, range: RB) -> Subject<'b, IntoIter<&'b T>, (), R>
+    where
+        T: 'b,
+        RB: RangeBounds<T> + Debug,
+    {
+        let elements: Vec<&T> = self
+            .actual()
+            .iter()
+            .filter(|element| range.contains(*element))
+            .collect();
+        self.new_owned_subject(
+            elements.into_iter(),
+            Some(format!("{}.range({:?})", self.description_or_expr(), range)),
+            (),
+        )
+    }
 }
 
 pub trait SetLike<T: Eq> {
@@ -226,12 +495,26 @@ pub trait SetLike<T: Eq> {
         Self: 'a;
     fn iter<'a>(&'a self) -> Self::It<'a>;
 
+    fn elements_ordered(&self) -> bool;
+
     fn len(&self) -> usize {
         self.iter().count()
     }
+
+    fn contains(&self, value: &T) -> bool {
+        self.iter().any(|v| v == value)
+    }
 }
 
-pub trait OrderedSetLike<T: PartialOrd + Eq>: SetLike<T> {}
+pub trait OrderedSetLike<T: PartialOrd + Eq>: SetLike<T> {
+    fn first(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    fn last(&self) -> Option<&T> {
+        self.iter().last()
+    }
+}
 
 impl<T: Hash + Eq> SetLike<T> for HashSet<T> {
     type It<'a> = std::collections::hash_set::Iter<'a, T> where T: 'a, Self: 'a;
@@ -239,6 +522,14 @@ impl<T: Hash + Eq> SetLike<T> for HashSet<T> {
     fn iter<'a>(&'a self) -> Self::It<'a> {
         self.into_iter()
     }
+
+    fn elements_ordered(&self) -> bool {
+        false
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        HashSet::contains(self, value)
+    }
 }
 
 impl<T: Eq + PartialOrd> SetLike<T> for BTreeSet<T> {
@@ -247,15 +538,57 @@ impl<T: Eq + PartialOrd> SetLike<T> for BTreeSet<T> {
     fn iter<'a>(&'a self) -> Self::It<'a> {
         self.into_iter()
     }
+
+    fn elements_ordered(&self) -> bool {
+        true
+    }
 }
 
 impl<T: PartialOrd + Eq> OrderedSetLike<T> for BTreeSet<T> {}
 
+/// Sets behind smart pointers, e.g. `Arc<HashSet<T>>`, are checked like the sets themselves, as
+/// with auto-deref.
+macro_rules! impl_set_like_for_pointers {
+    ($($pointer:ident),*) => {
+        $(
+            impl<T: Eq, S: SetLike<T>> SetLike<T> for $pointer<S> {
+                type It<'a> = S::It<'a> where T: 'a, Self: 'a;
+
+                fn iter<'a>(&'a self) -> Self::It<'a> {
+                    (**self).iter()
+                }
+
+                fn elements_ordered(&self) -> bool {
+                    (**self).elements_ordered()
+                }
+
+                fn len(&self) -> usize {
+                    (**self).len()
+                }
+
+                fn contains(&self, value: &T) -> bool {
+                    (**self).contains(value)
+                }
+            }
+
+            impl<T: PartialOrd + Eq, S: OrderedSetLike<T>> OrderedSetLike<T> for $pointer<S> {}
+        )*
+    };
+}
+
+impl_set_like_for_pointers!(Box, Rc, Arc);
+
+/// Returns the elements of `set` to be listed in failure facts, in a stable order.
+fn listed_elements<T: Eq + Debug, S: SetLike<T>>(set: &S) -> Vec<&T> {
+    listed_in_stable_order(set.iter(), set.elements_ordered())
+}
+
 #[cfg(test)]
 mod tests {
     use std::iter::FromIterator;
 
     use crate::testing::*;
+    use crate::OptionAssertion;
 
     use super::*;
 
@@ -282,20 +615,104 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn is_not_empty() {
+        assert_that!(HashSet::from([1])).is_not_empty();
+        assert_that!(check_that!(HashSet::<i32>::new()).is_not_empty()).facts_are(vec![
+            Fact::new_simple_fact("expected to be non-empty"),
+            Fact::new_splitter(),
+            Fact::new("actual", "[]"),
+        ]);
+    }
+
+    #[test]
+    fn has_length_bounds() {
+        let set = BTreeSet::from([1, 2, 3]);
+        assert_that!(set).has_length_at_least(3);
+        assert_that!(set).has_length_at_most(3);
+
+        // Failures
+        assert_that!(check_that!(set).has_length_at_least(4)).facts_are(vec![
+            Fact::new("value of", "set.len()"),
+            Fact::new("expected", "at least 4"),
+            Fact::new("actual", "3"),
+        ]);
+        assert_that!(check_that!(set).has_length_at_most(2)).facts_are(vec![
+            Fact::new("value of", "set.len()"),
+            Fact::new("expected", "at most 2"),
+            Fact::new("actual", "3"),
+        ]);
+    }
+
+    #[test]
+    fn has_length_between() {
+        let set = BTreeSet::from([1, 2, 3]);
+        assert_that!(set).has_length_between(2, 3);
+
+        // Failures
+        assert_that!(check_that!(set).has_length_between(0, 2)).facts_are(vec![
+            Fact::new("value of", "set.len()"),
+            Fact::new("expected", "between 0 and 2"),
+            Fact::new("actual", "3"),
+        ]);
+    }
+
     #[test]
     fn contains() {
         assert_that!(HashSet::from_iter(vec![1, 2, 3].iter())).contains(&3);
 
         // Failures
-        let result = check_that!(HashSet::from_iter(vec![1, 2, 3].iter())).contains(&10);
-        assert_that!(result).facts_are_at_least(vec![
-            Fact::new("expected to contain", "10"),
+        assert_that!(check_that!(HashSet::from_iter(vec![1, 2, 3].iter())).contains(&10))
+            .facts_are(vec![
+                Fact::new("expected to contain", "10"),
+                Fact::new_simple_fact("but did not"),
+                Fact::new_multi_value_fact("though it did contain", vec!["1", "2", "3"]),
+            ]);
+    }
+
+    #[test]
+    fn does_not_contain() {
+        assert_that!(HashSet::from([1, 2, 3])).does_not_contain(10);
+
+        // Failures
+        assert_that!(check_that!(HashSet::from([3, 1, 2])).does_not_contain(2)).facts_are(vec![
+            Fact::new("expected to not contain", "2"),
+            Fact::new_simple_fact("but element was found"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1", "2", "3"]),
+        ]);
+    }
+
+    #[test]
+    fn contains_suggestions() {
+        let tags = BTreeSet::from(["release".to_string(), "stable".to_string()]);
+        assert_that!(check_that!(tags).contains("relaese".to_string())).facts_are(vec![
+            Fact::new("expected to contain", r#""relaese""#),
             Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("did you mean", vec![r#""release""#]),
+            Fact::new_multi_value_fact(
+                "though it did contain",
+                vec![r#""release""#, r#""stable""#],
+            ),
         ]);
-        assert_that!(result)
-            .fact_keys()
-            .contains(&"though it did contain".to_string());
-        // Skip test for value because key order is not stable.
+    }
+
+    #[test]
+    #[allow(clippy::needless_borrows_for_generic_args)]
+    fn contains_any_of() {
+        assert_that!(HashSet::from_iter([1, 2, 3].iter())).contains_any_of(vec![&5, &2]);
+        assert_that!(HashSet::from([1, 2, 3])).contains_any_of([5, 2]);
+        assert_that!(HashSet::from([1, 2, 3])).contains_any_of(&[5, 2]);
+        assert_that!(BTreeSet::from([1, 2, 3])).does_not_contain_any([4, 5]);
+        assert_that!(BTreeSet::from([1, 2, 3])).does_not_contain_any(&[4, 5]);
+
+        // Failures
+        assert_that!(check_that!(BTreeSet::from([1, 2])).contains_any_of(vec![3, 4])).facts_are(
+            vec![
+                Fact::new_multi_value_fact("expected to contain any of", vec!["3", "4"]),
+                Fact::new_simple_fact("but did not"),
+                Fact::new_multi_value_fact("though it did contain", vec!["1", "2"]),
+            ],
+        );
     }
 
     #[test]
@@ -318,9 +735,88 @@ mod tests {
         assert_that!(BTreeSet::from([1, 2, 3])).contains_all_of_in_order(BTreeSet::from([1, 2, 3]));
     }
 
+    #[test]
+    fn contains_exactly() {
+        assert_that!(HashSet::from([1, 2, 3])).contains_exactly(HashSet::from([3, 2, 1]));
+        assert_that!(HashSet::<i32>::new()).contains_exactly(HashSet::new());
+        assert_that!(BTreeSet::from([1, 2])).contains_exactly(BTreeSet::from([2, 1]));
+        assert_that!(Arc::new(HashSet::from([1]))).contains_exactly(HashSet::from([1]));
+
+        // Failures
+        assert_that!(
+            check_that!(BTreeSet::from([1, 2, 3])).contains_exactly(BTreeSet::from([2, 3, 4, 5]))
+        )
+        .facts_are(vec![
+            Fact::new("only in actual (1)", "[1]"),
+            Fact::new("only in expected (2)", "[4, 5]"),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("expected", vec!["2", "3", "4", "5"]),
+            Fact::new_multi_value_fact("actual", vec!["1", "2", "3"]),
+        ]);
+        assert_that!(check_that!(HashSet::from([1])).contains_exactly(HashSet::from([2])))
+            .facts_are(vec![
+                Fact::new("only in actual (1)", "[1]"),
+                Fact::new("only in expected (1)", "[2]"),
+                Fact::new_splitter(),
+                Fact::new_multi_value_fact("expected", vec!["2"]),
+                Fact::new_multi_value_fact("actual", vec!["1"]),
+            ]);
+    }
+
     #[test]
     fn contains_exactly_in_order() {
         assert_that!(BTreeSet::from([1, 2, 3]))
             .contains_exactly_in_order(BTreeSet::from([1, 2, 3]));
     }
+
+    #[test]
+    fn smart_pointers() {
+        assert_that!(Box::new(HashSet::from([1, 2]))).contains(1);
+        assert_that!(Arc::new(BTreeSet::from([1, 2])))
+            .first()
+            .has_value(&1);
+
+        // Failures
+        assert_that!(check_that!(Rc::new(BTreeSet::from([1, 2]))).has_length(3)).facts_are(vec![
+            Fact::new("value of", "Rc::new(BTreeSet::from([1, 2])).len()"),
+            Fact::new("expected", "3"),
+            Fact::new("actual", "2"),
+        ]);
+    }
+
+    #[test]
+    fn first_and_last() {
+        let set = BTreeSet::from([3, 1, 2]);
+        assert_that!(set).first().has_value(&1);
+        assert_that!(set).last().has_value(&3);
+        assert_that!(BTreeSet::<i32>::new()).first().is_none();
+        assert_that!(BTreeSet::<i32>::new()).last().is_none();
+
+        // Failures
+        assert_that!(check_that!(set).first().has_value(&2)).facts_are(vec![
+            Fact::new("value of", "set.first()"),
+            Fact::new("expected", "Some(2)"),
+            Fact::new("actual", "Some(1)"),
+        ]);
+    }
+
+    #[test]
+    fn elements_in_range() {
+        let set = BTreeSet::from([1, 3, 5, 7, 9]);
+        assert_that!(set)
+            .elements_in_range(2..8)
+            .contains_exactly_in_order([3, 5, 7].iter());
+        assert_that!(set)
+            .elements_in_range(..=3)
+            .contains_exactly_in_order([1, 3].iter());
+        assert_that!(set).elements_in_range(10..).is_empty();
+
+        // Failures
+        assert_that!(check_that!(set).elements_in_range(5..).contains(3)).facts_are(vec![
+            Fact::new("value of", "set.range(5..)"),
+            Fact::new("expected to contain", "3"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["5", "7", "9"]),
+        ]);
+    }
 }
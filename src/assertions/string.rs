@@ -12,8 +12,18 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::assertions::basic::EqualityAssertion;
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+use std::any::type_name;
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::str::FromStr;
+use std::vec::IntoIter;
+
+use crate::assertions::basic::{add_difference_facts, EqualityAssertion};
+use crate::assertions::iterator::{check_len_bound, LengthBound};
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, DebugCache, FactKey, Subject,
+};
+use crate::diff::string::{alignment, edit_distance};
 
 /// Trait for string assertion.
 ///
@@ -21,13 +31,16 @@ use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
 /// ```
 /// use assertor::*;
 ///
-/// assert_that!("foobarbaz").is_same_string_to("foobarbaz");
+/// assert_that!("foobarbaz").is_equal_to("foobarbaz");
 /// assert_that!("foobarbaz").contains("bar");
 /// assert_that!("foobarbaz").starts_with("foo");
 /// assert_that!("foobarbaz").ends_with("baz");
 /// ```
 pub trait StringAssertion<R> {
     /// Checks that the subject is same string to `expected`.
+    ///
+    /// Alias of [`EqualityAssertion::is_equal_to`] taking anything convertible into a `String`,
+    /// which reports the position of the first differing character in the same way.
     #[track_caller]
     fn is_same_string_to<E: Into<String>>(&self, expected: E) -> R;
 
@@ -39,6 +52,63 @@ pub trait StringAssertion<R> {
     #[track_caller]
     fn does_not_contain<E: Into<String>>(&self, value: E) -> R;
 
+    /// Checks that the subject contains every one of `substrings`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("GET /index.html 200").contains_all_of(["GET", "200"]);
+    /// ```
+    /// ```
+    /// use assertor::*;
+    /// demo!(
+    ///     assert_that!("GET /index.html 404").contains_all_of(["GET", "200"]),
+    ///     r#"
+    ///     missing (1)                           : [ "200" ]
+    ///     ---
+    ///     expected a string that contains all of: [ "GET", "200" ]
+    ///     but was                               : GET /index.html 404
+    ///     "#
+    /// );
+    /// ```
+    #[track_caller]
+    fn contains_all_of<I: IntoIterator<Item = E>, E: Into<String>>(&self, substrings: I) -> R;
+
+    /// Checks that the subject contains at least one of `substrings`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("connection reset").contains_any_of(["refused", "reset"]);
+    /// ```
+    #[track_caller]
+    fn contains_any_of<I: IntoIterator<Item = E>, E: Into<String>>(&self, substrings: I) -> R;
+
+    /// Checks that the subject contains exactly `times` non-overlapping occurrences of
+    /// `expected`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("a, b, c").contains_exactly_n_times(", ", 2);
+    /// assert_that!("abc").contains_exactly_n_times("x", 0);
+    /// ```
+    /// ```
+    /// use assertor::*;
+    /// demo!(
+    ///     assert_that!("retry; retry").contains_exactly_n_times("retry", 3),
+    ///     r#"
+    ///     expected a string that contains: retry
+    ///     exactly                        : 3 times
+    ///     but it occurred                : 2 times
+    ///     at byte offsets                : [ 0, 7 ]
+    ///     actual                         : retry; retry
+    ///     "#
+    /// );
+    /// ```
+    #[track_caller]
+    fn contains_exactly_n_times<E: Into<String>>(&self, expected: E, times: usize) -> R;
+
     /// Checks that the subject starts with `expected`.
     #[track_caller]
     fn starts_with<E: Into<String>>(&self, expected: E) -> R;
@@ -46,6 +116,438 @@ pub trait StringAssertion<R> {
     /// Checks that the subject ends with `expected`.
     #[track_caller]
     fn ends_with<E: Into<String>>(&self, expected: E) -> R;
+
+    /// Returns a new subject which is the subject with leading and trailing whitespace removed.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("  foo\n").trimmed().is_equal_to("foo".to_string());
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(" foo ").trimmed().is_equal_to("bar".to_string());
+    /// // value of: " foo ".trim()
+    /// // expected: "bar"
+    /// // actual  : "foo"
+    /// ```
+    fn trimmed(&self) -> Subject<'_, String, (), R>;
+
+    /// Returns a new subject which is the lowercase equivalent of the subject.
+    fn to_lowercase(&self) -> Subject<'_, String, (), R>;
+
+    /// Returns a new subject which is the uppercase equivalent of the subject.
+    fn to_uppercase(&self) -> Subject<'_, String, (), R>;
+
+    /// Returns a new iterator subject over the pieces of the subject separated by `sep`, as
+    /// [`str::split`] does. `sep` is either a `char` or a string.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("a,b,,d").split(',').has_length(4);
+    /// assert_that!("a, b").split(", ").contains("b".to_string());
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!("a,b,c").split(',').has_length(2);
+    /// // value of: "a,b,c".split(',')
+    /// // value of: "a,b,c".split(',').size()
+    /// // expected: 2
+    /// // actual  : 3
+    /// ```
+    fn split<P: ToString + Debug>(&self, sep: P) -> Subject<'_, IntoIter<String>, (), R>;
+
+    /// Returns a new subject which is the subject parsed as `T` with [`str::parse`]. Panics with
+    /// the parse error if the subject can not be parsed.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("42").parses_as::<i32>().is_equal_to(42);
+    /// assert_that!("-7").parses_as::<i64>().is_less_than(0);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!("4x2").parses_as::<i32>();
+    /// // expected to parse as: i32
+    /// // but failed with     : ParseIntError { kind: InvalidDigit }
+    /// // ---
+    /// // actual              : "4x2"
+    /// ```
+    #[track_caller]
+    fn parses_as<T: FromStr>(&self) -> Subject<'_, T, (), R>
+    where
+        T::Err: Debug;
+
+    /// Checks that the subject can not be parsed as `T` with [`str::parse`].
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("4x2").fails_to_parse_as::<i32>();
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!("42").fails_to_parse_as::<i32>();
+    /// // expected to fail to parse as: i32
+    /// // but parsed as               : 42
+    /// ```
+    #[track_caller]
+    fn fails_to_parse_as<T: FromStr + Debug>(&self) -> R;
+
+    /// Returns a new subject which is the subject parsed as a [`serde_json::Value`]. Panics with
+    /// the parse error and its position if the subject is not valid JSON.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// use serde_json::json;
+    /// assert_that!(r#"{"id": 7}"#).as_json().is_equal_to(json!({"id": 7}));
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!("{\"id\": 7,}").as_json();
+    /// // expected to parse as: JSON
+    /// // but failed with     : trailing comma
+    /// // at                  : line 1, column 10
+    /// // ---
+    /// // actual              : "{\"id\": 7,}"
+    /// ```
+    #[cfg(feature = "serde_json")]
+    #[track_caller]
+    fn as_json(&self) -> Subject<'_, serde_json::Value, (), R>;
+
+    /// Checks that the subject matches `template`, in which each `{name}` placeholder matches any
+    /// non-empty substring. A placeholder used several times must match the same substring each
+    /// time, and `{{` and `}}` match literal braces.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("Hello, world!").matches_template("Hello, {name}!");
+    /// assert_that!("[2024-01-02] started").matches_template("[{date}] {event}");
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!("Hello, world.").matches_template("Hello, {name}!");
+    /// // expected to match template: Hello, {name}!
+    /// // but could not match       : "!"
+    /// // ---
+    /// // actual                    : "Hello, world."
+    /// ```
+    #[track_caller]
+    fn matches_template(&self, template: &str) -> R;
+
+    /// Returns a new subject which maps the placeholders of `template` to the substrings they
+    /// match, as [`StringAssertion::matches_template`] does. Panics if the subject does not match.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("user alice logged in")
+    ///     .template_captures("user {name} logged {direction}")
+    ///     .contains_entry("name".to_string(), "alice".to_string());
+    /// ```
+    #[track_caller]
+    fn template_captures(&self, template: &str) -> Subject<'_, BTreeMap<String, String>, (), R>;
+
+    /// Returns a new subject to check with [`EditDistanceAssertion::of`] that the subject can be
+    /// changed into the expected string with at most `distance` single character insertions,
+    /// deletions and substitutions.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("recieve").is_within_edit_distance(2).of("receive");
+    /// ```
+    /// ```
+    /// use assertor::*;
+    /// demo!(
+    ///     assert_that!("kitten").is_within_edit_distance(2).of("sitting"),
+    ///     r#"
+    ///     expected within edit distance: 2
+    ///     of                           : "sitting"
+    ///     but was                      : "kitten"
+    ///     with edit distance           : 3
+    ///     ---
+    ///     expected (aligned)           : sitting
+    ///     actual (aligned)             : kitten·
+    ///     differences                  : ^   ^ -
+    ///     "#
+    /// );
+    /// ```
+    fn is_within_edit_distance(&self, distance: usize) -> Subject<'_, String, EditDistance, R>;
+
+    /// Returns a new subject whose [`TrimmedStringAssertion`] assertions remove leading and
+    /// trailing whitespace from both the subject and the expected string before comparing them.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("  Done.\n")
+    ///     .ignoring_surrounding_whitespace()
+    ///     .is_same_string_to("Done.");
+    /// assert_that!("Usage: tool [OPTIONS]\n")
+    ///     .ignoring_surrounding_whitespace()
+    ///     .ends_with("[OPTIONS]");
+    /// ```
+    fn ignoring_surrounding_whitespace(&self) -> Subject<'_, String, IgnoringWhitespace, R>;
+
+    /// Returns a new subject whose [`PathStringAssertion`] assertions compare the subject and the
+    /// expected string as paths: `\\` separators are treated as `/`, and trailing separators are
+    /// ignored. Facts show the normalized forms.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("target\\debug\\").comparing_as_paths().is_same_path_as("target/debug");
+    /// assert_that!("C:\\src\\lib.rs").comparing_as_paths().starts_with("C:/src");
+    /// demo!(
+    ///     assert_that!("out\\bin\\tool.exe").comparing_as_paths().ends_with("bin/tool"),
+    ///     r#"
+    ///     expected a path that ends with: "bin/tool"
+    ///     but was                       : "out/bin/tool.exe"
+    ///     ignoring                      : separator style and trailing separators
+    ///     "#
+    /// );
+    /// ```
+    fn comparing_as_paths(&self) -> Subject<'_, String, AsPaths, R>;
+
+    /// Checks that the subject has at least `min` and at most `max` characters. A `min` greater
+    /// than `max` fails the assertion.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!("héllo").has_length_between(1, 5);
+    /// demo!(
+    ///     assert_that!("fuzz").has_length_between(8, 16),
+    ///     r#"
+    ///     value of: "fuzz".chars().count()
+    ///     expected: between 8 and 16
+    ///     actual  : 4
+    ///     "#
+    /// );
+    /// ```
+    #[track_caller]
+    fn has_length_between(&self, min: usize, max: usize) -> R;
+}
+
+/// Trait for assertions on a string subject returned by
+/// [`StringAssertion::is_within_edit_distance`].
+pub trait EditDistanceAssertion<R> {
+    /// Checks that the edit distance between the subject and `expected` is at most the distance
+    /// given to [`StringAssertion::is_within_edit_distance`].
+    #[track_caller]
+    fn of<E: Into<String>>(&self, expected: E) -> R;
+}
+
+pub struct EditDistance {
+    distance: usize,
+}
+
+/// Trait for assertions on a string subject returned by
+/// [`StringAssertion::ignoring_surrounding_whitespace`].
+///
+/// # Example
+/// ```should_panic
+/// use assertor::*;
+/// assert_that!("error: not found\n")
+///     .ignoring_surrounding_whitespace()
+///     .starts_with("warning:");
+/// // expected a string that starts with: "warning:"
+/// // but was                           : "error: not found\n"
+/// // ignoring                          : leading and trailing whitespace
+/// ```
+pub trait TrimmedStringAssertion<R> {
+    /// Checks that the trimmed subject is same string to the trimmed `expected`.
+    #[track_caller]
+    fn is_same_string_to<E: Into<String>>(&self, expected: E) -> R;
+
+    /// Checks that the trimmed subject starts with the trimmed `expected`.
+    #[track_caller]
+    fn starts_with<E: Into<String>>(&self, expected: E) -> R;
+
+    /// Checks that the trimmed subject ends with the trimmed `expected`.
+    #[track_caller]
+    fn ends_with<E: Into<String>>(&self, expected: E) -> R;
+}
+
+pub struct IgnoringWhitespace;
+
+/// Trait for assertions on a string subject returned by [`StringAssertion::comparing_as_paths`].
+pub trait PathStringAssertion<R> {
+    /// Checks that the subject is the same path as `expected`.
+    #[track_caller]
+    fn is_same_path_as<E: Into<String>>(&self, expected: E) -> R;
+
+    /// Checks that the leading components of the subject are those of `expected`.
+    #[track_caller]
+    fn starts_with<E: Into<String>>(&self, expected: E) -> R;
+
+    /// Checks that the trailing components of the subject are those of `expected`.
+    #[track_caller]
+    fn ends_with<E: Into<String>>(&self, expected: E) -> R;
+}
+
+pub struct AsPaths;
+
+impl<R> PathStringAssertion<R> for Subject<'_, String, AsPaths, R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn is_same_path_as<E: Into<String>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        check_as_paths(self, expected.into(), "expected", |actual, expected| {
+            actual == expected
+        })
+    }
+
+    fn starts_with<E: Into<String>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        check_as_paths(
+            self,
+            expected.into(),
+            "expected a path that starts with",
+            |actual, expected| {
+                expected.is_empty()
+                    || actual.strip_prefix(expected).map_or(false, |rest| {
+                        rest.is_empty() || rest.starts_with('/') || expected.ends_with('/')
+                    })
+            },
+        )
+    }
+
+    fn ends_with<E: Into<String>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        check_as_paths(
+            self,
+            expected.into(),
+            "expected a path that ends with",
+            |actual, expected| {
+                expected.is_empty()
+                    || actual.strip_suffix(expected).map_or(false, |rest| {
+                        rest.is_empty() || rest.ends_with('/') || expected.starts_with('/')
+                    })
+            },
+        )
+    }
+}
+
+/// Uses `/` as the only separator and removes trailing separators, except for a root path.
+fn normalize_path(path: &str) -> String {
+    let path = path.replace('\\', "/");
+    match path.trim_end_matches('/') {
+        "" if !path.is_empty() => "/".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
+
+fn check_as_paths<R>(
+    subject: &Subject<String, AsPaths, R>,
+    expected: String,
+    expectation: &str,
+    matches: fn(&str, &str) -> bool,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    let actual = normalize_path(subject.actual());
+    let expected = normalize_path(&expected);
+    if matches(&actual, &expected) {
+        subject.new_result().do_ok()
+    } else {
+        subject
+            .new_result()
+            .add_formatted_fact(expectation, expected)
+            .add_formatted_fact(FactKey::ButWas, actual)
+            .add_fact("ignoring", "separator style and trailing separators")
+            .do_fail()
+    }
+}
+
+impl<R> TrimmedStringAssertion<R> for Subject<'_, String, IgnoringWhitespace, R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn is_same_string_to<E: Into<String>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        check_trimmed(self, expected.into(), "expected", |actual, expected| {
+            actual == expected
+        })
+    }
+
+    fn starts_with<E: Into<String>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        check_trimmed(
+            self,
+            expected.into(),
+            "expected a string that starts with",
+            |actual, expected| actual.starts_with(expected),
+        )
+    }
+
+    fn ends_with<E: Into<String>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        check_trimmed(
+            self,
+            expected.into(),
+            "expected a string that ends with",
+            |actual, expected| actual.ends_with(expected),
+        )
+    }
+}
+
+fn check_trimmed<R>(
+    subject: &Subject<String, IgnoringWhitespace, R>,
+    expected: String,
+    expectation: &str,
+    matches: fn(&str, &str) -> bool,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    if matches(subject.actual().trim(), expected.trim()) {
+        subject.new_result().do_ok()
+    } else {
+        subject
+            .new_result()
+            .add_fact(expectation, format!("{:?}", expected))
+            .add_fact(FactKey::ButWas, format!("{:?}", subject.actual()))
+            .add_fact("ignoring", "leading and trailing whitespace")
+            .do_fail()
+    }
+}
+
+impl<R> EditDistanceAssertion<R> for Subject<'_, String, EditDistance, R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn of<E: Into<String>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
+        let expected = expected.into();
+        let max_distance = self.option().distance;
+        let distance = edit_distance(&expected, self.actual());
+        if distance <= max_distance {
+            return self.new_result().do_ok();
+        }
+        let result = self
+            .new_result()
+            .add_formatted_fact("expected within edit distance", max_distance)
+            .add_fact("of", format!("{:?}", expected))
+            .add_fact(FactKey::ButWas, format!("{:?}", self.actual()))
+            .add_formatted_fact("with edit distance", distance);
+        match alignment(&expected, self.actual()) {
+            None => result.do_fail(),
+            Some([expected_row, actual_row, markers]) => result
+                .add_splitter()
+                .add_fact("expected (aligned)", expected_row)
+                .add_fact("actual (aligned)", actual_row)
+                .add_fact("differences", markers)
+                .do_fail(),
+        }
+    }
 }
 
 impl<R> StringAssertion<R> for Subject<'_, String, (), R>
@@ -53,56 +555,151 @@ where
     AssertionResult: AssertionStrategy<R>,
 {
     fn is_same_string_to<E: Into<String>>(&self, expected: E) -> R {
-        let subject: Subject<String, (), R> = self.new_subject(self.actual(), None, ());
+        pass_if_skipped!(self);
+        let mut subject: Subject<String, (), R> = self.new_subject(self.actual(), None, ());
+        if let Some(formatter) = self.formatter() {
+            subject = subject.with_formatter(formatter);
+        }
         EqualityAssertion::is_equal_to(&subject, expected.into())
     }
 
     fn contains<E: Into<String>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
         let expected_str = expected.into();
-        if self.actual().contains(&expected_str) {
-            self.new_result().do_ok()
-        } else {
-            self.new_result()
-                .add_fact("expected a string that contains", expected_str)
-                .add_fact("but was", self.actual())
-                .do_fail()
-        }
+        check_string(
+            self.new_result(),
+            self.actual().contains(&expected_str),
+            "expected a string that contains",
+            expected_str,
+            render_actual(self),
+        )
     }
 
     fn does_not_contain<E: Into<String>>(&self, value: E) -> R {
+        pass_if_skipped!(self);
         let expected_str = value.into();
-        if self.actual().contains(&expected_str) {
-            self.new_result()
-                .add_fact("expected a string to not contain", expected_str)
-                .add_fact("but was", self.actual())
-                .do_fail()
-        } else {
-            self.new_result().do_ok()
-        }
+        check_string(
+            self.new_result(),
+            !self.actual().contains(&expected_str),
+            "expected a string to not contain",
+            expected_str,
+            render_actual(self),
+        )
+    }
+
+    fn contains_all_of<I: IntoIterator<Item = E>, E: Into<String>>(&self, substrings: I) -> R {
+        pass_if_skipped!(self);
+        check_contains_all_of(self, self.actual(), substrings)
+    }
+
+    fn contains_any_of<I: IntoIterator<Item = E>, E: Into<String>>(&self, substrings: I) -> R {
+        pass_if_skipped!(self);
+        check_contains_any_of(self, self.actual(), substrings)
+    }
+
+    fn contains_exactly_n_times<E: Into<String>>(&self, expected: E, times: usize) -> R {
+        pass_if_skipped!(self);
+        check_contains_n_times(self, self.actual(), expected.into(), times)
     }
 
     fn starts_with<E: Into<String>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
         let expected_str = expected.into();
-        if self.actual().starts_with(&expected_str) {
-            self.new_result().do_ok()
-        } else {
-            self.new_result()
-                .add_fact("expected a string that starts with", expected_str)
-                .add_fact("but was", self.actual())
-                .do_fail()
-        }
+        check_string(
+            self.new_result(),
+            self.actual().starts_with(&expected_str),
+            "expected a string that starts with",
+            expected_str,
+            render_actual(self),
+        )
     }
 
     fn ends_with<E: Into<String>>(&self, expected: E) -> R {
+        pass_if_skipped!(self);
         let expected_str = expected.into();
-        if self.actual().ends_with(&expected_str) {
-            self.new_result().do_ok()
-        } else {
-            self.new_result()
-                .add_fact("expected a string that ends with", expected_str)
-                .add_fact("but was", self.actual())
-                .do_fail()
-        }
+        check_string(
+            self.new_result(),
+            self.actual().ends_with(&expected_str),
+            "expected a string that ends with",
+            expected_str,
+            render_actual(self),
+        )
+    }
+
+    fn trimmed(&self) -> Subject<'_, String, (), R> {
+        derived_string(self, self.actual().trim().to_string(), "trim")
+    }
+
+    fn to_lowercase(&self) -> Subject<'_, String, (), R> {
+        derived_string(self, self.actual().to_lowercase(), "to_lowercase")
+    }
+
+    fn to_uppercase(&self) -> Subject<'_, String, (), R> {
+        derived_string(self, self.actual().to_uppercase(), "to_uppercase")
+    }
+
+    fn split<P: ToString + Debug>(&self, sep: P) -> Subject<'_, IntoIter<String>, (), R> {
+        split(self, self.actual(), sep)
+    }
+
+    fn parses_as<T: FromStr>(&self) -> Subject<'_, T, (), R>
+    where
+        T::Err: Debug,
+    {
+        parsed(self, self.actual())
+    }
+
+    fn fails_to_parse_as<T: FromStr + Debug>(&self) -> R {
+        pass_if_skipped!(self);
+        check_fails_to_parse_as::<T, R>(self.new_result(), self.actual())
+    }
+
+    #[cfg(feature = "serde_json")]
+    fn as_json(&self) -> Subject<'_, serde_json::Value, (), R> {
+        as_json(self, self.actual())
+    }
+
+    fn matches_template(&self, template: &str) -> R {
+        pass_if_skipped!(self);
+        check_matches_template(self, self.actual(), template)
+    }
+
+    fn template_captures(&self, template: &str) -> Subject<'_, BTreeMap<String, String>, (), R> {
+        template_captures(self, self.actual(), template)
+    }
+
+    fn is_within_edit_distance(&self, distance: usize) -> Subject<'_, String, EditDistance, R> {
+        self.new_owned_subject(
+            self.actual().to_string(),
+            self.description().clone(),
+            EditDistance { distance },
+        )
+    }
+
+    fn ignoring_surrounding_whitespace(&self) -> Subject<'_, String, IgnoringWhitespace, R> {
+        self.new_owned_subject(
+            self.actual().to_string(),
+            self.description().clone(),
+            IgnoringWhitespace,
+        )
+    }
+
+    fn comparing_as_paths(&self) -> Subject<'_, String, AsPaths, R> {
+        self.new_owned_subject(
+            self.actual().to_string(),
+            self.description().clone(),
+            AsPaths,
+        )
+    }
+
+    fn has_length_between(&self, min: usize, max: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().chars().count(),
+            format!("{}.chars().count()", self.description_or_expr()),
+            LengthBound::Between(min, max),
+        )
     }
 }
 
@@ -112,41 +709,587 @@ where
 {
     #[track_caller]
     fn is_same_string_to<E: Into<String>>(&self, expected: E) -> R {
-        self.new_owned_subject(self.actual().to_string(), None, ())
-            .is_same_string_to(expected)
+        pass_if_skipped!(self);
+        let expected_str = expected.into();
+        if expected_str.eq(self.actual()) {
+            self.new_result().do_ok()
+        } else {
+            let mut result = self.new_result();
+            let mut cache = DebugCache::default();
+            if self.formatter().is_none() && !self.is_redacted() {
+                result =
+                    add_difference_facts(result, &mut cache, expected_str.as_str(), *self.actual());
+            }
+            let result =
+                result.add_debug_fact(FactKey::Expected, expected_str.as_str(), &mut cache);
+            self.add_value_fact(result, FactKey::Actual, self.actual(), &mut cache)
+                .do_fail()
+        }
     }
 
     #[track_caller]
     fn contains<E: Into<String>>(&self, expected: E) -> R {
-        self.new_owned_subject(self.actual().to_string(), None, ())
-            .contains(expected)
+        pass_if_skipped!(self);
+        let expected_str = expected.into();
+        check_string(
+            self.new_result(),
+            self.actual().contains(&expected_str),
+            "expected a string that contains",
+            expected_str,
+            render_actual(self),
+        )
     }
 
     #[track_caller]
     fn does_not_contain<E: Into<String>>(&self, value: E) -> R {
-        self.new_owned_subject(self.actual().to_string(), None, ())
-            .does_not_contain(value)
+        pass_if_skipped!(self);
+        let expected_str = value.into();
+        check_string(
+            self.new_result(),
+            !self.actual().contains(&expected_str),
+            "expected a string to not contain",
+            expected_str,
+            render_actual(self),
+        )
+    }
+
+    fn contains_all_of<I: IntoIterator<Item = E>, E: Into<String>>(&self, substrings: I) -> R {
+        pass_if_skipped!(self);
+        check_contains_all_of(self, self.actual(), substrings)
+    }
+
+    fn contains_any_of<I: IntoIterator<Item = E>, E: Into<String>>(&self, substrings: I) -> R {
+        pass_if_skipped!(self);
+        check_contains_any_of(self, self.actual(), substrings)
+    }
+
+    fn contains_exactly_n_times<E: Into<String>>(&self, expected: E, times: usize) -> R {
+        pass_if_skipped!(self);
+        check_contains_n_times(self, self.actual(), expected.into(), times)
     }
 
     #[track_caller]
     fn starts_with<E: Into<String>>(&self, expected: E) -> R {
-        self.new_owned_subject(self.actual().to_string(), None, ())
-            .starts_with(expected)
+        pass_if_skipped!(self);
+        let expected_str = expected.into();
+        check_string(
+            self.new_result(),
+            self.actual().starts_with(&expected_str),
+            "expected a string that starts with",
+            expected_str,
+            render_actual(self),
+        )
     }
 
     #[track_caller]
     fn ends_with<E: Into<String>>(&self, expected: E) -> R {
-        self.new_owned_subject(self.actual().to_string(), None, ())
-            .ends_with(expected)
+        pass_if_skipped!(self);
+        let expected_str = expected.into();
+        check_string(
+            self.new_result(),
+            self.actual().ends_with(&expected_str),
+            "expected a string that ends with",
+            expected_str,
+            render_actual(self),
+        )
+    }
+
+    fn trimmed(&self) -> Subject<'_, String, (), R> {
+        derived_string(self, self.actual().trim().to_string(), "trim")
+    }
+
+    fn to_lowercase(&self) -> Subject<'_, String, (), R> {
+        derived_string(self, self.actual().to_lowercase(), "to_lowercase")
+    }
+
+    fn to_uppercase(&self) -> Subject<'_, String, (), R> {
+        derived_string(self, self.actual().to_uppercase(), "to_uppercase")
+    }
+
+    fn split<P: ToString + Debug>(&self, sep: P) -> Subject<'_, IntoIter<String>, (), R> {
+        split(self, self.actual(), sep)
+    }
+
+    fn parses_as<T: FromStr>(&self) -> Subject<'_, T, (), R>
+    where
+        T::Err: Debug,
+    {
+        parsed(self, self.actual())
+    }
+
+    fn fails_to_parse_as<T: FromStr + Debug>(&self) -> R {
+        pass_if_skipped!(self);
+        check_fails_to_parse_as::<T, R>(self.new_result(), self.actual())
+    }
+
+    #[cfg(feature = "serde_json")]
+    fn as_json(&self) -> Subject<'_, serde_json::Value, (), R> {
+        as_json(self, self.actual())
+    }
+
+    fn matches_template(&self, template: &str) -> R {
+        pass_if_skipped!(self);
+        check_matches_template(self, self.actual(), template)
+    }
+
+    fn template_captures(&self, template: &str) -> Subject<'_, BTreeMap<String, String>, (), R> {
+        template_captures(self, self.actual(), template)
+    }
+
+    fn is_within_edit_distance(&self, distance: usize) -> Subject<'_, String, EditDistance, R> {
+        self.new_owned_subject(
+            self.actual().to_string(),
+            self.description().clone(),
+            EditDistance { distance },
+        )
+    }
+
+    fn ignoring_surrounding_whitespace(&self) -> Subject<'_, String, IgnoringWhitespace, R> {
+        self.new_owned_subject(
+            self.actual().to_string(),
+            self.description().clone(),
+            IgnoringWhitespace,
+        )
+    }
+
+    fn comparing_as_paths(&self) -> Subject<'_, String, AsPaths, R> {
+        self.new_owned_subject(
+            self.actual().to_string(),
+            self.description().clone(),
+            AsPaths,
+        )
+    }
+
+    fn has_length_between(&self, min: usize, max: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().chars().count(),
+            format!("{}.chars().count()", self.description_or_expr()),
+            LengthBound::Between(min, max),
+        )
+    }
+}
+
+/// Derives a `String` subject described as `<subject>.<method>()`.
+fn derived_string<'a, S, R>(
+    subject: &Subject<'a, S, (), R>,
+    value: String,
+    method: &str,
+) -> Subject<'a, String, (), R> {
+    subject.new_owned_subject(
+        value,
+        Some(format!("{}.{}()", subject.description_or_expr(), method)),
+        (),
+    )
+}
+
+/// Derives an iterator subject over the pieces of `actual`, described as `<subject>.split(sep)`.
+fn split<'a, S, P, R>(
+    subject: &Subject<'a, S, (), R>,
+    actual: &str,
+    sep: P,
+) -> Subject<'a, IntoIter<String>, (), R>
+where
+    P: ToString + Debug,
+{
+    let pieces: Vec<String> = actual
+        .split(sep.to_string().as_str())
+        .map(String::from)
+        .collect();
+    subject.new_owned_subject(
+        pieces.into_iter(),
+        Some(format!(
+            "{}.split({:?})",
+            subject.description_or_expr(),
+            sep
+        )),
+        (),
+    )
+}
+
+/// Derives a subject described as `<subject>.parse::<T>()`, or fails with the parse error.
+#[track_caller]
+fn parsed<'a, S, T, R>(subject: &Subject<'a, S, (), R>, actual: &str) -> Subject<'a, T, (), R>
+where
+    S: Debug,
+    T: FromStr,
+    T::Err: Debug,
+{
+    match actual.parse::<T>() {
+        Ok(value) => subject.new_owned_subject(
+            value,
+            Some(format!(
+                "{}.parse::<{}>()",
+                subject.description_or_expr(),
+                type_name::<T>()
+            )),
+            (),
+        ),
+        Err(err) => {
+            // Like `ResultAssertion::ok`, a derived subject can not be returned, so panic.
+            let result: AssertionResult = subject
+                .new_result()
+                .add_fact("expected to parse as", type_name::<T>())
+                .add_formatted_fact("but failed with", err)
+                .add_splitter()
+                .add_fact(FactKey::Actual, subject.format_value(subject.actual()));
+            result.fail_derivation()
+        }
+    }
+}
+
+/// Derives a subject described as `<subject>.as_json()`, or fails with the position of the parse
+/// error if `actual` is not valid JSON.
+#[cfg(feature = "serde_json")]
+#[track_caller]
+fn as_json<'a, S: Debug, R>(
+    subject: &Subject<'a, S, (), R>,
+    actual: &str,
+) -> Subject<'a, serde_json::Value, (), R> {
+    match serde_json::from_str(actual) {
+        Ok(value) => subject.new_owned_subject(
+            value,
+            Some(format!("{}.as_json()", subject.description_or_expr())),
+            (),
+        ),
+        Err(err) => {
+            // Like `parsed`, a derived subject can not be returned, so panic.
+            let message = err.to_string();
+            let position = format!(" at line {} column {}", err.line(), err.column());
+            let result: AssertionResult = subject
+                .new_result()
+                .add_fact("expected to parse as", "JSON")
+                .add_fact(
+                    "but failed with",
+                    message.strip_suffix(&position).unwrap_or(&message),
+                )
+                .add_fact(
+                    "at",
+                    format!("line {}, column {}", err.line(), err.column()),
+                )
+                .add_splitter()
+                .add_fact(FactKey::Actual, subject.format_value(subject.actual()));
+            result.fail_derivation()
+        }
+    }
+}
+
+fn check_fails_to_parse_as<T, R>(assertion_result: AssertionResult, actual: &str) -> R
+where
+    T: FromStr + Debug,
+    AssertionResult: AssertionStrategy<R>,
+{
+    match actual.parse::<T>() {
+        Ok(value) => assertion_result
+            .add_fact("expected to fail to parse as", type_name::<T>())
+            .add_formatted_fact("but parsed as", value)
+            .do_fail(),
+        Err(_) => assertion_result.do_ok(),
+    }
+}
+
+/// A part of a template given to [`StringAssertion::matches_template`].
+#[derive(Debug, PartialEq)]
+enum TemplatePart {
+    Literal(String),
+    Placeholder(String),
+}
+
+/// Splits `template` into literals and `{name}` placeholders. Braces which do not form a
+/// placeholder, and the escapes `{{` and `}}`, are literal.
+fn parse_template(template: &str) -> Vec<TemplatePart> {
+    let mut parts = vec![];
+    let mut literal = String::new();
+    let mut rest = template;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            literal.push(c);
+            rest = &rest[2..];
+            continue;
+        }
+        if c == '{' {
+            if let Some(end) = rest.find('}').filter(|&end| end > 1) {
+                if !literal.is_empty() {
+                    parts.push(TemplatePart::Literal(std::mem::take(&mut literal)));
+                }
+                parts.push(TemplatePart::Placeholder(rest[1..end].to_string()));
+                rest = &rest[end + 1..];
+                continue;
+            }
+        }
+        literal.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    if !literal.is_empty() {
+        parts.push(TemplatePart::Literal(literal));
+    }
+    parts
+}
+
+/// Matches `actual` against `parts`, backtracking over the lengths of the placeholders. Records
+/// in `furthest` the index of the furthest part which could not be matched.
+fn match_template(
+    parts: &[TemplatePart],
+    actual: &str,
+    captures: &mut BTreeMap<String, String>,
+    index: usize,
+    furthest: &mut usize,
+) -> bool {
+    *furthest = (*furthest).max(index);
+    let part = match parts.get(index) {
+        None => return actual.is_empty(),
+        Some(part) => part,
+    };
+    match part {
+        TemplatePart::Literal(literal) => {
+            actual.starts_with(literal.as_str())
+                && match_template(
+                    parts,
+                    &actual[literal.len()..],
+                    captures,
+                    index + 1,
+                    furthest,
+                )
+        }
+        TemplatePart::Placeholder(name) => {
+            if let Some(captured) = captures.get(name).cloned() {
+                return actual.starts_with(captured.as_str())
+                    && match_template(
+                        parts,
+                        &actual[captured.len()..],
+                        captures,
+                        index + 1,
+                        furthest,
+                    );
+            }
+            for (end, c) in actual.char_indices() {
+                let end = end + c.len_utf8();
+                captures.insert(name.clone(), actual[..end].to_string());
+                if match_template(parts, &actual[end..], captures, index + 1, furthest) {
+                    return true;
+                }
+            }
+            captures.remove(name);
+            false
+        }
+    }
+}
+
+/// Returns the substrings matched by the placeholders of `template`, or the furthest part of the
+/// template which could not be matched.
+fn captures_of(actual: &str, template: &str) -> Result<BTreeMap<String, String>, String> {
+    let parts = parse_template(template);
+    let mut captures = BTreeMap::new();
+    let mut furthest = 0;
+    if match_template(&parts, actual, &mut captures, 0, &mut furthest) {
+        return Ok(captures);
+    }
+    Err(match parts.get(furthest) {
+        Some(TemplatePart::Literal(literal)) => format!("{:?}", literal),
+        Some(TemplatePart::Placeholder(name)) => format!("{{{}}}", name),
+        None => "the end of the template".to_string(),
+    })
+}
+
+/// Facts about `actual` not matching `template` at `unmatched`.
+fn template_mismatch<S: Debug, R>(
+    subject: &Subject<S, (), R>,
+    template: &str,
+    unmatched: String,
+) -> AssertionResult {
+    subject
+        .new_result()
+        .add_fact("expected to match template", template)
+        .add_fact("but could not match", unmatched)
+        .add_splitter()
+        .add_fact(FactKey::Actual, subject.format_value(subject.actual()))
+}
+
+fn check_matches_template<S: Debug, R>(
+    subject: &Subject<S, (), R>,
+    actual: &str,
+    template: &str,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    match captures_of(actual, template) {
+        Ok(_) => subject.new_result().do_ok(),
+        Err(unmatched) => template_mismatch(subject, template, unmatched).do_fail(),
+    }
+}
+
+/// Derives a subject described as `<subject>.template_captures(template)`, or fails if `actual`
+/// does not match `template`.
+#[track_caller]
+fn template_captures<'a, S: Debug, R>(
+    subject: &Subject<'a, S, (), R>,
+    actual: &str,
+    template: &str,
+) -> Subject<'a, BTreeMap<String, String>, (), R> {
+    match captures_of(actual, template) {
+        Ok(captures) => subject.new_owned_subject(
+            captures,
+            Some(format!(
+                "{}.template_captures({:?})",
+                subject.description_or_expr(),
+                template
+            )),
+            (),
+        ),
+        Err(unmatched) => {
+            // Like `parsed`, a derived subject can not be returned, so panic.
+            template_mismatch(subject, template, unmatched).fail_derivation()
+        }
+    }
+}
+
+/// Renders the actual string as is, unless a formatter is set by [`Subject::with_formatter`].
+fn render_actual<S: AsRef<str>, R>(subject: &Subject<S, (), R>) -> String {
+    match subject.formatter() {
+        None => subject.actual().as_ref().to_string(),
+        Some(formatter) => formatter(subject.actual()),
+    }
+}
+
+fn check_contains_all_of<S: AsRef<str>, I, E, R>(
+    subject: &Subject<S, (), R>,
+    actual: &str,
+    substrings: I,
+) -> R
+where
+    I: IntoIterator<Item = E>,
+    E: Into<String>,
+    AssertionResult: AssertionStrategy<R>,
+{
+    let substrings: Vec<String> = substrings.into_iter().map(Into::into).collect();
+    let missing: Vec<&String> = substrings
+        .iter()
+        .filter(|substring| !actual.contains(substring.as_str()))
+        .collect();
+    if missing.is_empty() {
+        subject.new_result().do_ok()
+    } else {
+        subject
+            .new_result()
+            .add_formatted_values_fact(FactKey::MissingN(missing.len()), missing)
+            .add_splitter()
+            .add_formatted_values_fact("expected a string that contains all of", substrings)
+            .add_fact(FactKey::ButWas, render_actual(subject))
+            .do_fail()
+    }
+}
+
+fn check_contains_any_of<S: AsRef<str>, I, E, R>(
+    subject: &Subject<S, (), R>,
+    actual: &str,
+    substrings: I,
+) -> R
+where
+    I: IntoIterator<Item = E>,
+    E: Into<String>,
+    AssertionResult: AssertionStrategy<R>,
+{
+    let substrings: Vec<String> = substrings.into_iter().map(Into::into).collect();
+    if substrings
+        .iter()
+        .any(|substring| actual.contains(substring.as_str()))
+    {
+        subject.new_result().do_ok()
+    } else {
+        subject
+            .new_result()
+            .add_formatted_values_fact("expected a string that contains any of", substrings)
+            .add_fact(FactKey::ButWas, render_actual(subject))
+            .do_fail()
+    }
+}
+
+fn check_contains_n_times<S: AsRef<str>, R>(
+    subject: &Subject<S, (), R>,
+    actual: &str,
+    expected: String,
+    times: usize,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    let offsets: Vec<usize> = actual
+        .match_indices(expected.as_str())
+        .map(|(offset, _)| offset)
+        .collect();
+    if offsets.len() == times {
+        return subject.new_result().do_ok();
+    }
+    let result = subject
+        .new_result()
+        .add_fact("expected a string that contains", expected)
+        .add_fact("exactly", plural_times(times))
+        .add_fact("but it occurred", plural_times(offsets.len()));
+    let result = if offsets.is_empty() {
+        result
+    } else {
+        result.add_formatted_values_fact("at byte offsets", offsets)
+    };
+    result
+        .add_fact(FactKey::Actual, render_actual(subject))
+        .do_fail()
+}
+
+fn plural_times(count: usize) -> String {
+    if count == 1 {
+        "1 time".to_string()
+    } else {
+        format!("{} times", count)
+    }
+}
+
+fn check_string<R>(
+    assertion_result: AssertionResult,
+    ok: bool,
+    expectation: &str,
+    expected: String,
+    rendered_actual: String,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    if ok {
+        assertion_result.do_ok()
+    } else {
+        assertion_result
+            .add_fact(expectation, expected)
+            .add_fact(FactKey::ButWas, rendered_actual)
+            .do_fail()
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::assertions::iterator::IteratorAssertion;
+    use crate::assertions::map::MapAssertion;
     use crate::testing::*;
 
     use super::*;
 
+    #[test]
+    fn is_equal_to() {
+        assert_that!("foo").is_equal_to("foo");
+        assert_that!("ninja".to_string()).is_equal_to("ninja".to_string());
+        assert_that!(check_that!("ninja").is_equal_to("bar")).facts_are(vec![
+            Fact::new("expected", r#""bar""#),
+            Fact::new("actual", r#""ninja""#),
+        ]);
+        assert_that!(check_that!("foo\nbar".to_string()).is_equal_to("foo\nbaz".to_string()))
+            .facts_are(vec![
+                Fact::new("differs at", "char 6 (line 2, column 3)"),
+                Fact::new_splitter(),
+                Fact::new("expected", r#""foo\nbaz""#),
+                Fact::new("actual", r#""foo\nbar""#),
+            ]);
+    }
+
     #[test]
     fn is_same_string_to() {
         assert_that!("foo").is_same_string_to("foo");
@@ -157,6 +1300,348 @@ mod tests {
             Fact::new("expected", r#""bar""#),
             Fact::new("actual", r#""ninja""#),
         ]);
+        assert_that!(check_that!("foobar").is_same_string_to("foobaz")).facts_are(vec![
+            Fact::new("differs at", "char 5"),
+            Fact::new_splitter(),
+            Fact::new("expected", r#""foobaz""#),
+            Fact::new("actual", r#""foobar""#),
+        ]);
+        assert_that!(check_that!("foobar".to_string()).is_same_string_to("foobaz")).facts_are(
+            vec![
+                Fact::new("differs at", "char 5"),
+                Fact::new_splitter(),
+                Fact::new("expected", r#""foobaz""#),
+                Fact::new("actual", r#""foobar""#),
+            ],
+        );
+    }
+
+    #[test]
+    fn with_formatter() {
+        let upper: fn(&String) -> String = |s| s.to_uppercase();
+        assert_that!(check_that!("ninja".to_string())
+            .with_formatter(upper)
+            .is_same_string_to("bar"))
+        .facts_are(vec![
+            Fact::new("expected", "BAR"),
+            Fact::new("actual", "NINJA"),
+        ]);
+        assert_that!(check_that!("foobarbaz".to_string())
+            .with_formatter(upper)
+            .contains("qux"))
+        .facts_are(vec![
+            Fact::new("expected a string that contains", "qux"),
+            Fact::new("but was", "FOOBARBAZ"),
+        ]);
+
+        // Expected values of `&str` subjects are `String`s, so only the actual value is formatted.
+        let upper_str: fn(&&str) -> String = |s| s.to_uppercase();
+        assert_that!(check_that!("ninja")
+            .with_formatter(upper_str)
+            .is_same_string_to("bar"))
+        .facts_are(vec![
+            Fact::new("expected", r#""bar""#),
+            Fact::new("actual", "NINJA"),
+        ]);
+        assert_that!(check_that!("foobarbaz")
+            .with_formatter(upper_str)
+            .ends_with("qux"))
+        .facts_are(vec![
+            Fact::new("expected a string that ends with", "qux"),
+            Fact::new("but was", "FOOBARBAZ"),
+        ]);
+    }
+
+    #[test]
+    fn derived_subjects() {
+        assert_that!("  foo\t")
+            .trimmed()
+            .is_equal_to("foo".to_string());
+        assert_that!("Foo".to_string())
+            .to_lowercase()
+            .is_equal_to("foo".to_string());
+        assert_that!("Foo").to_uppercase().ends_with("OO");
+
+        // Failures
+        assert_that!(check_that!(" Foo ")
+            .trimmed()
+            .is_equal_to("bar".to_string()))
+        .facts_are(vec![
+            Fact::new("value of", r#"" Foo ".trim()"#),
+            Fact::new("expected", r#""bar""#),
+            Fact::new("actual", r#""Foo""#),
+        ]);
+        assert_that!(check_that!(" Foo ".to_string())
+            .trimmed()
+            .to_lowercase()
+            .starts_with("x"))
+        .facts_are(vec![
+            Fact::new("value of", r#"" Foo ".to_string().trim().to_lowercase()"#),
+            Fact::new("expected a string that starts with", "x"),
+            Fact::new("but was", "foo"),
+        ]);
+    }
+
+    #[test]
+    fn split() {
+        assert_that!("a,b,,d").split(',').has_length(4);
+        assert_that!("a, b".to_string())
+            .split(", ")
+            .contains_exactly_in_order(["a".to_string(), "b".to_string()].into_iter());
+        assert_that!("").split(',').has_length(1);
+
+        // Failures
+        assert_that!(check_that!("a,b,c").split(',').has_length(2)).facts_are(vec![
+            Fact::new("value of", r#""a,b,c".split(',')"#),
+            Fact::new("value of", r#""a,b,c".split(',').size()"#),
+            Fact::new("expected", "2"),
+            Fact::new("actual", "3"),
+        ]);
+        assert_that!(check_that!(" a;b ".to_string())
+            .trimmed()
+            .split(";")
+            .contains("c".to_string()))
+        .facts_are(vec![
+            Fact::new("value of", r#"" a;b ".to_string().trim().split(";")"#),
+            Fact::new("expected to contain", r#""c""#),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec![r#""a""#, r#""b""#]),
+        ]);
+    }
+
+    #[test]
+    fn parses_as() {
+        assert_that!("42").parses_as::<i32>().is_equal_to(42);
+        assert_that!("true".to_string())
+            .parses_as::<bool>()
+            .is_equal_to(true);
+        assert_that!("4x2").fails_to_parse_as::<i32>();
+        assert_that!("-1".to_string()).fails_to_parse_as::<u8>();
+
+        // Failures
+        assert_that!(check_that!("42").parses_as::<i32>().is_equal_to(43)).facts_are(vec![
+            Fact::new("value of", r#""42".parse::<i32>()"#),
+            Fact::new("expected", "43"),
+            Fact::new("actual", "42"),
+        ]);
+        assert_that!(check_that!("42").fails_to_parse_as::<i32>()).facts_are(vec![
+            Fact::new("expected to fail to parse as", "i32"),
+            Fact::new("but parsed as", "42"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "but failed with     : ParseIntError { kind: InvalidDigit }")]
+    fn parses_as_panic() {
+        assert_that!("4x2").parses_as::<i32>();
+    }
+
+    #[test]
+    fn parse_template() {
+        use super::TemplatePart::*;
+        assert_eq!(
+            super::parse_template("a {x}{y} {{z}} {} {"),
+            vec![
+                Literal("a ".to_string()),
+                Placeholder("x".to_string()),
+                Placeholder("y".to_string()),
+                Literal(" {z} {} {".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn matches_template() {
+        assert_that!("Hello, world!").matches_template("Hello, {name}!");
+        assert_that!("Hello, world!".to_string()).matches_template("Hello, {name}!");
+        assert_that!("a-b-c").matches_template("{x}-{y}");
+        assert_that!("ab ab").matches_template("{x} {x}");
+        assert_that!("{a}").matches_template("{{{x}}}");
+        assert_that!("no placeholders").matches_template("no placeholders");
+
+        // Failures
+        assert_that!(check_that!("Hello, world.").matches_template("Hello, {name}!")).facts_are(
+            vec![
+                Fact::new("expected to match template", "Hello, {name}!"),
+                Fact::new("but could not match", r#""!""#),
+                Fact::new_splitter(),
+                Fact::new("actual", r#""Hello, world.""#),
+            ],
+        );
+        assert_that!(check_that!("Hello, ").matches_template("Hello, {name}!"))
+            .facts_are_at_least(vec![Fact::new("but could not match", "{name}")]);
+        assert_that!(check_that!("ab cd").matches_template("{x} {x}"))
+            .facts_are_at_least(vec![Fact::new("but could not match", "{x}")]);
+        assert_that!(check_that!("Hello, world!!").matches_template("Hello, {name}!!!"))
+            .facts_are_at_least(vec![Fact::new("but could not match", r#""!!!""#)]);
+        assert_that!(check_that!("abc").matches_template("ab")).facts_are_at_least(vec![
+            Fact::new("but could not match", "the end of the template"),
+        ]);
+    }
+
+    #[test]
+    fn template_captures() {
+        assert_that!("user alice logged in")
+            .template_captures("user {name} logged {direction}")
+            .is_equal_to(BTreeMap::from([
+                ("direction".to_string(), "in".to_string()),
+                ("name".to_string(), "alice".to_string()),
+            ]));
+        assert_that!(check_that!("user alice logged in")
+            .template_captures("user {name} logged {direction}")
+            .contains_key("id".to_string()))
+        .facts_are_at_least(vec![Fact::new(
+            "value of",
+            r#""user alice logged in".template_captures("user {name} logged {direction}")"#,
+        )]);
+    }
+
+    #[test]
+    fn is_within_edit_distance() {
+        assert_that!("recieve")
+            .is_within_edit_distance(2)
+            .of("receive");
+        assert_that!("same".to_string())
+            .is_within_edit_distance(0)
+            .of("same");
+        assert_that!("colour")
+            .is_within_edit_distance(1)
+            .of("color");
+
+        assert_that!(check_that!("kitten")
+            .is_within_edit_distance(2)
+            .of("sitting"))
+        .facts_are(vec![
+            Fact::new("expected within edit distance", "2"),
+            Fact::new("of", r#""sitting""#),
+            Fact::new("but was", r#""kitten""#),
+            Fact::new("with edit distance", "3"),
+            Fact::new_splitter(),
+            Fact::new("expected (aligned)", "sitting"),
+            Fact::new("actual (aligned)", "kitten·"),
+            Fact::new("differences", "^   ^ -"),
+        ]);
+        assert_that!(check_that!("the quick brown fox jumps".to_string())
+            .is_within_edit_distance(0)
+            .of("the quick brown fax jumps"))
+        .facts_are(vec![
+            Fact::new("expected within edit distance", "0"),
+            Fact::new("of", r#""the quick brown fax jumps""#),
+            Fact::new("but was", r#""the quick brown fox jumps""#),
+            Fact::new("with edit distance", "1"),
+            Fact::new_splitter(),
+            Fact::new("expected (aligned)", "…ck brown fax jumps"),
+            Fact::new("actual (aligned)", "…ck brown fox jumps"),
+            Fact::new("differences", "           ^"),
+        ]);
+    }
+
+    #[test]
+    fn ignoring_surrounding_whitespace() {
+        assert_that!("  Done.\n")
+            .ignoring_surrounding_whitespace()
+            .is_same_string_to("Done.");
+        assert_that!("Done.".to_string())
+            .ignoring_surrounding_whitespace()
+            .is_same_string_to("\tDone.\n");
+        assert_that!("\nUsage: tool [OPTIONS]\n")
+            .ignoring_surrounding_whitespace()
+            .starts_with("Usage:");
+        assert_that!("Usage: tool [OPTIONS]\n\n")
+            .ignoring_surrounding_whitespace()
+            .ends_with("[OPTIONS]\n");
+
+        assert_that!(check_that!("error: not found\n")
+            .ignoring_surrounding_whitespace()
+            .starts_with("warning:"))
+        .facts_are(vec![
+            Fact::new("expected a string that starts with", r#""warning:""#),
+            Fact::new("but was", r#""error: not found\n""#),
+            Fact::new("ignoring", "leading and trailing whitespace"),
+        ]);
+        assert_that!(check_that!("a b\n")
+            .ignoring_surrounding_whitespace()
+            .is_same_string_to("a  b"))
+        .facts_are(vec![
+            Fact::new("expected", r#""a  b""#),
+            Fact::new("but was", r#""a b\n""#),
+            Fact::new("ignoring", "leading and trailing whitespace"),
+        ]);
+        assert_that!(check_that!("x.rs:1\n".to_string())
+            .ignoring_surrounding_whitespace()
+            .ends_with(":2"))
+        .facts_are(vec![
+            Fact::new("expected a string that ends with", r#"":2""#),
+            Fact::new("but was", r#""x.rs:1\n""#),
+            Fact::new("ignoring", "leading and trailing whitespace"),
+        ]);
+    }
+
+    #[test]
+    fn has_length_between() {
+        assert_that!("héllo").has_length_between(5, 5);
+        assert_that!("héllo".to_string()).has_length_between(0, 8);
+
+        // Failures
+        assert_that!(check_that!("héllo").has_length_between(6, 8)).facts_are(vec![
+            Fact::new("value of", "\"héllo\".chars().count()"),
+            Fact::new("expected", "between 6 and 8"),
+            Fact::new("actual", "5"),
+        ]);
+    }
+
+    #[test]
+    fn comparing_as_paths() {
+        assert_that!("a\\b\\")
+            .comparing_as_paths()
+            .is_same_path_as("a/b");
+        assert_that!("a/b".to_string())
+            .comparing_as_paths()
+            .is_same_path_as("a\\b");
+        assert_that!("\\").comparing_as_paths().is_same_path_as("/");
+        assert_that!("src\\lib.rs")
+            .comparing_as_paths()
+            .starts_with("src/");
+        assert_that!("src\\lib.rs")
+            .comparing_as_paths()
+            .starts_with("src");
+        assert_that!("src\\lib.rs")
+            .comparing_as_paths()
+            .ends_with("lib.rs");
+        assert_that!("/usr/bin")
+            .comparing_as_paths()
+            .starts_with("/");
+
+        assert_that!(check_that!("a\\b\\")
+            .comparing_as_paths()
+            .is_same_path_as("a/c/"))
+        .facts_are(vec![
+            Fact::new("expected", r#""a/c""#),
+            Fact::new("but was", r#""a/b""#),
+            Fact::new("ignoring", "separator style and trailing separators"),
+        ]);
+        assert_that!(check_that!("src\\library")
+            .comparing_as_paths()
+            .starts_with("src/lib"))
+        .facts_are(vec![
+            Fact::new("expected a path that starts with", r#""src/lib""#),
+            Fact::new("but was", r#""src/library""#),
+            Fact::new("ignoring", "separator style and trailing separators"),
+        ]);
+        assert_that!(check_that!("src\\mylib.rs")
+            .comparing_as_paths()
+            .ends_with("lib.rs"))
+        .facts_are(vec![
+            Fact::new("expected a path that ends with", r#""lib.rs""#),
+            Fact::new("but was", r#""src/mylib.rs""#),
+            Fact::new("ignoring", "separator style and trailing separators"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected to match template")]
+    fn template_captures_panic() {
+        assert_that!("user alice").template_captures("user {name} logged in");
     }
 
     #[test]
@@ -202,4 +1687,95 @@ mod tests {
             Fact::new("but was", "foo"),
         ])
     }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn as_json() {
+        use serde_json::json;
+
+        assert_that!(r#"{"id": 7, "tags": ["a"]}"#)
+            .as_json()
+            .is_equal_to(json!({"id": 7, "tags": ["a"]}));
+        assert_that!("[1, 2]".to_string())
+            .as_json()
+            .is_equal_to(json!([1, 2]));
+
+        assert_that!(check_that!(r#"{"id": 7}"#)
+            .as_json()
+            .is_equal_to(json!({"id": 8})))
+        .facts_are_at_least(vec![Fact::new("value of", r##"r#"{"id": 7}"#.as_json()"##)]);
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    #[should_panic(expected = "at                  : line 2, column 8")]
+    fn as_json_panic() {
+        assert_that!("{\n  \"id\" 7\n}").as_json();
+    }
+
+    #[test]
+    fn contains_all_of() {
+        assert_that!("foobarbaz").contains_all_of(["foo", "baz"]);
+        assert_that!("foobarbaz".to_string()).contains_all_of(vec!["bar".to_string()]);
+        assert_that!("foo").contains_all_of(Vec::<String>::new());
+
+        assert_that!(check_that!("foobarbaz").contains_all_of(["foo", "qux", "quux"])).facts_are(
+            vec![
+                Fact::new_multi_value_fact("missing (2)", vec![r#""qux""#, r#""quux""#]),
+                Fact::new_splitter(),
+                Fact::new_multi_value_fact(
+                    "expected a string that contains all of",
+                    vec![r#""foo""#, r#""qux""#, r#""quux""#],
+                ),
+                Fact::new("but was", "foobarbaz"),
+            ],
+        );
+    }
+
+    #[test]
+    fn contains_exactly_n_times() {
+        assert_that!("a, b, c").contains_exactly_n_times(", ", 2);
+        assert_that!("aaaa".to_string()).contains_exactly_n_times("aa", 2);
+        assert_that!("abc").contains_exactly_n_times("x", 0);
+
+        assert_that!(check_that!("retry; retry").contains_exactly_n_times("retry", 3)).facts_are(
+            vec![
+                Fact::new("expected a string that contains", "retry"),
+                Fact::new("exactly", "3 times"),
+                Fact::new("but it occurred", "2 times"),
+                Fact::new_multi_value_fact("at byte offsets", vec!["0", "7"]),
+                Fact::new("actual", "retry; retry"),
+            ],
+        );
+        assert_that!(check_that!("abc".to_string()).contains_exactly_n_times("x", 1)).facts_are(
+            vec![
+                Fact::new("expected a string that contains", "x"),
+                Fact::new("exactly", "1 time"),
+                Fact::new("but it occurred", "0 times"),
+                Fact::new("actual", "abc"),
+            ],
+        );
+    }
+
+    #[test]
+    fn contains_any_of() {
+        assert_that!("foobarbaz").contains_any_of(["qux", "baz"]);
+        assert_that!("foobarbaz".to_string()).contains_any_of(["bar"]);
+
+        assert_that!(check_that!("foobarbaz".to_string()).contains_any_of(["qux", "quux"]))
+            .facts_are(vec![
+                Fact::new_multi_value_fact(
+                    "expected a string that contains any of",
+                    vec![r#""qux""#, r#""quux""#],
+                ),
+                Fact::new("but was", "foobarbaz"),
+            ]);
+        assert_that!(check_that!("foo").contains_any_of(Vec::<String>::new())).facts_are(vec![
+            Fact::new_multi_value_fact(
+                "expected a string that contains any of",
+                Vec::<String>::new(),
+            ),
+            Fact::new("but was", "foo"),
+        ]);
+    }
 }
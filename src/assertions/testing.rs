@@ -14,10 +14,18 @@
 
 use std::borrow::Borrow;
 use std::collections::HashSet;
+use std::fs;
+use std::path::Path;
+use std::vec::IntoIter;
 
 use crate::assertions::iterator::IteratorAssertion;
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Fact, Subject};
-use crate::testing::CheckThatResult;
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, Fact, Subject,
+};
+use crate::golden::{
+    add_update_facts, pending_golden_path, update_golden, write_golden, write_pending_golden,
+};
+use crate::testing::{golden_message, CheckThatResult};
 
 /// Trait for assertions for assertion messages.
 ///
@@ -45,9 +53,54 @@ pub trait CheckThatResultAssertion<'a, R> {
     #[track_caller]
     fn fact_value_for_key<I: Into<String>>(&self, key: I) -> Subject<String, (), R>;
 
+    /// Returns all fact values whose key is equal to `key`, in order. Values of multi-value facts
+    /// ([`Fact::KeyValues`]) are flattened.
+    #[track_caller]
+    fn fact_values_for_key<I: Into<String>>(&self, key: I) -> Subject<'a, IntoIter<String>, (), R>;
+
     /// Returns keys of the assertion messages.
     #[track_caller]
     fn fact_keys(&self) -> Subject<'a, HashSet<&String>, (), R>;
+
+    /// Checks that the assertion result has a code location whose file path ends with `suffix`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use assertor::*;
+    /// use assertor::testing::*;
+    ///
+    /// assert_that!(check_that!("actual").is_same_string_to("expected"))
+    ///     .location_file_ends_with("my_test.rs");
+    /// ```
+    #[track_caller]
+    fn location_file_ends_with<I: Into<String>>(&self, suffix: I) -> R;
+
+    /// Returns the line number of the code location of the assertion result. Fails when the
+    /// assertion result has no location.
+    #[track_caller]
+    fn location_line(&self) -> Subject<'a, u32, (), R>;
+
+    /// Checks that the failure message, without the code location, is equal to the contents of
+    /// the golden file at `path`. Relative paths are relative to the package root when run by
+    /// `cargo test`.
+    ///
+    /// When the environment variable [`UPDATE_GOLDEN_ENV`](crate::testing::UPDATE_GOLDEN_ENV)
+    /// is `1`, the golden file is written with the actual message instead. When it is `new`, a
+    /// mismatching message is written to a pending golden file to be reviewed with
+    /// `cargo assertor-review`.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// use assertor::*;
+    /// use assertor::testing::*;
+    ///
+    /// assert_that!(check_that!("actual").is_equal_to("expected"))
+    ///     .message_matches_golden("testdata/golden/is_equal_to.txt");
+    /// ```
+    #[track_caller]
+    fn message_matches_golden<P: AsRef<Path>>(&self, path: P) -> R;
 }
 
 fn get_assertion_result<'a, 'o, R>(
@@ -66,6 +119,7 @@ where
     AssertionResult: AssertionStrategy<R>,
 {
     fn facts_are<B: Borrow<Vec<Fact>>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
         self.new_owned_subject(
             get_assertion_result(self).facts().iter(),
             Some(format!("{}.facts()", self.description_or_expr())),
@@ -75,6 +129,7 @@ where
     }
 
     fn facts_are_at_least<B: Borrow<Vec<Fact>>>(&self, facts: B) -> R {
+        pass_if_skipped!(self);
         self.new_owned_subject(
             get_assertion_result(self).facts().iter(),
             Some(format!("{}.facts()", self.description_or_expr())),
@@ -109,6 +164,24 @@ where
         )
     }
 
+    fn fact_values_for_key<I: Into<String>>(&self, key: I) -> Subject<'a, IntoIter<String>, (), R> {
+        let key_str = key.into();
+        let values: Vec<String> = get_assertion_result(self)
+            .facts()
+            .iter()
+            .flat_map(|fact| match fact {
+                Fact::KeyValue { key: k, value } if k.eq(&key_str) => vec![value.clone()],
+                Fact::KeyValues { key: k, values } if k.eq(&key_str) => values.clone(),
+                _ => vec![],
+            })
+            .collect();
+        self.new_owned_subject(
+            values.into_iter(),
+            Some(format!("{}.[key={}]", self.description_or_expr(), key_str)),
+            (),
+        )
+    }
+
     fn fact_keys(&self) -> Subject<HashSet<&String>, (), R> {
         let assertion_result = get_assertion_result(self);
         let keys: HashSet<&String> = assertion_result
@@ -126,13 +199,115 @@ where
             (),
         )
     }
+
+    fn location_file_ends_with<I: Into<String>>(&self, suffix: I) -> R {
+        pass_if_skipped!(self);
+        let suffix_str = suffix.into();
+        match get_assertion_result(self).location() {
+            Some(location) if location.file().ends_with(&suffix_str) => self.new_result().do_ok(),
+            Some(location) => self
+                .new_result()
+                .add_fact("expected location file to end with", suffix_str)
+                .add_fact("but was", location.file())
+                .do_fail(),
+            None => self
+                .new_result()
+                .add_fact("expected location file to end with", suffix_str)
+                .add_simple_fact("but location was not set")
+                .do_fail(),
+        }
+    }
+
+    fn location_line(&self) -> Subject<'a, u32, (), R> {
+        match get_assertion_result(self).location() {
+            Some(location) => self.new_owned_subject(
+                location.line(),
+                Some(format!("{}.location().line()", self.description_or_expr())),
+                (),
+            ),
+            // A derived subject can not be returned, so panic.
+            None => self
+                .new_result()
+                .add_simple_fact("expected location to be set")
+                .add_fact("but location was", "None")
+                .fail_derivation(),
+        }
+    }
+
+    fn message_matches_golden<P: AsRef<Path>>(&self, path: P) -> R {
+        pass_if_skipped!(self);
+        let path = path.as_ref();
+        let actual = golden_message(get_assertion_result(self));
+        if update_golden() {
+            write_golden(path, format!("{}\n", actual).as_bytes());
+            return self.new_result().do_ok();
+        }
+        let expected = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) => {
+                let result = self
+                    .new_result()
+                    .add_fact(
+                        "expected message to match golden file",
+                        path.display().to_string(),
+                    )
+                    .add_fact("but could not read it", error.to_string());
+                return add_update_facts(
+                    result,
+                    path,
+                    format!("{}\n", actual).as_bytes(),
+                    "to create it, run tests with",
+                    write_pending_golden(),
+                )
+                .add_splitter()
+                .add_fact("actual message", actual)
+                .do_fail();
+            }
+        };
+        let expected = expected.strip_suffix('\n').unwrap_or(&expected);
+        if expected == actual {
+            if write_pending_golden() {
+                // A pending golden file of an earlier run is stale once the message matches.
+                let _ = fs::remove_file(pending_golden_path(path));
+            }
+            return self.new_result().do_ok();
+        }
+        let expected_lines: Vec<&str> = expected.split('\n').collect();
+        let actual_lines: Vec<&str> = actual.split('\n').collect();
+        let line = (0..expected_lines.len().max(actual_lines.len()))
+            .find(|&index| expected_lines.get(index) != actual_lines.get(index))
+            .unwrap_or(0);
+        let result = self
+            .new_result()
+            .add_fact(
+                "expected message to match golden file",
+                path.display().to_string(),
+            )
+            .add_fact("but differed at line", (line + 1).to_string());
+        add_update_facts(
+            result,
+            path,
+            format!("{}\n", actual).as_bytes(),
+            "to accept the new message, run tests with",
+            write_pending_golden(),
+        )
+        .add_splitter()
+        .add_fact("expected message", expected)
+        .add_fact("actual message", actual)
+        .do_fail()
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
     use std::fmt::Debug;
 
     use crate::testing::*;
+    use crate::{
+        check_all, BooleanAssertion, ComparableAssertion, EqualityAssertion, MapAssertion,
+        OptionAssertion, ResultAssertion, SetAssertion, StringAssertion, VecAssertion,
+    };
 
     use super::*;
 
@@ -166,6 +341,48 @@ mod tests {
             .facts_are(vec![Fact::new_simple_fact("not same")]);
     }
 
+    #[test]
+    fn check_that_result_accessors() {
+        let passed = check_that!(1).is_equal_to(1);
+        assert!(passed.is_ok());
+        assert_eq!(passed.message(), None);
+        assert_eq!(Result::<(), String>::from(passed), Ok(()));
+
+        let failed = check_that!(1).is_equal_to(2);
+        assert!(!failed.is_ok());
+        let message = failed.message().unwrap();
+        assert_that!(message).contains("expected: 2");
+        assert_eq!(Result::<(), String>::from(failed), Err(message));
+
+        // Skipped checks pass.
+        assert!(check_that!(1).skip_if(true).is_equal_to(2).is_ok());
+    }
+
+    #[test]
+    fn check_all() {
+        let value = 3;
+        assert!(check_all!(check_that!(value).is_equal_to(3)).is_ok());
+
+        // Failures
+        let result = check_all!(
+            check_that!(value).is_equal_to(2),
+            check_that!(value).is_equal_to(3),
+            check_that!(vec![1]).contains(2),
+        );
+        assert_that!(result).facts_are(vec![
+            Fact::new("expected all checks to pass", "but 2 of 3 did not"),
+            Fact::new_splitter(),
+            Fact::new("check", "check_that!(value).is_equal_to(2)"),
+            Fact::new("expected", "2"),
+            Fact::new("actual", "3"),
+            Fact::new_splitter(),
+            Fact::new("check", "check_that!(vec![1]).contains(2)"),
+            Fact::new("expected to contain", "2"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1"]),
+        ]);
+    }
+
     #[test]
     fn facts_are() {
         let failed: CheckThatResult = check_that!("actual").is_same_to("expected");
@@ -175,6 +392,172 @@ mod tests {
             Fact::new_splitter(),
             Fact::new_multi_value_fact::<&str, &str>("expected", vec![]),
             Fact::new_multi_value_fact("actual", vec!["Value { value: \"not same\" }"]),
+            Fact::new("note", "expected collection is empty"),
+            Fact::new(
+                "help",
+                "use is_empty to check that the subject has no elements",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn fact_values_for_key() {
+        let failed = check_that!([1, 2].iter()).contains_exactly_in_order([1, 2, 3].iter());
+        assert_that!(failed)
+            .fact_values_for_key("missing (1)")
+            .contains_exactly_in_order(vec!["[3]".to_string()].into_iter());
+        assert_that!(failed)
+            .fact_values_for_key("expected")
+            .contains_exactly_in_order(
+                vec!["1".to_string(), "2".to_string(), "3".to_string()].into_iter(),
+            );
+        assert_that!(failed)
+            .fact_values_for_key("not exist")
+            .is_empty();
+
+        // `is_err` emits the "actual" key twice.
+        let repeated = check_that!(Result::<usize, ()>::Ok(1)).is_err();
+        assert_that!(repeated)
+            .fact_values_for_key("actual")
+            .contains_exactly_in_order(
+                vec!["Result::Ok".to_string(), "Ok(1)".to_string()].into_iter(),
+            );
+    }
+
+    #[test]
+    fn location_file_ends_with() {
+        let failed = check_that!("actual").is_same_to("expected");
+        assert_that!(failed).location_file_ends_with("testing.rs");
+        assert_that!(failed).location_file_ends_with("assertions/testing.rs");
+
+        // Failures
+        assert_that!(check_that!(failed).location_file_ends_with("map.rs")).facts_are(vec![
+            Fact::new("expected location file to end with", "map.rs"),
+            Fact::new("but was", file!()),
+        ]);
+    }
+
+    #[test]
+    fn location_line() {
+        let line = line!();
+        let failed = check_that!("actual").is_same_to("expected");
+        assert_that!(failed).location_line().is_equal_to(line + 1);
+        assert_that!(failed).location_line().is_greater_than(line);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected location to be set")]
+    fn location_line_without_location() {
+        let failed: CheckThatResult =
+            AssertionStrategy::do_fail(AssertionResult::new(&None).add_simple_fact("failed"));
+        assert_that!(failed).location_line();
+    }
+
+    fn golden_path(name: &str) -> String {
+        format!(
+            "{}/testdata/golden/{}.txt",
+            env!("CARGO_MANIFEST_DIR"),
+            name
+        )
+    }
+
+    #[test]
+    fn golden_messages() {
+        let cases = vec![
+            ("is_equal_to", check_that!(1 + 1).is_equal_to(3)),
+            (
+                "is_equal_to_multiline_string",
+                check_that!("first\nsecond\n").is_equal_to("first\nthird\n"),
+            ),
+            ("is_true", check_that!(1 > 2).is_true()),
+            ("string_contains", check_that!("foobar").contains("baz")),
+            (
+                "string_starts_with",
+                check_that!("foobar").starts_with("bar"),
+            ),
+            ("vec_contains", check_that!(vec![1, 2, 3]).contains(4)),
+            (
+                "vec_has_length_at_most",
+                check_that!(vec![1, 2, 3]).has_length_at_most(2),
+            ),
+            (
+                "vec_contains_exactly",
+                check_that!(vec![1, 2, 3]).contains_exactly([2, 3, 4]),
+            ),
+            (
+                "vec_contains_exactly_in_order",
+                check_that!(vec![1, 2, 3]).contains_exactly_in_order([3, 2, 1]),
+            ),
+            (
+                "vec_contains_exactly_with_extras",
+                check_that!(vec![1, 2, 3]).contains_exactly([2, 3]),
+            ),
+            (
+                "set_contains",
+                check_that!(BTreeSet::from(["apple", "banana"])).contains("aple"),
+            ),
+            (
+                "set_is_not_empty",
+                check_that!(BTreeSet::<i32>::new()).is_not_empty(),
+            ),
+            (
+                "map_has_length_at_least",
+                check_that!(BTreeMap::from([("one", 1)])).has_length_at_least(2),
+            ),
+            (
+                "map_contains_key",
+                check_that!(BTreeMap::from([("one", 1), ("two", 2)])).contains_key("three"),
+            ),
+            (
+                "map_contains_entry",
+                check_that!(BTreeMap::from([("one", 1), ("two", 2)])).contains_entry("one", 2),
+            ),
+            ("option_has_value", check_that!(Some(1)).has_value(2)),
+            ("option_is_none", check_that!(Some(1)).is_none()),
+            (
+                "result_has_ok",
+                check_that!(Result::<i32, &str>::Err("failure")).has_ok(1),
+            ),
+        ];
+        for (name, result) in cases {
+            assert_that!(result).message_matches_golden(golden_path(name));
+        }
+    }
+
+    #[test]
+    fn message_matches_golden() {
+        if update_golden() {
+            return;
+        }
+        let path = std::env::temp_dir().join("assertor_message_matches_golden.txt");
+        fs::write(&path, "expected: 3\nactual  : 2\n").unwrap();
+        assert_that!(check_that!(2).is_equal_to(3)).message_matches_golden(&path);
+
+        // Failures
+        let result = check_that!(check_that!(2).is_equal_to(4)).message_matches_golden(&path);
+        assert_that!(result).facts_are(vec![
+            Fact::new(
+                "expected message to match golden file",
+                path.display().to_string(),
+            ),
+            Fact::new("but differed at line", "1"),
+            Fact::new(
+                "to accept the new message, run tests with",
+                "ASSERTOR_UPDATE_GOLDEN=1",
+            ),
+            Fact::new_splitter(),
+            Fact::new("expected message", "expected: 3\nactual  : 2"),
+            Fact::new("actual message", "expected: 4\nactual  : 2"),
         ]);
+
+        let missing = std::env::temp_dir().join("assertor_missing_golden_file.txt");
+        let result = check_that!(check_that!(2).is_equal_to(3)).message_matches_golden(&missing);
+        assert_that!(result)
+            .fact_keys()
+            .contains(&"but could not read it".to_string());
+        assert_that!(result)
+            .fact_value_for_key("to create it, run tests with")
+            .is_equal_to("ASSERTOR_UPDATE_GOLDEN=1".to_string());
+        fs::remove_file(&path).unwrap();
     }
 }
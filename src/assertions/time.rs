@@ -0,0 +1,553 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::fmt::Debug;
+use std::time::{Duration, Instant, SystemTime};
+
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+
+/// Points in time, or spans of time, whose distance can be measured as a [`Duration`].
+///
+/// Implement this trait for other time types (e.g. `chrono::DateTime`) to use
+/// [`TimeAssertion`] with them.
+pub trait TimeLike: Copy + Debug {
+    /// Returns the absolute distance between `self` and `other`.
+    fn distance(&self, other: &Self) -> Duration;
+}
+
+impl TimeLike for Duration {
+    fn distance(&self, other: &Self) -> Duration {
+        if self > other {
+            *self - *other
+        } else {
+            *other - *self
+        }
+    }
+}
+
+impl TimeLike for Instant {
+    fn distance(&self, other: &Self) -> Duration {
+        if self > other {
+            self.duration_since(*other)
+        } else {
+            other.duration_since(*self)
+        }
+    }
+}
+
+impl TimeLike for SystemTime {
+    fn distance(&self, other: &Self) -> Duration {
+        self.duration_since(*other)
+            .unwrap_or_else(|err| err.duration())
+    }
+}
+
+/// Trait for time assertion.
+///
+/// # Example
+/// ```
+/// use std::time::{Duration, Instant};
+/// use assertor::*;
+///
+/// let start = Instant::now();
+/// assert_that!(start + Duration::from_millis(3))
+///     .with_tolerance(Duration::from_millis(5))
+///     .is_approx_equal_to(start);
+/// ```
+/// ```should_panic
+/// use std::time::Duration;
+/// use assertor::*;
+///
+/// assert_that!(Duration::from_millis(10))
+///     .with_tolerance(Duration::from_millis(5))
+///     .is_approx_equal_to(Duration::from_millis(20));
+/// // expected         : 20ms
+/// // but was          : 10ms
+/// // outside tolerance: 5ms
+/// ```
+pub trait TimeAssertion<'a, S, R> {
+    /// Set the tolerance.
+    fn with_tolerance(self, tolerance: Duration) -> Subject<'a, S, TimeTolerance, R>;
+
+    /// Checks the subject is equal to `expected` with tolerance. Without a tolerance, the
+    /// subject must be exactly equal to `expected`.
+    ///
+    /// The equality with tolerance is defined as following:
+    /// ```math
+    /// abs(actual - expected) <= tolerance
+    /// ```
+    #[track_caller]
+    fn is_approx_equal_to<B: Borrow<S>>(&self, expected: B) -> R;
+}
+
+pub struct TimeTolerance {
+    tolerance: Duration,
+}
+
+impl<'a, S, R> TimeAssertion<'a, S, R> for Subject<'a, S, TimeTolerance, R>
+where
+    S: TimeLike,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn with_tolerance(mut self, tolerance: Duration) -> Subject<'a, S, TimeTolerance, R> {
+        self.option_mut().tolerance = tolerance;
+        self
+    }
+
+    fn is_approx_equal_to<B: Borrow<S>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
+        check_approx_equal(self, expected.borrow(), self.option().tolerance)
+    }
+}
+
+impl<'a, S, R: 'a> TimeAssertion<'a, S, R> for Subject<'a, S, (), R>
+where
+    S: TimeLike,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn with_tolerance(self, tolerance: Duration) -> Subject<'a, S, TimeTolerance, R> {
+        let subject = self.new_owned_subject(
+            *self.actual(),
+            self.description().clone(),
+            TimeTolerance { tolerance },
+        );
+        match self.formatter() {
+            None => subject,
+            Some(formatter) => subject.with_formatter(formatter),
+        }
+    }
+
+    fn is_approx_equal_to<B: Borrow<S>>(&self, expected: B) -> R {
+        pass_if_skipped!(self);
+        check_approx_equal(self, expected.borrow(), Duration::ZERO)
+    }
+}
+
+fn check_approx_equal<S, O, R>(subject: &Subject<S, O, R>, expected: &S, tolerance: Duration) -> R
+where
+    S: TimeLike,
+    AssertionResult: AssertionStrategy<R>,
+{
+    if subject.actual().distance(expected) <= tolerance {
+        subject.new_result().do_ok()
+    } else {
+        let result = subject
+            .new_result()
+            .add_fact(FactKey::Expected, subject.format_value(expected))
+            .add_fact(FactKey::ButWas, subject.format_value(subject.actual()));
+        if tolerance.is_zero() {
+            result.do_fail()
+        } else {
+            result
+                .add_formatted_fact(FactKey::OutsideTolerance, tolerance)
+                .do_fail()
+        }
+    }
+}
+
+/// Trait for [`Duration`] assertions.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use assertor::*;
+///
+/// let backoff = Duration::from_millis(1080);
+/// assert_that!(backoff)
+///     .is_approximately(Duration::from_secs(1))
+///     .with_jitter(10.0);
+/// demo!(
+///     assert_that!(Duration::from_millis(1250))
+///         .is_approximately(Duration::from_secs(1))
+///         .with_jitter(10.0),
+///     r#"
+///     expected          : 1s
+///     with jitter       : ±10% (900ms to 1.1s)
+///     but was           : 1.25s
+///     absolute deviation: +250ms
+///     relative deviation: +25.0%
+///     "#
+/// );
+/// ```
+pub trait DurationAssertion<R> {
+    /// Returns a new subject to check with [`JitterAssertion::with_jitter`] that the subject is
+    /// within a percentage of `expected`, e.g. a delay computed by a scheduler or a backoff
+    /// policy with random jitter.
+    ///
+    /// The subject should be a delay computed from a frozen or mocked clock, rather than measured
+    /// with [`Instant::now`], so that only the jitter is checked.
+    fn is_approximately(&self, expected: Duration) -> Subject<'_, Duration, Approximately, R>;
+}
+
+/// Trait for assertions on a duration subject returned by
+/// [`DurationAssertion::is_approximately`].
+pub trait JitterAssertion<R> {
+    /// Checks that the subject deviates from the expected duration by at most `percent` percent
+    /// of it, in either direction. A negative, infinite or NaN percentage fails the assertion.
+    #[track_caller]
+    fn with_jitter(&self, percent: f64) -> R;
+}
+
+pub struct Approximately {
+    expected: Duration,
+}
+
+impl<R> DurationAssertion<R> for Subject<'_, Duration, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn is_approximately(&self, expected: Duration) -> Subject<'_, Duration, Approximately, R> {
+        self.new_owned_subject(
+            *self.actual(),
+            self.description().clone(),
+            Approximately { expected },
+        )
+    }
+}
+
+impl<R> JitterAssertion<R> for Subject<'_, Duration, Approximately, R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn with_jitter(&self, percent: f64) -> R {
+        pass_if_skipped!(self);
+        if !percent.is_finite() || percent < 0. {
+            return self
+                .new_result()
+                .add_formatted_fact("invalid jitter", percent)
+                .add_simple_fact("jitter must be a finite non-negative percentage")
+                .do_fail();
+        }
+        let expected = self.option().expected;
+        let actual = *self.actual();
+        let deviation = actual.distance(&expected);
+        // Huge percentages allow any duration, rather than overflowing.
+        let allowed = Duration::try_from_secs_f64(percent / 100. * expected.as_secs_f64())
+            .unwrap_or(Duration::MAX);
+        if deviation <= allowed {
+            return self.new_result().do_ok();
+        }
+        let sign = if actual > expected { "+" } else { "-" };
+        let result = self
+            .new_result()
+            .add_formatted_fact(FactKey::Expected, expected)
+            .add_fact(
+                "with jitter",
+                format!(
+                    "±{}% ({:?} to {:?})",
+                    percent,
+                    expected.saturating_sub(allowed),
+                    expected.checked_add(allowed).unwrap_or(Duration::MAX)
+                ),
+            )
+            .add_formatted_fact(FactKey::ButWas, actual)
+            .add_fact("absolute deviation", format!("{}{:?}", sign, deviation));
+        if expected.is_zero() {
+            return result.do_fail();
+        }
+        let relative = deviation.as_secs_f64() / expected.as_secs_f64() * 100.;
+        result
+            .add_fact("relative deviation", format!("{}{:.1}%", sign, relative))
+            .do_fail()
+    }
+}
+
+/// Trait for integer subjects which are durations in milliseconds, e.g. latencies reported by a
+/// metrics library.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use assertor::*;
+///
+/// let latency_ms: u64 = 850;
+/// assert_that!(latency_ms)
+///     .as_millis_duration()
+///     .is_at_most(Duration::from_secs(1));
+/// demo!(
+///     assert_that!(1250_u64)
+///         .as_millis_duration()
+///         .is_at_most(Duration::from_micros(999_500)),
+///     r#"
+///     value of: 1250_u64.as_millis_duration()
+///     expected: at most 999.5ms
+///     but was : 1250ms
+///     delta   : +250.5ms
+///     "#
+/// );
+/// ```
+pub trait MillisDurationAssertion<R> {
+    /// Returns a new subject which is the subject as a [`Duration`] of that many milliseconds,
+    /// whose comparison failures show both durations in the same unit.
+    fn as_millis_duration(&self) -> Subject<'_, Duration, (), R>;
+}
+
+macro_rules! impl_millis_duration_assertion {
+    ($($int:ty),*) => {
+        $(
+            impl<R> MillisDurationAssertion<R> for Subject<'_, $int, (), R>
+            where
+                AssertionResult: AssertionStrategy<R>,
+            {
+                fn as_millis_duration(&self) -> Subject<'_, Duration, (), R> {
+                    self.new_owned_subject(
+                        Duration::from_millis(u64::from(*self.actual())),
+                        Some(format!("{}.as_millis_duration()", self.description_or_expr())),
+                        (),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_millis_duration_assertion!(u8, u16, u32, u64);
+
+/// Units of durations from the smallest, with their length in nanoseconds, as in the [`Debug`]
+/// representation of [`Duration`].
+const UNITS: [(&str, u128); 4] = [
+    ("ns", 1),
+    ("µs", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+];
+
+/// Formats the durations `expected` and `actual`, given as their [`Debug`] representations, in
+/// the same unit, followed by the signed difference of `actual` from `expected`. The unit is
+/// chosen for the smallest of the three, so that none is shown as a fraction of the unit. Returns
+/// `None` if either is not the representation of a [`Duration`].
+pub(crate) fn scale_durations(expected: &str, actual: &str) -> Option<[String; 3]> {
+    let (expected, actual) = (parse_nanos(expected)?, parse_nanos(actual)?);
+    let delta = expected.abs_diff(actual);
+    let smallest = [expected, actual, delta]
+        .into_iter()
+        .filter(|nanos| *nanos > 0)
+        .min()
+        .unwrap_or(0);
+    let unit = UNITS
+        .iter()
+        .rev()
+        .find(|(_, length)| *length <= smallest)
+        .unwrap_or(&UNITS[0]);
+    let sign = match actual.cmp(&expected) {
+        Ordering::Greater => "+",
+        Ordering::Less => "-",
+        Ordering::Equal => "",
+    };
+    Some([
+        format_nanos(expected, unit),
+        format_nanos(actual, unit),
+        format!("{}{}", sign, format_nanos(delta, unit)),
+    ])
+}
+
+/// Parses the [`Debug`] representation of a [`Duration`], e.g. `1.25s` or `900ms`, into
+/// nanoseconds.
+fn parse_nanos(repr: &str) -> Option<u128> {
+    UNITS.iter().find_map(|(unit, length)| {
+        let number = repr.strip_suffix(unit)?;
+        let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+        let digits = length.ilog10() as usize;
+        if !whole.bytes().all(|b| b.is_ascii_digit())
+            || !fraction.bytes().all(|b| b.is_ascii_digit())
+            || fraction.len() > digits
+        {
+            return None;
+        }
+        let fraction = if fraction.is_empty() {
+            0
+        } else {
+            format!("{:0<digits$}", fraction).parse::<u128>().ok()?
+        };
+        Some(whole.parse::<u128>().ok()? * length + fraction)
+    })
+}
+
+fn format_nanos(nanos: u128, (unit, length): &(&str, u128)) -> String {
+    let (whole, fraction) = (nanos / length, nanos % length);
+    if fraction == 0 {
+        return format!("{}{}", whole, unit);
+    }
+    let digits = length.ilog10() as usize;
+    let fraction = format!("{:0digits$}", fraction);
+    format!("{}.{}{}", whole, fraction.trim_end_matches('0'), unit)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::{ComparableAssertion, EqualityAssertion, OptionAssertion, StringAssertion};
+
+    use super::*;
+
+    #[test]
+    fn duration() {
+        let ms = Duration::from_millis;
+        assert_that!(ms(10))
+            .with_tolerance(ms(5))
+            .is_approx_equal_to(ms(15));
+        assert_that!(ms(15))
+            .with_tolerance(ms(5))
+            .is_approx_equal_to(ms(10));
+        assert_that!(ms(15)).is_approx_equal_to(ms(15));
+
+        // Failures
+        assert_that!(check_that!(ms(10))
+            .with_tolerance(ms(5))
+            .is_approx_equal_to(ms(20)))
+        .facts_are(vec![
+            Fact::new("expected", "20ms"),
+            Fact::new("but was", "10ms"),
+            Fact::new("outside tolerance", "5ms"),
+        ]);
+        assert_that!(check_that!(ms(10)).is_approx_equal_to(ms(11))).facts_are(vec![
+            Fact::new("expected", "11ms"),
+            Fact::new("but was", "10ms"),
+        ]);
+    }
+
+    #[test]
+    fn instant() {
+        let start = Instant::now();
+        let later = start + Duration::from_millis(3);
+        assert_that!(later)
+            .with_tolerance(Duration::from_millis(5))
+            .is_approx_equal_to(start);
+        assert_that!(start)
+            .with_tolerance(Duration::from_millis(5))
+            .is_approx_equal_to(later);
+
+        // Failures
+        assert_that!(check_that!(later)
+            .with_tolerance(Duration::from_millis(2))
+            .is_approx_equal_to(start))
+        .fact_value_for_key("outside tolerance")
+        .is_equal_to("2ms".to_string());
+    }
+
+    #[test]
+    fn system_time() {
+        let epoch = SystemTime::UNIX_EPOCH;
+        let later = epoch + Duration::from_secs(1);
+        assert_that!(epoch)
+            .with_tolerance(Duration::from_secs(1))
+            .is_approx_equal_to(later);
+        assert_that!(later)
+            .with_tolerance(Duration::from_secs(1))
+            .is_approx_equal_to(epoch);
+
+        // Failures
+        assert_that!(check_that!(later)
+            .with_tolerance(Duration::from_millis(999))
+            .is_approx_equal_to(epoch))
+        .fact_value_for_key("outside tolerance")
+        .is_equal_to("999ms".to_string());
+    }
+
+    #[test]
+    fn with_jitter() {
+        let ms = Duration::from_millis;
+        assert_that!(ms(1100))
+            .is_approximately(ms(1000))
+            .with_jitter(10.);
+        assert_that!(ms(900))
+            .is_approximately(ms(1000))
+            .with_jitter(10.);
+        assert_that!(ms(0)).is_approximately(ms(0)).with_jitter(0.);
+
+        // Failures
+        assert_that!(check_that!(ms(850))
+            .is_approximately(ms(1000))
+            .with_jitter(12.5))
+        .facts_are(vec![
+            Fact::new("expected", "1s"),
+            Fact::new("with jitter", "±12.5% (875ms to 1.125s)"),
+            Fact::new("but was", "850ms"),
+            Fact::new("absolute deviation", "-150ms"),
+            Fact::new("relative deviation", "-15.0%"),
+        ]);
+        assert_that!(check_that!(ms(5)).is_approximately(ms(0)).with_jitter(50.)).facts_are(vec![
+            Fact::new("expected", "0ns"),
+            Fact::new("with jitter", "±50% (0ns to 0ns)"),
+            Fact::new("but was", "5ms"),
+            Fact::new("absolute deviation", "+5ms"),
+        ]);
+        assert_that!(check_that!(ms(5)).is_approximately(ms(5)).with_jitter(-1.)).facts_are(vec![
+            Fact::new("invalid jitter", "-1.0"),
+            Fact::new_simple_fact("jitter must be a finite non-negative percentage"),
+        ]);
+        assert_that!(check_that!(ms(5))
+            .is_approximately(ms(5))
+            .with_jitter(f64::INFINITY))
+        .facts_are(vec![
+            Fact::new("invalid jitter", "inf"),
+            Fact::new_simple_fact("jitter must be a finite non-negative percentage"),
+        ]);
+    }
+
+    #[test]
+    fn with_jitter_saturates() {
+        let ms = Duration::from_millis;
+        assert_that!(Duration::MAX)
+            .is_approximately(ms(1000))
+            .with_jitter(f64::MAX);
+        assert_that!(check_that!(ms(0))
+            .is_approximately(Duration::MAX)
+            .with_jitter(50.))
+        .fact_value_for_key("with jitter")
+        .ends_with(format!("to {:?})", Duration::MAX));
+    }
+
+    #[test]
+    fn as_millis_duration() {
+        assert_that!(850_u64)
+            .as_millis_duration()
+            .is_equal_to(Duration::from_millis(850));
+        assert_that!(5_u8)
+            .as_millis_duration()
+            .is_less_than(Duration::from_micros(5001));
+
+        // Failures
+        assert_that!(check_that!(1250_u32)
+            .as_millis_duration()
+            .is_at_most(Duration::from_secs(1)))
+        .facts_are(vec![
+            Fact::new("value of", "1250_u32.as_millis_duration()"),
+            Fact::new("expected", "at most 1000ms"),
+            Fact::new("but was", "1250ms"),
+            Fact::new("delta", "+250ms"),
+        ]);
+    }
+
+    #[test]
+    fn scale_durations() {
+        let scale = |expected: Duration, actual: Duration| {
+            super::scale_durations(&format!("{:?}", expected), &format!("{:?}", actual))
+                .map(|scaled| scaled.join(", "))
+        };
+        let (ms, ns) = (Duration::from_millis, Duration::from_nanos);
+        assert_that!(scale(ms(900), ms(1250))).has_value("900ms, 1250ms, +350ms".to_string());
+        assert_that!(scale(ns(1500), ns(999))).has_value("1500ns, 999ns, -501ns".to_string());
+        assert_that!(scale(ms(3000), ms(2500))).has_value("3000ms, 2500ms, -500ms".to_string());
+        assert_that!(scale(ms(1000), ns(1_000_000_010)))
+            .has_value("1000000000ns, 1000000010ns, +10ns".to_string());
+        assert_that!(scale(Duration::ZERO, ns(1000))).has_value("0µs, 1µs, +1µs".to_string());
+        assert_that!(super::scale_durations("1.5", "2s")).is_none();
+        assert_that!(super::scale_durations("1.5ms", "2.5.1s")).is_none();
+    }
+}
@@ -0,0 +1,209 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Debug, Formatter};
+use std::sync::Mutex;
+
+use crate::base::{pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, Subject};
+
+/// A telemetry event captured by [`CapturedEvents`], with its recorded fields.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CapturedEvent {
+    /// Name of the event.
+    pub name: String,
+    /// Recorded fields, in the order they were recorded. Values are formatted as strings.
+    pub fields: Vec<(String, String)>,
+}
+
+impl CapturedEvent {
+    /// Creates an event without fields.
+    pub fn new<N: Into<String>>(name: N) -> Self {
+        CapturedEvent {
+            name: name.into(),
+            fields: vec![],
+        }
+    }
+
+    /// Adds a field to the event.
+    pub fn with_field<K: Into<String>, V: ToString>(mut self, key: K, value: V) -> Self {
+        self.fields.push((key.into(), value.to_string()));
+        self
+    }
+
+    fn has_field(&self, key: &str, value: &str) -> bool {
+        self.fields.iter().any(|(k, v)| k == key && v == value)
+    }
+}
+
+impl Debug for CapturedEvent {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        for (key, value) in &self.fields {
+            write!(f, " {}={}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+/// Telemetry events captured during a test, to be checked with [`EventAssertion`].
+///
+/// Events are added through a shared reference so that a test subscriber (e.g. a
+/// `tracing::Subscriber` implementation) can forward every event it receives with
+/// [`CapturedEvents::record`].
+#[derive(Debug, Default)]
+pub struct CapturedEvents {
+    events: Mutex<Vec<CapturedEvent>>,
+}
+
+impl CapturedEvents {
+    /// Creates an empty capture.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Captures an event.
+    pub fn record(&self, event: CapturedEvent) {
+        self.events.lock().unwrap().push(event);
+    }
+
+    /// Returns the captured events, in the order they were captured.
+    pub fn events(&self) -> Vec<CapturedEvent> {
+        self.events.lock().unwrap().clone()
+    }
+}
+
+/// Trait for captured telemetry event assertion.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let events = CapturedEvents::new();
+/// events.record(CapturedEvent::new("login").with_field("user_id", 42));
+///
+/// assert_that!(events).contains_event("login");
+/// assert_that!(events).contains_event_with_field("user_id", "42");
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// let events = CapturedEvents::new();
+/// events.record(CapturedEvent::new("login").with_field("user_id", 42));
+///
+/// assert_that!(events).contains_event_with_field("user_id", "7");
+/// // expected an event with field: user_id=7
+/// // but did not
+/// // captured events             : [ login user_id=42 ]
+/// ```
+pub trait EventAssertion<R> {
+    /// Checks that an event with the given name was captured.
+    #[track_caller]
+    fn contains_event<N: AsRef<str>>(&self, name: N) -> R;
+
+    /// Checks that an event recording `key` with the formatted `value` was captured.
+    #[track_caller]
+    fn contains_event_with_field<K: AsRef<str>, V: AsRef<str>>(&self, key: K, value: V) -> R;
+}
+
+impl<R> EventAssertion<R> for Subject<'_, CapturedEvents, (), R>
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn contains_event<N: AsRef<str>>(&self, name: N) -> R {
+        pass_if_skipped!(self);
+        let events = self.actual().events();
+        let name = name.as_ref();
+        if events.iter().any(|event| event.name == name) {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_fact("expected an event named", name)
+                .add_simple_fact("but did not")
+                .add_formatted_values_fact("captured events", events)
+                .do_fail()
+        }
+    }
+
+    fn contains_event_with_field<K: AsRef<str>, V: AsRef<str>>(&self, key: K, value: V) -> R {
+        pass_if_skipped!(self);
+        let events = self.actual().events();
+        let (key, value) = (key.as_ref(), value.as_ref());
+        if events.iter().any(|event| event.has_field(key, value)) {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_fact("expected an event with field", format!("{}={}", key, value))
+                .add_simple_fact("but did not")
+                .add_formatted_values_fact("captured events", events)
+                .do_fail()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+
+    use super::*;
+
+    fn login_events() -> CapturedEvents {
+        let events = CapturedEvents::new();
+        events.record(CapturedEvent::new("start"));
+        events.record(
+            CapturedEvent::new("login")
+                .with_field("user_id", 42)
+                .with_field("ok", true),
+        );
+        events
+    }
+
+    #[test]
+    fn contains_event() {
+        assert_that!(login_events()).contains_event("start");
+        assert_that!(login_events()).contains_event("login");
+
+        // Failures
+        assert_that!(check_that!(login_events()).contains_event("logout")).facts_are(vec![
+            Fact::new("expected an event named", "logout"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact(
+                "captured events",
+                vec!["start", "login user_id=42 ok=true"],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn contains_event_with_field() {
+        assert_that!(login_events()).contains_event_with_field("user_id", "42");
+        assert_that!(login_events()).contains_event_with_field("ok", "true");
+
+        // Failures
+        assert_that!(check_that!(login_events()).contains_event_with_field("user_id", "7"))
+            .facts_are(vec![
+                Fact::new("expected an event with field", "user_id=7"),
+                Fact::new_simple_fact("but did not"),
+                Fact::new_multi_value_fact(
+                    "captured events",
+                    vec!["start", "login user_id=42 ok=true"],
+                ),
+            ]);
+        assert_that!(check_that!(CapturedEvents::new()).contains_event_with_field("ok", "true"))
+            .facts_are(vec![
+                Fact::new("expected an event with field", "ok=true"),
+                Fact::new_simple_fact("but did not"),
+                Fact::new_multi_value_fact("captured events", Vec::<String>::new()),
+            ]);
+    }
+}
@@ -0,0 +1,369 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+
+/// URLs whose parts can be inspected by [`UrlAssertion`].
+///
+/// Implemented for strings, which are split at the delimiters of RFC 3986 without being validated
+/// nor normalized. To use [`UrlAssertion`] with URL types of other crates (e.g. `url::Url`),
+/// implement this trait for a wrapper type, as assertor does not depend on them.
+pub trait UrlLike {
+    /// Returns the scheme, e.g. `https`. Empty if the URL has no scheme.
+    fn scheme(&self) -> String;
+
+    /// Returns the host, e.g. `example.com`, without user info or port.
+    fn host(&self) -> Option<String>;
+
+    /// Returns the path, e.g. `/a/b`.
+    fn path(&self) -> String;
+
+    /// Returns the percent-decoded query parameters, in order.
+    fn query_pairs(&self) -> Vec<(String, String)>;
+}
+
+impl UrlLike for &str {
+    fn scheme(&self) -> String {
+        UrlParts::parse(self).scheme.to_string()
+    }
+
+    fn host(&self) -> Option<String> {
+        UrlParts::parse(self).host.map(String::from)
+    }
+
+    fn path(&self) -> String {
+        UrlParts::parse(self).path.to_string()
+    }
+
+    fn query_pairs(&self) -> Vec<(String, String)> {
+        UrlParts::parse(self).query_pairs()
+    }
+}
+
+impl UrlLike for String {
+    fn scheme(&self) -> String {
+        self.as_str().scheme()
+    }
+
+    fn host(&self) -> Option<String> {
+        self.as_str().host()
+    }
+
+    fn path(&self) -> String {
+        self.as_str().path()
+    }
+
+    fn query_pairs(&self) -> Vec<(String, String)> {
+        self.as_str().query_pairs()
+    }
+}
+
+/// Trait for URL assertion.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let url = "https://example.com/a/b?q=x%20y&page=2";
+/// assert_that!(url).has_scheme("https");
+/// assert_that!(url).has_host("example.com");
+/// assert_that!(url).has_path("/a/b");
+/// assert_that!(url).has_query_param("q", "x y");
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// assert_that!("http://example.com/a?q=x").has_scheme("https");
+/// // expected scheme: https
+/// // but was        : http
+/// // ---
+/// // scheme         : http
+/// // host           : example.com
+/// // path           : /a
+/// // query          : q=x
+/// ```
+pub trait UrlAssertion<R> {
+    /// Checks that the subject has the given scheme.
+    #[track_caller]
+    fn has_scheme<E: AsRef<str>>(&self, scheme: E) -> R;
+
+    /// Checks that the subject has the given host.
+    #[track_caller]
+    fn has_host<E: AsRef<str>>(&self, host: E) -> R;
+
+    /// Checks that the subject has the given path.
+    #[track_caller]
+    fn has_path<E: AsRef<str>>(&self, path: E) -> R;
+
+    /// Checks that the subject has a query parameter `name` whose decoded value is `value`.
+    #[track_caller]
+    fn has_query_param<N: AsRef<str>, V: AsRef<str>>(&self, name: N, value: V) -> R;
+}
+
+impl<S, R> UrlAssertion<R> for Subject<'_, S, (), R>
+where
+    S: UrlLike,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn has_scheme<E: AsRef<str>>(&self, scheme: E) -> R {
+        pass_if_skipped!(self);
+        let actual = self.actual().scheme();
+        check_part(self, "scheme", scheme.as_ref(), Some(actual))
+    }
+
+    fn has_host<E: AsRef<str>>(&self, host: E) -> R {
+        pass_if_skipped!(self);
+        check_part(self, "host", host.as_ref(), self.actual().host())
+    }
+
+    fn has_path<E: AsRef<str>>(&self, path: E) -> R {
+        pass_if_skipped!(self);
+        let actual = self.actual().path();
+        check_part(self, "path", path.as_ref(), Some(actual))
+    }
+
+    fn has_query_param<N: AsRef<str>, V: AsRef<str>>(&self, name: N, value: V) -> R {
+        pass_if_skipped!(self);
+        let (name, value) = (name.as_ref(), value.as_ref());
+        let values: Vec<String> = self
+            .actual()
+            .query_pairs()
+            .into_iter()
+            .filter(|(n, _)| n == name)
+            .map(|(_, v)| v)
+            .collect();
+        if values.iter().any(|v| v == value) {
+            return self.new_result().do_ok();
+        }
+        let actual = if values.is_empty() {
+            None
+        } else {
+            Some(
+                values
+                    .iter()
+                    .map(|v| format!("{}={}", name, v))
+                    .collect::<Vec<_>>()
+                    .join("&"),
+            )
+        };
+        fail_part(self, "query param", &format!("{}={}", name, value), actual)
+    }
+}
+
+fn check_part<S: UrlLike, R>(
+    subject: &Subject<S, (), R>,
+    part: &str,
+    expected: &str,
+    actual: Option<String>,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    if actual.as_deref() == Some(expected) {
+        subject.new_result().do_ok()
+    } else {
+        fail_part(subject, part, expected, actual)
+    }
+}
+
+fn fail_part<S: UrlLike, R>(
+    subject: &Subject<S, (), R>,
+    part: &str,
+    expected: &str,
+    actual: Option<String>,
+) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    let url = subject.actual();
+    let query = url
+        .query_pairs()
+        .iter()
+        .map(|(n, v)| format!("{}={}", n, v))
+        .collect::<Vec<_>>()
+        .join("&");
+    subject
+        .new_result()
+        .add_fact(format!("expected {}", part), expected)
+        .add_fact(FactKey::ButWas, actual.unwrap_or_else(|| NONE.to_string()))
+        .add_splitter()
+        .add_fact("scheme", url.scheme())
+        .add_fact("host", url.host().unwrap_or_else(|| NONE.to_string()))
+        .add_fact("path", url.path())
+        .add_fact("query", query)
+        .do_fail()
+}
+
+/// Rendered in facts for absent URL parts.
+const NONE: &str = "<none>";
+
+/// A URL split into its parts, as described in RFC 3986 section 3.
+struct UrlParts<'a> {
+    scheme: &'a str,
+    host: Option<&'a str>,
+    path: &'a str,
+    query: Option<&'a str>,
+}
+
+impl<'a> UrlParts<'a> {
+    /// Splits `url` at the delimiters of its parts. This is not a URL parser: invalid URLs are
+    /// split too, and no part is normalized, e.g. the scheme and host keep their case and dot
+    /// segments stay in the path. Only query parameters are percent-decoded.
+    fn parse(url: &'a str) -> Self {
+        let url = url.split('#').next().unwrap_or_default();
+        let (scheme, rest) = match url.split_once(':') {
+            Some((scheme, rest)) if is_scheme(scheme) => (scheme, rest),
+            _ => ("", url),
+        };
+        let (rest, query) = match rest.split_once('?') {
+            Some((rest, query)) => (rest, Some(query)),
+            None => (rest, None),
+        };
+        let (host, path) = match rest.strip_prefix("//") {
+            Some(authority_and_path) => {
+                let (authority, path) = match authority_and_path.find('/') {
+                    Some(i) => authority_and_path.split_at(i),
+                    None => (authority_and_path, ""),
+                };
+                (Some(host_of(authority)), path)
+            }
+            None => (None, rest),
+        };
+        UrlParts {
+            scheme,
+            host,
+            path,
+            query,
+        }
+    }
+
+    fn query_pairs(&self) -> Vec<(String, String)> {
+        self.query
+            .unwrap_or_default()
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .map(|pair| {
+                let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+                (percent_decode(name), percent_decode(value))
+            })
+            .collect()
+    }
+}
+
+fn is_scheme(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().map_or(false, |c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+}
+
+/// Strips user info and port from an authority, e.g. `user@[::1]:8080` to `[::1]`.
+fn host_of(authority: &str) -> &str {
+    let host_and_port = authority.rsplit('@').next().unwrap_or_default();
+    match host_and_port.rfind(':') {
+        Some(i) if !host_and_port[i..].contains(']') => &host_and_port[..i],
+        _ => host_and_port,
+    }
+}
+
+/// Decodes `%XX` escapes and `+` as used in `application/x-www-form-urlencoded` queries.
+/// Invalid escapes are kept as is.
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                match (hex_digit(bytes[i + 1]), hex_digit(bytes[i + 2])) {
+                    (Some(high), Some(low)) => {
+                        decoded.push(high << 4 | low);
+                        i += 2;
+                    }
+                    _ => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+fn hex_digit(byte: u8) -> Option<u8> {
+    (byte as char).to_digit(16).map(|d| d as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::EqualityAssertion;
+
+    use super::*;
+
+    #[test]
+    fn parts() {
+        let url = "https://user@example.com:8080/a/b?q=x%20y&flag&page=2#top";
+        assert_that!(url).has_scheme("https");
+        assert_that!(url).has_host("example.com");
+        assert_that!(url).has_path("/a/b");
+        assert_that!(url).has_query_param("q", "x y");
+        assert_that!(url).has_query_param("flag", "");
+        assert_that!(url.to_string()).has_query_param("page", "2");
+
+        assert_that!("http://[::1]:80").has_host("[::1]");
+        assert_that!("http://[::1]").has_host("[::1]");
+        assert_that!("http://example.com").has_path("");
+        assert_that!("mailto:someone@example.com").has_path("someone@example.com");
+        assert_that!("/relative/path?a+b=c%2").has_query_param("a b", "c%2");
+        assert_that!("/relative/path").has_scheme("");
+    }
+
+    #[test]
+    fn failures() {
+        assert_that!(check_that!("http://example.com/a?q=x").has_scheme("https")).facts_are(vec![
+            Fact::new("expected scheme", "https"),
+            Fact::new("but was", "http"),
+            Fact::new_splitter(),
+            Fact::new("scheme", "http"),
+            Fact::new("host", "example.com"),
+            Fact::new("path", "/a"),
+            Fact::new("query", "q=x"),
+        ]);
+        assert_that!(check_that!("/a").has_host("example.com")).facts_are(vec![
+            Fact::new("expected host", "example.com"),
+            Fact::new("but was", "<none>"),
+            Fact::new_splitter(),
+            Fact::new("scheme", ""),
+            Fact::new("host", "<none>"),
+            Fact::new("path", "/a"),
+            Fact::new("query", ""),
+        ]);
+        assert_that!(check_that!("https://example.com/?q=x&q=y").has_query_param("q", "z"))
+            .facts_are(vec![
+                Fact::new("expected query param", "q=z"),
+                Fact::new("but was", "q=x&q=y"),
+                Fact::new_splitter(),
+                Fact::new("scheme", "https"),
+                Fact::new("host", "example.com"),
+                Fact::new("path", "/"),
+                Fact::new("query", "q=x&q=y"),
+            ]);
+        assert_that!(check_that!("https://example.com/").has_query_param("q", "z"))
+            .fact_value_for_key("but was")
+            .is_equal_to("<none>".to_string());
+    }
+}
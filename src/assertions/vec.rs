@@ -12,13 +12,113 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::borrow::Borrow;
+use std::borrow::{Borrow, Cow};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::RangeBounds;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::vec::IntoIter;
 
+use crate::assertions::basic::EqualityAssertion;
+use crate::assertions::index;
 use crate::assertions::iterator::{
-    check_has_length, check_is_empty, check_is_not_empty, IteratorAssertion,
+    aggregated, check_all_equal_to, check_all_satisfy, check_all_within, check_any_satisfy,
+    check_contains_any_of, check_has_length, check_has_same_elements, check_is_empty,
+    check_is_not_empty, check_len_bound, counted, derived_chunks, derived_region, derived_windows,
+    distinct, extreme, mean, percentile, std_dev, IteratorAssertion, LengthBound,
 };
-use crate::base::{AssertionApi, AssertionResult, AssertionStrategy, Subject};
+use crate::base::{
+    pass_if_skipped, AssertionApi, AssertionResult, AssertionStrategy, FactKey, Subject,
+};
+
+/// Ordered containers whose elements can be iterated, enabling [`VecAssertion`] for them.
+///
+/// Implement this trait for custom sequences (e.g. ring buffers or gap buffers) to get the same
+/// assertions, and failure messages, as [`Vec`].
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// struct Ring(Vec<u32>);
+///
+/// impl SequenceLike<u32> for Ring {
+///     type It<'a> = std::slice::Iter<'a, u32>;
+///
+///     fn elements_iter<'a>(&'a self) -> Self::It<'a> {
+///         self.0.iter()
+///     }
+/// }
+///
+/// assert_that!(Ring(vec![1, 2, 3])).contains_exactly_in_order(vec![1, 2, 3]);
+/// ```
+pub trait SequenceLike<T> {
+    /// Iterator over the elements, in order.
+    type It<'a>: Iterator<Item = &'a T> + Clone
+    where
+        T: 'a,
+        Self: 'a;
+
+    /// Returns an iterator over the elements, in order.
+    fn elements_iter<'a>(&'a self) -> Self::It<'a>;
+}
+
+impl<T> SequenceLike<T> for Vec<T> {
+    type It<'a>
+        = std::slice::Iter<'a, T>
+    where
+        T: 'a;
+
+    fn elements_iter<'a>(&'a self) -> Self::It<'a> {
+        self.iter()
+    }
+}
+
+impl<T> SequenceLike<T> for VecDeque<T> {
+    type It<'a>
+        = std::collections::vec_deque::Iter<'a, T>
+    where
+        T: 'a;
+
+    fn elements_iter<'a>(&'a self) -> Self::It<'a> {
+        self.iter()
+    }
+}
+
+/// Copy-on-write slices, e.g. returned by APIs which only allocate when they need to modify their
+/// input, are checked like `Vec`s whether they are borrowed or owned.
+impl<T: Clone> SequenceLike<T> for Cow<'_, [T]> {
+    type It<'a>
+        = std::slice::Iter<'a, T>
+    where
+        T: 'a,
+        Self: 'a;
+
+    fn elements_iter<'a>(&'a self) -> Self::It<'a> {
+        self.iter()
+    }
+}
+
+/// Sequences behind smart pointers, e.g. `Box<Vec<T>>`, are checked like the sequences
+/// themselves, as with auto-deref.
+macro_rules! impl_sequence_like_for_pointers {
+    ($($pointer:ident),*) => {
+        $(
+            impl<T, S: SequenceLike<T>> SequenceLike<T> for $pointer<S> {
+                type It<'a> = S::It<'a> where T: 'a, Self: 'a;
+
+                fn elements_iter<'a>(&'a self) -> Self::It<'a> {
+                    (**self).elements_iter()
+                }
+            }
+        )*
+    };
+}
+
+impl_sequence_like_for_pointers!(Box, Rc, Arc);
 
 /// Trait for vector assertion.
 ///
@@ -73,16 +173,21 @@ where
         B: Borrow<T>,
         T: PartialEq + Debug;
 
-    /// Checks that the subject exactly contains elements of `expected_vec`.
+    /// Checks that the subject exactly contains elements of `expected`.
     ///
     /// This method doesn't take care of the order. Use
     /// [contains_exactly_in_order](`VecAssertion::contains_exactly_in_order`) to check
     /// elements are in the same order.
     ///
+    /// Like other methods taking several elements, `expected` can be any collection of elements
+    /// or references to them, e.g. a `Vec`, an array or a slice.
+    ///
     /// # Example
     /// ```
     /// use assertor::*;
     /// assert_that!(vec![1, 2, 3]).contains_exactly(vec![3, 2, 1]);
+    /// assert_that!(vec![1, 2, 3]).contains_exactly([3, 2, 1]);
+    /// assert_that!(vec![1, 2, 3]).contains_exactly(&[3, 2, 1]);
     /// ```
     /// ```should_panic
     /// use assertor::*;
@@ -90,11 +195,43 @@ where
     /// assert_that!(vec![1,2]).contains_exactly(vec![1]);
     /// ```
     #[track_caller]
-    fn contains_exactly<B: Borrow<Vec<T>>>(self, expected_vec: B) -> R
+    fn contains_exactly<E>(self, expected: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
+        T: PartialEq + Debug;
+
+    /// Checks that the subject and `expected` have the same elements, in any order, as
+    /// [`crate::IteratorAssertion::has_same_elements_as`] does.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec!["b", "a"]).has_same_elements_as(["a", "b"]);
+    /// ```
+    #[track_caller]
+    fn has_same_elements_as<E>(&self, expected: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
+        T: PartialEq + Debug;
+
+    /// Like [`VecAssertion::has_same_elements_as`], but ignores how many times each element
+    /// occurs.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec!["a", "b", "a"]).has_same_distinct_elements_as(["b", "a"]);
+    /// ```
+    #[track_caller]
+    fn has_same_distinct_elements_as<E>(&self, expected: E) -> R
     where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
         T: PartialEq + Debug;
 
-    /// Checks that the subject exactly contains `expected_vec` in the same order.
+    /// Checks that the subject exactly contains `expected` in the same order.
     ///
     /// # Example
     /// ```
@@ -107,8 +244,10 @@ where
     /// assert_that!(vec![1,2]).contains_exactly_in_order(vec![1]);
     /// ```
     #[track_caller]
-    fn contains_exactly_in_order<B: Borrow<Vec<T>>>(self, expected_vec: B) -> R
+    fn contains_exactly_in_order<E>(self, expected: E) -> R
     where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
         T: PartialEq + Debug;
 
     /// Checks that the subject does not contain any element of `elements`.
@@ -123,166 +262,1189 @@ where
     /// assert_that!(vec![1,2]).does_not_contain_any(vec![1]);
     /// ```
     #[track_caller]
-    fn does_not_contain_any<B: Borrow<Vec<T>>>(&self, elements: B) -> R
+    fn does_not_contain_any<E>(&self, elements: E) -> R
     where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
         T: PartialEq + Debug;
 
-    /// Checks that the subject is empty.
+    /// Checks that the subject contains at least one element of `elements`.
     ///
     /// # Example
     /// ```
     /// use assertor::*;
-    /// assert_that!(Vec::<usize>::new()).is_empty();
+    /// assert_that!(vec![1, 2, 3]).contains_any_of(vec![0, 3]);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2]).contains_any_of(vec![3]);
     /// ```
     #[track_caller]
-    fn is_empty(&self) -> R
+    fn contains_any_of<E>(&self, elements: E) -> R
     where
-        T: Debug;
+        E: IntoIterator,
+        E::Item: Borrow<T>,
+        T: PartialEq + Debug;
 
-    /// Checks that the subject is not empty.
+    /// Checks that the subject contains an element which satisfies `predicate`. `description`
+    /// describes the predicate in failure messages.
     ///
     /// # Example
     /// ```
     /// use assertor::*;
-    /// assert_that!(vec![1]).is_not_empty();
+    ///
+    /// #[derive(Debug)]
+    /// struct User {
+    ///     id: u32,
+    /// }
+    /// assert_that!(vec![User { id: 3 }, User { id: 7 }]).contains_element_with(|u| u.id == 7, "id == 7");
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3]).contains_element_with(|n| *n > 5, "n > 5");
+    /// // expected to contain an element matching: n > 5
+    /// // but did not
+    /// // though it did contain                  : [1, 2, 3]
     /// ```
     #[track_caller]
-    fn is_not_empty(&self) -> R
+    fn contains_element_with<P: Fn(&T) -> bool>(&self, predicate: P, description: &str) -> R
     where
         T: Debug;
 
-    /// Checks that the subject is the given length.
+    /// Returns a new subject which is the number of elements satisfying `predicate`.
     ///
     /// # Example
     /// ```
     /// use assertor::*;
-    /// assert_that!(vec![1, 2, 3]).has_length(3);
+    /// assert_that!(vec![1, 2, 3, 4]).matching_count(|n| n % 2 == 0).is_equal_to(2);
     /// ```
-    #[track_caller]
-    fn has_length(&self, length: usize) -> R;
-}
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3, 4]).matching_count(|n| n % 2 == 0).is_equal_to(3);
+    /// // value of: vec![1, 2, 3, 4].matching_count(..)
+    /// // expected: 3
+    /// // actual  : 2
+    /// ```
+    fn matching_count<P: Fn(&T) -> bool>(&self, predicate: P) -> Subject<'a, usize, (), R>;
 
-impl<'a, T, R> VecAssertion<'a, Vec<T>, T, R> for Subject<'a, Vec<T>, (), R>
-where
-    AssertionResult: AssertionStrategy<R>,
-{
-    fn contains<B>(&self, element: B) -> R
+    /// Returns a new subject which is the elements in reverse order.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3]).reversed().contains_exactly_in_order(vec![3, 2, 1]);
+    /// ```
+    fn reversed(&self) -> Subject<'a, Vec<T>, (), R>
     where
-        B: Borrow<T>,
-        T: PartialEq + Debug,
-    {
-        self.new_subject(&self.actual().iter(), None, ())
-            .contains(element.borrow())
-    }
+        T: Clone;
 
-    fn does_not_contain<B>(&self, element: B) -> R
+    /// Returns a new subject which is the elements sorted in ascending order, so that order
+    /// insensitive checks can be expressed explicitly.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![3, 1, 2]).sorted().is_equal_to(vec![1, 2, 3]);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(vec![3, 1, 2]).sorted().is_equal_to(vec![1, 2, 4]);
+    /// // value of  : vec![3, 1, 2].sorted()
+    /// // expected  : [1, 2, 4]
+    /// // actual    : [1, 2, 3]
+    /// // differs at: [2] (expected: 4, actual: 3)
+    /// ```
+    fn sorted(&self) -> Subject<'a, Vec<T>, (), R>
     where
-        B: Borrow<T>,
-        T: PartialEq + Debug,
-    {
-        self.new_owned_subject(self.actual().iter(), None, ())
-            .does_not_contain(element.borrow())
-    }
+        T: Clone + Ord;
 
-    fn contains_exactly<B: Borrow<Vec<T>>>(self, expected_iter: B) -> R
+    /// Returns a new subject which is the elements sorted with the comparator `compare`. The sort
+    /// is stable.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec!["bb", "a", "ccc"])
+    ///     .sorted_by(|a, b| b.len().cmp(&a.len()))
+    ///     .contains_exactly_in_order(vec!["ccc", "bb", "a"]);
+    /// ```
+    fn sorted_by<F: FnMut(&T, &T) -> Ordering>(&self, compare: F) -> Subject<'a, Vec<T>, (), R>
     where
-        T: PartialEq + Debug,
-    {
-        self.new_owned_subject(self.actual().iter(), None, ())
-            .contains_exactly(expected_iter.borrow().iter())
-    }
+        T: Clone;
 
-    fn contains_exactly_in_order<B: Borrow<Vec<T>>>(self, expected_iter: B) -> R
+    /// Returns a new subject which is the unique elements, in the order of their first
+    /// occurrence. See [`IteratorAssertion::distinct`] for how elements are compared.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec!["a", "b", "a"]).distinct().is_equal_to(vec!["a", "b"]);
+    /// ```
+    fn distinct(&self) -> Subject<'a, Vec<T>, (), R>
     where
-        T: PartialEq + Debug,
-    {
-        self.new_owned_subject(self.actual().iter(), None, ())
-            .contains_exactly_in_order(expected_iter.borrow().iter())
-    }
+        T: Clone + PartialEq;
 
-    fn does_not_contain_any<B: Borrow<Vec<T>>>(&self, elements: B) -> R
+    /// Returns a new subject which maps each distinct element to its number of occurrences.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec!["a", "b", "a"]).counted().contains_entry("a", 2);
+    /// ```
+    fn counted(&self) -> Subject<'a, BTreeMap<T, usize>, (), R>
     where
-        T: PartialEq + Debug,
-    {
-        self.new_owned_subject(self.actual().iter(), None, ())
-            .does_not_contain_any(elements.borrow().iter())
-    }
+        T: Clone + Ord;
 
-    fn is_empty(&self) -> R
+    /// Returns a new subject which is the sum of the elements.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3]).sum().is_equal_to(6);
+    /// ```
+    fn sum(&self) -> Subject<'a, T, (), R>
     where
-        T: Debug,
-    {
-        check_is_empty(self.new_result(), self.actual().iter())
-    }
+        T: Clone + Sum;
 
-    fn is_not_empty(&self) -> R
+    /// Returns a new subject which is the smallest element. See [`IteratorAssertion::min`].
+    #[track_caller]
+    fn min(&self) -> Subject<'a, T, (), R>
     where
-        T: Debug,
-    {
-        check_is_not_empty(self.new_result(), self.actual().iter())
-    }
+        T: Clone + PartialOrd;
 
-    fn has_length(&self, length: usize) -> R {
-        check_has_length(self.new_result(), self.actual().iter(), self.expr(), length)
-    }
-}
+    /// Returns a new subject which is the largest element. See [`IteratorAssertion::max`].
+    #[track_caller]
+    fn max(&self) -> Subject<'a, T, (), R>
+    where
+        T: Clone + PartialOrd;
 
-#[cfg(test)]
-mod tests {
-    use crate::testing::*;
+    /// Returns a new subject which is the arithmetic mean of the elements. Panics if the subject
+    /// is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// let latencies = vec![1.5, 2.5, 5.0];
+    /// assert_that!(latencies).mean().is_less_than(5.0);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// let latencies = vec![4.0, 8.0];
+    /// assert_that!(latencies).mean().is_equal_to(5.0);
+    /// // value of: latencies.mean()
+    /// // expected: 5.0
+    /// // actual  : 6.0
+    /// ```
+    #[track_caller]
+    fn mean(&self) -> Subject<'a, f64, (), R>
+    where
+        T: Clone + Into<f64>;
 
-    use super::*;
+    /// Returns a new subject which is the `p`-th percentile of the elements. See
+    /// [`IteratorAssertion::percentile`].
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// let latencies_ms = vec![12, 15, 11, 90, 14];
+    /// assert_that!(latencies_ms).percentile(50.0).is_less_than(20.0);
+    /// ```
+    #[track_caller]
+    fn percentile(&self, p: f64) -> Subject<'a, f64, (), R>
+    where
+        T: Clone + Into<f64>;
 
-    #[test]
-    fn contains() {
-        assert_that!(vec![1, 2, 3]).contains(&3);
+    /// Returns a new subject which is the population standard deviation of the elements. Panics
+    /// if the subject is empty.
+    #[track_caller]
+    fn std_dev(&self) -> Subject<'a, f64, (), R>
+    where
+        T: Clone + Into<f64>;
 
-        // Failures
-        assert_that!(check_that!(vec![1, 2, 3]).contains(&10)).facts_are(vec![
-            Fact::new("expected to contain", "10"),
-            Fact::new_simple_fact("but did not"),
-            Fact::new_multi_value_fact("though it did contain", vec!["1", "2", "3"]),
-        ]);
-    }
+    /// Checks that all elements are within `range`, listing the elements which are not.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![0.5, 0.9, 0.1]).all_within(0.0..1.0);
+    /// ```
+    #[track_caller]
+    fn all_within<B: RangeBounds<T> + Debug>(&self, range: B) -> R
+    where
+        T: PartialOrd + Debug;
 
-    #[test]
-    fn contains_exactly() {
-        assert_that!(vec![1, 2, 3]).contains_exactly(vec![1, 2, 3]);
-        assert_that!(vec![2, 1, 3]).contains_exactly(vec![1, 2, 3]);
-    }
+    /// Checks that all elements are `true`, listing the indices of those which are not.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![true, true]).are_all_true();
+    /// ```
+    #[track_caller]
+    fn are_all_true(&self) -> R
+    where
+        T: Borrow<bool>;
 
-    #[test]
-    fn contains_exactly_in_order() {
-        assert_that!(vec![1, 2, 3]).contains_exactly_in_order(vec![1, 2, 3]);
-        assert_that!(check_that!(vec![2, 1, 3]).contains_exactly_in_order(vec![1, 2, 3])).facts_are(
-            vec![
-                Fact::new_simple_fact("contents match, but order was wrong"),
-                Fact::new_splitter(),
-                Fact::new_multi_value_fact("expected", vec!["1", "2", "3"]),
-                Fact::new_multi_value_fact("actual", vec!["2", "1", "3"]),
-            ],
-        )
-    }
+    /// Checks that all elements are `false`, listing the indices of those which are not.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![false, false]).are_all_false();
+    /// ```
+    #[track_caller]
+    fn are_all_false(&self) -> R
+    where
+        T: Borrow<bool>;
 
-    #[test]
-    fn is_empty() {
-        assert_that!(Vec::<usize>::new()).is_empty();
+    /// Checks that all elements satisfy `predicate`, listing the elements which do not with
+    /// their indices. `description` describes the predicate in failure messages.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec!["a", "bc"]).all_satisfy(|s| !s.is_empty(), "non-empty");
+    /// ```
+    #[track_caller]
+    fn all_satisfy<P: Fn(&T) -> bool>(&self, predicate: P, description: &str) -> R
+    where
+        T: Debug;
 
-        // Failures
-        assert_that!(check_that!(vec![1]).is_empty()).facts_are(vec![
-            Fact::new_simple_fact("expected to be empty"),
-            Fact::new_splitter(),
-            Fact::new_multi_value_fact("actual", vec!["1"]),
-        ])
-    }
+    /// Checks that at least one element satisfies `predicate`. `description` describes the
+    /// predicate in failure messages.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec!["a", ""]).any_satisfy(|s| s.is_empty(), "empty");
+    /// ```
+    #[track_caller]
+    fn any_satisfy<P: Fn(&T) -> bool>(&self, predicate: P, description: &str) -> R
+    where
+        T: Debug;
 
-    #[test]
-    fn is_not_empty() {
-        assert_that!(Vec::<usize>::from([1])).is_not_empty();
+    /// Returns a new subject which yields the elements in chunks of `size` elements, as `Vec`s,
+    /// and which implements [`crate::IteratorAssertion`]. The last chunk is shorter if the
+    /// length is not a multiple of `size`. Panics if `size` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// let items: Vec<u32> = (0..250).collect();
+    /// assert_that!(items)
+    ///     .chunks(100)
+    ///     .all_satisfy(|chunk| chunk.len() <= 100, "batch size");
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3, 4, 5])
+    ///     .chunks(2)
+    ///     .all_satisfy(|chunk| chunk.len() == 2, "full batch");
+    /// // value of                        : vec![1, 2, 3, 4, 5].chunks(2)
+    /// // expected all elements to satisfy: full batch
+    /// // found (1)                       : [[5] (index 2)]
+    /// ```
+    #[track_caller]
+    fn chunks(&self, size: usize) -> Subject<'a, IntoIter<Vec<T>>, (), R>
+    where
+        T: Clone;
 
-        // Failures
-        assert_that!(check_that!(Vec::<usize>::new()).is_not_empty()).facts_are(vec![
-            Fact::new_simple_fact("expected to be non-empty"),
+    /// Returns a new subject which yields all runs of `size` consecutive elements, as `Vec`s, and
+    /// which implements [`crate::IteratorAssertion`]. Windows are indexed by the position of their
+    /// first element in failure messages. Panics if `size` is zero.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// let readings = vec![10, 12, 11, 13, 15];
+    /// assert_that!(readings)
+    ///     .windows(3)
+    ///     .any_satisfy(|w| w[0] < w[1] && w[1] < w[2], "three increasing values");
+    /// ```
+    #[track_caller]
+    fn windows(&self, size: usize) -> Subject<'a, IntoIter<Vec<T>>, (), R>
+    where
+        T: Clone;
+
+    /// Returns a new subject which yields the leading elements satisfying `predicate`, which is
+    /// described as `description` in failure messages, and which implements
+    /// [`crate::IteratorAssertion`].
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// let log = vec!["boot", "boot", "ready", "serve"];
+    /// assert_that!(log)
+    ///     .taking_while(|e| *e == "boot", "startup")
+    ///     .has_length(2);
+    /// ```
+    #[track_caller]
+    fn taking_while<P: Fn(&T) -> bool>(
+        &self,
+        predicate: P,
+        description: &str,
+    ) -> Subject<'a, IntoIter<T>, (), R>
+    where
+        T: Clone;
+
+    /// Returns a new subject which yields the elements after the leading elements satisfying
+    /// `predicate`, which is described as `description` in failure messages, and which implements
+    /// [`crate::IteratorAssertion`].
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// let log = vec!["boot", "boot", "ready", "serve"];
+    /// assert_that!(log)
+    ///     .skipping_while(|e| *e == "boot", "startup")
+    ///     .contains_exactly_in_order(["ready", "serve"].into_iter());
+    /// ```
+    #[track_caller]
+    fn skipping_while<P: Fn(&T) -> bool>(
+        &self,
+        predicate: P,
+        description: &str,
+    ) -> Subject<'a, IntoIter<T>, (), R>
+    where
+        T: Clone;
+
+    /// Returns a new subject which is the first element. Panics if the subject is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec!["start", "step", "end"]).first().is_equal_to("start");
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(Vec::<&str>::new()).first();
+    /// // expected to be non-empty to compute first()
+    /// // but was empty
+    /// ```
+    #[track_caller]
+    fn first(&self) -> Subject<'a, T, (), R>
+    where
+        T: Clone;
+
+    /// Returns a new subject which is the last element. Panics if the subject is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec!["start", "step", "end"]).last().is_equal_to("end");
+    /// ```
+    #[track_caller]
+    fn last(&self) -> Subject<'a, T, (), R>
+    where
+        T: Clone;
+
+    /// Returns a new subject which is the element at `index`. Unlike `v[index]` inside the macro,
+    /// an index out of bounds fails with the length of the subject.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec!["start", "step", "end"]).element_at(1).is_equal_to("step");
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(vec!["start", "step", "end"]).element_at(3);
+    /// // expected an element at index: 3
+    /// // but length was              : 3
+    /// // last valid index            : 2
+    /// ```
+    #[track_caller]
+    fn element_at(&self, index: usize) -> Subject<'a, T, (), R>
+    where
+        T: Clone;
+
+    /// Checks that the subject has an element equal to `expected` at `index`.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3]).has_element_at(2, 3);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3]).has_element_at(1, 5);
+    /// // value of: vec![1, 2, 3][1]
+    /// // expected: 5
+    /// // actual  : 2
+    /// ```
+    #[track_caller]
+    fn has_element_at<B>(&self, index: usize, expected: B) -> R
+    where
+        B: Borrow<T>,
+        T: PartialEq + Debug;
+
+    /// Checks that the subject is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(Vec::<usize>::new()).is_empty();
+    /// ```
+    #[track_caller]
+    fn is_empty(&self) -> R
+    where
+        T: Debug;
+
+    /// Checks that the subject is not empty.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1]).is_not_empty();
+    /// ```
+    #[track_caller]
+    fn is_not_empty(&self) -> R
+    where
+        T: Debug;
+
+    /// Checks that the subject is the given length.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3]).has_length(3);
+    /// ```
+    #[track_caller]
+    fn has_length(&self, length: usize) -> R;
+
+    /// Checks that the subject has at least `length` elements.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3]).has_length_at_least(2);
+    /// ```
+    #[track_caller]
+    fn has_length_at_least(&self, length: usize) -> R;
+
+    /// Checks that the subject has at most `length` elements.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3]).has_length_at_most(3);
+    /// ```
+    #[track_caller]
+    fn has_length_at_most(&self, length: usize) -> R;
+
+    /// Checks that the subject has at least `min` and at most `max` elements. A `min` greater than
+    /// `max` fails the assertion.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    /// assert_that!(vec![1, 2, 3]).has_length_between(2, 4);
+    /// ```
+    #[track_caller]
+    fn has_length_between(&self, min: usize, max: usize) -> R;
+}
+
+impl<'a, S, T, R> VecAssertion<'a, S, T, R> for Subject<'a, S, (), R>
+where
+    S: SequenceLike<T>,
+    AssertionResult: AssertionStrategy<R>,
+{
+    fn contains<B>(&self, element: B) -> R
+    where
+        B: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        self.new_subject(
+            &self.actual().elements_iter(),
+            self.description().clone(),
+            (),
+        )
+        .contains(element.borrow())
+    }
+
+    fn does_not_contain<B>(&self, element: B) -> R
+    where
+        B: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        self.new_owned_subject(
+            self.actual().elements_iter(),
+            self.description().clone(),
+            (),
+        )
+        .does_not_contain(element.borrow())
+    }
+
+    fn contains_exactly<E>(self, expected: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        let expected: Vec<E::Item> = expected.into_iter().collect();
+        let subject = self.new_owned_subject(
+            self.actual().elements_iter(),
+            self.description().clone(),
+            (),
+        );
+        subject.contains_exactly(expected.iter().map(Borrow::borrow))
+    }
+
+    fn has_same_elements_as<E>(&self, expected: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        let expected: Vec<E::Item> = expected.into_iter().collect();
+        check_has_same_elements(
+            self.new_result(),
+            self.actual().elements_iter().collect(),
+            expected.iter().map(Borrow::borrow).collect(),
+            false,
+        )
+    }
+
+    fn has_same_distinct_elements_as<E>(&self, expected: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        let expected: Vec<E::Item> = expected.into_iter().collect();
+        check_has_same_elements(
+            self.new_result(),
+            self.actual().elements_iter().collect(),
+            expected.iter().map(Borrow::borrow).collect(),
+            true,
+        )
+    }
+
+    fn contains_exactly_in_order<E>(self, expected: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        let expected: Vec<E::Item> = expected.into_iter().collect();
+        let subject = self.new_owned_subject(
+            self.actual().elements_iter(),
+            self.description().clone(),
+            (),
+        );
+        subject.contains_exactly_in_order(expected.iter().map(Borrow::borrow))
+    }
+
+    fn does_not_contain_any<E>(&self, elements: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        let elements: Vec<E::Item> = elements.into_iter().collect();
+        let subject = self.new_owned_subject(
+            self.actual().elements_iter(),
+            self.description().clone(),
+            (),
+        );
+        subject.does_not_contain_any(elements.iter().map(Borrow::borrow))
+    }
+
+    fn contains_any_of<E>(&self, elements: E) -> R
+    where
+        E: IntoIterator,
+        E::Item: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        let elements: Vec<E::Item> = elements.into_iter().collect();
+        check_contains_any_of(
+            self.new_result(),
+            self.actual().elements_iter(),
+            elements.iter().map(Borrow::borrow),
+        )
+    }
+
+    fn contains_element_with<P: Fn(&T) -> bool>(&self, predicate: P, description: &str) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        if self.actual().elements_iter().any(predicate) {
+            self.new_result().do_ok()
+        } else {
+            self.new_result()
+                .add_fact("expected to contain an element matching", description)
+                .add_simple_fact("but did not")
+                .add_formatted_values_fact(
+                    FactKey::ThoughItDidContain,
+                    self.actual().elements_iter().collect(),
+                )
+                .do_fail()
+        }
+    }
+
+    fn matching_count<P: Fn(&T) -> bool>(&self, predicate: P) -> Subject<'a, usize, (), R> {
+        self.new_owned_subject(
+            self.actual()
+                .elements_iter()
+                .filter(|el| predicate(el))
+                .count(),
+            Some(format!("{}.matching_count(..)", self.description_or_expr())),
+            (),
+        )
+    }
+
+    fn reversed(&self) -> Subject<'a, Vec<T>, (), R>
+    where
+        T: Clone,
+    {
+        let mut elements: Vec<T> = self.actual().elements_iter().cloned().collect();
+        elements.reverse();
+        derived_vec(self, elements, "reversed()")
+    }
+
+    fn sorted(&self) -> Subject<'a, Vec<T>, (), R>
+    where
+        T: Clone + Ord,
+    {
+        let mut elements: Vec<T> = self.actual().elements_iter().cloned().collect();
+        elements.sort();
+        derived_vec(self, elements, "sorted()")
+    }
+
+    fn sorted_by<F: FnMut(&T, &T) -> Ordering>(&self, compare: F) -> Subject<'a, Vec<T>, (), R>
+    where
+        T: Clone,
+    {
+        let mut elements: Vec<T> = self.actual().elements_iter().cloned().collect();
+        elements.sort_by(compare);
+        derived_vec(self, elements, "sorted_by(..)")
+    }
+
+    fn distinct(&self) -> Subject<'a, Vec<T>, (), R>
+    where
+        T: Clone + PartialEq,
+    {
+        let elements = distinct(self.actual().elements_iter().cloned());
+        derived_vec(self, elements, "distinct()")
+    }
+
+    fn counted(&self) -> Subject<'a, BTreeMap<T, usize>, (), R>
+    where
+        T: Clone + Ord,
+    {
+        self.new_owned_subject(
+            counted(self.actual().elements_iter().cloned()),
+            Some(format!("{}.counted()", self.description_or_expr())),
+            (),
+        )
+    }
+
+    fn sum(&self) -> Subject<'a, T, (), R>
+    where
+        T: Clone + Sum,
+    {
+        let sum = self.actual().elements_iter().cloned().sum();
+        aggregated(self, Some(sum), "sum()")
+    }
+
+    fn min(&self) -> Subject<'a, T, (), R>
+    where
+        T: Clone + PartialOrd,
+    {
+        let min = extreme(self.actual().elements_iter().cloned(), Ordering::Less);
+        aggregated(self, min, "min()")
+    }
+
+    fn max(&self) -> Subject<'a, T, (), R>
+    where
+        T: Clone + PartialOrd,
+    {
+        let max = extreme(self.actual().elements_iter().cloned(), Ordering::Greater);
+        aggregated(self, max, "max()")
+    }
+
+    fn mean(&self) -> Subject<'a, f64, (), R>
+    where
+        T: Clone + Into<f64>,
+    {
+        aggregated(self, mean(self.actual().elements_iter().cloned()), "mean()")
+    }
+
+    fn percentile(&self, p: f64) -> Subject<'a, f64, (), R>
+    where
+        T: Clone + Into<f64>,
+    {
+        let value = percentile(self, self.actual().elements_iter().cloned(), p);
+        aggregated(self, value, &format!("percentile({:?})", p))
+    }
+
+    fn std_dev(&self) -> Subject<'a, f64, (), R>
+    where
+        T: Clone + Into<f64>,
+    {
+        let std_dev = std_dev(self.actual().elements_iter().cloned());
+        aggregated(self, std_dev, "std_dev()")
+    }
+
+    fn all_within<B: RangeBounds<T> + Debug>(&self, range: B) -> R
+    where
+        T: PartialOrd + Debug,
+    {
+        pass_if_skipped!(self);
+        check_all_within::<_, _, T, _, _>(self.new_result(), self.actual().elements_iter(), &range)
+    }
+
+    fn are_all_true(&self) -> R
+    where
+        T: Borrow<bool>,
+    {
+        pass_if_skipped!(self);
+        check_all_equal_to(
+            self.new_result(),
+            self.actual().elements_iter().map(|el| *el.borrow()),
+            true,
+        )
+    }
+
+    fn are_all_false(&self) -> R
+    where
+        T: Borrow<bool>,
+    {
+        pass_if_skipped!(self);
+        check_all_equal_to(
+            self.new_result(),
+            self.actual().elements_iter().map(|el| *el.borrow()),
+            false,
+        )
+    }
+
+    fn all_satisfy<P: Fn(&T) -> bool>(&self, predicate: P, description: &str) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_all_satisfy(
+            self.new_result(),
+            self.actual().elements_iter(),
+            |el: &&T| predicate(el),
+            description,
+        )
+    }
+
+    fn any_satisfy<P: Fn(&T) -> bool>(&self, predicate: P, description: &str) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_any_satisfy(
+            self.new_result(),
+            self.actual().elements_iter(),
+            |el: &&T| predicate(el),
+            description,
+        )
+    }
+
+    fn chunks(&self, size: usize) -> Subject<'a, IntoIter<Vec<T>>, (), R>
+    where
+        T: Clone,
+    {
+        derived_chunks(self, self.actual().elements_iter().cloned(), size)
+    }
+
+    fn windows(&self, size: usize) -> Subject<'a, IntoIter<Vec<T>>, (), R>
+    where
+        T: Clone,
+    {
+        derived_windows(self, self.actual().elements_iter().cloned(), size)
+    }
+
+    fn taking_while<P: Fn(&T) -> bool>(
+        &self,
+        predicate: P,
+        description: &str,
+    ) -> Subject<'a, IntoIter<T>, (), R>
+    where
+        T: Clone,
+    {
+        let elements = self
+            .actual()
+            .elements_iter()
+            .take_while(|el| predicate(el))
+            .cloned();
+        derived_region(self, elements, "taking_while", description)
+    }
+
+    fn skipping_while<P: Fn(&T) -> bool>(
+        &self,
+        predicate: P,
+        description: &str,
+    ) -> Subject<'a, IntoIter<T>, (), R>
+    where
+        T: Clone,
+    {
+        let elements = self
+            .actual()
+            .elements_iter()
+            .skip_while(|el| predicate(el))
+            .cloned();
+        derived_region(self, elements, "skipping_while", description)
+    }
+
+    fn first(&self) -> Subject<'a, T, (), R>
+    where
+        T: Clone,
+    {
+        aggregated(
+            self,
+            self.actual().elements_iter().next().cloned(),
+            "first()",
+        )
+    }
+
+    fn last(&self) -> Subject<'a, T, (), R>
+    where
+        T: Clone,
+    {
+        aggregated(
+            self,
+            self.actual().elements_iter().last().cloned(),
+            "last()",
+        )
+    }
+
+    fn element_at(&self, index: usize) -> Subject<'a, T, (), R>
+    where
+        T: Clone,
+    {
+        match self.actual().elements_iter().nth(index) {
+            Some(element) => self.new_owned_subject(
+                element.clone(),
+                Some(format!("{}[{}]", self.description_or_expr(), index)),
+                (),
+            ),
+            None => {
+                // Like `aggregated`, a derived subject can not be returned, so panic.
+                let result = index_out_of_bounds(self, index);
+                result.fail_derivation()
+            }
+        }
+    }
+
+    fn has_element_at<B>(&self, index: usize, expected: B) -> R
+    where
+        B: Borrow<T>,
+        T: PartialEq + Debug,
+    {
+        pass_if_skipped!(self);
+        match self.actual().elements_iter().nth(index) {
+            Some(element) => self
+                .new_subject(
+                    element,
+                    Some(format!("{}[{}]", self.description_or_expr(), index)),
+                    (),
+                )
+                .is_equal_to(expected.borrow()),
+            None => index_out_of_bounds(self, index).do_fail(),
+        }
+    }
+
+    fn is_empty(&self) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_is_empty(self.new_result(), self.actual().elements_iter())
+    }
+
+    fn is_not_empty(&self) -> R
+    where
+        T: Debug,
+    {
+        pass_if_skipped!(self);
+        check_is_not_empty(self.new_result(), self.actual().elements_iter())
+    }
+
+    fn has_length(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_has_length(
+            self.new_result(),
+            self.actual().elements_iter(),
+            self.description_or_expr(),
+            length,
+        )
+    }
+
+    fn has_length_at_least(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().elements_iter().count(),
+            format!("{}.size()", self.description_or_expr()),
+            LengthBound::AtLeast(length),
+        )
+    }
+
+    fn has_length_at_most(&self, length: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().elements_iter().count(),
+            format!("{}.size()", self.description_or_expr()),
+            LengthBound::AtMost(length),
+        )
+    }
+
+    fn has_length_between(&self, min: usize, max: usize) -> R {
+        pass_if_skipped!(self);
+        check_len_bound(
+            self.new_result(),
+            self.actual().elements_iter().count(),
+            format!("{}.size()", self.description_or_expr()),
+            LengthBound::Between(min, max),
+        )
+    }
+}
+
+/// Facts about an `index` beyond the elements of `subject`, with the valid indices.
+fn index_out_of_bounds<S, T, R>(subject: &Subject<S, (), R>, index: usize) -> AssertionResult
+where
+    S: SequenceLike<T>,
+{
+    let length = subject.actual().elements_iter().count();
+    index::index_out_of_bounds(subject.new_result(), index, length)
+}
+
+/// Derives a `Vec` subject described as `<subject>.<method>`.
+fn derived_vec<'a, S, T, R>(
+    subject: &Subject<'a, S, (), R>,
+    elements: Vec<T>,
+    method: &str,
+) -> Subject<'a, Vec<T>, (), R> {
+    subject.new_owned_subject(
+        elements,
+        Some(format!("{}.{}", subject.description_or_expr(), method)),
+        (),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::{EqualityAssertion, MapAssertion};
+
+    use super::*;
+
+    #[test]
+    fn contains() {
+        assert_that!(vec![1, 2, 3]).contains(3);
+
+        // Failures
+        assert_that!(check_that!(vec![1, 2, 3]).contains(10)).facts_are(vec![
+            Fact::new("expected to contain", "10"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1", "2", "3"]),
+        ]);
+    }
+
+    #[test]
+    fn contains_exactly() {
+        assert_that!(vec![1, 2, 3]).contains_exactly(vec![1, 2, 3]);
+        assert_that!(vec![2, 1, 3]).contains_exactly(vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn empty_expected() {
+        let empty: Vec<i32> = vec![];
+        assert_that!(check_that!(vec![1, 2]).contains_exactly(&empty)).facts_are_at_least(vec![
+            Fact::new("note", "expected collection is empty"),
+            Fact::new(
+                "help",
+                "use is_empty to check that the subject has no elements",
+            ),
+        ]);
+        assert_that!(check_that!(vec![1, 2])
+            .reject_empty_expected()
+            .does_not_contain_any(&empty))
+        .facts_are_at_least(vec![Fact::new_simple_fact("expected collection is empty")]);
+    }
+
+    #[test]
+    #[allow(clippy::needless_borrows_for_generic_args)]
+    fn expected_collections() {
+        let expected = vec![1, 2, 3];
+        assert_that!(vec![1, 2, 3]).contains_exactly([1, 2, 3]);
+        assert_that!(vec![1, 2, 3]).contains_exactly(&[1, 2, 3]);
+        assert_that!(vec![1, 2, 3]).contains_exactly(&expected);
+        assert_that!(vec![1, 2, 3]).contains_exactly(expected.as_slice());
+        assert_that!(vec![1, 2, 3]).contains_exactly_in_order([1, 2, 3]);
+        assert_that!(vec![1, 2, 3]).contains_exactly_in_order(&expected);
+        assert_that!(vec![1, 2, 3]).does_not_contain_any([4, 5]);
+        assert_that!(vec![1, 2, 3]).does_not_contain_any(&[4, 5]);
+        assert_that!(vec![1, 2, 3]).contains_any_of([5, 2]);
+        assert_that!(vec![1, 2, 3]).contains_any_of(&[5, 2]);
+        assert_that!(vec![1, 2, 3]).contains_any_of(3..=5);
+    }
+
+    #[test]
+    fn has_same_elements_as() {
+        assert_that!(vec!["b", "a"]).has_same_elements_as(["a", "b"]);
+        assert_that!(vec!["a", "b", "a"]).has_same_distinct_elements_as(["b", "a"]);
+
+        assert_that!(check_that!(vec!["a", "b", "a"]).has_same_elements_as(["b", "a"])).facts_are(
+            vec![
+                Fact::new_simple_fact("expected the same elements, ignoring order"),
+                Fact::new_multi_value_fact("only in actual (1)", vec![r#""a""#]),
+                Fact::new("in both", "2"),
+            ],
+        );
+    }
+
+    #[test]
+    fn contains_exactly_fail_fast() {
+        assert_that!(vec![1, 2])
+            .fail_fast()
+            .contains_exactly(vec![2, 1]);
+
+        // Failures
+        assert_that!(check_that!(vec![1, 2, 3])
+            .fail_fast()
+            .contains_exactly_in_order(vec![1, 2, 4]))
+        .facts_are(vec![
+            Fact::new("first difference at", "index 2"),
+            Fact::new("expected", "4"),
+            Fact::new("actual", "3"),
+            Fact::new(
+                "note",
+                "stopped after first difference; rerun with full_diff() for details",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn contains_exactly_in_order() {
+        assert_that!(vec![1, 2, 3]).contains_exactly_in_order(vec![1, 2, 3]);
+        assert_that!(check_that!(vec![2, 1, 3]).contains_exactly_in_order(vec![1, 2, 3])).facts_are(
+            vec![
+                Fact::new_simple_fact("contents match, but order was wrong"),
+                Fact::new_splitter(),
+                Fact::new_multi_value_fact("expected", vec!["1", "2", "3"]),
+                Fact::new_multi_value_fact("actual", vec!["2", "1", "3"]),
+                Fact::new("help", "use contains_exactly if the order does not matter"),
+            ],
+        )
+    }
+
+    #[test]
+    fn contains_any_of() {
+        assert_that!(vec![1, 2, 3]).contains_any_of(vec![5, 2]);
+
+        // Failures
+        assert_that!(check_that!(vec![1, 2]).contains_any_of(vec![3])).facts_are(vec![
+            Fact::new_multi_value_fact("expected to contain any of", vec!["3"]),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1", "2"]),
+        ]);
+    }
+
+    #[test]
+    fn contains_element_with() {
+        use crate::EqualityAssertion;
+
+        #[derive(Debug)]
+        struct User {
+            id: u32,
+        }
+        let users = vec![User { id: 3 }, User { id: 7 }];
+        assert_that!(users).contains_element_with(|u| u.id == 7, "id == 7");
+        assert_that!(users)
+            .matching_count(|u| u.id > 1)
+            .is_equal_to(2);
+
+        // Failures
+        assert_that!(check_that!(users).contains_element_with(|u| u.id == 1, "id == 1")).facts_are(
+            vec![
+                Fact::new("expected to contain an element matching", "id == 1"),
+                Fact::new_simple_fact("but did not"),
+                Fact::new_multi_value_fact(
+                    "though it did contain",
+                    vec!["User { id: 3 }", "User { id: 7 }"],
+                ),
+            ],
+        );
+        assert_that!(check_that!(users)
+            .matching_count(|u| u.id > 5)
+            .is_equal_to(2))
+        .facts_are(vec![
+            Fact::new("value of", "users.matching_count(..)"),
+            Fact::new("expected", "2"),
+            Fact::new("actual", "1"),
+        ]);
+    }
+
+    #[test]
+    fn element_at() {
+        let v = vec!["a", "b", "c"];
+        assert_that!(v).element_at(0).is_equal_to("a");
+        assert_that!(v).element_at(2).is_equal_to("c");
+        assert_that!(VecDeque::from([1, 2]))
+            .element_at(1)
+            .is_equal_to(2);
+        assert_that!(v).has_element_at(1, "b");
+
+        // Failures
+        assert_that!(check_that!(v).element_at(1).is_equal_to("x")).facts_are(vec![
+            Fact::new("value of", "v[1]"),
+            Fact::new("expected", r#""x""#),
+            Fact::new("actual", r#""b""#),
+        ]);
+        assert_that!(check_that!(v).has_element_at(2, "b")).facts_are(vec![
+            Fact::new("value of", "v[2]"),
+            Fact::new("expected", r#""b""#),
+            Fact::new("actual", r#""c""#),
+        ]);
+        assert_that!(check_that!(v).has_element_at(5, "b")).facts_are(vec![
+            Fact::new("expected an element at index", "5"),
+            Fact::new("but length was", "3"),
+            Fact::new("last valid index", "2"),
+        ]);
+        assert_that!(check_that!(Vec::<i32>::new()).has_element_at(0, 1)).facts_are(vec![
+            Fact::new("expected an element at index", "0"),
+            Fact::new_simple_fact("but was empty"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected an element at index: 3")]
+    fn element_at_out_of_bounds() {
+        assert_that!(vec![1, 2, 3]).element_at(3);
+    }
+
+    #[test]
+    fn is_empty() {
+        assert_that!(Vec::<usize>::new()).is_empty();
+
+        // Failures
+        assert_that!(check_that!(vec![1]).is_empty()).facts_are(vec![
+            Fact::new_simple_fact("expected to be empty"),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("actual", vec!["1"]),
+        ])
+    }
+
+    #[test]
+    fn is_not_empty() {
+        assert_that!(Vec::<usize>::from([1])).is_not_empty();
+
+        // Failures
+        assert_that!(check_that!(Vec::<usize>::new()).is_not_empty()).facts_are(vec![
+            Fact::new_simple_fact("expected to be non-empty"),
             Fact::new_splitter(),
             Fact::new("actual", "[]"),
         ])
@@ -300,4 +1462,351 @@ mod tests {
             Fact::new("actual", "0"),
         ]);
     }
+
+    #[test]
+    fn has_length_bounds() {
+        assert_that!(vec![1, 2, 3]).has_length_at_least(3);
+        assert_that!(vec![1, 2, 3]).has_length_at_most(3);
+        assert_that!(Vec::<usize>::new()).has_length_at_most(0);
+
+        // Failures
+        assert_that!(check_that!(vec![1, 2, 3]).has_length_at_least(4)).facts_are(vec![
+            Fact::new("value of", "vec![1, 2, 3].size()"),
+            Fact::new("expected", "at least 4"),
+            Fact::new("actual", "3"),
+        ]);
+        assert_that!(check_that!(vec![1, 2, 3]).has_length_at_most(1)).facts_are(vec![
+            Fact::new("value of", "vec![1, 2, 3].size()"),
+            Fact::new("expected", "at most 1"),
+            Fact::new("actual", "3"),
+        ]);
+    }
+
+    #[test]
+    fn has_length_between() {
+        assert_that!(vec![1, 2, 3]).has_length_between(3, 5);
+        assert_that!(vec![1, 2, 3]).has_length_between(1, 3);
+        assert_that!(Vec::<usize>::new()).has_length_between(0, 0);
+
+        // Failures
+        assert_that!(check_that!(vec![1, 2, 3]).has_length_between(4, 8)).facts_are(vec![
+            Fact::new("value of", "vec![1, 2, 3].size()"),
+            Fact::new("expected", "between 4 and 8"),
+            Fact::new("actual", "3"),
+        ]);
+        assert_that!(check_that!(vec![1, 2, 3]).has_length_between(3, 2)).facts_are(vec![
+            Fact::new("invalid length range", "between 3 and 2"),
+            Fact::new_simple_fact("min must not exceed max"),
+        ]);
+    }
+
+    #[test]
+    fn vec_deque() {
+        let deque: VecDeque<i32> = VecDeque::from([1, 2, 3]);
+        assert_that!(deque).contains(2);
+        assert_that!(deque).has_length(3);
+        assert_that!(deque).contains_exactly_in_order(vec![1, 2, 3]);
+
+        // Failures
+        assert_that!(check_that!(deque).contains_exactly_in_order(vec![1, 3, 2])).facts_are(vec![
+            Fact::new_simple_fact("contents match, but order was wrong"),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("expected", vec!["1", "3", "2"]),
+            Fact::new_multi_value_fact("actual", vec!["1", "2", "3"]),
+            Fact::new("help", "use contains_exactly if the order does not matter"),
+        ]);
+    }
+
+    #[test]
+    fn smart_pointers() {
+        assert_that!(Box::new(vec![1, 2, 3])).contains(2);
+        assert_that!(Rc::new(vec![1, 2, 3])).has_length(3);
+        assert_that!(Arc::new(VecDeque::from([1, 2]))).contains_exactly_in_order(vec![1, 2]);
+
+        // Failures
+        assert_that!(check_that!(Arc::new(vec![1, 2])).has_length(3)).facts_are(vec![
+            Fact::new("value of", "Arc::new(vec![1, 2]).size()"),
+            Fact::new("expected", "3"),
+            Fact::new("actual", "2"),
+        ]);
+    }
+
+    #[test]
+    fn cow_slices() {
+        use crate::CowAssertion;
+
+        let words = vec!["a", "b", "c"];
+        let borrowed: Cow<[&str]> = Cow::Borrowed(&words);
+        assert_that!(borrowed).is_borrowed();
+        assert_that!(borrowed).contains("b");
+        assert_that!(borrowed).contains_exactly_in_order(vec!["a", "b", "c"]);
+        let owned: Cow<[&str]> = Cow::Owned(vec!["a", "c"]);
+        assert_that!(owned).is_owned();
+        assert_that!(owned).contains_exactly(vec!["c", "a"]);
+
+        // Failures
+        assert_that!(check_that!(owned).contains_exactly_in_order(vec!["a", "b", "c"])).facts_are(
+            vec![
+                Fact::new("missing (1)", r#"["b"]"#),
+                Fact::new_splitter(),
+                Fact::new_multi_value_fact("expected", vec![r#""a""#, r#""b""#, r#""c""#]),
+                Fact::new_multi_value_fact("actual", vec![r#""a""#, r#""c""#]),
+            ],
+        );
+    }
+
+    #[test]
+    fn custom_sequence() {
+        /// Ring buffer whose elements start at `head`.
+        struct Ring {
+            buffer: Vec<i32>,
+            head: usize,
+        }
+
+        impl SequenceLike<i32> for Ring {
+            type It<'a> = std::iter::Chain<std::slice::Iter<'a, i32>, std::slice::Iter<'a, i32>>;
+
+            fn elements_iter<'a>(&'a self) -> Self::It<'a> {
+                let (tail, head) = self.buffer.split_at(self.head);
+                head.iter().chain(tail.iter())
+            }
+        }
+
+        let ring = Ring {
+            buffer: vec![3, 1, 2],
+            head: 1,
+        };
+        assert_that!(ring).contains_exactly_in_order(vec![1, 2, 3]);
+        assert_that!(check_that!(ring).contains(4)).facts_are(vec![
+            Fact::new("expected to contain", "4"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1", "2", "3"]),
+        ]);
+    }
+
+    #[test]
+    fn reversed_and_sorted() {
+        assert_that!(vec![1, 2, 3])
+            .reversed()
+            .contains_exactly_in_order(vec![3, 2, 1]);
+        assert_that!(VecDeque::from([3, 1, 2]))
+            .sorted()
+            .contains_exactly_in_order(vec![1, 2, 3]);
+        assert_that!(vec![(1, 'b'), (0, 'z'), (1, 'a')])
+            .sorted_by(|a, b| a.0.cmp(&b.0))
+            .contains_exactly_in_order(vec![(0, 'z'), (1, 'b'), (1, 'a')]);
+
+        // Failures
+        assert_that!(check_that!(vec![3, 1, 2])
+            .sorted()
+            .reversed()
+            .contains_exactly_in_order(vec![3, 1, 2]))
+        .facts_are(vec![
+            Fact::new("value of", "vec![3, 1, 2].sorted().reversed()"),
+            Fact::new_simple_fact("contents match, but order was wrong"),
+            Fact::new_splitter(),
+            Fact::new_multi_value_fact("expected", vec!["3", "1", "2"]),
+            Fact::new_multi_value_fact("actual", vec!["3", "2", "1"]),
+            Fact::new("help", "use contains_exactly if the order does not matter"),
+        ]);
+        assert_that!(check_that!(vec!["bb", "a"])
+            .sorted_by(|a, b| a.len().cmp(&b.len()))
+            .has_length(3))
+        .facts_are(vec![
+            Fact::new("value of", r#"vec!["bb", "a"].sorted_by(..)"#),
+            Fact::new("value of", r#"vec!["bb", "a"].sorted_by(..).size()"#),
+            Fact::new("expected", "3"),
+            Fact::new("actual", "2"),
+        ]);
+    }
+
+    #[test]
+    fn distinct() {
+        assert_that!(vec![3, 1, 3, 2, 1])
+            .distinct()
+            .contains_exactly_in_order(vec![3, 1, 2]);
+
+        // Failures
+        assert_that!(check_that!(vec![3, 1, 3]).distinct().has_length(3)).facts_are(vec![
+            Fact::new("value of", "vec![3, 1, 3].distinct()"),
+            Fact::new("value of", "vec![3, 1, 3].distinct().size()"),
+            Fact::new("expected", "3"),
+            Fact::new("actual", "2"),
+        ]);
+    }
+
+    #[test]
+    fn counted() {
+        assert_that!(vec!["a", "b", "a"])
+            .counted()
+            .contains_entry("a", 2);
+        assert_that!(Vec::<u8>::new()).counted().is_empty();
+
+        // Failures
+        assert_that!(check_that!(vec!["a", "b"]).counted().contains_entry("c", 1)).facts_are(vec![
+            Fact::new("value of", r#"vec!["a", "b"].counted()"#),
+            Fact::new("expected key to be mapped to value", r#""c" ⟶ 1"#),
+            Fact::new("but key was not found", r#""c""#),
+            Fact::new_splitter(),
+            Fact::new("though it did contain keys", r#"["a", "b"]"#),
+        ]);
+    }
+
+    #[test]
+    fn aggregations() {
+        assert_that!(vec![1, 2, 3]).sum().is_equal_to(6);
+        assert_that!(VecDeque::from([2.5, 0.5]))
+            .min()
+            .is_equal_to(0.5);
+        assert_that!(vec!["b", "c", "a"]).max().is_equal_to("c");
+        assert_that!(vec![1_i32, -3]).mean().is_equal_to(-1.0);
+
+        // Failures
+        let latencies = vec![4.0, 8.0];
+        assert_that!(check_that!(latencies).mean().is_equal_to(5.0)).facts_are(vec![
+            Fact::new("value of", "latencies.mean()"),
+            Fact::new("expected", "5.0"),
+            Fact::new("actual", "6.0"),
+        ]);
+        assert_that!(check_that!(vec![1, 2]).distinct().sum().is_equal_to(4)).facts_are(vec![
+            Fact::new("value of", "vec![1, 2].distinct().sum()"),
+            Fact::new("expected", "4"),
+            Fact::new("actual", "3"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected to be non-empty to compute mean()\nbut was empty")]
+    fn mean_of_empty() {
+        assert_that!(Vec::<f64>::new()).mean();
+    }
+
+    #[test]
+    fn statistics() {
+        let latencies = vec![12, 15, 11, 90, 14];
+        assert_that!(latencies).percentile(50.0).is_equal_to(14.0);
+        assert_that!(vec![1.0_f32, 3.0]).std_dev().is_equal_to(1.0);
+        assert_that!(latencies).all_within(10..=90);
+
+        // Failures
+        assert_that!(check_that!(latencies).percentile(25.0).is_equal_to(11.0)).facts_are(vec![
+            Fact::new("value of", "latencies.percentile(25.0)"),
+            Fact::new("expected", "11.0"),
+            Fact::new("actual", "12.0"),
+        ]);
+        assert_that!(check_that!(latencies).all_within(..50)).facts_are(vec![
+            Fact::new("expected all elements within", "..50"),
+            Fact::new_multi_value_fact("found (1)", vec!["90 (index 3)"]),
+        ]);
+    }
+
+    #[test]
+    fn all_satisfy() {
+        assert_that!(vec!["a", "bc"]).all_satisfy(|s| !s.is_empty(), "non-empty");
+
+        // Failures
+        assert_that!(check_that!(vec!["a", "", "c"]).all_satisfy(|s| !s.is_empty(), "non-empty"))
+            .facts_are(vec![
+                Fact::new("expected all elements to satisfy", "non-empty"),
+                Fact::new_multi_value_fact("found (1)", vec![r#""" (index 1)"#]),
+            ]);
+    }
+
+    #[test]
+    fn first_and_last() {
+        let steps = vec!["start", "step", "end"];
+        assert_that!(steps).first().is_equal_to("start");
+        assert_that!(steps).last().is_equal_to("end");
+        assert_that!(VecDeque::from([1, 2])).last().is_equal_to(2);
+
+        // Failures
+        assert_that!(check_that!(steps).first().is_equal_to("end")).facts_are(vec![
+            Fact::new("value of", "steps.first()"),
+            Fact::new("expected", r#""end""#),
+            Fact::new("actual", r#""start""#),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected to be non-empty to compute last()\nbut was empty")]
+    fn last_of_empty() {
+        assert_that!(Vec::<i32>::new()).last();
+    }
+
+    #[test]
+    fn chunks() {
+        let items: Vec<u32> = (0..5).collect();
+        assert_that!(items)
+            .chunks(2)
+            .contains_exactly_in_order([vec![0, 1], vec![2, 3], vec![4]].into_iter());
+        assert_that!(items)
+            .chunks(5)
+            .all_satisfy(|chunk| chunk.len() == 5, "full batch");
+
+        // Failures
+        assert_that!(check_that!(items)
+            .chunks(2)
+            .all_satisfy(|chunk| chunk.len() == 2, "full batch"))
+        .facts_are(vec![
+            Fact::new("value of", "items.chunks(2)"),
+            Fact::new("expected all elements to satisfy", "full batch"),
+            Fact::new_multi_value_fact("found (1)", vec!["[4] (index 2)"]),
+        ]);
+    }
+
+    #[test]
+    fn windows() {
+        let readings: Vec<u32> = vec![10, 12, 11, 13, 15];
+        assert_that!(readings).windows(2).contains(vec![11, 13]);
+        assert_that!(readings).any_satisfy(|r| *r > 14, "above 14");
+
+        // Failures
+        assert_that!(check_that!(readings)
+            .windows(2)
+            .all_satisfy(|w| w[0] < w[1], "increasing"))
+        .facts_are(vec![
+            Fact::new("value of", "readings.windows(2)"),
+            Fact::new("expected all elements to satisfy", "increasing"),
+            Fact::new_multi_value_fact("found (1)", vec!["[12, 11] (index 1)"]),
+        ]);
+        assert_that!(check_that!(readings).any_satisfy(|r| *r > 20, "above 20")).facts_are(vec![
+            Fact::new("expected any element to satisfy", "above 20"),
+            Fact::new_simple_fact("but none did"),
+            Fact::new_multi_value_fact("though it did contain", vec!["10", "12", "11", "13", "15"]),
+        ]);
+    }
+
+    #[test]
+    fn taking_and_skipping_while() {
+        let log = vec!["boot", "boot", "ready", "serve"];
+        assert_that!(log)
+            .taking_while(|e| *e == "boot", "startup")
+            .contains_exactly_in_order(["boot", "boot"].into_iter());
+        assert_that!(log)
+            .skipping_while(|e| *e == "boot", "startup")
+            .contains_exactly_in_order(["ready", "serve"].into_iter());
+
+        // Failures
+        assert_that!(check_that!(log)
+            .skipping_while(|e| *e == "boot", "startup")
+            .contains("boot"))
+        .facts_are(vec![
+            Fact::new("value of", "log.skipping_while(startup)"),
+            Fact::new("expected to contain", r#""boot""#),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec![r#""ready""#, r#""serve""#]),
+        ]);
+    }
+
+    #[test]
+    fn are_all_true_and_false() {
+        assert_that!(vec![true, true]).are_all_true();
+        assert_that!(VecDeque::from([false])).are_all_false();
+
+        // Failures
+        assert_that!(check_that!(vec![false, true, false]).are_all_false()).facts_are(vec![
+            Fact::new("expected all elements to be", "false"),
+            Fact::new("but was true at indices", "[1]"),
+        ]);
+    }
 }
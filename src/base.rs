@@ -12,10 +12,24 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::{DefaultHasher, RandomState};
+use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Debug;
+use std::hash::{BuildHasher, Hash, Hasher};
 use std::marker::PhantomData;
 use std::ops::Deref;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use crate::assertions::basic::pattern_mismatch;
+use crate::catalog;
+use crate::diff::debug::{flatten_wrappers, group_digits};
+use crate::error::{self, AssertionError};
+use crate::stats;
+use crate::timing;
 
 /// An assertion macro that panics when the assertion fails.
 #[macro_export]
@@ -23,10 +37,375 @@ macro_rules! assert_that {
     ($actual:expr) => {
         $crate::Subject::new(
             &$actual,
-            stringify!($actual)
-                .to_string()
-                .replace(" ", "")
-                .replace("\n", ""),
+            $crate::expr_text(stringify!($actual)),
+            /* description= */ None,
+            /* option= */ (),
+            Some($crate::Location::new(
+                file!().to_string(),
+                line!(),
+                column!(),
+            )),
+            std::marker::PhantomData::<()>,
+        )
+    };
+}
+
+/// Like [`assert_that`] followed by an assertion, but only checked in builds with debug assertions
+/// enabled, like [`debug_assert`]. The assertion is given after the actual value, with its
+/// arguments and any derived subjects before it.
+///
+/// Without debug assertions, neither the actual value nor the arguments of the assertion are
+/// evaluated, so it can check invariants in non-test code at no cost in release builds.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// fn average(values: &[u32]) -> u32 {
+///     debug_assert_that!(values.len(), is_greater_than(0));
+///     values.iter().sum::<u32>() / values.len() as u32
+/// }
+///
+/// assert_eq!(average(&[1, 2, 3]), 2);
+/// debug_assert_that!("a, b", split(", ").has_length(2));
+/// ```
+#[macro_export]
+macro_rules! debug_assert_that {
+    ($actual:expr, $($assertion:tt)+) => {
+        if cfg!(debug_assertions) {
+            $crate::assert_that!($actual).$($assertion)+;
+        }
+    };
+}
+
+/// Checks an assertion at compile time, for invariants such as the size of a type. The assertion
+/// is given after the actual value like in [`debug_assert_that`], and is one of `is_equal_to`,
+/// `is_not_equal_to`, `is_less_than`, `is_greater_than`, `is_at_most`, `is_at_least`, `is_true`
+/// and `is_false`.
+///
+/// Both values must be const-evaluable, so they are limited to integers, `bool` and `char`. As
+/// const panics can not format values, a failure is a compile error which shows the expressions
+/// rather than the values, e.g. `expected size_of::<Header>() to be at most 16`.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// use std::mem::size_of;
+///
+/// struct Header {
+///     id: u32,
+///     flags: u16,
+/// }
+///
+/// const_assert_that!(size_of::<Header>(), is_at_most(8));
+/// const_assert_that!(u16::MAX as u32, is_less_than(u32::MAX));
+/// const_assert_that!(cfg!(debug_assertions) || cfg!(not(debug_assertions)), is_true());
+/// ```
+#[macro_export]
+macro_rules! const_assert_that {
+    ($actual:expr, is_equal_to($expected:expr)) => {
+        $crate::const_assert_that!(@check $actual == $expected, $actual, "to be equal to", $expected);
+    };
+    ($actual:expr, is_not_equal_to($expected:expr)) => {
+        $crate::const_assert_that!(@check $actual != $expected, $actual, "to not be equal to", $expected);
+    };
+    ($actual:expr, is_less_than($expected:expr)) => {
+        $crate::const_assert_that!(@check $actual < $expected, $actual, "to be less than", $expected);
+    };
+    ($actual:expr, is_greater_than($expected:expr)) => {
+        $crate::const_assert_that!(@check $actual > $expected, $actual, "to be greater than", $expected);
+    };
+    ($actual:expr, is_at_most($expected:expr)) => {
+        $crate::const_assert_that!(@check $actual <= $expected, $actual, "to be at most", $expected);
+    };
+    ($actual:expr, is_at_least($expected:expr)) => {
+        $crate::const_assert_that!(@check $actual >= $expected, $actual, "to be at least", $expected);
+    };
+    ($actual:expr, is_true()) => {
+        const _: () = assert!($actual, concat!("expected ", stringify!($actual), " to be true"));
+    };
+    ($actual:expr, is_false()) => {
+        const _: () = assert!(!$actual, concat!("expected ", stringify!($actual), " to be false"));
+    };
+    (@check $holds:expr, $actual:expr, $relation:literal, $expected:expr) => {
+        const _: () = assert!(
+            $holds,
+            concat!(
+                "expected ",
+                stringify!($actual),
+                " ",
+                $relation,
+                " ",
+                stringify!($expected)
+            )
+        );
+    };
+}
+
+/// Makes a subject of a closure computing the actual value, to check how the value evolves over
+/// time with [`CodeAssertion`](crate::CodeAssertion).
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use std::sync::Arc;
+/// use std::time::Duration;
+///
+/// let ready = Arc::new(AtomicBool::new(false));
+/// let worker = {
+///     let ready = Arc::clone(&ready);
+///     std::thread::spawn(move || ready.store(true, Ordering::SeqCst))
+/// };
+/// assert_that_code!(|| ready.load(Ordering::SeqCst))
+///     .satisfies_within(Duration::from_secs(5), |ready| *ready);
+/// worker.join().unwrap();
+/// ```
+#[macro_export]
+macro_rules! assert_that_code {
+    ($code:expr) => {
+        $crate::assert_that!($code)
+    };
+}
+
+/// Makes a subject of the values of several expressions of the same type, to check relations
+/// between all of them with [`AllValuesAssertion`](crate::AllValuesAssertion). Facts refer to
+/// each value by its expression.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// fn sum_loop(n: u32) -> u32 {
+///     (1..=n).sum()
+/// }
+/// fn sum_formula(n: u32) -> u32 {
+///     n * (n + 1) / 2
+/// }
+/// assert_that_all!([sum_loop(10), sum_formula(10), 55]).are_equal();
+/// ```
+#[macro_export]
+macro_rules! assert_that_all {
+    ([$($actual:expr),+ $(,)?]) => {
+        $crate::Subject::new(
+            &$crate::LabeledValues::new(vec![$((
+                $crate::expr_text(stringify!($actual)),
+                &$actual,
+            )),+]),
+            $crate::expr_text(stringify!([$($actual),+])),
+            /* description= */ None,
+            /* option= */ (),
+            Some($crate::Location::new(
+                file!().to_string(),
+                line!(),
+                column!(),
+            )),
+            std::marker::PhantomData::<()>,
+        )
+    };
+}
+
+/// Makes a subject of the values of two expressions, to check relations between them with
+/// [`PairAssertion`](crate::PairAssertion). Facts refer to both values by their expressions.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// use std::collections::BTreeSet;
+///
+/// let granted = BTreeSet::from(["read"]);
+/// let requested = BTreeSet::from(["read", "write"]);
+/// assert_that_pair!(granted, requested).left_is_subset_of_right();
+/// ```
+#[macro_export]
+macro_rules! assert_that_pair {
+    ($left:expr, $right:expr $(,)?) => {
+        $crate::Subject::new(
+            &$crate::Pair::new(
+                $crate::expr_text(stringify!($left)),
+                &$left,
+                $crate::expr_text(stringify!($right)),
+                &$right,
+            ),
+            $crate::expr_text(stringify!(($left, $right))),
+            /* description= */ None,
+            /* option= */ (),
+            Some($crate::Location::new(
+                file!().to_string(),
+                line!(),
+                column!(),
+            )),
+            std::marker::PhantomData::<()>,
+        )
+    };
+}
+
+/// Like [`assert_that`], but assertions return `Result<(), E>` instead of panicking, where `E` is
+/// any error type convertible from a failed [`AssertionResult`], and
+/// [`AssertionError`](crate::AssertionError) if omitted.
+///
+/// This lets parameterized tests and test functions returning `Result` use `?` on assertions.
+///
+/// It also lets property testing frameworks, which expect failures as errors, report assertor
+/// failures and keep their facts in the output of shrunk cases. assertor does not depend on
+/// proptest or quickcheck, so it provides no conversion into their error types; implement
+/// `From<AssertionResult>` for a local error type and convert that one instead. For example with
+/// proptest, where `PropError` converts into `TestCaseError`:
+///
+/// ```ignore
+/// proptest! {
+///     #[test]
+///     fn reversed_twice(v: Vec<u8>) {
+///         let twice: Vec<u8> = v.iter().rev().rev().copied().collect();
+///         try_assert_that!(twice, PropError).is_equal_to(&v)?;
+///     }
+/// }
+/// ```
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// #[derive(Debug)]
+/// struct Failure(String);
+///
+/// impl From<AssertionResult> for Failure {
+///     fn from(result: AssertionResult) -> Self {
+///         Failure(result.generate_message())
+///     }
+/// }
+///
+/// fn property(x: u32) -> Result<(), Failure> {
+///     try_assert_that!(x / 2 * 2, Failure).is_at_most(x)?;
+///     try_assert_that!(x.saturating_add(1), Failure).is_greater_than(x)
+/// }
+///
+/// assert!(property(7).is_ok());
+/// assert!(property(u32::MAX).is_err());
+/// ```
+#[macro_export]
+macro_rules! try_assert_that {
+    ($actual:expr) => {
+        $crate::try_assert_that!($actual, $crate::AssertionError)
+    };
+    ($actual:expr, $error:ty) => {
+        $crate::Subject::new(
+            &$actual,
+            $crate::expr_text(stringify!($actual)),
+            /* description= */ None,
+            /* option= */ (),
+            Some($crate::Location::new(
+                file!().to_string(),
+                line!(),
+                column!(),
+            )),
+            std::marker::PhantomData::<Result<(), $error>>,
+        )
+    };
+}
+
+/// Like [`assert_that`], but collects the elements of an iterator into a `Vec` subject, for
+/// iterators which can not be cloned and so can not be checked with
+/// [`IteratorAssertion`](crate::IteratorAssertion), such as `BufRead::lines()` or
+/// `Vec::drain(..)`. The iterator is consumed once, when the subject is made.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// use std::io::{BufRead, Cursor};
+///
+/// let mut queue = vec![1, 2, 3];
+/// assert_that_collected!(queue.drain(..2)).contains_exactly_in_order(vec![1, 2]);
+///
+/// let input = Cursor::new("a\nb\n");
+/// assert_that_collected!(input.lines())
+///     .all_ok()
+///     .contains_exactly_in_order(vec!["a".to_string(), "b".to_string()]);
+/// ```
+#[macro_export]
+macro_rules! assert_that_collected {
+    ($iter:expr) => {
+        $crate::Subject::new_collected(
+            $iter,
+            $crate::expr_text(stringify!($iter)),
+            /* description= */ None,
+            /* option= */ (),
+            Some($crate::Location::new(
+                file!().to_string(),
+                line!(),
+                column!(),
+            )),
+            std::marker::PhantomData::<()>,
+        )
+    };
+}
+
+/// Like [`assert_that`], but takes a closure computing the actual value, which is called when the
+/// first assertion runs.
+///
+/// The value is computed at most once, and not at all if no assertion runs or the subject is
+/// skipped with [`Subject::assuming`]. A panic while computing the value fails the assertion, with
+/// its location and the panic message.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// assert_that_lazy!(|| (1..=4).product::<u64>()).is_equal_to(24);
+/// ```
+/// ```should_panic
+/// use assertor::*;
+///
+/// assert_that_lazy!(|| "x".parse::<u32>().unwrap()).is_equal_to(1);
+/// // value of                          : "x".parse::<u32>().unwrap()
+/// // failed to compute the actual value
+/// // panicked with                     : called `Result::unwrap()` on an `Err` value: ...
+/// ```
+#[macro_export]
+macro_rules! assert_that_lazy {
+    ($init:expr) => {
+        $crate::Subject::new_lazy(
+            &$crate::LazyValue::new(
+                $init,
+                $crate::closure_body_text(stringify!($init)),
+                Some($crate::Location::new(
+                    file!().to_string(),
+                    line!(),
+                    column!(),
+                )),
+            ),
+            $crate::closure_body_text(stringify!($init)),
+            /* description= */ None,
+            /* option= */ (),
+            Some($crate::Location::new(
+                file!().to_string(),
+                line!(),
+                column!(),
+            )),
+            std::marker::PhantomData::<()>,
+        )
+    };
+}
+
+/// Like [`assert_that`], but for values only known to implement [`Debug`], such as `&dyn Trait` or
+/// `Box<dyn Trait>` subjects of a trait with a `Debug` supertrait. The subject is a
+/// [`DynDebug`](crate::DynDebug), to which only [`Debug`]-based assertions apply, such as
+/// [`DynDebugAssertion::has_debug`](crate::DynDebugAssertion::has_debug), instead of assertions
+/// whose trait bounds a trait object does not satisfy.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let error: Box<dyn std::error::Error> = "missing key".into();
+/// assert_that_dyn!(error).has_debug("\"missing key\"");
+/// ```
+#[macro_export]
+macro_rules! assert_that_dyn {
+    ($actual:expr) => {
+        $crate::Subject::new(
+            &$crate::DynDebug::new(&$actual),
+            $crate::expr_text(stringify!($actual)),
             /* description= */ None,
             /* option= */ (),
             Some($crate::Location::new(
@@ -64,6 +443,116 @@ pub struct Subject<'a, Sub, Opt, Ret> {
 
     location: Option<Location>,
     return_type: PhantomData<Ret>,
+
+    /// Overrides how values of the subject type are rendered in facts. See
+    /// [`Subject::with_formatter`].
+    formatter: Option<fn(&Sub) -> String>,
+
+    /// How values are rendered in facts. Inherited by derived subjects.
+    rendering: Rendering,
+
+    /// Whether failures are ignored. Inherited by derived subjects. See [`Subject::assuming`].
+    skipped: bool,
+
+    /// Facts added to failures. Inherited by derived subjects. See [`Subject::with_facts`].
+    site_facts: Vec<SiteFacts>,
+}
+
+/// Facts computed on failure, as given to [`Subject::with_facts`].
+type SiteFacts = Arc<dyn Fn() -> Vec<Fact> + Send + Sync>;
+
+/// Options for rendering values in facts, shared by a subject, its derived subjects and their
+/// assertion results.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Rendering {
+    /// See [`Subject::redacted`].
+    redacted: bool,
+    /// See [`Subject::pretty_debug`].
+    pretty: bool,
+    /// See [`Subject::max_listed_elements`].
+    max_listed_elements: Option<usize>,
+    /// See [`Subject::max_evaluated_elements`].
+    max_evaluated_elements: Option<usize>,
+    /// See [`Subject::sample_listed_elements`].
+    sampled: bool,
+    /// See [`Subject::align_keys_per_group`].
+    align_per_group: bool,
+    /// See [`Subject::max_key_width`].
+    max_key_width: Option<usize>,
+    /// See [`Subject::group_digits`].
+    group_digits: bool,
+    /// See [`Subject::fail_fast`].
+    fail_fast: bool,
+    /// See [`Subject::reject_empty_expected`].
+    reject_empty_expected: bool,
+    /// Whether the elements of the subject come from an unordered container. See
+    /// [`Subject::with_unordered_elements`].
+    unordered: bool,
+    /// When the original subject was created, if assertions are timed. See
+    /// [`set_slow_assertion_threshold`](crate::set_slow_assertion_threshold).
+    started: Option<Instant>,
+}
+
+impl Rendering {
+    /// Returns the options of a new subject, starting its timing if assertions are timed.
+    fn new() -> Self {
+        Rendering {
+            started: timing::start(),
+            ..Rendering::default()
+        }
+    }
+
+    /// Formats `value` with [`Debug`], using the alternate form `{:#?}` if `pretty` is set.
+    /// Otherwise nested `Option` and `Result` values are flattened, e.g. `Some→Ok→Some: 3`.
+    /// Integers are grouped by thousands if `group_digits` is set.
+    fn debug<V: Debug + ?Sized>(&self, value: &V) -> String {
+        if self.pretty {
+            return format!("{:#?}", value);
+        }
+        self.compact(format!("{:?}", value))
+    }
+
+    /// Applies the options to the `{:?}` representation `repr` of a value, unless `pretty` is
+    /// set, in which case the value must be formatted again.
+    fn compact(&self, repr: String) -> String {
+        if self.group_digits {
+            if let Some(grouped) = group_digits(&repr) {
+                return grouped;
+            }
+        }
+        flatten_wrappers(&repr).unwrap_or(repr)
+    }
+}
+
+/// Subjects can be cloned to reuse them with builder methods taking `self`, such as tolerances.
+/// Assertion methods take `&self`, so a subject can also be kept for several assertions. As the
+/// subject borrows the actual value, the value must outlive it.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let values = vec![1, 2, 3];
+/// let subject = assert_that!(values);
+/// subject.has_length(3);
+/// subject.contains(2);
+/// subject.contains_exactly(vec![3, 2, 1]);
+/// ```
+impl<Sub: Clone, Opt: Clone, Ret> Clone for Subject<'_, Sub, Opt, Ret> {
+    fn clone(&self) -> Self {
+        Subject {
+            actual: self.actual.clone(),
+            expr: self.expr.clone(),
+            description: self.description.clone(),
+            option: self.option.clone(),
+            location: self.location.clone(),
+            return_type: PhantomData,
+            formatter: self.formatter,
+            rendering: self.rendering,
+            skipped: self.skipped,
+            site_facts: self.site_facts.clone(),
+        }
+    }
 }
 
 impl<'a, Sub, Opt, Ret> Subject<'a, Sub, Opt, Ret> {
@@ -84,25 +573,557 @@ impl<'a, Sub, Opt, Ret> Subject<'a, Sub, Opt, Ret> {
             option,
             location,
             return_type,
+            formatter: None,
+            rendering: Rendering::new(),
+            skipped: false,
+            site_facts: vec![],
+        }
+    }
+
+    #[allow(dead_code)] // Used by macros.
+    /// Creates a new subject whose actual value is computed when first accessed.
+    pub fn new_lazy(
+        actual: &'a dyn LazyActual<Sub>,
+        expr: String,
+        description: Option<String>,
+        option: Opt,
+        location: Option<Location>,
+        return_type: PhantomData<Ret>,
+    ) -> Self {
+        Subject {
+            actual: ActualValue::Lazy(actual),
+            expr,
+            description,
+            option,
+            location,
+            return_type,
+            formatter: None,
+            rendering: Rendering::new(),
+            skipped: false,
+            site_facts: vec![],
+        }
+    }
+
+    /// Creates a new subject with an owned actual value.
+    pub(super) fn new_from_owned_actual(
+        actual: Sub,
+        expr: String,
+        description: Option<String>,
+        option: Opt,
+        location: Option<Location>,
+        return_type: PhantomData<Ret>,
+    ) -> Self {
+        Subject {
+            actual: ActualValue::Owned(actual),
+            expr,
+            description,
+            option,
+            location,
+            return_type,
+            formatter: None,
+            rendering: Rendering::new(),
+            skipped: false,
+            site_facts: vec![],
+        }
+    }
+}
+
+impl<T, Opt, Ret> Subject<'_, Vec<T>, Opt, Ret> {
+    #[allow(dead_code)] // Used by macros.
+    /// Creates a new subject with the elements of `iter` collected into a `Vec`.
+    pub fn new_collected<I: IntoIterator<Item = T>>(
+        iter: I,
+        expr: String,
+        description: Option<String>,
+        option: Opt,
+        location: Option<Location>,
+        return_type: PhantomData<Ret>,
+    ) -> Self {
+        Subject::new_from_owned_actual(
+            iter.into_iter().collect(),
+            expr,
+            description,
+            option,
+            location,
+            return_type,
+        )
+    }
+}
+
+impl<'a, Sub, Opt, Ret> Subject<'a, Sub, Opt, Ret> {
+    /// Overrides how the actual value, and expected values of the same type, are rendered in the
+    /// facts of assertions on this subject. By default, values are rendered with [`Debug`].
+    ///
+    /// The formatter is a function pointer, so closures passed here must not capture variables.
+    /// A boxed closure would give subjects drop glue, which would reject temporaries dropped
+    /// before the subject, as in `assert_that!(v.iter()).contains_exactly(vec![1].iter())`.
+    ///
+    /// Only values of the subject type are rendered with the formatter. Elements of a collection
+    /// subject, e.g. in `though it did contain` facts, have another type and are still rendered
+    /// with [`Debug`].
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// assert_that!(255).with_formatter(|v| format!("{:#x}", v)).is_equal_to(255);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// assert_that!(255).with_formatter(|v| format!("{:#x}", v)).is_equal_to(256);
+    /// // expected: 0x100
+    /// // actual  : 0xff
+    /// ```
+    pub fn with_formatter(mut self, formatter: fn(&Sub) -> String) -> Self {
+        self.formatter = Some(formatter);
+        self
+    }
+
+    /// Redacts rendered values in the facts of assertions on this subject and its derived subjects,
+    /// while the assertions still compare the real values. A redacted value is shown with its
+    /// length and hash so that values can still be told apart. The hash is randomly keyed for each
+    /// process, so it can not be used to guess short values.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// let token = "s3cr3t";
+    /// assert_that!(token).redacted().is_same_string_to("s3cr3t");
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// let token = "s3cr3t";
+    /// assert_that!(token).redacted().is_same_string_to("password");
+    /// // expected: «redacted» (len=10, hash=...)
+    /// // actual  : «redacted» (len=8, hash=...)
+    /// ```
+    pub fn redacted(mut self) -> Self {
+        self.rendering.redacted = true;
+        self
+    }
+
+    /// Renders values with the alternate [`Debug`] form (`{:#?}`) in the facts of assertions on
+    /// this subject and its derived subjects. Useful for nested structs and collections.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Point {
+    ///     x: i32,
+    ///     y: i32,
+    /// }
+    /// assert_that!(Point { x: 1, y: 2 }).pretty_debug().is_equal_to(Point { x: 1, y: 3 });
+    /// // differs at: .y (expected: 3, actual: 2)
+    /// // ---
+    /// // expected  : Point {
+    /// //                 x: 1,
+    /// //                 y: 3,
+    /// //             }
+    /// // actual    : Point {
+    /// //                 x: 1,
+    /// //                 y: 2,
+    /// //             }
+    /// ```
+    pub fn pretty_debug(mut self) -> Self {
+        self.rendering.pretty = true;
+        self
+    }
+
+    /// Sets how many elements of `though it did contain` facts are listed in the messages of
+    /// assertions on this subject and its derived subjects. Elements are labeled with their
+    /// indices, and the rest are summarized by their count. Defaults to 32.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// assert_that!(vec![1, 2, 3, 4]).max_listed_elements(2).contains(5);
+    /// // expected to contain  : 5
+    /// // but did not
+    /// // though it did contain: [ [0]: 1, [1]: 2, ... (2 more) ]
+    /// ```
+    pub fn max_listed_elements(mut self, max: usize) -> Self {
+        self.rendering.max_listed_elements = Some(max);
+        self
+    }
+
+    /// Sets how many elements of an iterator subject, and its derived subjects, are evaluated
+    /// before its assertions fail, instead of hanging on an accidentally infinite iterator.
+    /// Defaults to 1,000,000. Iterators with an upper bound on their length, such as those of
+    /// collections, are not capped. As the elements are evaluated while checking, the failure
+    /// panics even for subjects which return a result, like those of a lazily computed value.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// assert_that!((0..).step_by(2)).max_evaluated_elements(10).contains(6);
+    /// ```
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// assert_that!((0..).step_by(2)).max_evaluated_elements(10).contains(7);
+    /// // value of                : (0..).step_by(2)
+    /// // expected the iterator to end
+    /// // but it yielded more than: 10 elements
+    /// // ---
+    /// // = help: bound the subject with take(), or raise the cap with max_evaluated_elements()
+    /// ```
+    pub fn max_evaluated_elements(mut self, max: usize) -> Self {
+        self.rendering.max_evaluated_elements = Some(max);
+        self
+    }
+
+    /// Lists a sample of the elements of `though it did contain` facts which exceed
+    /// [`Subject::max_listed_elements`], instead of the first ones. The sample is spread over all
+    /// elements and seeded by a hash of their contents, so that repeated runs show the same
+    /// excerpt of the same values.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// assert_that!((0..100).collect::<Vec<_>>())
+    ///     .max_listed_elements(3)
+    ///     .sample_listed_elements()
+    ///     .contains(100);
+    /// // expected to contain  : 100
+    /// // but did not
+    /// // though it did contain: [ [6]: 6, [48]: 48, [86]: 86, ... (97 more) ]
+    /// ```
+    pub fn sample_listed_elements(mut self) -> Self {
+        self.rendering.sampled = true;
+        self
+    }
+
+    /// Aligns the keys of facts only with the keys of the facts between the same splitters,
+    /// instead of with all keys of the message. Results built with [`FactGroup`]s are aligned per
+    /// group regardless.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// assert_that!(vec![1, 2]).align_keys_per_group().contains_exactly(vec![2, 3]);
+    /// // missing (1)   : [3]
+    /// // unexpected (1): [1]
+    /// // ---
+    /// // expected: [ 2, 3 ]
+    /// // actual  : [ 1, 2 ]
+    /// ```
+    pub fn align_keys_per_group(mut self) -> Self {
+        self.rendering.align_per_group = true;
+        self
+    }
+
+    /// Sets the widest key the values of facts are aligned to. Longer keys are put on their own
+    /// line, followed by their value on the next line in the aligned column.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// assert_that!(vec![1, 2]).max_key_width(10).contains_exactly(vec![2, 3]);
+    /// // missing (1):
+    /// //           [3]
+    /// // unexpected (1):
+    /// //           [1]
+    /// // ---
+    /// // expected: [ 2, 3 ]
+    /// // actual  : [ 1, 2 ]
+    /// ```
+    pub fn max_key_width(mut self, width: usize) -> Self {
+        self.rendering.max_key_width = Some(width);
+        self
+    }
+
+    /// Groups the digits of integer values with more than four digits by thousands, like
+    /// `1_234_567`, in the facts of assertions on this subject and its derived subjects, so that
+    /// values off by orders of magnitude stand out.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// assert_that!(12_345_678_u64).group_digits().is_equal_to(1_234_567);
+    /// // expected: 1_234_567
+    /// // actual  : 12_345_678
+    /// ```
+    pub fn group_digits(mut self) -> Self {
+        self.rendering.group_digits = true;
+        self
+    }
+
+    /// Makes `contains_exactly` and `contains_exactly_in_order` of sequences and maps stop at the
+    /// first difference instead of listing all of them, for assertions in hot loops such as
+    /// property tests. Inherited by derived subjects. See [`Subject::full_diff`].
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// assert_that!(vec![1, 2, 3]).fail_fast().contains_exactly_in_order(vec![1, 2, 3]);
+    /// demo!(
+    ///     assert_that!(vec![1, 2, 3, 4]).fail_fast().contains_exactly_in_order(vec![1, 5, 3, 6]),
+    ///     r#"
+    ///     first difference at: index 1
+    ///     expected           : 5
+    ///     actual             : 2
+    ///     ---
+    ///     = note: stopped after first difference; rerun with full_diff() for details
+    ///     "#
+    /// );
+    /// ```
+    pub fn fail_fast(mut self) -> Self {
+        self.rendering.fail_fast = true;
+        self
+    }
+
+    /// Makes comparisons list all differences, which is the default. Undoes
+    /// [`Subject::fail_fast`].
+    pub fn full_diff(mut self) -> Self {
+        self.rendering.fail_fast = false;
+        self
+    }
+
+    /// Fails assertions comparing this subject and its derived subjects with an empty expected
+    /// collection, such as `contains_all_of`, which passes for any subject, or `contains_exactly`,
+    /// which is the same as `is_empty`. Catches expectations which are accidentally empty, e.g.
+    /// filtered out or not yet populated.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// let expected: Vec<i32> = vec![];
+    /// assert_that!([1, 2, 3].iter()).contains_all_of(expected.iter());
+    /// demo!(
+    ///     assert_that!([1, 2, 3].iter()).reject_empty_expected().contains_all_of(expected.iter()),
+    ///     r#"
+    ///     expected collection is empty
+    ///     ---
+    ///     = note: rejected by reject_empty_expected(), as contains_all_of passes for any subject
+    ///     "#
+    /// );
+    /// ```
+    pub fn reject_empty_expected(mut self) -> Self {
+        self.rendering.reject_empty_expected = true;
+        self
+    }
+
+    /// Ignores failures of assertions on this subject and its derived subjects unless `condition`
+    /// holds. Useful for expectations which only apply to some platforms or configurations.
+    ///
+    /// Assertions deriving a subject, such as `first()` of an empty iterator, still panic when the
+    /// derived subject can not be created.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// assert_that!(std::path::MAIN_SEPARATOR)
+    ///     .assuming(cfg!(windows))
+    ///     .is_equal_to('\\');
+    /// ```
+    pub fn assuming(mut self, condition: bool) -> Self {
+        self.skipped |= !condition;
+        self
+    }
+
+    /// Ignores failures of assertions on this subject and its derived subjects if `condition`
+    /// holds. The opposite of [`Subject::assuming`].
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// assert_that!(std::env::consts::FAMILY)
+    ///     .skip_if(cfg!(windows))
+    ///     .is_equal_to("unix");
+    /// ```
+    pub fn skip_if(self, condition: bool) -> Self {
+        self.assuming(!condition)
+    }
+
+    /// Whether failures are ignored. See [`Subject::assuming`].
+    pub(crate) fn is_skipped(&self) -> bool {
+        self.skipped
+    }
+
+    /// Marks the elements of the subject as coming from an unordered container, e.g. the keys of
+    /// a `HashMap`, so that failures list them in a stable order. Inherited by derived subjects.
+    pub(crate) fn with_unordered_elements(mut self, unordered: bool) -> Self {
+        self.rendering.unordered |= unordered;
+        self
+    }
+
+    /// See [`Subject::with_unordered_elements`].
+    pub(crate) fn has_unordered_elements(&self) -> bool {
+        self.rendering.unordered
+    }
+
+    /// Adds the facts returned by `facts` to failures of assertions on this subject and its
+    /// derived subjects, e.g. identifiers which help debugging a failure at this assertion site.
+    /// `facts` is only called if an assertion fails.
+    ///
+    /// # Example
+    /// ```should_panic
+    /// use assertor::*;
+    ///
+    /// let request_id = 42;
+    /// assert_that!(vec![200, 500])
+    ///     .with_facts(move || vec![Fact::new("request id", request_id.to_string())])
+    ///     .does_not_contain(500);
+    /// // expected to not contain: 500
+    /// // but element was found
+    /// // though it did contain  : [ [0]: 200, [1]: 500 ]
+    /// // ---
+    /// // request id             : 42
+    /// ```
+    pub fn with_facts<F>(mut self, facts: F) -> Self
+    where
+        F: Fn() -> Vec<Fact> + Send + Sync + 'static,
+    {
+        self.site_facts.push(Arc::new(facts));
+        self
+    }
+
+    /// Consumes the subject and returns the actual value. A borrowed actual value, as taken by
+    /// [`assert_that`], is cloned, while a derived subject owning its value, such as the length of
+    /// a sorted collection, gives it up.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// let sorted = assert_that!(vec![3, 1, 2]).sorted().into_actual();
+    /// assert_eq!(sorted, vec![1, 2, 3]);
+    /// ```
+    pub fn into_actual(self) -> Sub
+    where
+        Sub: Clone,
+    {
+        match self.actual {
+            ActualValue::Owned(value) => value,
+            ActualValue::Borrowed(value) => value.clone(),
+            ActualValue::Lazy(value) => value.get().clone(),
+        }
+    }
+
+    /// Returns the actual value with the expression of the subject, or its description for a
+    /// derived subject, such as `values.sorted()`. Lets helpers taking a [`Subject`] inspect the value
+    /// before delegating to other assertions.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// fn assert_even_length<T: std::fmt::Debug>(subject: Subject<Vec<T>, (), ()>) {
+    ///     let (actual, expr) = subject.actual_with_expr();
+    ///     assert!(actual.len() % 2 == 0, "{} has an odd length", expr);
+    ///     subject.is_not_empty();
+    /// }
+    ///
+    /// let values = vec![1, 2];
+    /// assert_even_length(assert_that!(values));
+    /// ```
+    pub fn actual_with_expr(&self) -> (&Sub, &str) {
+        (&self.actual, self.description_or_expr())
+    }
+
+    /// Creates a subject for the field `name` of the actual value, returned by `field`, described
+    /// as `<subject>.<name>`.
+    #[doc(hidden)] // Used by `#[derive(Assertable)]`.
+    pub fn field_subject<F>(&self, name: &str, field: fn(&Sub) -> &F) -> Subject<'_, F, (), Ret> {
+        self.new_subject(
+            field(self.actual()),
+            Some(format!("{}.{}", self.description_or_expr(), name)),
+            (),
+        )
+    }
+
+    /// Creates a subject for the value of the enum variant matching `pattern`, returned by
+    /// `variant`, described as `<subject>.<name>`. Fails if the actual value is another variant.
+    #[doc(hidden)] // Used by `#[derive(Assertable)]`.
+    #[track_caller]
+    pub fn variant_subject<F>(
+        &self,
+        name: &str,
+        pattern: &str,
+        variant: fn(&Sub) -> Option<&F>,
+    ) -> Subject<'_, F, (), Ret>
+    where
+        Sub: Debug,
+    {
+        match variant(self.actual()) {
+            Some(value) => self.new_subject(
+                value,
+                Some(format!("{}.{}", self.description_or_expr(), name)),
+                (),
+            ),
+            None => pattern_mismatch(self, pattern).fail_derivation(),
+        }
+    }
+
+    pub(crate) fn formatter(&self) -> Option<fn(&Sub) -> String> {
+        self.formatter
+    }
+
+    pub(crate) fn is_redacted(&self) -> bool {
+        self.rendering.redacted
+    }
+
+    pub(crate) fn is_fail_fast(&self) -> bool {
+        self.rendering.fail_fast
+    }
+
+    pub(crate) fn max_evaluated(&self) -> Option<usize> {
+        self.rendering.max_evaluated_elements
+    }
+
+    pub(crate) fn is_rejecting_empty_expected(&self) -> bool {
+        self.rendering.reject_empty_expected
+    }
+
+    /// Adds a fact with `value` formatted like [`AssertionApi::format_value`] does, reusing the
+    /// representation computed by `cache`, if any.
+    pub(crate) fn add_value_fact<'v, K: Into<String>>(
+        &self,
+        result: AssertionResult,
+        key: K,
+        value: &'v Sub,
+        cache: &mut DebugCache<'v>,
+    ) -> AssertionResult
+    where
+        Sub: Debug,
+    {
+        match self.formatter {
+            None => result.add_debug_fact(key, value, cache),
+            Some(formatter) => result.add_fact(key, formatter(value)),
         }
     }
 
-    /// Creates a new subject with an owned actual value.
-    pub(super) fn new_from_owned_actual(
-        actual: Sub,
-        expr: String,
-        description: Option<String>,
-        option: Opt,
-        location: Option<Location>,
-        return_type: PhantomData<Ret>,
-    ) -> Self {
-        Subject {
-            actual: ActualValue::Owned(actual),
-            expr,
-            description,
-            option,
-            location,
-            return_type,
+    /// Adds `expected` and `actual` facts for `expected` and the actual value with
+    /// [`AssertionResult::add_expected_actual`], or with the formatter set by
+    /// [`Subject::with_formatter`] for the actual value.
+    pub(crate) fn add_expected_actual_facts<E: Debug>(
+        &self,
+        result: AssertionResult,
+        expected: E,
+    ) -> AssertionResult
+    where
+        Sub: Debug,
+    {
+        match self.formatter {
+            None => result.add_expected_actual(expected, self.actual()),
+            Some(formatter) => result
+                .add_formatted_fact(FactKey::Expected, expected)
+                .add_fact(FactKey::Actual, formatter(self.actual())),
         }
     }
 }
@@ -110,6 +1131,7 @@ impl<'a, Sub, Opt, Ret> Subject<'a, Sub, Opt, Ret> {
 pub enum ActualValue<'a, S> {
     Owned(S),
     Borrowed(&'a S),
+    Lazy(&'a dyn LazyActual<S>),
 }
 
 impl<'a, S> Deref for ActualValue<'a, S> {
@@ -119,6 +1141,84 @@ impl<'a, S> Deref for ActualValue<'a, S> {
         match &self {
             ActualValue::Owned(value) => value,
             ActualValue::Borrowed(value) => value,
+            ActualValue::Lazy(value) => value.get(),
+        }
+    }
+}
+
+impl<'a, S: Clone> Clone for ActualValue<'a, S> {
+    fn clone(&self) -> Self {
+        match self {
+            ActualValue::Owned(value) => ActualValue::Owned(value.clone()),
+            ActualValue::Borrowed(value) => ActualValue::Borrowed(value),
+            ActualValue::Lazy(value) => ActualValue::Lazy(*value),
+        }
+    }
+}
+
+/// An actual value computed on first access, created by [`assert_that_lazy`].
+pub struct LazyValue<S, F> {
+    value: RefCell<Option<S>>,
+    init: Cell<Option<F>>,
+    expr: String,
+    location: Option<Location>,
+}
+
+impl<S, F: FnOnce() -> S> LazyValue<S, F> {
+    /// Creates a value computed by `init`, for a subject of the expression `expr`.
+    pub fn new(init: F, expr: String, location: Option<Location>) -> Self {
+        LazyValue {
+            value: RefCell::new(None),
+            init: Cell::new(Some(init)),
+            expr,
+            location,
+        }
+    }
+}
+
+/// Object-safe access to a [`LazyValue`], so that subjects do not depend on the closure type.
+pub trait LazyActual<S> {
+    /// Returns the value, computing it if needed. Fails the assertion, with the location of the
+    /// subject, if the computation panics.
+    fn get(&self) -> &S;
+}
+
+impl<S, F: FnOnce() -> S> LazyValue<S, F> {
+    /// Computes the value, or fails the assertion if the computation panics.
+    fn compute(&self) -> S {
+        let init = self.init.take();
+        let payload = match init.map(|init| panic::catch_unwind(AssertUnwindSafe(init))) {
+            Some(Ok(value)) => return value,
+            Some(Err(payload)) => payload,
+            None => Box::new("an earlier computation panicked"),
+        };
+        let message = match payload.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => match payload.downcast_ref::<String>() {
+                Some(message) => message.clone(),
+                None => "Box<dyn Any>".to_string(),
+            },
+        };
+        let result = AssertionResult::new(&self.location)
+            .add_fact(FactKey::ValueOf, &self.expr)
+            .add_simple_fact("failed to compute the actual value")
+            .add_fact("panicked with", message);
+        result.fail_derivation()
+    }
+}
+
+impl<S, F: FnOnce() -> S> LazyActual<S> for LazyValue<S, F> {
+    fn get(&self) -> &S {
+        if self.value.borrow().is_none() {
+            let value = self.compute();
+            *self.value.borrow_mut() = Some(value);
+        }
+        // SAFETY: the value is set once above and never replaced or taken afterwards, and no
+        // `RefMut` is alive past this point, so it is neither moved nor mutated while `self` is
+        // borrowed.
+        match unsafe { &*self.value.as_ptr() } {
+            Some(value) => value,
+            None => unreachable!(),
         }
     }
 }
@@ -155,6 +1255,11 @@ pub trait AssertionApi<'a, Sub, Opt, Ret> {
     /// Code location.
     fn location(&self) -> &Option<Location>;
 
+    /// Renders `value` for facts, using the formatter set by [`Subject::with_formatter`] if any.
+    fn format_value(&self, value: &Sub) -> String
+    where
+        Sub: Debug;
+
     /// Creates a new derived subject.
     ///
     /// `new_description` should describe how it derives from the previous subject in
@@ -194,9 +1299,12 @@ impl<'a, Sub, Opt, Ret> AssertionApi<'a, Sub, Opt, Ret> for Subject<'a, Sub, Opt
         match &self.description {
             None => {}
             Some(description) => {
-                result = result.add_fact("value of", description);
+                result = result.add_fact(FactKey::ValueOf, description);
             }
         };
+        result.rendering = self.rendering;
+        result.skipped = self.skipped;
+        result.site_facts = self.site_facts.clone();
         result
     }
 
@@ -230,20 +1338,34 @@ impl<'a, Sub, Opt, Ret> AssertionApi<'a, Sub, Opt, Ret> for Subject<'a, Sub, Opt
         &self.location
     }
 
+    fn format_value(&self, value: &Sub) -> String
+    where
+        Sub: Debug,
+    {
+        match &self.formatter {
+            None => self.rendering.debug(value),
+            Some(formatter) => formatter(value),
+        }
+    }
+
     fn new_subject<NewSub, NewOpt>(
         &self,
         new_actual: &'a NewSub,
         new_description: Option<String>,
         new_option: NewOpt,
     ) -> Subject<NewSub, NewOpt, Ret> {
-        Subject::new(
+        let mut subject = Subject::new(
             new_actual,
             self.expr.clone(),
             new_description,
             new_option,
             self.location.clone(),
             self.return_type,
-        )
+        );
+        subject.rendering = self.rendering;
+        subject.skipped = self.skipped;
+        subject.site_facts = self.site_facts.clone();
+        subject
     }
     fn new_owned_subject<'b, NewSub, NewOpt>(
         &self,
@@ -251,17 +1373,35 @@ impl<'a, Sub, Opt, Ret> AssertionApi<'a, Sub, Opt, Ret> for Subject<'a, Sub, Opt
         new_description: Option<String>,
         new_option: NewOpt,
     ) -> Subject<'b, NewSub, NewOpt, Ret> {
-        Subject::new_from_owned_actual(
+        let mut subject = Subject::new_from_owned_actual(
             new_actual,
             self.expr.clone(),
             new_description,
             new_option,
             self.location.clone(),
             self.return_type,
-        )
+        );
+        subject.rendering = self.rendering;
+        subject.skipped = self.skipped;
+        subject.site_facts = self.site_facts.clone();
+        subject
     }
 }
 
+/// Returns a pass from the enclosing assertion method if the subject is skipped, before the actual
+/// value is read, so that the value of [`assert_that_lazy`] is not computed. See
+/// [`Subject::assuming`].
+macro_rules! pass_if_skipped {
+    ($subject:expr) => {
+        if $subject.is_skipped() {
+            return $crate::base::AssertionStrategy::do_ok($crate::base::AssertionApi::new_result(
+                $subject,
+            ));
+        }
+    };
+}
+pub(crate) use pass_if_skipped;
+
 /// A behavior for assertion pass and failure. [`AssertionResult`] implements this traits.  
 ///
 /// Behavior for assertion pass and failure is different between [`assert_that`] and [`check_that`].
@@ -279,57 +1419,238 @@ pub trait AssertionStrategy<R> {
 
 impl AssertionStrategy<()> for AssertionResult {
     fn do_fail(self) {
-        std::panic::panic_any(self.generate_message());
+        if self.skipped {
+            return;
+        }
+        stats::record(true);
+        let result = self.with_site_facts().with_slow_evaluation();
+        if error::structured_panics() {
+            std::panic::panic_any(AssertionError::from(result));
+        }
+        // Panic hooks on WebAssembly, such as the one of `wasm_bindgen_test`, print the formatted
+        // panic message, which payloads passed to `panic_any` do not have.
+        #[cfg(target_family = "wasm")]
+        panic!("{}", result.generate_panic_message());
+        #[cfg(not(target_family = "wasm"))]
+        std::panic::panic_any(result.generate_panic_message());
+    }
+
+    fn do_ok(self) {
+        if !self.skipped {
+            stats::record(false);
+            self.report_slow_pass();
+        }
+    }
+}
+
+impl<E: From<AssertionResult>> AssertionStrategy<Result<(), E>> for AssertionResult {
+    fn do_fail(self) -> Result<(), E> {
+        if self.skipped {
+            return Ok(());
+        }
+        stats::record(true);
+        Err(E::from(self.with_site_facts().with_slow_evaluation()))
     }
 
-    fn do_ok(self) {}
+    fn do_ok(self) -> Result<(), E> {
+        if !self.skipped {
+            stats::record(false);
+            self.report_slow_pass();
+        }
+        Ok(())
+    }
 }
 
 /// Contains assertion results which will be shown in the assertion messages.
 #[allow(missing_docs)]
 #[derive(Clone)]
 pub struct AssertionResult {
-    location: Option<String>,
+    location: Option<Location>,
     facts: Vec<Fact>,
+    rendering: Rendering,
+    skipped: bool,
+    /// Indices of the first facts of the non-empty [`FactGroup`]s added so far. A group following
+    /// a non-empty one needs a splitter, and keys are aligned within each group.
+    group_starts: Vec<usize>,
+    /// See [`AssertionResult::with_duplicate_keys`].
+    duplicate_keys: DuplicateKeys,
+    /// See [`Subject::with_facts`].
+    site_facts: Vec<SiteFacts>,
+}
+
+/// `{:?}` representations of the values formatted during an assertion, so that a value in several
+/// facts is formatted once. Values are identified by address, size and type, and stay borrowed
+/// for `'v`, so that no value can be dropped and its address reused while the cache is in use.
+#[derive(Default)]
+pub(crate) struct DebugCache<'v> {
+    entries: HashMap<(usize, usize, &'static str), String>,
+    values: PhantomData<&'v ()>,
+}
+
+impl<'v> DebugCache<'v> {
+    /// Returns the `{:?}` representation of `value`, formatting it only the first time.
+    pub(crate) fn debug_of<V: Debug + ?Sized>(&mut self, value: &'v V) -> String {
+        let key = (
+            value as *const V as *const () as usize,
+            std::mem::size_of_val(value),
+            std::any::type_name::<V>(),
+        );
+        self.entries
+            .entry(key)
+            .or_insert_with(|| format!("{:?}", value))
+            .clone()
+    }
+}
+
+/// How a fact is added whose key is already used by a fact of the same group. See
+/// [`AssertionResult::with_duplicate_keys`].
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum DuplicateKeys {
+    /// Adds the fact as is.
+    #[default]
+    Keep,
+    /// Appends the values of the fact to the first fact with the same key.
+    Merge,
+    /// Suffixes the keys of the facts with their indices, e.g. `actual #1` and `actual #2`.
+    Index,
 }
 
 #[allow(missing_docs)]
 impl AssertionResult {
     const DEBUG_LENGTH_WRAP_LIMIT: usize = 80;
+    const MAX_LISTED_ELEMENTS: usize = 32;
 
-    pub(self) fn new(location: &Option<Location>) -> Self {
+    pub(crate) fn new(location: &Option<Location>) -> Self {
         AssertionResult {
-            location: location.as_ref().map(|loc| format!("{}", loc)),
+            location: location.clone(),
             facts: vec![],
+            rendering: Rendering::default(),
+            skipped: false,
+            group_starts: vec![],
+            duplicate_keys: DuplicateKeys::Keep,
+            site_facts: vec![],
+        }
+    }
+
+    /// Like [`AssertionResult::add_formatted_fact`], but reuses the representation of `value`
+    /// computed by `cache`, if any.
+    pub(crate) fn add_debug_fact<'v, K: Into<String>, V: Debug + ?Sized>(
+        mut self,
+        key: K,
+        value: &'v V,
+        cache: &mut DebugCache<'v>,
+    ) -> Self {
+        let value = if self.rendering.pretty {
+            self.rendering.debug(value)
+        } else {
+            let repr = cache.debug_of(value);
+            self.rendering.compact(repr)
+        };
+        let value = self.render(value);
+        self.push_keyed(Fact::new(key, value));
+        self
+    }
+
+    /// Returns `value` as is, or its redacted form if values are redacted.
+    fn render(&self, value: String) -> String {
+        static REDACTION_KEYS: Mutex<Option<RandomState>> = Mutex::new(None);
+        if !self.rendering.redacted {
+            return value;
         }
+        let keys = REDACTION_KEYS
+            .lock()
+            .unwrap()
+            .get_or_insert_with(RandomState::new)
+            .clone();
+        let mut hasher = keys.build_hasher();
+        value.hash(&mut hasher);
+        format!(
+            "«redacted» (len={}, hash={:016x})",
+            value.chars().count(),
+            hasher.finish()
+        )
     }
 
     #[inline]
     pub fn add_fact<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
-        self.facts.push(Fact::new(key, value));
+        let key = key.into();
+        let value = value.into();
+        // `value of` describes the expression of the actual value, not the value itself.
+        let value = if FactKey::ValueOf.is_phrase_of(&key) {
+            value
+        } else {
+            self.render(value)
+        };
+        self.push_keyed(Fact::new(key, value));
         self
     }
 
     pub fn add_formatted_fact<K: Into<String>, V: Debug>(mut self, key: K, value: V) -> Self {
-        self.facts.push(Fact::new(key, format!("{:?}", value)));
+        let value = self.render(self.rendering.debug(&value));
+        self.push_keyed(Fact::new(key, value));
         self
     }
 
+    /// Adds `expected` and `actual` facts, formatting both values with [`Debug`] like the
+    /// assertions of this crate do, e.g. with nested `Option` and `Result` values flattened and
+    /// values redacted for [redacted](Subject::redacted) subjects. Custom assertions can use it to
+    /// report a mismatch in the same style.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// fn add_status(result: AssertionResult, expected: u16, actual: u16) -> AssertionResult {
+    ///     // Adds `expected: Some(200)` and `actual: Some(404)`.
+    ///     result.add_expected_actual(Some(expected), Some(actual))
+    /// }
+    /// ```
+    pub fn add_expected_actual<E: Debug, A: Debug>(self, expected: E, actual: A) -> Self {
+        self.add_formatted_fact(FactKey::Expected, expected)
+            .add_formatted_fact(FactKey::Actual, actual)
+    }
+
     #[inline]
     pub fn add_formatted_values_fact<K: Into<String>, V: Debug>(
         mut self,
         key: K,
         values: Vec<V>,
     ) -> Self {
-        let str_values = values.iter().map(|v| format!("{:?}", v)).collect();
-        self.facts.push(Fact::new_multi_value_fact(key, str_values));
+        let str_values = values
+            .iter()
+            .map(|v| self.render(self.rendering.debug(v)))
+            .collect();
+        self.push_keyed(Fact::new_multi_value_fact(key, str_values));
+        self
+    }
+
+    /// Like [`AssertionResult::add_formatted_values_fact`], but lists at most
+    /// [`Subject::max_listed_elements`] values, followed by the number of values left out.
+    pub(crate) fn add_capped_values_fact<K: Into<String>, V: Debug>(
+        mut self,
+        key: K,
+        values: Vec<V>,
+    ) -> Self {
+        let max = self
+            .rendering
+            .max_listed_elements
+            .unwrap_or(Self::MAX_LISTED_ELEMENTS);
+        let mut str_values: Vec<String> = values
+            .iter()
+            .take(max)
+            .map(|v| self.render(self.rendering.debug(v)))
+            .collect();
+        if values.len() > max {
+            str_values.push(format!("... ({} more)", values.len() - max));
+        }
+        self.push_keyed(Fact::new_multi_value_fact(key, str_values));
         self
     }
 
     #[inline]
     pub fn add_simple_formatted_fact<V: Debug>(mut self, value: V) -> Self {
-        self.facts
-            .push(Fact::new_simple_fact(format!("{:?}", value)));
+        let value = self.render(self.rendering.debug(&value));
+        self.facts.push(Fact::new_simple_fact(value));
         self
     }
 
@@ -339,43 +1660,242 @@ impl AssertionResult {
         self
     }
 
+    /// Adds `fact` as is, e.g. a fact of another result whose values are already rendered.
+    pub(crate) fn push_fact(mut self, fact: Fact) -> Self {
+        self.push_keyed(fact);
+        self
+    }
+
+    /// Sets how facts added from now on are handled if their key is already used in the same
+    /// group, so that facts added by several helpers do not end up with confusingly repeated keys.
+    /// Facts of other groups, and `note` and `help` trailers, are left as is.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// fn add_actual(result: AssertionResult, value: i32) -> AssertionResult {
+    ///     result.add_formatted_fact(FactKey::Actual, value)
+    /// }
+    ///
+    /// fn add_actuals(result: AssertionResult, first: i32, second: i32) -> AssertionResult {
+    ///     // Adds facts keyed `actual #1` and `actual #2`.
+    ///     let result = result.with_duplicate_keys(DuplicateKeys::Index);
+    ///     add_actual(add_actual(result, first), second)
+    /// }
+    /// ```
+    pub fn with_duplicate_keys(mut self, duplicate_keys: DuplicateKeys) -> Self {
+        self.duplicate_keys = duplicate_keys;
+        self
+    }
+
+    /// Adds `fact`, handling a key already used in the current group as set by
+    /// [`AssertionResult::with_duplicate_keys`].
+    fn push_keyed(&mut self, mut fact: Fact) {
+        let key = match &fact {
+            Fact::KeyValue { key, .. } | Fact::KeyValues { key, .. }
+                if self.duplicate_keys != DuplicateKeys::Keep && !fact.is_trailer() =>
+            {
+                key.clone()
+            }
+            _ => {
+                self.facts.push(fact);
+                return;
+            }
+        };
+        let start = self.group_starts.last().copied().unwrap_or(0);
+        let same_key: Vec<usize> = (start..self.facts.len())
+            .filter(|&index| match &self.facts[index] {
+                Fact::KeyValue { key: other, .. } | Fact::KeyValues { key: other, .. } => {
+                    unindexed_key(other) == key
+                }
+                _ => false,
+            })
+            .collect();
+        match (self.duplicate_keys, same_key.first()) {
+            (DuplicateKeys::Merge, Some(&first)) => {
+                let mut values = self.facts[first].take_values();
+                values.extend(fact.take_values());
+                self.facts[first] = Fact::new_multi_value_fact(key, values);
+            }
+            (DuplicateKeys::Index, Some(&first)) => {
+                if same_key.len() == 1 {
+                    self.facts[first].set_key(format!("{} #1", key));
+                }
+                fact.set_key(format!("{} #{}", key, same_key.len() + 1));
+                self.facts.push(fact);
+            }
+            _ => self.facts.push(fact),
+        }
+    }
+
     #[inline]
     pub fn add_splitter(mut self) -> Self {
         self.facts.push(Fact::new_splitter());
         self
     }
 
+    /// Adds a `note` trailer, which is rendered at the end of the message like the notes of the
+    /// compiler, e.g. `= note: all expected elements were found`.
+    #[inline]
+    pub fn add_note<V: Into<String>>(mut self, note: V) -> Self {
+        self.facts.push(Fact::new(FactKey::Note, note));
+        self
+    }
+
+    /// Adds a `help` trailer, which is rendered at the end of the message like the help of the
+    /// compiler, e.g. `= help: use contains_exactly if the order does not matter`.
+    #[inline]
+    pub fn add_help<V: Into<String>>(mut self, help: V) -> Self {
+        self.facts.push(Fact::new(FactKey::Help, help));
+        self
+    }
+
+    /// Adds the facts of [`Subject::with_facts`] after a splitter.
+    pub(crate) fn with_site_facts(mut self) -> Self {
+        let facts: Vec<Fact> = std::mem::take(&mut self.site_facts)
+            .iter()
+            .flat_map(|facts| facts())
+            .collect();
+        if facts.is_empty() {
+            return self;
+        }
+        facts
+            .into_iter()
+            .fold(self.add_splitter(), AssertionResult::push_fact)
+    }
+
+    /// Makes the failure panic even if the subject is skipped, for assertions deriving a subject,
+    /// e.g. `first()` of an empty iterator, as no derived subject can be returned.
+    pub(crate) fn unskippable(mut self) -> Self {
+        if self.skipped {
+            self.skipped = false;
+            self = self
+                .add_note("the subject is skipped, but its derived subject could not be created");
+        }
+        self
+    }
+
+    /// Panics with the failure of an assertion deriving a subject. See
+    /// [`AssertionResult::unskippable`].
+    #[track_caller]
+    pub(crate) fn fail_derivation(self) -> ! {
+        AssertionStrategy::<()>::do_fail(self.unskippable());
+        unreachable!()
+    }
+
+    /// Adds an `evaluation took` fact if the assertion was slow. See
+    /// [`set_slow_assertion_threshold`](crate::set_slow_assertion_threshold).
+    pub(crate) fn with_slow_evaluation(self) -> Self {
+        match timing::slow(self.rendering.started) {
+            Some(elapsed) => self.add_fact("evaluation took", format!("{:?}", elapsed)),
+            None => self,
+        }
+    }
+
+    /// Prints the location of a passed assertion to stderr if it was slow.
+    pub(crate) fn report_slow_pass(&self) {
+        if let Some(elapsed) = timing::slow(self.rendering.started) {
+            let location = match &self.location {
+                None => String::new(),
+                Some(loc) => format!(": {}", loc),
+            };
+            eprintln!("slow assertion{}\nevaluation took: {:?}", location, elapsed);
+        }
+    }
+
     /// Generates an assertion message from the assertion result.
     pub fn generate_message(&self) -> String {
-        let mut messages = vec![];
+        if error::minimal_messages() {
+            return self.generate_minimal_message();
+        }
+        self.generate_default_message()
+    }
 
-        messages.push(format!(
-            "assertion failed{maybe_loc}",
+    /// Renders the default multi-line message, even if minimal messages are enabled, e.g. for golden
+    /// files.
+    pub(crate) fn generate_default_message(&self) -> String {
+        let header = format!(
+            "{header}{maybe_loc}",
+            header = catalog::translate("assertion failed"),
             maybe_loc = match &self.location {
                 None => String::new(),
                 Some(loc) => format!(": {}", loc),
             }
-        ));
+        );
+        let mut messages = vec![header];
+        messages.extend(self.format_fact_lines());
+        messages.join("\n")
+    }
 
-        let longest_key_length = self
+    /// Renders the message on a single line, e.g. `assertion failed: expected: 3; actual: 1`. See
+    /// [`set_minimal_messages`](crate::set_minimal_messages).
+    fn generate_minimal_message(&self) -> String {
+        let single_line = |value: &str| {
+            value
+                .split('\n')
+                .map(str::trim)
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let facts: Vec<String> = self
             .facts
             .iter()
-            .flat_map(|fact| match fact {
-                Fact::KeyValue { key, .. } => Some(key),
-                Fact::KeyValues { key, .. } => Some(key),
-                _ => None,
+            .filter_map(|fact| match catalog::localize(fact) {
+                Fact::KeyValue { key, value } => Some(format!("{}: {}", key, single_line(&value))),
+                Fact::KeyValues { key, values } => Some(match values.len() {
+                    1 => format!("{}: 1 value", key),
+                    n => format!("{}: {} values", key, n),
+                }),
+                Fact::Value { value } => Some(single_line(&value)),
+                Fact::Splitter => None,
             })
-            .map(|key| key.len())
-            .max()
-            .unwrap_or(0);
+            .collect();
+        if facts.is_empty() {
+            return catalog::translate("assertion failed");
+        }
+        format!(
+            "{}: {}",
+            catalog::translate("assertion failed"),
+            facts.join("; ")
+        )
+    }
+
+    /// Like [`AssertionResult::generate_message`], but colors the `expected` and `actual` values
+    /// if the `color` feature is enabled. Only panic messages are colored, so that
+    /// [`AssertionError`]s stay plain text.
+    fn generate_panic_message(&self) -> String {
+        #[cfg(feature = "color")]
+        if crate::color::enabled() {
+            let mut colored = self.clone();
+            crate::color::colorize(&mut colored.facts);
+            return colored.generate_message();
+        }
+        self.generate_message()
+    }
+
+    /// Renders the facts line by line, as they appear after the header of the message.
+    fn format_fact_lines(&self) -> Vec<String> {
+        let mut messages: Vec<String> = vec![];
+
+        let facts: Vec<Fact> = self
+            .facts
+            .iter()
+            .map(|fact| catalog::localize(&self.label_elements(fact)))
+            .collect();
+        let key_widths = self.key_widths(&facts);
+        let mut trailers = vec![];
 
-        for x in self.facts.iter() {
+        for ((x, &width), original) in facts.iter().zip(key_widths.iter()).zip(&self.facts) {
+            if original.is_trailer() {
+                trailers.push(x);
+                continue;
+            }
             match x {
                 Fact::KeyValue { key, value } => messages.push(format!(
-                    "{key:width$}: {value}",
-                    key = key,
-                    value = value,
-                    width = longest_key_length
+                    "{}{}",
+                    key_prefix(key, width),
+                    indent_continuation_lines(value, width + 2),
                 )),
                 Fact::KeyValues { key, values } => {
                     let values_size = values.len();
@@ -385,13 +1905,14 @@ impl AssertionResult {
                         .map(|x| format!("{:?}", x).len())
                         .max_by(|x, y| x.cmp(y))
                         .unwrap_or(0)
-                        > Self::DEBUG_LENGTH_WRAP_LIMIT;
+                        > Self::DEBUG_LENGTH_WRAP_LIMIT
+                        || values.iter().any(|value| value.contains('\n'));
                     let formatted_values = format!(
                         "{}",
                         if use_multiline_output {
                             let elements = values
                                 .iter()
-                                .map(|el| format!("  - {}", el))
+                                .map(|el| format!("  - {}", indent_continuation_lines(el, 4)))
                                 .collect::<Vec<_>>()
                                 .join("\n");
                             if values_size > 0 {
@@ -410,32 +1931,356 @@ impl AssertionResult {
                             )
                         }
                     );
-                    println!("{}", formatted_values);
-                    messages.push(format!(
-                        "{key:width$}: {value}",
-                        key = key,
-                        value = formatted_values,
-                        width = longest_key_length
-                    ));
+                    messages.push(format!("{}{}", key_prefix(key, width), formatted_values));
                 }
                 Fact::Value { value } => messages.push(value.to_string()),
                 Fact::Splitter => messages.push(String::from("---")),
             }
         }
-        messages.join("\n")
+        // Trailers follow the other facts after a splitter, unless there are none.
+        if !trailers.is_empty() && !messages.is_empty() && messages.last().unwrap() != "---" {
+            messages.push(String::from("---"));
+        }
+        for trailer in trailers {
+            if let Fact::KeyValue { key, value } = trailer {
+                let indent = key.chars().count() + 4;
+                messages.push(format!(
+                    "= {}: {}",
+                    key,
+                    indent_continuation_lines(value, indent)
+                ));
+            }
+        }
+        messages
+    }
+
+    /// Returns the widths the keys of `facts` are padded to: the length of the longest key within
+    /// the same group, leaving out keys longer than [`Subject::max_key_width`].
+    fn key_widths(&self, facts: &[Fact]) -> Vec<usize> {
+        let mut starts: Vec<usize> = if self.rendering.align_per_group {
+            facts
+                .iter()
+                .enumerate()
+                .filter(|(_, fact)| matches!(fact, Fact::Splitter))
+                .map(|(index, _)| index + 1)
+                .collect()
+        } else {
+            self.group_starts.clone()
+        };
+        starts.insert(0, 0);
+        starts.push(facts.len());
+
+        let mut widths = vec![0; facts.len()];
+        for bounds in starts.windows(2) {
+            let group = bounds[0]..bounds[1];
+            let width = facts[group.clone()]
+                .iter()
+                .zip(&self.facts[group.clone()])
+                .filter(|(_, original)| !original.is_trailer())
+                .flat_map(|(fact, _)| match fact {
+                    Fact::KeyValue { key, .. } => Some(key),
+                    Fact::KeyValues { key, .. } => Some(key),
+                    _ => None,
+                })
+                .map(|key| key.chars().count())
+                .filter(|&len| self.rendering.max_key_width.map_or(true, |max| len <= max))
+                .max()
+                .unwrap_or(0);
+            widths[group].fill(width);
+        }
+        widths
+    }
+
+    /// Labels the elements of a `though it did contain` fact with their indices, leaving out the
+    /// elements beyond [`Subject::max_listed_elements`].
+    fn label_elements(&self, fact: &Fact) -> Fact {
+        match fact {
+            Fact::KeyValues { key, values } if FactKey::ThoughItDidContain.is_phrase_of(key) => {
+                let max = self
+                    .rendering
+                    .max_listed_elements
+                    .unwrap_or(Self::MAX_LISTED_ELEMENTS);
+                let indices: Vec<usize> = if self.rendering.sampled && values.len() > max {
+                    Self::sample_indices(values, max)
+                } else {
+                    (0..values.len().min(max)).collect()
+                };
+                let mut labeled: Vec<String> = indices
+                    .into_iter()
+                    .map(|index| format!("[{}]: {}", index, values[index]))
+                    .collect();
+                if values.len() > max {
+                    labeled.push(format!("... ({} more)", values.len() - max));
+                }
+                Fact::new_multi_value_fact(key, labeled)
+            }
+            _ => fact.clone(),
+        }
+    }
+
+    /// Picks `count` indices of `values` in ascending order. The indices are ranked by a hash of
+    /// each index together with all values, so that the sample only depends on the contents.
+    fn sample_indices(values: &[String], count: usize) -> Vec<usize> {
+        let mut hasher = DefaultHasher::new();
+        values.hash(&mut hasher);
+        let seed = hasher.finish();
+        let mut ranked: Vec<(u64, usize)> = (0..values.len())
+            .map(|index| {
+                let mut hasher = DefaultHasher::new();
+                (seed, index).hash(&mut hasher);
+                (hasher.finish(), index)
+            })
+            .collect();
+        ranked.sort_unstable();
+        let mut indices: Vec<usize> = ranked.into_iter().take(count).map(|(_, i)| i).collect();
+        indices.sort_unstable();
+        indices
+    }
+
+    pub fn facts(&self) -> &Vec<Fact> {
+        &self.facts
+    }
+
+    /// Code location of the assertion, if known.
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    /// Whether the assertion is skipped, so that a failure must be treated as a pass. See
+    /// [`Subject::assuming`].
+    pub fn is_skipped(&self) -> bool {
+        self.skipped
+    }
+
+    /// Starts a group of facts, which is separated by a splitter from the facts of the preceding
+    /// group if both are non-empty. Useful for assertions which report several independent
+    /// findings, e.g. missing and unexpected entries.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// fn add_missing_facts(result: AssertionResult, missing: &[&str]) -> AssertionResult {
+    ///     if missing.is_empty() {
+    ///         return result;
+    ///     }
+    ///     result
+    ///         .begin_group()
+    ///         .add_fact("expected to contain all", "but some were missing")
+    ///         .add_splitter()
+    ///         .add_formatted_values_fact("missing", missing.to_vec())
+    ///         .end_group()
+    /// }
+    /// ```
+    pub fn begin_group(self) -> FactGroup {
+        let splitter_pending = !self.group_starts.is_empty();
+        FactGroup {
+            result: self,
+            splitter_pending,
+            empty: true,
+        }
+    }
+}
+
+/// Facts added between [`AssertionResult::begin_group`] and [`FactGroup::end_group`]. A splitter
+/// is inserted before the first fact of a group following a non-empty group, so that extensions
+/// need not track whether a splitter is needed.
+pub struct FactGroup {
+    result: AssertionResult,
+    splitter_pending: bool,
+    empty: bool,
+}
+
+#[allow(missing_docs)]
+impl FactGroup {
+    /// Adds the splitter separating this group from the preceding group, if needed.
+    fn open(mut self) -> AssertionResult {
+        if self.splitter_pending {
+            self.result = self.result.add_splitter();
+        }
+        self.result
+    }
+
+    fn with(self, add: impl FnOnce(AssertionResult) -> AssertionResult) -> Self {
+        let empty = self.empty;
+        let mut result = self.open();
+        if empty {
+            result.group_starts.push(result.facts.len());
+        }
+        FactGroup {
+            result: add(result),
+            splitter_pending: false,
+            empty: false,
+        }
+    }
+
+    pub fn add_fact<K: Into<String>, V: Into<String>>(self, key: K, value: V) -> Self {
+        self.with(|result| result.add_fact(key, value))
+    }
+
+    pub fn add_formatted_fact<K: Into<String>, V: Debug>(self, key: K, value: V) -> Self {
+        self.with(|result| result.add_formatted_fact(key, value))
+    }
+
+    pub fn add_formatted_values_fact<K: Into<String>, V: Debug>(
+        self,
+        key: K,
+        values: Vec<V>,
+    ) -> Self {
+        self.with(|result| result.add_formatted_values_fact(key, values))
     }
 
-    pub fn facts(&self) -> &Vec<Fact> {
-        &self.facts
+    pub fn add_simple_formatted_fact<V: Debug>(self, value: V) -> Self {
+        self.with(|result| result.add_simple_formatted_fact(value))
+    }
+
+    pub fn add_simple_fact<V: Into<String>>(self, value: V) -> Self {
+        self.with(|result| result.add_simple_fact(value))
+    }
+
+    pub(crate) fn push_fact(self, fact: Fact) -> Self {
+        self.with(|result| result.push_fact(fact))
+    }
+
+    /// Adds a splitter inside the group, e.g. between a summary and details.
+    pub fn add_splitter(self) -> Self {
+        self.with(AssertionResult::add_splitter)
+    }
+
+    /// Finishes the group and returns the assertion result.
+    pub fn end_group(self) -> AssertionResult {
+        self.result
+    }
+}
+
+/// Returns `key` without the index suffixed by [`DuplicateKeys::Index`], e.g. `actual` for
+/// `actual #2`.
+fn unindexed_key(key: &str) -> &str {
+    match key.rsplit_once(" #") {
+        Some((base, index)) if index.parse::<usize>().is_ok() => base,
+        _ => key,
+    }
+}
+
+/// Formats `facts` like the lines following the header of a failure message, with the keys
+/// aligned and the trailers at the end.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let facts = vec![
+///     Fact::new("expected", "2"),
+///     Fact::new_multi_value_fact("but was in", vec!["0", "1"]),
+/// ];
+/// assert_eq!(format_facts(&facts), "expected  : 2\nbut was in: [ 0, 1 ]");
+/// ```
+pub fn format_facts(facts: &[Fact]) -> String {
+    let mut result = AssertionResult::new(&None);
+    result.facts = facts.to_vec();
+    result.format_fact_lines().join("\n")
+}
+
+/// Renders `key` padded to `width`, followed by the separator from its value. Keys longer than
+/// `width` are put on their own line, and the value starts on the next line at the same column.
+fn key_prefix(key: &str, width: usize) -> String {
+    if key.chars().count() > width {
+        format!("{}:\n{}", key, " ".repeat(width + 2))
+    } else {
+        format!("{key:width$}: ")
     }
 }
 
+/// Indents all lines but the first one of `value` by `indent` spaces, so that multi-line values
+/// stay aligned with the column where they start.
+fn indent_continuation_lines(value: &str, indent: usize) -> String {
+    value.replace('\n', &format!("\n{}", " ".repeat(indent)))
+}
+
 impl Debug for AssertionResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(&self.generate_message())
     }
 }
 
+/// Like [`expr_text`], but renders only the body of a closure given by `stringify!`, e.g. `1 + 1`
+/// for `move || 1 + 1` and `{ 1 + 1 }` for `|| -> i32 { 1 + 1 }`. Used by [`assert_that_lazy`].
+#[doc(hidden)]
+pub fn closure_body_text(stringified: &str) -> String {
+    let closure = stringified.trim();
+    let closure = closure
+        .strip_prefix("move")
+        .map(str::trim_start)
+        .filter(|rest| rest.starts_with("||"))
+        .unwrap_or(closure);
+    let body = match closure.strip_prefix("||").map(str::trim_start) {
+        None => closure,
+        // Closures with a return type have a block body.
+        Some(body) => match body.strip_prefix("->") {
+            Some(typed) => typed.find('{').map_or(body, |start| &typed[start..]),
+            None => body,
+        },
+    };
+    expr_text(body)
+}
+
+/// Renders the source of an expression given by `stringify!` for facts, collapsing whitespace
+/// outside string and character literals into single spaces. Used by the macros making subjects.
+#[doc(hidden)]
+pub fn expr_text(stringified: &str) -> String {
+    let mut text = String::with_capacity(stringified.len());
+    let mut chars = stringified.trim().chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                while chars.next_if(|c| c.is_whitespace()).is_some() {}
+                text.push(' ');
+            }
+            '"' => {
+                text.push(c);
+                while let Some(c) = chars.next() {
+                    text.push(c);
+                    match c {
+                        '\\' => text.extend(chars.next()),
+                        '"' => break,
+                        _ => {}
+                    }
+                }
+            }
+            // Raw string literals, e.g. `r#"a "quoted"  text"#`, have no escapes.
+            'r' if !text.ends_with(|c: char| c.is_alphanumeric() || c == '_')
+                && chars.clone().find(|c| *c != '#') == Some('"') =>
+            {
+                text.push(c);
+                let hashes: String = std::iter::from_fn(|| chars.next_if_eq(&'#')).collect();
+                text.push_str(&hashes);
+                text.extend(chars.next());
+                let closing = format!("\"{}", hashes);
+                for c in chars.by_ref() {
+                    text.push(c);
+                    if text.ends_with(&closing) {
+                        break;
+                    }
+                }
+            }
+            // A character literal, unlike a lifetime, is closed by a second quote.
+            '\'' if chars.clone().nth(1) == Some('\'') || chars.peek() == Some(&'\\') => {
+                text.push(c);
+                while let Some(c) = chars.next() {
+                    text.push(c);
+                    match c {
+                        '\\' => text.extend(chars.next()),
+                        '\'' => break,
+                        _ => {}
+                    }
+                }
+            }
+            _ => text.push(c),
+        }
+    }
+    text
+}
+
 /// Code location.
 ///
 /// # Related
@@ -457,6 +2302,21 @@ impl Location {
             column,
         }
     }
+
+    /// File path of the location.
+    pub fn file(&self) -> &str {
+        &self.file
+    }
+
+    /// Line number of the location.
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Column number of the location.
+    pub fn column(&self) -> u32 {
+        self.column
+    }
 }
 
 impl fmt::Display for Location {
@@ -501,6 +2361,153 @@ pub enum Fact {
     Splitter,
 }
 
+/// Well-known keys of [`Fact::KeyValue`] and [`Fact::KeyValues`] facts.
+///
+/// Assertions in this library build their fact keys from [`FactKey`] instead of string literals,
+/// so meta-tests can match facts without depending on the English phrasing.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// assert_eq!(String::from(FactKey::Expected), "expected");
+/// assert_eq!(String::from(FactKey::MissingN(2)), "missing (2)");
+/// assert_eq!(Fact::new(FactKey::Actual, "1"), Fact::new("actual", "1"));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum FactKey {
+    /// `value of`: describes how the actual value was derived.
+    ValueOf,
+    /// `expected`
+    Expected,
+    /// `actual`
+    Actual,
+    /// `but was`
+    ButWas,
+    /// `missing (N)`: `N` expected elements were not found.
+    MissingN(usize),
+    /// `unexpected (N)`: `N` elements were found but not expected.
+    UnexpectedN(usize),
+    /// `found (N)`: `N` elements were found but expected to be absent.
+    FoundN(usize),
+    /// `expected to contain`
+    ExpectedToContain,
+    /// `expected to not contain`
+    ExpectedToNotContain,
+    /// `expected to contain at least`
+    ExpectedToContainAtLeast,
+    /// `expected to contain none of`
+    ExpectedToContainNoneOf,
+    /// `expected to contain any of`
+    ExpectedToContainAnyOf,
+    /// `though it did contain`
+    ThoughItDidContain,
+    /// `outside tolerance`
+    OutsideTolerance,
+    /// `differs at`: a path inside the `Debug` representation where two values differ.
+    DiffersAt,
+    /// `did you mean`: elements similar to an expected element which was not found.
+    DidYouMean,
+    /// `note`: a trailer explaining the failure. See [`AssertionResult::add_note`].
+    Note,
+    /// `help`: a trailer suggesting a fix. See [`AssertionResult::add_help`].
+    Help,
+}
+
+impl FactKey {
+    /// Returns the phrase of the key, unless it contains a count.
+    fn static_phrase(self) -> Option<&'static str> {
+        Some(match self {
+            FactKey::ValueOf => "value of",
+            FactKey::Expected => "expected",
+            FactKey::Actual => "actual",
+            FactKey::ButWas => "but was",
+            FactKey::ExpectedToContain => "expected to contain",
+            FactKey::ExpectedToNotContain => "expected to not contain",
+            FactKey::ExpectedToContainAtLeast => "expected to contain at least",
+            FactKey::ExpectedToContainNoneOf => "expected to contain none of",
+            FactKey::ExpectedToContainAnyOf => "expected to contain any of",
+            FactKey::ThoughItDidContain => "though it did contain",
+            FactKey::OutsideTolerance => "outside tolerance",
+            FactKey::DiffersAt => "differs at",
+            FactKey::DidYouMean => "did you mean",
+            FactKey::Note => "note",
+            FactKey::Help => "help",
+            FactKey::MissingN(_) | FactKey::UnexpectedN(_) | FactKey::FoundN(_) => return None,
+        })
+    }
+
+    /// Whether `key` is the phrase of this key. Keys without a count are compared without
+    /// formatting the phrase.
+    pub(crate) fn is_phrase_of(self, key: &str) -> bool {
+        match self.static_phrase() {
+            Some(phrase) => phrase == key,
+            None => self.to_string() == key,
+        }
+    }
+}
+
+impl fmt::Display for FactKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FactKey::MissingN(n) => write!(f, "missing ({})", n),
+            FactKey::UnexpectedN(n) => write!(f, "unexpected ({})", n),
+            FactKey::FoundN(n) => write!(f, "found ({})", n),
+            key => f.write_str(key.static_phrase().unwrap_or_default()),
+        }
+    }
+}
+
+impl std::str::FromStr for FactKey {
+    type Err = ();
+
+    /// Parses a fact key phrase produced by [`FactKey`]'s `Display` implementation.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let counted = |prefix: &str| {
+            s.strip_prefix(prefix)
+                .and_then(|rest| rest.strip_suffix(')'))
+                .and_then(|n| n.parse::<usize>().ok())
+        };
+        match s {
+            "value of" => Ok(FactKey::ValueOf),
+            "expected" => Ok(FactKey::Expected),
+            "actual" => Ok(FactKey::Actual),
+            "but was" => Ok(FactKey::ButWas),
+            "expected to contain" => Ok(FactKey::ExpectedToContain),
+            "expected to not contain" => Ok(FactKey::ExpectedToNotContain),
+            "expected to contain at least" => Ok(FactKey::ExpectedToContainAtLeast),
+            "expected to contain none of" => Ok(FactKey::ExpectedToContainNoneOf),
+            "expected to contain any of" => Ok(FactKey::ExpectedToContainAnyOf),
+            "though it did contain" => Ok(FactKey::ThoughItDidContain),
+            "outside tolerance" => Ok(FactKey::OutsideTolerance),
+            "differs at" => Ok(FactKey::DiffersAt),
+            "did you mean" => Ok(FactKey::DidYouMean),
+            "note" => Ok(FactKey::Note),
+            "help" => Ok(FactKey::Help),
+            _ => counted("missing (")
+                .map(FactKey::MissingN)
+                .or_else(|| counted("unexpected (").map(FactKey::UnexpectedN))
+                .or_else(|| counted("found (").map(FactKey::FoundN))
+                .ok_or(()),
+        }
+    }
+}
+
+impl From<FactKey> for String {
+    fn from(key: FactKey) -> Self {
+        key.to_string()
+    }
+}
+
+/// Formats the fact as it appears in failure messages, e.g. `expected: 2`. See [`format_facts`]
+/// to align the keys of several facts.
+impl fmt::Display for Fact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&format_facts(std::slice::from_ref(self)))
+    }
+}
+
 #[allow(missing_docs)]
 impl Fact {
     pub fn new<K: Into<String>, V: Into<String>>(key: K, value: V) -> Fact {
@@ -523,12 +2530,37 @@ impl Fact {
     pub fn new_splitter() -> Fact {
         Fact::Splitter
     }
+
+    /// Takes the values of a keyed fact, leaving it without values.
+    fn take_values(&mut self) -> Vec<String> {
+        match self {
+            Fact::KeyValue { value, .. } => vec![std::mem::take(value)],
+            Fact::KeyValues { values, .. } => std::mem::take(values),
+            _ => vec![],
+        }
+    }
+
+    fn set_key(&mut self, new_key: String) {
+        if let Fact::KeyValue { key, .. } | Fact::KeyValues { key, .. } = self {
+            *key = new_key;
+        }
+    }
+
+    /// Whether the fact is a `note` or `help` trailer, rendered at the end of the message.
+    fn is_trailer(&self) -> bool {
+        match self {
+            Fact::KeyValue { key, .. } => {
+                FactKey::Note.is_phrase_of(key) || FactKey::Help.is_phrase_of(key)
+            }
+            _ => false,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use crate::testing::{CheckThatResult, CheckThatResultAssertion};
     use crate::*;
-    use crate::testing::CheckThatResult;
 
     use super::*;
 
@@ -539,6 +2571,72 @@ mod tests {
         assert_that!(vec![""]);
     }
 
+    #[test]
+    fn debug_assert_that() {
+        debug_assert_that!(1, is_equal_to(1));
+        debug_assert_that!(vec![1, 2], contains(2));
+        debug_assert_that!("a b", split(" ").has_length(2));
+
+        let mut evaluations = 0;
+        debug_assert_that!(
+            {
+                evaluations += 1;
+                evaluations
+            },
+            is_at_least(1)
+        );
+        assert_eq!(evaluations, usize::from(cfg!(debug_assertions)));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[cfg_attr(not(feature = "color"), should_panic(expected = "expected: 2"))]
+    // Whether the value is colored depends on `NO_COLOR`.
+    #[cfg_attr(feature = "color", should_panic(expected = "expected: "))]
+    fn debug_assert_that_failure() {
+        debug_assert_that!(1, is_equal_to(2));
+    }
+
+    #[test]
+    fn const_assert_that() {
+        const_assert_that!(std::mem::size_of::<u32>(), is_equal_to(4));
+        const_assert_that!(1, is_not_equal_to(2));
+        const_assert_that!(1, is_less_than(2));
+        const_assert_that!(2, is_greater_than(1));
+        const_assert_that!(u8::MAX as u32, is_at_most(255));
+        const_assert_that!('b', is_at_least('a'));
+        const_assert_that!(1 < 2, is_true());
+        const_assert_that!(1 > 2, is_false());
+    }
+
+    #[test]
+    fn format_facts() {
+        let facts = vec![
+            Fact::new("expected", "2"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_splitter(),
+            Fact::new("actual", "1\n3"),
+            Fact::new(FactKey::Help, "use is_at_least"),
+        ];
+        assert_that!(super::format_facts(&facts)).is_equal_to(
+            "expected: 2\nbut did not\n---\nactual  : 1\n          3\n---\n= help: use is_at_least"
+                .to_string(),
+        );
+        assert_that!(super::format_facts(&[])).is_equal_to(String::new());
+    }
+
+    #[test]
+    fn fact_display() {
+        assert_that!(Fact::new("expected", "2").to_string()).is_equal_to("expected: 2".to_string());
+        assert_that!(Fact::new_multi_value_fact("values", vec!["1", "2"]).to_string())
+            .is_equal_to("values: [ 1, 2 ]".to_string());
+        assert_that!(Fact::new_simple_fact("but did not").to_string())
+            .is_equal_to("but did not".to_string());
+        assert_that!(Fact::new_splitter().to_string()).is_equal_to("---".to_string());
+        assert_that!(Fact::new(FactKey::Note, "n").to_string())
+            .is_equal_to("= note: n".to_string());
+    }
+
     #[test]
     fn assert_that_unit_return_type() {
         assert_eq!(assert_that!(1).return_type, PhantomData::<()>::default());
@@ -567,6 +2665,215 @@ mod tests {
         );
     }
 
+    #[test]
+    fn reuse_subject() {
+        let values = vec![1, 2, 3].into_iter();
+        let subject = assert_that!(values);
+        subject.contains_exactly_in_order(vec![1, 2, 3].into_iter());
+        subject.contains_all_of(vec![3, 1].into_iter());
+        subject.contains_exactly(vec![3, 2, 1].into_iter());
+
+        let subject = assert_that!(1000_u32);
+        subject.clone().with_abs_tol(5).is_approx_equal_to(1005);
+        subject.with_abs_tol(10).is_approx_equal_to(990);
+    }
+
+    #[test]
+    fn assert_that_lazy() {
+        let calls = Cell::new(0);
+        let compute = || {
+            calls.set(calls.get() + 1);
+            vec![1, 2, 3]
+        };
+        let _ = assert_that_lazy!(compute);
+        assert_eq!(calls.get(), 0);
+        assert_that_lazy!(compute).has_length(3);
+        assert_eq!(calls.get(), 1);
+        assert_eq!(
+            assert_that_lazy!(|| 1 + 1).description_or_expr(),
+            &"1 + 1".to_string()
+        );
+        assert_eq!(
+            assert_that_lazy!(|| -> i32 { 1 + 1 }).description_or_expr(),
+            &"{ 1 + 1 }".to_string()
+        );
+        assert_eq!(
+            assert_that_lazy!(move || 1 + 1).description_or_expr(),
+            &"1 + 1".to_string()
+        );
+    }
+
+    #[test]
+    fn assert_that_lazy_skipped() {
+        let calls = Cell::new(0);
+        assert_that_lazy!(|| {
+            calls.set(calls.get() + 1);
+            1
+        })
+        .skip_if(true)
+        .is_equal_to(2);
+        assert_that_lazy!(|| -> Vec<i32> { panic!("not computed") })
+            .assuming(false)
+            .contains(1);
+        assert_eq!(calls.get(), 0);
+    }
+
+    #[test]
+    fn assert_that_lazy_panicking() {
+        set_structured_panics(true);
+        let payload = panic::catch_unwind(|| {
+            assert_that_lazy!(|| "x".parse::<u32>().unwrap()).is_equal_to(1)
+        });
+        set_structured_panics(false);
+
+        let error = payload.unwrap_err().downcast::<AssertionError>().unwrap();
+        assert_that!(error.location().unwrap().file()).is_equal_to("src/base.rs");
+        assert_that!(error.facts().to_vec()).contains_exactly_in_order(vec![
+            Fact::new("value of", r#""x".parse::<u32>().unwrap()"#),
+            Fact::new_simple_fact("failed to compute the actual value"),
+            Fact::new(
+                "panicked with",
+                "called `Result::unwrap()` on an `Err` value: ParseIntError { kind: InvalidDigit }",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn assuming() {
+        assert_that!(1).assuming(false).is_equal_to(2);
+        assert_that!(vec![1]).skip_if(true).has_length(2);
+        assert_that!(1).assuming(true).skip_if(false).is_equal_to(1);
+        // Derived subjects are skipped too.
+        assert_that!(Some(vec![1]))
+            .assuming(false)
+            .some()
+            .contains(2);
+        assert_eq!(
+            try_assert_that!(1, Failure).skip_if(true).is_equal_to(2),
+            Ok(())
+        );
+
+        // Failures
+        assert_that!(check_that!(1).assuming(true).is_equal_to(2))
+            .facts_are(vec![Fact::new("expected", "2"), Fact::new("actual", "1")]);
+        assert!(check_that!(1)
+            .assuming(false)
+            .is_equal_to(2)
+            .as_ref()
+            .is_ok());
+    }
+
+    #[test]
+    fn assuming_with_underivable_subject() {
+        set_structured_panics(true);
+        let payload = panic::catch_unwind(|| {
+            assert_that!(Vec::<i32>::new())
+                .skip_if(true)
+                .first()
+                .is_equal_to(1)
+        });
+        set_structured_panics(false);
+
+        // No derived subject can be returned, so the failure is not skipped.
+        let error = payload.unwrap_err().downcast::<AssertionError>().unwrap();
+        assert_that!(error.facts().to_vec()).contains_exactly_in_order(vec![
+            Fact::new_simple_fact("expected to be non-empty to compute first()"),
+            Fact::new_simple_fact("but was empty"),
+            Fact::new(
+                "note",
+                "the subject is skipped, but its derived subject could not be created",
+            ),
+        ]);
+    }
+
+    #[test]
+    fn add_expected_actual() {
+        let result = AssertionResult::new(&None).add_expected_actual(Some(Ok::<_, ()>(3)), "3");
+        assert_eq!(
+            result.facts(),
+            &vec![
+                Fact::new("expected", "Some→Ok: 3"),
+                Fact::new("actual", r#""3""#),
+            ]
+        );
+    }
+
+    #[test]
+    fn with_facts() {
+        assert_that!(1)
+            .with_facts(|| panic!("facts of a passing assertion"))
+            .is_equal_to(1);
+
+        // Failures
+        let shard = 3;
+        assert_that!(check_that!(1)
+            .with_facts(move || vec![Fact::new("shard", shard.to_string())])
+            .with_facts(|| vec![Fact::new_simple_fact("retried")])
+            .is_equal_to(2))
+        .facts_are(vec![
+            Fact::new("expected", "2"),
+            Fact::new("actual", "1"),
+            Fact::new_splitter(),
+            Fact::new("shard", "3"),
+            Fact::new_simple_fact("retried"),
+        ]);
+        // Derived subjects have the facts too.
+        assert_that!(check_that!(Some(1))
+            .with_facts(|| vec![Fact::new("shard", "3")])
+            .some()
+            .is_equal_to(2))
+        .facts_are(vec![
+            Fact::new("expected", "2"),
+            Fact::new("actual", "1"),
+            Fact::new_splitter(),
+            Fact::new("shard", "3"),
+        ]);
+        // Facts which are empty add no splitter.
+        assert_that!(check_that!(1).with_facts(Vec::new).is_equal_to(2))
+            .facts_are(vec![Fact::new("expected", "2"), Fact::new("actual", "1")]);
+    }
+
+    #[test]
+    fn into_actual_and_actual_with_expr() {
+        let values = vec![3, 1, 2];
+        assert_eq!(assert_that!(values).into_actual(), vec![3, 1, 2]);
+        assert_eq!(assert_that!(values).sorted().into_actual(), vec![1, 2, 3]);
+        assert_eq!(assert_that_lazy!(|| 1 + 1).into_actual(), 2);
+
+        let subject = assert_that!(values);
+        assert_eq!(subject.actual_with_expr(), (&vec![3, 1, 2], "values"));
+        let sorted = subject.sorted();
+        assert_eq!(
+            sorted.actual_with_expr(),
+            (&vec![1, 2, 3], "values.sorted()")
+        );
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Failure(Vec<Fact>);
+
+    impl From<AssertionResult> for Failure {
+        fn from(result: AssertionResult) -> Self {
+            Failure(result.facts().clone())
+        }
+    }
+
+    #[test]
+    fn try_assert_that() {
+        assert_eq!(try_assert_that!(1, Failure).is_equal_to(1), Ok(()));
+        assert_eq!(
+            try_assert_that!(1, Failure).is_equal_to(2),
+            Err(Failure(vec![
+                Fact::new("expected", "2"),
+                Fact::new("actual", "1"),
+            ]))
+        );
+        assert_eq!(
+            try_assert_that!(vec![1], Failure).return_type,
+            PhantomData::<Result<(), Failure>>
+        );
+    }
+
     #[test]
     fn assert_result_message_generation() {
         assert_eq!(
@@ -717,4 +3024,472 @@ k: LongOutputData { val: Some(1), nested: ["123", "321"] }
 LongOutputData { val: Some(2), nested: ["1234"] }"#
         );
     }
+
+    #[test]
+    fn minimal_messages_keep_location() {
+        let result = AssertionResult::new(&Some(Location::new("foo.rs", 123, 456)))
+            .add_fact("expected", "3")
+            .add_fact("actual", "1");
+        set_minimal_messages(true);
+        let message = result.generate_message();
+        set_minimal_messages(false);
+        assert_eq!(message, "assertion failed: expected: 3; actual: 1");
+        assert_eq!(result.location().map(Location::line), Some(123));
+        assert_eq!(
+            result.generate_message(),
+            "assertion failed: foo.rs:123:456\nexpected: 3\nactual  : 1"
+        );
+    }
+
+    #[test]
+    fn fact_key() {
+        assert_eq!(FactKey::ValueOf.to_string(), "value of");
+        assert_eq!(FactKey::UnexpectedN(3).to_string(), "unexpected (3)");
+        assert_eq!(
+            Fact::new(FactKey::FoundN(1), "[1]"),
+            Fact::new("found (1)", "[1]")
+        );
+        assert_eq!(
+            Fact::new_multi_value_fact(FactKey::ButWas, vec!["1"]),
+            Fact::new_multi_value_fact("but was", vec!["1"])
+        );
+        assert_eq!("missing (12)".parse::<FactKey>(), Ok(FactKey::MissingN(12)));
+        assert_eq!("expected".parse::<FactKey>(), Ok(FactKey::Expected));
+        assert_eq!("missing (x)".parse::<FactKey>(), Err(()));
+        assert_eq!("foo".parse::<FactKey>(), Err(()));
+    }
+
+    #[test]
+    fn redacted() {
+        assert_that!("s3cr3t")
+            .redacted()
+            .is_same_string_to("s3cr3t");
+
+        // Failures
+        let result = check_that!("s3cr3t")
+            .redacted()
+            .is_same_string_to("password");
+        assert_that!(result)
+            .fact_value_for_key("actual")
+            .starts_with("«redacted» (len=8, hash=");
+        assert_that!(result)
+            .fact_value_for_key("expected")
+            .starts_with("«redacted» (len=10, hash=");
+        assert_that!(result)
+            .fact_value_for_key("expected")
+            .does_not_contain("password");
+
+        // Derived subjects are redacted too, and the same values have the same hash.
+        let result = check_that!(vec!["s3cr3t"]).redacted().has_length(2);
+        assert_that!(result)
+            .fact_value_for_key("value of")
+            .is_same_string_to("vec![\"s3cr3t\"].size()");
+        assert_that!(result)
+            .fact_value_for_key("actual")
+            .starts_with("«redacted» (len=1, hash=");
+        let first = check_that!("s3cr3t").redacted().contains("x");
+        let second = check_that!("s3cr3t").redacted().contains("y");
+        let but_was =
+            |result: &CheckThatResult| match &result.as_ref().as_ref().unwrap_err().facts()[1] {
+                Fact::KeyValue { value, .. } => value.clone(),
+                _ => unreachable!(),
+            };
+        assert_that!(but_was(&first)).is_equal_to(but_was(&second));
+    }
+
+    #[test]
+    fn pretty_debug() {
+        #[derive(Debug, PartialEq)]
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+        assert_that!(Point { x: 1, y: 2 })
+            .pretty_debug()
+            .is_equal_to(Point { x: 1, y: 2 });
+
+        // Failures
+        let result = check_that!(Point { x: 1, y: 2 })
+            .pretty_debug()
+            .is_equal_to(Point { x: 1, y: 3 });
+        assert_that!(result).facts_are(vec![
+            Fact::new("differs at", ".y (expected: 3, actual: 2)"),
+            Fact::new_splitter(),
+            Fact::new("expected", "Point {\n    x: 1,\n    y: 3,\n}"),
+            Fact::new("actual", "Point {\n    x: 1,\n    y: 2,\n}"),
+        ]);
+        let result = check_that!(vec![Point { x: 1, y: 2 }])
+            .pretty_debug()
+            .contains(Point { x: 0, y: 0 });
+        assert_that!(result).facts_are(vec![
+            Fact::new("expected to contain", "Point {\n    x: 0,\n    y: 0,\n}"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact(
+                "though it did contain",
+                vec!["Point {\n    x: 1,\n    y: 2,\n}"],
+            ),
+        ]);
+    }
+
+    #[test]
+    fn debug_of() {
+        let values = vec![1, 2];
+        let mut cache = DebugCache::default();
+        assert_eq!(cache.debug_of(&values), "[1, 2]");
+        assert_eq!(cache.debug_of(&values[..1]), "[1]");
+        assert_eq!(cache.debug_of(&values[..]), "[1, 2]");
+        assert_eq!(cache.debug_of(&values[0]), "1");
+        assert_eq!(cache.entries.len(), 4);
+        assert_eq!(cache.debug_of(&values[..1]), "[1]");
+        assert_eq!(cache.entries.len(), 4);
+    }
+
+    #[test]
+    fn group_digits() {
+        assert_that!(1_000_000)
+            .group_digits()
+            .is_equal_to(1_000_000);
+
+        // Failures
+        assert_that!(check_that!(12_345_678_u64)
+            .group_digits()
+            .is_equal_to(1_234_567))
+        .facts_are(vec![
+            Fact::new("expected", "1_234_567"),
+            Fact::new("actual", "12_345_678"),
+        ]);
+        assert_that!(check_that!(vec![-123_456, 7]).group_digits().contains(8)).facts_are(vec![
+            Fact::new("expected to contain", "8"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["-123_456", "7"]),
+        ]);
+        assert_that!(check_that!(12_345_678).is_equal_to(1_234_567)).facts_are(vec![
+            Fact::new("expected", "1234567"),
+            Fact::new("actual", "12345678"),
+        ]);
+    }
+
+    #[test]
+    fn listed_elements_message_generation() {
+        let result = AssertionResult::new(&None)
+            .add_formatted_values_fact("though it did contain", vec!["a", "b"])
+            .add_formatted_values_fact("other", vec!["c"]);
+        assert_eq!(
+            result.generate_message(),
+            r#"assertion failed
+though it did contain: [ [0]: "a", [1]: "b" ]
+other                : [ "c" ]"#
+        );
+
+        let result = check_that!((0..40).collect::<Vec<_>>()).contains(40);
+        let message = result.into_result().unwrap_err().to_string();
+        assert!(message.ends_with("[30]: 30, [31]: 31, ... (8 more) ]"));
+
+        // Facts keep all elements.
+        let result = check_that!(vec![1, 2, 3])
+            .max_listed_elements(1)
+            .contains(4);
+        assert_that!(result).facts_are(vec![
+            Fact::new("expected to contain", "4"),
+            Fact::new_simple_fact("but did not"),
+            Fact::new_multi_value_fact("though it did contain", vec!["1", "2", "3"]),
+        ]);
+        assert!(result.into_result().unwrap_err().to_string().ends_with(
+            r#"
+expected to contain  : 4
+but did not
+though it did contain: [ [0]: 1, ... (2 more) ]"#
+        ));
+    }
+
+    #[test]
+    fn sampled_elements_message_generation() {
+        let message = |values: Vec<i32>| {
+            check_that!(values)
+                .max_listed_elements(3)
+                .sample_listed_elements()
+                .contains(-1)
+                .into_result()
+                .unwrap_err()
+                .to_string()
+        };
+        let first = message((0..100).collect());
+        assert_eq!(first, message((0..100).collect()));
+        assert!(first.ends_with("[ [6]: 6, [48]: 48, [86]: 86, ... (97 more) ]"));
+
+        // Fewer elements than the maximum are all listed.
+        assert!(message(vec![1, 2]).ends_with("[ [0]: 1, [1]: 2 ]"));
+    }
+
+    #[test]
+    fn expr_text() {
+        assert_eq!(super::expr_text(stringify!(foo(a, b))), "foo(a, b)");
+        assert_eq!(super::expr_text(" x\n    .len() "), "x .len()");
+        assert_eq!(
+            super::expr_text(r#"vec!["a  b", "c\"  d"]"#),
+            r#"vec!["a  b", "c\"  d"]"#
+        );
+        assert_eq!(super::expr_text("[' ',  '\'',  'x']"), "[' ', '\'', 'x']");
+        assert_eq!(
+            super::expr_text(r###"f::<'a,  'b>(r#"a  "b"  c"#,  br"x  \")"###),
+            r###"f::<'a, 'b>(r#"a  "b"  c"#, br"x  \")"###
+        );
+        assert_eq!(super::expr_text("for  x"), "for x");
+    }
+
+    #[test]
+    fn tricky_expressions() {
+        fn add(a: i32, b: i32) -> i32 {
+            a + b
+        }
+        assert_that!(add(1, 2)).is_equal_to(3);
+        assert_that!({
+            let x = add(1, 1);
+            x * 2
+        })
+        .is_equal_to(4);
+        assert_that!(if add(0, 1) > 0 { "yes" } else { "no" }).is_equal_to("yes");
+        assert_that!(format!("{}, {}", 1, 2)).is_equal_to("1, 2".to_string());
+        assert_that!(vec![add(1, 2), 4]).contains(3);
+
+        // Expressions are shown with the spacing of string literals preserved.
+        let result = try_assert_that!(vec!["a  b", "c"]).has_length(3);
+        assert_that!(result.unwrap_err().facts().to_vec())
+            .contains(Fact::new("value of", r#"vec!["a  b", "c"].size()"#));
+    }
+
+    #[test]
+    fn fact_groups() {
+        let result = AssertionResult::new(&None)
+            .add_fact("value of", "x")
+            .begin_group()
+            .add_fact("a", "1")
+            .add_splitter()
+            .add_fact("b", "2")
+            .end_group()
+            .begin_group()
+            .end_group()
+            .begin_group()
+            .add_simple_fact("c")
+            .end_group();
+        assert_eq!(
+            result.facts(),
+            &vec![
+                Fact::new("value of", "x"),
+                Fact::new("a", "1"),
+                Fact::new_splitter(),
+                Fact::new("b", "2"),
+                Fact::new_splitter(),
+                Fact::new_simple_fact("c"),
+            ]
+        );
+    }
+
+    #[test]
+    fn fact_groups_message_generation() {
+        let result = AssertionResult::new(&None)
+            .add_fact("value of", "x")
+            .begin_group()
+            .add_fact("a", "1")
+            .add_fact("a very long key", "2")
+            .end_group()
+            .begin_group()
+            .add_fact("b", "3")
+            .end_group();
+        assert_eq!(
+            result.generate_message(),
+            r#"assertion failed
+value of: x
+a              : 1
+a very long key: 2
+---
+b: 3"#
+        );
+    }
+
+    #[test]
+    fn duplicate_keys() {
+        let add_facts = |duplicate_keys| {
+            AssertionResult::new(&None)
+                .with_duplicate_keys(duplicate_keys)
+                .add_fact("actual", "1")
+                .add_formatted_values_fact("actual", vec![2, 3])
+                .add_fact("expected", "4")
+                .add_fact("actual", "5")
+                .add_note("a")
+                .add_note("b")
+                .begin_group()
+                .add_fact("actual", "6")
+                .end_group()
+                .facts()
+                .clone()
+        };
+        assert_that!(add_facts(DuplicateKeys::Keep)).contains_exactly_in_order(vec![
+            Fact::new("actual", "1"),
+            Fact::new_multi_value_fact("actual", vec!["2", "3"]),
+            Fact::new("expected", "4"),
+            Fact::new("actual", "5"),
+            Fact::new("note", "a"),
+            Fact::new("note", "b"),
+            Fact::new("actual", "6"),
+        ]);
+        assert_that!(add_facts(DuplicateKeys::Merge)).contains_exactly_in_order(vec![
+            Fact::new_multi_value_fact("actual", vec!["1", "2", "3", "5"]),
+            Fact::new("expected", "4"),
+            Fact::new("note", "a"),
+            Fact::new("note", "b"),
+            Fact::new("actual", "6"),
+        ]);
+        assert_that!(add_facts(DuplicateKeys::Index)).contains_exactly_in_order(vec![
+            Fact::new("actual #1", "1"),
+            Fact::new_multi_value_fact("actual #2", vec!["2", "3"]),
+            Fact::new("expected", "4"),
+            Fact::new("actual #3", "5"),
+            Fact::new("note", "a"),
+            Fact::new("note", "b"),
+            Fact::new("actual", "6"),
+        ]);
+    }
+
+    #[test]
+    fn trailers_message_generation() {
+        let result = AssertionResult::new(&None)
+            .add_note("first note")
+            .add_fact("expected", "1")
+            .add_fact("actual", "2")
+            .add_help("multi-line\nhelp");
+        assert_eq!(
+            result.facts().first(),
+            Some(&Fact::new(FactKey::Note, "first note"))
+        );
+        assert_eq!(
+            result.generate_message(),
+            r#"assertion failed
+expected: 1
+actual  : 2
+---
+= note: first note
+= help: multi-line
+        help"#
+        );
+
+        // No splitter is added without other facts or after a splitter.
+        let result = AssertionResult::new(&None).add_help("help");
+        assert_eq!(result.generate_message(), "assertion failed\n= help: help");
+        let result = AssertionResult::new(&None)
+            .add_simple_fact("failed")
+            .add_splitter()
+            .add_note("note");
+        assert_eq!(
+            result.generate_message(),
+            "assertion failed\nfailed\n---\n= note: note"
+        );
+    }
+
+    #[test]
+    fn key_layout_message_generation() {
+        let message = |result: CheckThatResult| result.into_result().unwrap_err().to_string();
+        assert!(
+            message(check_that!(vec![1, 2]).contains_exactly(vec![2, 3])).ends_with(
+                r#"
+missing (1)   : [3]
+unexpected (1): [1]
+---
+expected      : [ 2, 3 ]
+actual        : [ 1, 2 ]"#
+            )
+        );
+        assert!(message(
+            check_that!(vec![1, 2])
+                .align_keys_per_group()
+                .contains_exactly(vec![2, 3])
+        )
+        .ends_with(
+            r#"
+missing (1)   : [3]
+unexpected (1): [1]
+---
+expected: [ 2, 3 ]
+actual  : [ 1, 2 ]"#
+        ));
+        assert!(message(
+            check_that!(vec![1, 2])
+                .max_key_width(10)
+                .contains_exactly(vec![2, 3])
+        )
+        .ends_with(
+            r#"
+missing (1):
+          [3]
+unexpected (1):
+          [1]
+---
+expected: [ 2, 3 ]
+actual  : [ 1, 2 ]"#
+        ));
+
+        // Multi-line values of wrapped keys are indented to the aligned column.
+        let result = AssertionResult::new(&None)
+            .add_fact("key", "value")
+            .add_fact("long key", "Point {\n    x: 1,\n}");
+        let result = AssertionResult {
+            rendering: Rendering {
+                max_key_width: Some(3),
+                ..Rendering::default()
+            },
+            ..result
+        };
+        assert_eq!(
+            result.generate_message(),
+            r#"assertion failed
+key: value
+long key:
+     Point {
+         x: 1,
+     }"#
+        );
+    }
+
+    #[test]
+    fn multi_line_message_generation() {
+        let mut result = AssertionResult::new(&Some(Location::new("foo.rs", 123, 456)))
+            .add_fact("key", "value")
+            .add_fact("long key", "Point {\n    x: 1,\n}");
+        result.facts.push(Fact::new_multi_value_fact(
+            "values",
+            vec!["A {\n    a: 1,\n}", "B"],
+        ));
+        assert_eq!(
+            result.generate_message(),
+            r#"assertion failed: foo.rs:123:456
+key     : value
+long key: Point {
+              x: 1,
+          }
+values  : [
+  - A {
+        a: 1,
+    }
+  - B
+]"#
+        );
+    }
+
+    #[test]
+    fn minimal_message_generation() {
+        let result = AssertionResult::new(&None)
+            .add_fact("long key", "Point {\n    x: 1,\n}")
+            .add_splitter()
+            .add_formatted_values_fact("values", vec![1, 2])
+            .add_simple_fact("failed")
+            .add_note("note");
+        assert_eq!(
+            result.generate_minimal_message(),
+            "assertion failed: long key: Point { x: 1, }; values: 2 values; failed; note: note"
+        );
+        assert_eq!(
+            AssertionResult::new(&None).generate_minimal_message(),
+            "assertion failed"
+        );
+    }
 }
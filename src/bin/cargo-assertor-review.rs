@@ -0,0 +1,236 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reviews the pending golden files written by `message_matches_golden` when tests are run with
+//! `ASSERTOR_UPDATE_GOLDEN=new`, like `cargo insta review` does for `.snap.new` files.
+//!
+//! ```text
+//! cargo assertor-review [review|accept|reject] [DIR]
+//! ```
+//!
+//! `review`, the default, shows the changes of each pending golden file under `DIR`, the current
+//! directory by default, and asks whether to accept it, replacing the golden file, or reject it,
+//! deleting the pending golden file. `accept` and `reject` do so for all pending golden files.
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use assertor::testing::PENDING_GOLDEN_EXTENSION;
+
+const USAGE: &str = "usage: cargo assertor-review [review|accept|reject] [DIR]";
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Decision {
+    Accept,
+    Reject,
+    Skip,
+}
+
+/// Decides what to do with a pending golden file, given its path and the golden file path.
+type Decide = Box<dyn FnMut(&Path, &Path) -> io::Result<Decision>>;
+
+fn main() -> ExitCode {
+    // Cargo runs `cargo-assertor-review assertor-review ARGS..` for `cargo assertor-review ARGS..`.
+    let args: Vec<String> = std::env::args()
+        .skip(1)
+        .skip_while(|arg| arg == "assertor-review")
+        .collect();
+    let (command, dir) = match args.as_slice() {
+        [] => ("review", "."),
+        [command] => (command.as_str(), "."),
+        [command, dir] => (command.as_str(), dir.as_str()),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let decide: Decide = match command {
+        "review" => Box::new(ask),
+        "accept" => Box::new(|_, _| Ok(Decision::Accept)),
+        "reject" => Box::new(|_, _| Ok(Decision::Reject)),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    match review(Path::new(dir), decide) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {}", error);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Applies the decision of `decide` to each pending golden file under `dir`.
+fn review(
+    dir: &Path,
+    mut decide: impl FnMut(&Path, &Path) -> io::Result<Decision>,
+) -> io::Result<()> {
+    let pending_files = find_pending(dir)?;
+    if pending_files.is_empty() {
+        println!("no pending golden files");
+        return Ok(());
+    }
+    let (mut accepted, mut rejected, mut skipped) = (0, 0, 0);
+    for pending in pending_files {
+        let golden = pending.with_extension("");
+        match decide(&pending, &golden)? {
+            Decision::Accept => {
+                fs::rename(&pending, &golden)?;
+                accepted += 1;
+            }
+            Decision::Reject => {
+                fs::remove_file(&pending)?;
+                rejected += 1;
+            }
+            Decision::Skip => skipped += 1,
+        }
+    }
+    println!(
+        "accepted: {}, rejected: {}, skipped: {}",
+        accepted, rejected, skipped
+    );
+    Ok(())
+}
+
+/// Returns the pending golden files under `dir`, sorted, skipping hidden directories and build
+/// outputs.
+fn find_pending(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut found = vec![];
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in fs::read_dir(&dir)? {
+            let path = entry?.path();
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            if path.is_dir() {
+                if !name.starts_with('.') && name != "target" {
+                    dirs.push(path);
+                }
+            } else if path
+                .extension()
+                .map_or(false, |ext| ext == PENDING_GOLDEN_EXTENSION)
+            {
+                found.push(path);
+            }
+        }
+    }
+    found.sort();
+    Ok(found)
+}
+
+/// Shows the changes of `pending` to `golden`, and asks what to do.
+fn ask(pending: &Path, golden: &Path) -> io::Result<Decision> {
+    let old = fs::read_to_string(golden).unwrap_or_default();
+    let new = fs::read_to_string(pending)?;
+    println!("golden file: {}", golden.display());
+    for line in diff_lines(&old, &new) {
+        println!("{}", line);
+    }
+    let stdin = io::stdin();
+    loop {
+        print!("accept (a), reject (r), skip (s)? ");
+        io::stdout().flush()?;
+        let mut answer = String::new();
+        if stdin.lock().read_line(&mut answer)? == 0 {
+            return Ok(Decision::Skip);
+        }
+        match answer.trim() {
+            "a" => return Ok(Decision::Accept),
+            "r" => return Ok(Decision::Reject),
+            "s" => return Ok(Decision::Skip),
+            _ => {}
+        }
+    }
+}
+
+/// Returns the lines of `old` and `new` prefixed with `-` if removed, `+` if added and a space if
+/// kept, from a longest common subsequence of lines.
+fn diff_lines(old: &str, new: &str) -> Vec<String> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+    // common[i][j] is the length of the longest common subsequence of old[i..] and new[j..].
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    let (mut i, mut j) = (0, 0);
+    let mut lines = vec![];
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(format!(" {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            lines.push(format!("-{}", old[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+{}", new[j]));
+            j += 1;
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use assertor::*;
+
+    use super::*;
+
+    #[test]
+    fn diff() {
+        assert_that!(diff_lines("a\nb\nc\n", "a\nx\nc\n")).contains_exactly_in_order(vec![
+            " a".to_string(),
+            "-b".to_string(),
+            "+x".to_string(),
+            " c".to_string(),
+        ]);
+        assert_that!(diff_lines("", "a\n")).contains_exactly_in_order(vec!["+a".to_string()]);
+    }
+
+    #[test]
+    fn accept_and_reject() {
+        let dir = std::env::temp_dir().join("assertor_review");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("a.txt"), "old\n").unwrap();
+        fs::write(dir.join("a.txt.new"), "new\n").unwrap();
+        fs::write(dir.join("nested/b.txt.new"), "new\n").unwrap();
+
+        assert_that!(find_pending(&dir).unwrap())
+            .contains_exactly_in_order(vec![dir.join("a.txt.new"), dir.join("nested/b.txt.new")]);
+        review(&dir, |pending, _| {
+            Ok(if pending.ends_with("a.txt.new") {
+                Decision::Accept
+            } else {
+                Decision::Reject
+            })
+        })
+        .unwrap();
+        assert_that!(fs::read_to_string(dir.join("a.txt")).unwrap())
+            .is_equal_to("new\n".to_string());
+        assert_that!(find_pending(&dir).unwrap()).is_empty();
+        assert_that!(dir.join("nested/b.txt").exists()).is_false();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
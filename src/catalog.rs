@@ -0,0 +1,222 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+use crate::base::{Fact, FactKey};
+
+/// A catalog of phrases used in assertion messages.
+///
+/// Only the rendered message (see [`crate::AssertionResult::generate_message`]) is translated.
+/// [`Fact`]s stored in [`crate::AssertionResult`] are kept in English so that tests against the
+/// facts do not depend on the installed catalog.
+///
+/// A catalog is installed for the whole process with [`set_message_catalog`], or for the current
+/// thread while running a closure with [`with_message_catalog`], which takes precedence.
+///
+/// # Example
+/// ```
+/// use std::collections::HashMap;
+/// use assertor::*;
+///
+/// let catalog: HashMap<String, String> = HashMap::from([
+///     ("expected".to_string(), "erwartet".to_string()),
+///     ("actual".to_string(), "tatsächlich".to_string()),
+/// ]);
+/// let error = with_message_catalog(catalog, || try_assert_that!(1).is_equal_to(2)).unwrap_err();
+/// assert!(error.to_string().contains("erwartet"));
+/// // erwartet   : 2
+/// // tatsächlich: 1
+/// ```
+pub trait MessageCatalog: Send + Sync {
+    /// Returns the translation of a well-known fact key, or `None` to fall back to
+    /// [`MessageCatalog::phrase`].
+    fn fact_key(&self, key: FactKey) -> Option<String> {
+        let _ = key;
+        None
+    }
+
+    /// Returns the translation of `phrase`, or `None` to keep the English phrase. `phrase` is a
+    /// fact key, a simple fact, or the `assertion failed` header.
+    fn phrase(&self, phrase: &str) -> Option<String>;
+}
+
+impl MessageCatalog for HashMap<String, String> {
+    fn phrase(&self, phrase: &str) -> Option<String> {
+        self.get(phrase).cloned()
+    }
+}
+
+static CATALOG: RwLock<Option<Box<dyn MessageCatalog>>> = RwLock::new(None);
+
+thread_local! {
+    static SCOPED_CATALOG: RefCell<Option<Box<dyn MessageCatalog>>> = RefCell::new(None);
+}
+
+/// Installs `catalog` as the process-wide message catalog. Prefer [`with_message_catalog`] in
+/// tests, as the process-wide catalog also applies to tests running in parallel.
+pub fn set_message_catalog<C: MessageCatalog + 'static>(catalog: C) {
+    *CATALOG.write().unwrap_or_else(|e| e.into_inner()) = Some(Box::new(catalog));
+}
+
+/// Restores the default English messages.
+pub fn reset_message_catalog() {
+    *CATALOG.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Runs `f` with `catalog` as the message catalog of the current thread, and restores the previous
+/// one afterwards, even if `f` panics.
+pub fn with_message_catalog<C: MessageCatalog + 'static, T, F: FnOnce() -> T>(
+    catalog: C,
+    f: F,
+) -> T {
+    struct Restore(Option<Box<dyn MessageCatalog>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SCOPED_CATALOG.with(|scoped| *scoped.borrow_mut() = previous);
+        }
+    }
+
+    let previous = SCOPED_CATALOG.with(|scoped| scoped.replace(Some(Box::new(catalog))));
+    let _restore = Restore(previous);
+    f()
+}
+
+/// Translates `phrase` with the catalog of the current thread, or else the process-wide one.
+pub(crate) fn translate(phrase: &str) -> String {
+    let scoped = SCOPED_CATALOG.with(|scoped| {
+        scoped
+            .borrow()
+            .as_deref()
+            .map(|catalog| translate_with(catalog, phrase))
+    });
+    if let Some(translation) = scoped {
+        return translation;
+    }
+    let catalog = CATALOG.read().unwrap_or_else(|e| e.into_inner());
+    match catalog.as_deref() {
+        None => phrase.to_string(),
+        Some(catalog) => translate_with(catalog, phrase),
+    }
+}
+
+/// Translates `phrase` with `catalog`.
+fn translate_with(catalog: &dyn MessageCatalog, phrase: &str) -> String {
+    phrase
+        .parse::<FactKey>()
+        .ok()
+        .and_then(|key| catalog.fact_key(key))
+        .or_else(|| catalog.phrase(phrase))
+        .unwrap_or_else(|| phrase.to_string())
+}
+
+/// Returns a copy of `fact` whose key or simple value is translated with the installed catalog.
+pub(crate) fn localize(fact: &Fact) -> Fact {
+    localize_with(fact, translate)
+}
+
+/// Returns a copy of `fact` whose key or simple value is translated by `translate`.
+fn localize_with<F: Fn(&str) -> String>(fact: &Fact, translate: F) -> Fact {
+    match fact {
+        Fact::KeyValue { key, value } => Fact::new(translate(key), value.clone()),
+        Fact::KeyValues { key, values } => {
+            Fact::new_multi_value_fact(translate(key), values.clone())
+        }
+        Fact::Value { value } => Fact::new_simple_fact(translate(value)),
+        Fact::Splitter => Fact::Splitter,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::{AssertionResult, EqualityAssertion, VecAssertion};
+
+    use super::*;
+
+    struct Upper;
+
+    impl MessageCatalog for Upper {
+        fn fact_key(&self, key: FactKey) -> Option<String> {
+            match key {
+                FactKey::MissingN(n) => Some(format!("MISSING[{}]", n)),
+                _ => None,
+            }
+        }
+
+        fn phrase(&self, phrase: &str) -> Option<String> {
+            match phrase {
+                "catalog test key" | "catalog test fact" => Some(phrase.to_uppercase()),
+                _ => None,
+            }
+        }
+    }
+
+    #[test]
+    fn translate_with_catalog() {
+        assert_that!(translate_with(&Upper, "catalog test key"))
+            .is_equal_to("CATALOG TEST KEY".to_string());
+        assert_that!(translate_with(&Upper, &FactKey::MissingN(2).to_string()))
+            .is_equal_to("MISSING[2]".to_string());
+        assert_that!(translate_with(&Upper, "not in catalog"))
+            .is_equal_to("not in catalog".to_string());
+        assert_that!(translate("catalog test key")).is_equal_to("catalog test key".to_string());
+    }
+
+    #[test]
+    fn localize_facts() {
+        let result: AssertionResult = match check_that!(1).is_equal_to(2).as_ref() {
+            Err(result) => result.clone(),
+            Ok(_) => unreachable!(),
+        };
+        let result = result
+            .add_fact("catalog test key", "value")
+            .add_simple_fact("catalog test fact");
+
+        let localized: Vec<Fact> = result
+            .facts()
+            .iter()
+            .map(|fact| localize_with(fact, |phrase| translate_with(&Upper, phrase)))
+            .collect();
+        assert_that!(localized).contains_exactly_in_order(vec![
+            Fact::new("expected", "2"),
+            Fact::new("actual", "1"),
+            Fact::new("CATALOG TEST KEY", "value"),
+            Fact::new_simple_fact("CATALOG TEST FACT"),
+        ]);
+        // Facts in the result are kept untouched.
+        assert_that!(result.facts().clone()).contains(Fact::new("catalog test key", "value"));
+    }
+
+    #[test]
+    fn scoped_catalog() {
+        let translated = with_message_catalog(Upper, || {
+            let inner = with_message_catalog(HashMap::new(), || translate("catalog test key"));
+            (translate("catalog test key"), inner)
+        });
+        assert_that!(translated).is_equal_to((
+            "CATALOG TEST KEY".to_string(),
+            "catalog test key".to_string(),
+        ));
+        assert_that!(translate("catalog test key")).is_equal_to("catalog test key".to_string());
+
+        // The previous catalog is restored when the closure panics.
+        let _ = std::panic::catch_unwind(|| with_message_catalog(Upper, || panic!("failed")));
+        assert_that!(translate("catalog test key")).is_equal_to("catalog test key".to_string());
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::stats;
+use crate::{AssertionError, AssertionResult, AssertionStrategy, Location};
+
+/// An assertion macro to get the result of an assertion as a [`CheckThatResult`] instead of
+/// panicking. Mostly used to test assertions, with the `testing` feature.
+///
+/// # Example
+///
+/// ```ignore
+/// use assertor::*;
+/// use assertor::testing::*;
+///
+/// assert_that!(check_that!("actual_string").is_same_string_to("expected_string")).facts_are(vec![
+///     Fact::new("expected", "expected_string"),
+///     Fact::new("actual", "actual_string"),
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! check_that {
+    ($actual:expr) => {
+        $crate::Subject::new(
+            &$actual,
+            $crate::expr_text(stringify!($actual)),
+            /* description= */ None,
+            /* option= */ (),
+            Some($crate::Location::new(
+                file!().to_string(),
+                line!(),
+                column!(),
+            )),
+            std::marker::PhantomData::<$crate::CheckThatResult>,
+        )
+    };
+}
+
+/// Evaluates several [`check_that`](crate::check_that) expressions and merges their failures into
+/// one [`CheckThatResult`], each labeled with its expression. Useful to validate many properties
+/// and report all failures together without panicking.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let (name, tags) = ("assertor", vec!["testing"]);
+/// let result = check_all!(
+///     check_that!(name).starts_with("assert"),
+///     check_that!(tags).contains("rust"),
+///     check_that!(name.len()).is_equal_to(4),
+/// );
+/// assert!(result.message().unwrap().contains("but 2 of 3 did not"));
+/// // expected all checks to pass: but 2 of 3 did not
+/// // ---
+/// // check                : check_that!(tags).contains("rust")
+/// // expected to contain  : "rust"
+/// // but did not
+/// // though it did contain: [ [0]: "testing" ]
+/// // ---
+/// // check   : check_that!(name.len()).is_equal_to(4)
+/// // expected: 4
+/// // actual  : 8
+/// ```
+#[macro_export]
+macro_rules! check_all {
+    ($($check:expr),+ $(,)?) => {
+        $crate::CheckThatResult::merge(
+            vec![$(($crate::expr_text(stringify!($check)), $check)),+],
+            Some($crate::Location::new(
+                file!().to_string(),
+                line!(),
+                column!(),
+            )),
+        )
+    };
+}
+
+/// Data structure that contains assertion result and messages.
+pub struct CheckThatResult(Result<(), AssertionResult>);
+
+impl AssertionStrategy<CheckThatResult> for AssertionResult {
+    fn do_fail(self) -> CheckThatResult {
+        if self.is_skipped() {
+            return CheckThatResult(Ok(()));
+        }
+        stats::record(true);
+        CheckThatResult(Err(self.with_site_facts().with_slow_evaluation()))
+    }
+
+    fn do_ok(self) -> CheckThatResult {
+        if !self.is_skipped() {
+            stats::record(false);
+            self.report_slow_pass();
+        }
+        // XXX: Unnecessary AssertionResult instantiation for ok cases.
+        CheckThatResult(Ok(()))
+    }
+}
+
+impl CheckThatResult {
+    /// Converts into a [`Result`], with an [`AssertionError`] if the check failed.
+    pub fn into_result(self) -> Result<(), AssertionError> {
+        self.0.map_err(AssertionError::from)
+    }
+
+    /// Whether the check passed or was skipped.
+    pub fn is_ok(&self) -> bool {
+        self.0.is_ok()
+    }
+
+    /// Returns the assertion message if the check failed.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// let result = check_that!(vec![1, 2]).contains(3);
+    /// assert!(!result.is_ok());
+    /// assert!(result.message().unwrap().contains("expected to contain"));
+    /// assert_eq!(check_that!(1).is_equal_to(1).message(), None);
+    /// ```
+    pub fn message(&self) -> Option<String> {
+        self.0.as_ref().err().map(AssertionResult::generate_message)
+    }
+
+    /// Merges the failures of `checks`, labeled with their expressions. Used by
+    /// [`check_all`](crate::check_all).
+    #[doc(hidden)]
+    pub fn merge(checks: Vec<(String, CheckThatResult)>, location: Option<Location>) -> Self {
+        let total = checks.len();
+        let failures: Vec<(String, AssertionResult)> = checks
+            .into_iter()
+            .filter_map(|(expr, check)| check.0.err().map(|failure| (expr, failure)))
+            .collect();
+        if failures.is_empty() {
+            return CheckThatResult(Ok(()));
+        }
+        let result = AssertionResult::new(&location)
+            .begin_group()
+            .add_fact(
+                "expected all checks to pass",
+                format!("but {} of {} did not", failures.len(), total),
+            )
+            .end_group();
+        CheckThatResult(Err(failures.into_iter().fold(
+            result,
+            |result, (expr, failure)| {
+                failure
+                    .facts()
+                    .iter()
+                    .fold(
+                        result.begin_group().add_fact("check", expr),
+                        |group, fact| group.push_fact(fact.clone()),
+                    )
+                    .end_group()
+            },
+        )))
+    }
+}
+
+/// Converts into a [`Result`] with the assertion message if the check failed, e.g. to log it.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let result: Result<(), String> = check_that!("abc").starts_with("x").into();
+/// assert!(result.unwrap_err().starts_with("assertion failed"));
+/// ```
+impl From<CheckThatResult> for Result<(), String> {
+    fn from(result: CheckThatResult) -> Self {
+        result.0.map_err(|result| result.generate_message())
+    }
+}
+
+impl AsRef<Result<(), AssertionResult>> for CheckThatResult {
+    fn as_ref(&self) -> &Result<(), AssertionResult> {
+        &self.0
+    }
+}
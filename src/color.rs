@@ -0,0 +1,165 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Inline coloring of `expected` and `actual` values in panic messages, like
+//! `pretty_assertions`. Enabled by the `color` feature, unless the `NO_COLOR` environment
+//! variable is set to a non-empty value (<https://no-color.org>).
+
+use crate::base::{Fact, FactKey};
+use crate::diff::string::edit_script;
+
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const REVERSE: &str = "\x1b[7m";
+const NO_REVERSE: &str = "\x1b[27m";
+const RESET: &str = "\x1b[0m";
+
+/// Whether panic messages are colored.
+pub(crate) fn enabled() -> bool {
+    std::env::var_os("NO_COLOR").map_or(true, |value| value.is_empty())
+}
+
+/// Colors the value of each `expected` fact in red and the value of the following `actual` or
+/// `but was` fact in green, highlighting the characters which differ.
+pub(crate) fn colorize(facts: &mut [Fact]) {
+    let mut expected_index = None;
+    for index in 0..facts.len() {
+        let Fact::KeyValue { key, .. } = &facts[index] else {
+            continue;
+        };
+        if FactKey::Expected.is_phrase_of(key) {
+            expected_index = Some(index);
+        } else if FactKey::Actual.is_phrase_of(key) || FactKey::ButWas.is_phrase_of(key) {
+            if let Some(expected_index) = expected_index.take() {
+                let (left, right) = facts.split_at_mut(index);
+                colorize_pair(&mut left[expected_index], &mut right[0]);
+            }
+        }
+    }
+}
+
+fn colorize_pair(expected: &mut Fact, actual: &mut Fact) {
+    let (
+        Fact::KeyValue {
+            value: expected, ..
+        },
+        Fact::KeyValue { value: actual, .. },
+    ) = (expected, actual)
+    else {
+        return;
+    };
+    if let Some((colored_expected, colored_actual)) = highlight(expected, actual) {
+        *expected = colored_expected;
+        *actual = colored_actual;
+    }
+}
+
+/// Renders `expected` in red and `actual` in green, with the characters which differ in reverse
+/// video. Returns `None` if they are equal or too long to be compared.
+pub(crate) fn highlight(expected: &str, actual: &str) -> Option<(String, String)> {
+    if expected == actual {
+        return None;
+    }
+    let mut colored_expected = Highlighter::new(RED);
+    let mut colored_actual = Highlighter::new(GREEN);
+    for (e, a) in edit_script(expected, actual)? {
+        let changed = e != a;
+        if let Some(e) = e {
+            colored_expected.push(e, changed);
+        }
+        if let Some(a) = a {
+            colored_actual.push(a, changed);
+        }
+    }
+    Some((colored_expected.finish(), colored_actual.finish()))
+}
+
+struct Highlighter {
+    colored: String,
+    reversed: bool,
+}
+
+impl Highlighter {
+    fn new(color: &str) -> Self {
+        Highlighter {
+            colored: color.to_string(),
+            reversed: false,
+        }
+    }
+
+    /// Appends `c`, in reverse video if `changed`. Line breaks are never reversed, so that the
+    /// indentation of the following line is not highlighted.
+    fn push(&mut self, c: char, changed: bool) {
+        let reverse = changed && c != '\n';
+        if reverse != self.reversed {
+            self.colored
+                .push_str(if reverse { REVERSE } else { NO_REVERSE });
+            self.reversed = reverse;
+        }
+        self.colored.push(c);
+    }
+
+    fn finish(mut self) -> String {
+        self.colored.push_str(RESET);
+        self.colored
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlights_differences() {
+        assert_eq!(
+            highlight("foo bar", "fo bat"),
+            Some((
+                "\x1b[31mf\x1b[7mo\x1b[27mo ba\x1b[7mr\x1b[0m".to_string(),
+                "\x1b[32mfo ba\x1b[7mt\x1b[0m".to_string(),
+            ))
+        );
+        assert_eq!(highlight("same", "same"), None);
+    }
+
+    #[test]
+    fn keeps_line_breaks_plain() {
+        assert_eq!(
+            highlight("a\nb", "x\ny"),
+            Some((
+                "\x1b[31m\x1b[7ma\x1b[27m\n\x1b[7mb\x1b[0m".to_string(),
+                "\x1b[32m\x1b[7mx\x1b[27m\n\x1b[7my\x1b[0m".to_string(),
+            ))
+        );
+    }
+
+    #[test]
+    fn colorizes_expected_and_actual_pairs() {
+        let mut facts = vec![
+            Fact::new("expected", "1"),
+            Fact::new("but was", "2"),
+            Fact::new("value of", "x"),
+            Fact::new("actual", "3"),
+        ];
+        colorize(&mut facts);
+        assert_eq!(
+            facts,
+            vec![
+                Fact::new("expected", "\x1b[31m\x1b[7m1\x1b[0m"),
+                Fact::new("but was", "\x1b[32m\x1b[7m2\x1b[0m"),
+                Fact::new("value of", "x"),
+                Fact::new("actual", "3"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,81 @@
+// Copyright 2021 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Common misuse of the assertions, which must keep failing to compile with the listed error
+//! codes. Compiler errors are part of the API: an assertion that does not apply to the subject
+//! is reported as a missing method, and a wrong expected value as an unsatisfied bound on the
+//! expected type.
+//!
+//! Only the error codes are checked here, not the wording of the diagnostics: there is no
+//! snapshot suite of the compiler output, as it changes between compiler versions. The trait
+//! bounds are not annotated with custom diagnostics either, which need a newer compiler than
+//! the minimum supported one.
+//!
+//! Assertions not supported by the subject type:
+//! ```compile_fail,E0599
+//! use assertor::*;
+//!
+//! assert_that!(1).contains(1);
+//! ```
+//! ```compile_fail,E0599
+//! use assertor::*;
+//!
+//! assert_that!("abc").has_length(3);
+//! ```
+//! ```compile_fail,E0599
+//! use assertor::*;
+//!
+//! struct NoDebug;
+//! assert_that!(NoDebug).is_equal_to(NoDebug);
+//! ```
+//! ```compile_fail,E0599
+//! use assertor::*;
+//!
+//! let error: Box<dyn std::error::Error> = "missing key".into();
+//! assert_that_dyn!(error).is_equal_to("missing key");
+//! ```
+//!
+//! Expected values of a wrong type:
+//! ```compile_fail,E0277
+//! use assertor::*;
+//!
+//! assert_that!(vec![1, 2]).contains_exactly(vec!["a"]);
+//! ```
+//! ```compile_fail,E0277
+//! use assertor::*;
+//!
+//! assert_that!(vec![1, 2].iter()).contains_exactly(vec!["a"].iter());
+//! ```
+//! ```compile_fail,E0277
+//! use assertor::*;
+//!
+//! assert_that!(1).is_equal_to("1");
+//! ```
+//! ```compile_fail,E0277
+//! use assertor::*;
+//!
+//! assert_that!(Some(1)).has_value("1");
+//! ```
+//!
+//! Compile-time assertions which do not hold:
+//! ```compile_fail,E0080
+//! use assertor::*;
+//!
+//! const_assert_that!(std::mem::size_of::<u64>(), is_at_most(4));
+//! ```
+//! ```compile_fail,E0080
+//! use assertor::*;
+//!
+//! const_assert_that!(u8::MAX == 0, is_true());
+//! ```
@@ -0,0 +1,243 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+
+use crate::base::{AssertionResult, AssertionStrategy, Fact, Location};
+use crate::error::{set_structured_panics, AssertionError};
+
+/// Runs a closure making assertions on several threads at once, many times on each, and reports
+/// the distinct failures with the number of times each occurred, to reproduce flaky concurrency
+/// bugs under a single readable message.
+///
+/// The closure is called with the index of the thread and of the iteration. Failures which have
+/// the same facts are reported once, most frequent first, with the first thread and iteration
+/// they occurred on. Panics other than failed assertions are reported by their message.
+///
+/// # Example
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use assertor::*;
+///
+/// let counter = AtomicUsize::new(0);
+/// assert_concurrent!(threads = 4, iterations = 100, |_thread, _iteration| {
+///     let previous = counter.fetch_add(1, Ordering::SeqCst);
+///     assert_that!(previous).is_less_than(400);
+/// });
+/// assert_that!(counter.into_inner()).is_equal_to(400);
+///
+/// demo!(
+///     assert_concurrent!(threads = 2, iterations = 3, |thread, iteration| {
+///         assert_that!(vec![thread, iteration]).does_not_contain(2);
+///     }),
+///     r#"
+///     expected all runs to pass: but 2 of 6 did not
+///     ---
+///     occurrences            : 1 (first on thread 0, iteration 2)
+///     expected to not contain: 2
+///     but element was found
+///     though it did contain  : [ [0]: 0, [1]: 2 ]
+///     ---
+///     occurrences            : 1 (first on thread 1, iteration 2)
+///     expected to not contain: 2
+///     but element was found
+///     though it did contain  : [ [0]: 1, [1]: 2 ]
+///     "#
+/// );
+/// ```
+#[macro_export]
+macro_rules! assert_concurrent {
+    (threads = $threads:expr, iterations = $iterations:expr, $check:expr $(,)?) => {
+        $crate::run_concurrent(
+            $threads,
+            $iterations,
+            $check,
+            Some($crate::Location::new(
+                file!().to_string(),
+                line!(),
+                column!(),
+            )),
+        )
+    };
+}
+
+/// A distinct failure, with where it first occurred and how often.
+struct Failure {
+    facts: Vec<Fact>,
+    first: (usize, usize),
+    occurrences: usize,
+}
+
+/// Runs `check` for `iterations` iterations on each of `threads` threads, and fails with the
+/// distinct failures. Called by [`assert_concurrent`](crate::assert_concurrent).
+#[doc(hidden)]
+pub fn run_concurrent<F>(threads: usize, iterations: usize, check: F, location: Option<Location>)
+where
+    F: Fn(usize, usize) + Sync,
+{
+    if let Some(result) = run(threads, iterations, &check, &location) {
+        AssertionStrategy::<()>::do_fail(result)
+    }
+}
+
+fn run<F>(
+    threads: usize,
+    iterations: usize,
+    check: &F,
+    location: &Option<Location>,
+) -> Option<AssertionResult>
+where
+    F: Fn(usize, usize) + Sync,
+{
+    let outcomes: Vec<Vec<(Vec<Fact>, usize, usize)>> = thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|thread| {
+                scope.spawn(move || {
+                    set_structured_panics(true);
+                    (0..iterations)
+                        .filter_map(|iteration| {
+                            panic::catch_unwind(AssertUnwindSafe(|| check(thread, iteration)))
+                                .err()
+                                .map(|payload| (failure_facts(payload), thread, iteration))
+                        })
+                        .collect()
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or_default())
+            .collect()
+    });
+
+    let mut failures: Vec<Failure> = vec![];
+    let mut failed = 0;
+    for (facts, thread, iteration) in outcomes.into_iter().flatten() {
+        failed += 1;
+        match failures.iter_mut().find(|failure| failure.facts == facts) {
+            Some(failure) => {
+                failure.occurrences += 1;
+                failure.first = failure.first.min((thread, iteration));
+            }
+            None => failures.push(Failure {
+                facts,
+                first: (thread, iteration),
+                occurrences: 1,
+            }),
+        }
+    }
+    if failures.is_empty() {
+        return None;
+    }
+    failures.sort_by(|a, b| {
+        b.occurrences
+            .cmp(&a.occurrences)
+            .then(a.first.cmp(&b.first))
+    });
+
+    let result = AssertionResult::new(location)
+        .begin_group()
+        .add_fact(
+            "expected all runs to pass",
+            format!("but {} of {} did not", failed, threads * iterations),
+        )
+        .end_group();
+    Some(failures.into_iter().fold(result, |result, failure| {
+        let (thread, iteration) = failure.first;
+        let group = result.begin_group().add_fact(
+            "occurrences",
+            format!(
+                "{} (first on thread {}, iteration {})",
+                failure.occurrences, thread, iteration
+            ),
+        );
+        failure
+            .facts
+            .into_iter()
+            .fold(group, |group, fact| group.push_fact(fact))
+            .end_group()
+    }))
+}
+
+/// Returns the facts of a failed assertion, or the message of another panic.
+fn failure_facts(payload: Box<dyn Any + Send>) -> Vec<Fact> {
+    let payload = match payload.downcast::<AssertionError>() {
+        Ok(error) => return error.facts().to_vec(),
+        Err(payload) => payload,
+    };
+    let message = match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or("Box<dyn Any>", |message| message)
+            .to_string(),
+    };
+    vec![Fact::new("panicked with", message)]
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::testing::*;
+    use crate::{ComparableAssertion, EqualityAssertion};
+
+    use super::*;
+
+    #[test]
+    fn all_pass() {
+        let calls = AtomicUsize::new(0);
+        assert_concurrent!(threads = 3, iterations = 5, |_, _| {
+            calls.fetch_add(1, Ordering::SeqCst);
+        });
+        assert_that!(calls.into_inner()).is_equal_to(15);
+        assert_concurrent!(threads = 0, iterations = 5, |_, _| unreachable!());
+    }
+
+    #[test]
+    fn distinct_failures() {
+        let result = run(
+            2,
+            4,
+            &|thread, iteration| {
+                if iteration == 3 {
+                    panic!("iteration {}", iteration);
+                }
+                assert_that!(thread).is_equal_to(0);
+            },
+            &None,
+        )
+        .unwrap();
+        assert_that!(AssertionStrategy::<CheckThatResult>::do_fail(result)).facts_are(vec![
+            Fact::new("expected all runs to pass", "but 5 of 8 did not"),
+            Fact::new_splitter(),
+            Fact::new("occurrences", "3 (first on thread 1, iteration 0)"),
+            Fact::new("expected", "0"),
+            Fact::new("actual", "1"),
+            Fact::new_splitter(),
+            Fact::new("occurrences", "2 (first on thread 0, iteration 3)"),
+            Fact::new("panicked with", "iteration 3"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected all runs to pass: but 4 of 4 did not")]
+    fn failure() {
+        assert_concurrent!(threads = 2, iterations = 2, |_, _| {
+            assert_that!(1).is_greater_than(2);
+        });
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::error::{minimal_messages, structured_panics};
+use crate::{set_minimal_messages, set_structured_panics, AssertionError};
+
+/// Checks that an assertion fails with the given message, to keep the failure messages shown in
+/// documentation examples in sync with the real ones.
+///
+/// The first line of the message, which contains the code location, is not compared. Leading and
+/// trailing whitespace of each line, and blank lines around the message, are ignored, so that the
+/// expected message can be indented with the surrounding code. Demos show the default messages, so
+/// minimal messages are disabled while the assertion runs.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// demo!(
+///     assert_that!(vec![1, 2]).contains(3),
+///     "
+///     expected to contain  : 3
+///     but did not
+///     though it did contain: [ [0]: 1, [1]: 2 ]
+///     "
+/// );
+/// ```
+#[macro_export]
+macro_rules! demo {
+    ($assertion:expr, $expected:expr $(,)?) => {
+        $crate::check_demo(
+            || {
+                $assertion;
+            },
+            $expected,
+        )
+    };
+}
+
+/// Runs `assertion` and panics unless it fails with `expected`. Used by [`demo`](crate::demo).
+#[doc(hidden)]
+#[track_caller]
+pub fn check_demo<F: FnOnce()>(assertion: F, expected: &str) {
+    let (structured, minimal) = (structured_panics(), minimal_messages());
+    set_structured_panics(true);
+    set_minimal_messages(false);
+    let outcome = panic::catch_unwind(AssertUnwindSafe(assertion));
+    set_structured_panics(structured);
+    set_minimal_messages(minimal);
+
+    let payload = match outcome {
+        Ok(()) => panic!("expected the assertion to fail, but it passed"),
+        Err(payload) => payload,
+    };
+    let message = match payload.downcast::<AssertionError>() {
+        Ok(error) => error.to_string(),
+        Err(payload) => panic::resume_unwind(payload),
+    };
+    let actual = normalize(
+        message
+            .split_once('\n')
+            .map_or("", |(_header, facts)| facts),
+    );
+    let expected = normalize(expected);
+    if actual != expected {
+        panic!(
+            "failure message differs from the demo\nexpected:\n{}\nactual:\n{}",
+            expected, actual
+        );
+    }
+}
+
+fn normalize(message: &str) -> String {
+    message
+        .trim()
+        .lines()
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn matching_message() {
+        demo!(
+            assert_that!(1).is_equal_to(2),
+            "
+            expected: 2
+            actual  : 1
+            "
+        );
+    }
+
+    #[test]
+    fn matching_message_with_minimal_messages() {
+        set_minimal_messages(true);
+        demo!(
+            assert_that!(1).is_equal_to(2),
+            "
+            expected: 2
+            actual  : 1
+            "
+        );
+        set_minimal_messages(false);
+    }
+
+    #[test]
+    #[should_panic(expected = "failure message differs from the demo")]
+    fn different_message() {
+        demo!(assert_that!(1).is_equal_to(2), "expected: 3\nactual  : 1");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected the assertion to fail, but it passed")]
+    fn passing_assertion() {
+        demo!(assert_that!(1).is_equal_to(1), "");
+    }
+
+    #[test]
+    #[should_panic(expected = "not an assertion")]
+    fn other_panics_are_resumed() {
+        demo!(panic!("not an assertion"), "");
+    }
+}
@@ -17,6 +17,9 @@ pub(crate) mod map {
     use std::collections::{BTreeMap, HashMap};
     use std::fmt::Debug;
     use std::hash::Hash;
+    use std::ops::RangeBounds;
+    use std::rc::Rc;
+    use std::sync::Arc;
 
     /// Difference for a single key in a Map-like data structure.
     pub(crate) struct MapValueDiff<K: Debug, V: PartialEq + Debug> {
@@ -67,7 +70,11 @@ pub(crate) mod map {
         fn entries(&self) -> Vec<(&K, &V)>;
     }
 
-    pub trait OrderedMapLike<K: Eq + Ord, V>: MapLike<K, V> {}
+    pub trait OrderedMapLike<K: Eq + Ord, V>: MapLike<K, V> {
+        fn first_key(&self) -> Option<&K>;
+        fn last_key(&self) -> Option<&K>;
+        fn entries_in_range<R: RangeBounds<K>>(&self, range: R) -> Vec<(&K, &V)>;
+    }
 
     impl<K: Eq + Ord, V> MapLike<K, V> for BTreeMap<K, V> {
         type It<'a> = std::collections::btree_map::Keys<'a, K, V> where K: 'a, V: 'a;
@@ -93,7 +100,19 @@ pub(crate) mod map {
         }
     }
 
-    impl<K: Eq + Ord, V> OrderedMapLike<K, V> for BTreeMap<K, V> {}
+    impl<K: Eq + Ord, V> OrderedMapLike<K, V> for BTreeMap<K, V> {
+        fn first_key(&self) -> Option<&K> {
+            self.keys().next()
+        }
+
+        fn last_key(&self) -> Option<&K> {
+            self.keys().next_back()
+        }
+
+        fn entries_in_range<R: RangeBounds<K>>(&self, range: R) -> Vec<(&K, &V)> {
+            self.range(range).collect()
+        }
+    }
 
     impl<K: Eq + Hash, V> MapLike<K, V> for HashMap<K, V> {
         type It<'a> = std::collections::hash_map::Keys<'a, K, V> where K: 'a, V: 'a;
@@ -119,6 +138,58 @@ pub(crate) mod map {
         }
     }
 
+    /// Maps behind references and smart pointers, e.g. `&HashMap<K, V>` in an `Option` or
+    /// `Arc<HashMap<K, V>>`, are checked like the maps themselves, as with auto-deref.
+    macro_rules! impl_map_like_for_pointers {
+        ($($pointer:ty),*) => {
+            $(
+                impl<K: Eq, V, M: MapLike<K, V>> MapLike<K, V> for $pointer {
+                    type It<'a> = M::It<'a> where K: 'a, V: 'a, Self: 'a;
+
+                    fn get(&self, k: &K) -> Option<&V> {
+                        (**self).get(k)
+                    }
+
+                    fn keys_iter<'a>(&'a self) -> Self::It<'a>
+                    where
+                        K: 'a,
+                        V: 'a,
+                    {
+                        (**self).keys_iter()
+                    }
+
+                    fn keys_ordered(&self) -> bool {
+                        (**self).keys_ordered()
+                    }
+
+                    fn len(&self) -> usize {
+                        (**self).len()
+                    }
+
+                    fn entries(&self) -> Vec<(&K, &V)> {
+                        (**self).entries()
+                    }
+                }
+
+                impl<K: Eq + Ord, V, M: OrderedMapLike<K, V>> OrderedMapLike<K, V> for $pointer {
+                    fn first_key(&self) -> Option<&K> {
+                        (**self).first_key()
+                    }
+
+                    fn last_key(&self) -> Option<&K> {
+                        (**self).last_key()
+                    }
+
+                    fn entries_in_range<R: RangeBounds<K>>(&self, range: R) -> Vec<(&K, &V)> {
+                        (**self).entries_in_range(range)
+                    }
+                }
+            )*
+        };
+    }
+
+    impl_map_like_for_pointers!(&M, Box<M>, Rc<M>, Arc<M>);
+
     impl<K: Eq + Debug, V: PartialEq + Debug> MapComparison<K, V> {
         pub(crate) fn from_map_like<'a, M1, M2>(
             actual: &'a M1,
@@ -486,3 +557,1257 @@ pub(crate) mod iter {
         }
     }
 }
+
+pub(crate) mod debug {
+    use std::fmt;
+    use std::fmt::Debug;
+
+    use crate::diff::iter::{SequenceComparison, SequenceOrderComparison};
+
+    /// A value whose `{:?}` representation differs between expected and actual.
+    #[derive(Debug, Clone, PartialEq)]
+    pub(crate) struct DebugDifference {
+        /// Path to the value in field access syntax, e.g. `.address.zip` or `.items[2]`.
+        pub(crate) path: String,
+        /// `None` if the value is absent in the expected representation.
+        pub(crate) expected: Option<String>,
+        /// `None` if the value is absent in the actual representation.
+        pub(crate) actual: Option<String>,
+    }
+
+    impl fmt::Display for DebugDifference {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let or_absent = |v: &Option<String>| v.as_deref().unwrap_or("<absent>").to_string();
+            write!(
+                f,
+                "{} (expected: {}, actual: {})",
+                self.path,
+                or_absent(&self.expected),
+                or_absent(&self.actual)
+            )
+        }
+    }
+
+    /// Compares the parsed `{:?}` representations `expected` and `actual` and returns the
+    /// innermost differing paths.
+    ///
+    /// Returns an empty vector if either value is made of more than [`MAX_COMPARED_VALUES`]
+    /// values, or if the values differ as a whole (e.g. two integers or two enum variants), since
+    /// the paths would not tell more than the values themselves.
+    pub(crate) fn debug_differences(
+        expected: &DebugValue,
+        actual: &DebugValue,
+    ) -> Vec<DebugDifference> {
+        if expected.count() > MAX_COMPARED_VALUES || actual.count() > MAX_COMPARED_VALUES {
+            return vec![];
+        }
+        let mut differences = vec![];
+        compare("", expected, actual, &mut differences);
+        if differences.iter().any(|d| d.path.is_empty()) {
+            return vec![];
+        }
+        differences
+    }
+
+    /// Returns the elements missing from and unexpected in `actual`, if both parsed `{:?}`
+    /// representations `expected` and `actual` are sets, e.g. of `HashSet` or `BTreeSet`, their
+    /// elements differ, and neither is made of more than [`MAX_COMPARED_VALUES`] values.
+    pub(crate) fn set_difference(
+        expected: &DebugValue,
+        actual: &DebugValue,
+    ) -> Option<(Vec<String>, Vec<String>)> {
+        fn elements(value: &DebugValue) -> Option<&[DebugValue]> {
+            if value.count() > MAX_COMPARED_VALUES {
+                return None;
+            }
+            match value {
+                DebugValue::Set(items) => Some(items),
+                // An empty set is formatted as an empty map.
+                DebugValue::Map(entries) if entries.is_empty() => Some(&[]),
+                _ => None,
+            }
+        }
+        let (expected, actual) = (elements(expected)?, elements(actual)?);
+        let missing: Vec<String> = expected
+            .iter()
+            .filter(|e| !actual.contains(e))
+            .map(DebugValue::to_string)
+            .collect();
+        let unexpected: Vec<String> = actual
+            .iter()
+            .filter(|a| !expected.contains(a))
+            .map(DebugValue::to_string)
+            .collect();
+        if missing.is_empty() && unexpected.is_empty() {
+            return None;
+        }
+        Some((missing, unexpected))
+    }
+
+    /// Differences between two values formatted as lists, e.g. `Vec`, arrays or slices.
+    #[derive(Debug, Clone, PartialEq)]
+    pub(crate) struct ListDifference {
+        /// Index of the first element which differs, or of the first element of the longer list
+        /// beyond the end of the shorter one.
+        pub(crate) first_mismatch: usize,
+        /// Expected elements absent in the actual list.
+        pub(crate) missing: Vec<String>,
+        /// Actual elements absent in the expected list.
+        pub(crate) unexpected: Vec<String>,
+    }
+
+    /// Compares the parsed `{:?}` representations `expected` and `actual` element by element if
+    /// both are lists, and returns `None` if they are not lists, their elements are equal, or
+    /// either list is made of more than [`MAX_COMPARED_VALUES`] values.
+    pub(crate) fn list_difference(
+        expected: &DebugValue,
+        actual: &DebugValue,
+    ) -> Option<ListDifference> {
+        fn elements(value: &DebugValue) -> Option<&[DebugValue]> {
+            match value {
+                DebugValue::List(items) if value.count() <= MAX_COMPARED_VALUES => Some(items),
+                _ => None,
+            }
+        }
+        let (expected, actual) = (elements(expected)?, elements(actual)?);
+        if expected == actual {
+            return None;
+        }
+        let first_mismatch = expected
+            .iter()
+            .zip(actual)
+            .take_while(|(e, a)| e == a)
+            .count();
+        let comparison = SequenceComparison::from_iter(
+            actual.iter(),
+            expected.iter(),
+            SequenceOrderComparison::Strict,
+        );
+        Some(ListDifference {
+            first_mismatch,
+            missing: comparison.missing.iter().map(|v| v.to_string()).collect(),
+            unexpected: comparison.extra.iter().map(|v| v.to_string()).collect(),
+        })
+    }
+
+    /// Returns the position of the first difference if both `{:?}` representations `expected`
+    /// and `actual` are string literals, e.g. of `String` or `&str`.
+    pub(crate) fn string_difference(expected: &str, actual: &str) -> Option<String> {
+        let expected = unescape_string_literal(expected)?;
+        let actual = unescape_string_literal(actual)?;
+        crate::diff::string::first_difference(&expected, &actual)
+    }
+
+    /// Returns the indices of up to three `candidates` within a small edit distance of `target`,
+    /// closest first, if `target` is formatted as a string literal.
+    pub(crate) fn similar_strings<T: Debug>(target: &T, candidates: &[T]) -> Vec<usize> {
+        let target = match unescape_string_literal(&format!("{:?}", target)) {
+            Some(target) => target,
+            None => return vec![],
+        };
+        let max_distance = (target.chars().count() / 3).max(1);
+        let mut similar: Vec<(usize, usize)> = candidates
+            .iter()
+            .enumerate()
+            .filter_map(|(index, candidate)| {
+                let candidate = unescape_string_literal(&format!("{:?}", candidate))?;
+                let distance = crate::diff::string::edit_distance(&target, &candidate);
+                (distance <= max_distance).then_some((distance, index))
+            })
+            .collect();
+        similar.sort();
+        similar
+            .into_iter()
+            .take(3)
+            .map(|(_, index)| index)
+            .collect()
+    }
+
+    /// Flattens a `{:?}` representation of nested `Option` and `Result` values into the chain of
+    /// variants followed by the innermost value, e.g. `Some(Ok(Some(3)))` into `Some→Ok→Some: 3`.
+    ///
+    /// Returns `None` unless at least two wrappers are nested, since a single `Some(3)` is already
+    /// easy to read.
+    pub(crate) fn flatten_wrappers(repr: &str) -> Option<String> {
+        let mut variants = vec![];
+        let mut inner = repr;
+        while let Some((variant, unwrapped)) = unwrap_variant(inner) {
+            variants.push(variant);
+            inner = unwrapped;
+        }
+        if variants.len() < 2 {
+            return None;
+        }
+        Some(format!("{}: {}", variants.join("→"), inner))
+    }
+
+    /// Groups the digits of a `{:?}` representation of an integer by thousands, like Rust integer
+    /// literals, e.g. `1234567` into `1_234_567`.
+    ///
+    /// Returns `None` for other values and integers of at most four digits, which are easy to
+    /// read as is.
+    pub(crate) fn group_digits(repr: &str) -> Option<String> {
+        let (sign, digits) = match repr.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", repr),
+        };
+        if digits.len() <= 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let mut grouped = String::from(sign);
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index) % 3 == 0 {
+                grouped.push('_');
+            }
+            grouped.push(digit);
+        }
+        Some(grouped)
+    }
+
+    /// Splits `Some(value)`, `Ok(value)` or `Err(value)` into the variant and `value`.
+    fn unwrap_variant(repr: &str) -> Option<(&str, &str)> {
+        let (variant, rest) = repr.split_once('(')?;
+        if !["Some", "Ok", "Err"].contains(&variant) {
+            return None;
+        }
+        // Make sure the parentheses enclose a single value, unlike `Some(1) (2)`.
+        match DebugValue::parse(repr)? {
+            DebugValue::Tuple(_, items) if items.len() == 1 => {}
+            _ => return None,
+        }
+        Some((variant, rest.strip_suffix(')')?.trim()))
+    }
+
+    /// Reverses the escaping of `<str as Debug>::fmt`.
+    fn unescape_string_literal(repr: &str) -> Option<String> {
+        let mut chars = repr.strip_prefix('"')?.strip_suffix('"')?.chars();
+        let mut unescaped = String::new();
+        while let Some(c) = chars.next() {
+            if c != '\\' {
+                unescaped.push(c);
+                continue;
+            }
+            unescaped.push(match chars.next()? {
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                '0' => '\0',
+                'u' => {
+                    if chars.next()? != '{' {
+                        return None;
+                    }
+                    let hex: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                    char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+                }
+                escaped => escaped,
+            });
+        }
+        Some(unescaped)
+    }
+
+    fn compare(
+        path: &str,
+        expected: &DebugValue,
+        actual: &DebugValue,
+        differences: &mut Vec<DebugDifference>,
+    ) {
+        if expected == actual {
+            return;
+        }
+        match (expected, actual) {
+            (DebugValue::Struct(e_name, e_fields), DebugValue::Struct(a_name, a_fields))
+                if e_name == a_name =>
+            {
+                for (name, e_value) in e_fields {
+                    let field_path = format!("{}.{}", path, name);
+                    match a_fields.iter().find(|(n, _)| n == name) {
+                        Some((_, a_value)) => compare(&field_path, e_value, a_value, differences),
+                        None => differences.push(absent_in_actual(field_path, e_value)),
+                    }
+                }
+                for (name, a_value) in a_fields {
+                    if !e_fields.iter().any(|(n, _)| n == name) {
+                        differences.push(absent_in_expected(format!("{}.{}", path, name), a_value));
+                    }
+                }
+            }
+            (DebugValue::Tuple(e_name, e_items), DebugValue::Tuple(a_name, a_items))
+                if e_name == a_name && e_items.len() == a_items.len() =>
+            {
+                for (i, (e_item, a_item)) in e_items.iter().zip(a_items).enumerate() {
+                    compare(&format!("{}.{}", path, i), e_item, a_item, differences);
+                }
+            }
+            (DebugValue::List(e_items), DebugValue::List(a_items)) => {
+                for i in 0..e_items.len().max(a_items.len()) {
+                    let item_path = format!("{}[{}]", path, i);
+                    match (e_items.get(i), a_items.get(i)) {
+                        (Some(e), Some(a)) => compare(&item_path, e, a, differences),
+                        (Some(e), None) => differences.push(absent_in_actual(item_path, e)),
+                        (None, Some(a)) => differences.push(absent_in_expected(item_path, a)),
+                        (None, None) => unreachable!(),
+                    }
+                }
+            }
+            (DebugValue::Map(e_entries), DebugValue::Map(a_entries)) => {
+                for (key, e_value) in e_entries {
+                    let entry_path = format!("{}[{}]", path, key);
+                    match a_entries.iter().find(|(k, _)| k == key) {
+                        Some((_, a_value)) => compare(&entry_path, e_value, a_value, differences),
+                        None => differences.push(absent_in_actual(entry_path, e_value)),
+                    }
+                }
+                for (key, a_value) in a_entries {
+                    if !e_entries.iter().any(|(k, _)| k == key) {
+                        differences.push(absent_in_expected(format!("{}[{}]", path, key), a_value));
+                    }
+                }
+            }
+            (DebugValue::Set(e_items), DebugValue::Set(a_items)) => {
+                for e_item in e_items.iter().filter(|e| !a_items.contains(e)) {
+                    differences.push(absent_in_actual(path.to_string(), e_item));
+                }
+                for a_item in a_items.iter().filter(|a| !e_items.contains(a)) {
+                    differences.push(absent_in_expected(path.to_string(), a_item));
+                }
+            }
+            _ => differences.push(DebugDifference {
+                path: path.to_string(),
+                expected: Some(expected.to_string()),
+                actual: Some(actual.to_string()),
+            }),
+        }
+    }
+
+    fn absent_in_actual(path: String, expected: &DebugValue) -> DebugDifference {
+        DebugDifference {
+            path,
+            expected: Some(expected.to_string()),
+            actual: None,
+        }
+    }
+
+    fn absent_in_expected(path: String, actual: &DebugValue) -> DebugDifference {
+        DebugDifference {
+            path,
+            expected: None,
+            actual: Some(actual.to_string()),
+        }
+    }
+
+    /// Structure of a `{:?}` representation produced by `#[derive(Debug)]` and the standard
+    /// collections.
+    #[derive(Debug, Clone, PartialEq)]
+    pub(crate) enum DebugValue {
+        /// Leaf such as `1`, `"foo"` or `None`.
+        Atom(String),
+        /// `Name { field: value, .. }`
+        Struct(String, Vec<(String, DebugValue)>),
+        /// `Name(value, ..)`, or `(value, ..)` with an empty name.
+        Tuple(String, Vec<DebugValue>),
+        /// `[value, ..]`
+        List(Vec<DebugValue>),
+        /// `{key: value, ..}`
+        Map(Vec<(DebugValue, DebugValue)>),
+        /// `{value, ..}`
+        Set(Vec<DebugValue>),
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Token {
+        Punct(char),
+        Word(String),
+    }
+
+    /// Representations longer than this, in bytes, are not parsed.
+    const MAX_PARSED_LENGTH: usize = 1 << 20;
+
+    /// Values nested deeper than this are not parsed, so that the recursive parser, comparison
+    /// and rendering can not overflow the stack.
+    const MAX_PARSED_DEPTH: usize = 64;
+
+    /// Values made of more values than this are not compared, as comparing lists, maps and sets
+    /// takes quadratic time.
+    const MAX_COMPARED_VALUES: usize = 1_000;
+
+    impl DebugValue {
+        /// Parses a `{:?}` representation, or returns `None` if it has another structure, or is
+        /// longer than [`MAX_PARSED_LENGTH`] or nested deeper than [`MAX_PARSED_DEPTH`].
+        pub(crate) fn parse(repr: &str) -> Option<DebugValue> {
+            if repr.len() > MAX_PARSED_LENGTH {
+                return None;
+            }
+            let tokens = tokenize(repr)?;
+            let mut parser = Parser {
+                tokens,
+                pos: 0,
+                depth: 0,
+            };
+            let value = parser.value()?;
+            if parser.pos == parser.tokens.len() {
+                Some(value)
+            } else {
+                None
+            }
+        }
+
+        /// Returns the number of values this value is made of, itself included.
+        fn count(&self) -> usize {
+            1 + match self {
+                DebugValue::Atom(_) => 0,
+                DebugValue::Struct(_, fields) => fields.iter().map(|(_, v)| v.count()).sum(),
+                DebugValue::Tuple(_, items) | DebugValue::List(items) | DebugValue::Set(items) => {
+                    items.iter().map(DebugValue::count).sum()
+                }
+                DebugValue::Map(entries) => {
+                    entries.iter().map(|(k, v)| k.count() + v.count()).sum()
+                }
+            }
+        }
+    }
+
+    impl fmt::Display for DebugValue {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fn join<T, F: Fn(&T) -> String>(items: &[T], render: F) -> String {
+                items.iter().map(render).collect::<Vec<_>>().join(", ")
+            }
+            match self {
+                DebugValue::Atom(atom) => f.write_str(atom),
+                DebugValue::Struct(name, fields) => write!(
+                    f,
+                    "{} {{ {} }}",
+                    name,
+                    join(fields, |(k, v)| format!("{}: {}", k, v))
+                ),
+                DebugValue::Tuple(name, items) => {
+                    write!(f, "{}({})", name, join(items, |v| v.to_string()))
+                }
+                DebugValue::List(items) => write!(f, "[{}]", join(items, |v| v.to_string())),
+                DebugValue::Map(entries) => {
+                    write!(f, "{{{}}}", join(entries, |(k, v)| format!("{}: {}", k, v)))
+                }
+                DebugValue::Set(items) => write!(f, "{{{}}}", join(items, |v| v.to_string())),
+            }
+        }
+    }
+
+    fn tokenize(repr: &str) -> Option<Vec<Token>> {
+        let mut tokens = vec![];
+        let mut chars = repr.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                c if c.is_whitespace() => {}
+                '{' | '}' | '(' | ')' | '[' | ']' | ',' => tokens.push(Token::Punct(c)),
+                ':' if chars.peek() != Some(&':') => tokens.push(Token::Punct(c)),
+                '"' | '\'' => {
+                    let mut literal = c.to_string();
+                    loop {
+                        let next = chars.next()?;
+                        literal.push(next);
+                        if next == '\\' {
+                            literal.push(chars.next()?);
+                        } else if next == c {
+                            break;
+                        }
+                    }
+                    tokens.push(Token::Word(literal));
+                }
+                _ => {
+                    let mut word = c.to_string();
+                    while let Some(&next) = chars.peek() {
+                        if next.is_whitespace() || "{}()[],\"'".contains(next) {
+                            break;
+                        }
+                        if next == ':' {
+                            // `::` in paths is a part of the word, a single `:` is a separator.
+                            let mut lookahead = chars.clone();
+                            lookahead.next();
+                            if lookahead.peek() != Some(&':') {
+                                break;
+                            }
+                            word.push(chars.next()?);
+                        }
+                        word.push(chars.next()?);
+                    }
+                    tokens.push(Token::Word(word));
+                }
+            }
+        }
+        Some(tokens)
+    }
+
+    struct Parser {
+        tokens: Vec<Token>,
+        pos: usize,
+        /// Number of values being parsed, i.e. the value at `pos` and its parents.
+        depth: usize,
+    }
+
+    impl Parser {
+        fn peek(&self) -> Option<&Token> {
+            self.tokens.get(self.pos)
+        }
+
+        fn next(&mut self) -> Option<Token> {
+            let token = self.tokens.get(self.pos).cloned();
+            self.pos += 1;
+            token
+        }
+
+        fn consume_punct(&mut self, c: char) -> bool {
+            if self.peek() == Some(&Token::Punct(c)) {
+                self.pos += 1;
+                true
+            } else {
+                false
+            }
+        }
+
+        /// Parses a value, or gives up if it is nested deeper than [`MAX_PARSED_DEPTH`].
+        fn value(&mut self) -> Option<DebugValue> {
+            if self.depth == MAX_PARSED_DEPTH {
+                return None;
+            }
+            self.depth += 1;
+            let value = self.nested_value();
+            self.depth -= 1;
+            value
+        }
+
+        fn nested_value(&mut self) -> Option<DebugValue> {
+            match self.next()? {
+                Token::Punct('[') => Some(DebugValue::List(self.items(']')?)),
+                Token::Punct('(') => Some(DebugValue::Tuple(String::new(), self.items(')')?)),
+                Token::Punct('{') => self.map_or_set(),
+                Token::Punct(_) => None,
+                Token::Word(word) => {
+                    if self.consume_punct('{') {
+                        Some(DebugValue::Struct(word, self.fields()?))
+                    } else if self.consume_punct('(') {
+                        Some(DebugValue::Tuple(word, self.items(')')?))
+                    } else {
+                        Some(DebugValue::Atom(word))
+                    }
+                }
+            }
+        }
+
+        /// Parses comma separated values until `close`, which is consumed.
+        fn items(&mut self, close: char) -> Option<Vec<DebugValue>> {
+            let mut items = vec![];
+            while !self.consume_punct(close) {
+                items.push(self.value()?);
+                if !self.consume_punct(',') && self.peek() != Some(&Token::Punct(close)) {
+                    return None;
+                }
+            }
+            Some(items)
+        }
+
+        /// Parses `field: value` pairs until `}`, which is consumed.
+        fn fields(&mut self) -> Option<Vec<(String, DebugValue)>> {
+            let mut fields = vec![];
+            while !self.consume_punct('}') {
+                match self.next()? {
+                    // Non-exhaustive structs end with `..`.
+                    Token::Word(name) if name == ".." => {}
+                    Token::Word(name) => {
+                        if !self.consume_punct(':') {
+                            return None;
+                        }
+                        fields.push((name, self.value()?));
+                    }
+                    Token::Punct(_) => return None,
+                }
+                if !self.consume_punct(',') && self.peek() != Some(&Token::Punct('}')) {
+                    return None;
+                }
+            }
+            Some(fields)
+        }
+
+        /// Parses the rest of `{key: value, ..}` or `{value, ..}` after `{`.
+        fn map_or_set(&mut self) -> Option<DebugValue> {
+            let mut entries = vec![];
+            let mut items = vec![];
+            while !self.consume_punct('}') {
+                let value = self.value()?;
+                if self.consume_punct(':') {
+                    if !items.is_empty() {
+                        return None;
+                    }
+                    entries.push((value, self.value()?));
+                } else {
+                    if !entries.is_empty() {
+                        return None;
+                    }
+                    items.push(value);
+                }
+                if !self.consume_punct(',') && self.peek() != Some(&Token::Punct('}')) {
+                    return None;
+                }
+            }
+            if items.is_empty() {
+                Some(DebugValue::Map(entries))
+            } else {
+                Some(DebugValue::Set(items))
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::collections::{BTreeMap, BTreeSet};
+
+        use super::*;
+
+        #[allow(dead_code)] // Only read through `Debug`.
+        #[derive(Debug)]
+        struct Address {
+            city: String,
+            zip: u32,
+        }
+
+        #[allow(dead_code)] // Only read through `Debug`.
+        #[derive(Debug)]
+        struct Person {
+            name: &'static str,
+            address: Address,
+            tags: Vec<&'static str>,
+            nickname: Option<&'static str>,
+        }
+
+        fn person(zip: u32, tags: Vec<&'static str>, nickname: Option<&'static str>) -> Person {
+            Person {
+                name: "Alice, \"the\" {first}",
+                address: Address {
+                    city: "Tokyo".to_string(),
+                    zip,
+                },
+                tags,
+                nickname,
+            }
+        }
+
+        fn repr<T: Debug + ?Sized>(value: &T) -> String {
+            format!("{:?}", value)
+        }
+
+        fn parse<T: Debug + ?Sized>(value: &T) -> DebugValue {
+            DebugValue::parse(&repr(value)).unwrap()
+        }
+
+        fn difference(path: &str, expected: Option<&str>, actual: Option<&str>) -> DebugDifference {
+            DebugDifference {
+                path: path.to_string(),
+                expected: expected.map(str::to_string),
+                actual: actual.map(str::to_string),
+            }
+        }
+
+        #[test]
+        fn parse_round_trip() {
+            let repr = format!("{:?}", person(1, vec!["a"], Some("b")));
+            assert_eq!(DebugValue::parse(&repr).unwrap().to_string(), repr);
+            let map = BTreeMap::from([("a", vec![(1, 2)]), ("b", vec![])]);
+            let repr = format!("{:?}", map);
+            assert_eq!(DebugValue::parse(&repr).unwrap().to_string(), repr);
+            assert_eq!(
+                DebugValue::parse("std::time::Instant { t: 1 }"),
+                Some(DebugValue::Struct(
+                    "std::time::Instant".to_string(),
+                    vec![("t".to_string(), DebugValue::Atom("1".to_string()))]
+                ))
+            );
+            assert_eq!(DebugValue::parse("Foo { a: 1"), None);
+            assert_eq!(DebugValue::parse("\"unterminated"), None);
+            assert_eq!(DebugValue::parse("[1] [2]"), None);
+        }
+
+        #[test]
+        fn nested_field() {
+            assert_eq!(
+                debug_differences(
+                    &parse(&person(1, vec![], None)),
+                    &parse(&person(2, vec![], None))
+                ),
+                vec![difference(".address.zip", Some("1"), Some("2"))]
+            );
+        }
+
+        #[test]
+        fn list_and_tuple_elements() {
+            assert_eq!(
+                debug_differences(
+                    &parse(&person(1, vec!["a", "b"], Some("x"))),
+                    &parse(&person(1, vec!["a", "c", "d"], Some("y")))
+                ),
+                vec![
+                    difference(".tags[1]", Some("\"b\""), Some("\"c\"")),
+                    difference(".tags[2]", None, Some("\"d\"")),
+                    difference(".nickname.0", Some("\"x\""), Some("\"y\"")),
+                ]
+            );
+            assert_eq!(
+                debug_differences(
+                    &parse(&person(1, vec![], None)),
+                    &parse(&person(1, vec![], Some("y")))
+                ),
+                vec![difference(".nickname", Some("None"), Some("Some(\"y\")"))]
+            );
+        }
+
+        #[test]
+        fn map_entries() {
+            let expected = BTreeMap::from([("a", 1), ("b", 2)]);
+            let actual = BTreeMap::from([("a", 1), ("b", 3), ("c", 4)]);
+            assert_eq!(
+                debug_differences(&parse(&expected), &parse(&actual)),
+                vec![
+                    difference("[\"b\"]", Some("2"), Some("3")),
+                    difference("[\"c\"]", None, Some("4")),
+                ]
+            );
+        }
+
+        #[test]
+        fn set_elements() {
+            #[derive(Debug)]
+            struct Tagged {
+                #[allow(dead_code)] // Only read through `Debug`.
+                tags: BTreeSet<&'static str>,
+            }
+            let tagged = |tags: &[&'static str]| Tagged {
+                tags: tags.iter().copied().collect(),
+            };
+            assert_eq!(
+                debug_differences(&parse(&tagged(&["a", "b"])), &parse(&tagged(&["b", "c"]))),
+                vec![
+                    difference(".tags", Some("\"a\""), None),
+                    difference(".tags", None, Some("\"c\"")),
+                ]
+            );
+
+            let set = |items: &[u32]| items.iter().copied().collect::<BTreeSet<u32>>();
+            assert_eq!(
+                set_difference(&parse(&set(&[1, 2, 3])), &parse(&set(&[2, 3, 4, 5]))),
+                Some((
+                    vec!["1".to_string()],
+                    vec!["4".to_string(), "5".to_string()]
+                ))
+            );
+            assert_eq!(
+                set_difference(&parse(&set(&[])), &parse(&set(&[1]))),
+                Some((vec![], vec!["1".to_string()]))
+            );
+            assert_eq!(set_difference(&parse(&set(&[1])), &parse(&set(&[1]))), None);
+            assert_eq!(set_difference(&parse(&vec![1]), &parse(&vec![2])), None);
+        }
+
+        #[test]
+        fn list_elements() {
+            assert_eq!(
+                list_difference(&parse(&vec![1, 2, 3, 4]), &parse(&vec![1, 2, 5])),
+                Some(ListDifference {
+                    first_mismatch: 2,
+                    missing: vec!["3".to_string(), "4".to_string()],
+                    unexpected: vec!["5".to_string()],
+                })
+            );
+            assert_eq!(
+                list_difference(&parse(&[1, 2]), &parse(&[2, 1])),
+                Some(ListDifference {
+                    first_mismatch: 0,
+                    missing: vec![],
+                    unexpected: vec![],
+                })
+            );
+            assert_eq!(
+                list_difference(&parse(&vec!["a"]), &parse(&vec!["a", "b"])),
+                Some(ListDifference {
+                    first_mismatch: 1,
+                    missing: vec![],
+                    unexpected: vec![r#""b""#.to_string()],
+                })
+            );
+            assert_eq!(list_difference(&parse(&vec![1]), &parse(&vec![1])), None);
+            assert_eq!(list_difference(&parse(&Some(1)), &parse(&Some(2))), None);
+        }
+
+        #[test]
+        fn similar_string_candidates() {
+            assert_eq!(
+                similar_strings(&"colour", &["size", "color", "colors", "flavour"]),
+                vec![1, 2]
+            );
+            assert_eq!(
+                similar_strings(&"ab".to_string(), &["abc".to_string(), "xyz".to_string()]),
+                vec![0]
+            );
+            assert_eq!(
+                similar_strings(&"name", &["title", "id"]),
+                Vec::<usize>::new()
+            );
+            assert_eq!(similar_strings(&12, &[13, 2]), Vec::<usize>::new());
+        }
+
+        #[test]
+        fn nested_wrappers() {
+            let flatten = |value: &dyn Debug| flatten_wrappers(&format!("{:?}", value));
+            assert_eq!(
+                flatten(&Some(Ok::<_, ()>(Some(3)))),
+                Some("Some→Ok→Some: 3".to_string())
+            );
+            assert_eq!(
+                flatten(&Err::<(), _>(Some("a(b)"))),
+                Some(r#"Err→Some: "a(b)""#.to_string())
+            );
+            assert_eq!(
+                flatten(&Some(Some(Address {
+                    city: "Tokyo".to_string(),
+                    zip: 1
+                }))),
+                Some(r#"Some→Some: Address { city: "Tokyo", zip: 1 }"#.to_string())
+            );
+            assert_eq!(
+                flatten(&Some(Some((1, 2)))),
+                Some("Some→Some: (1, 2)".to_string())
+            );
+            assert_eq!(
+                flatten(&Some(Some(None::<()>))),
+                Some("Some→Some: None".to_string())
+            );
+            assert_eq!(flatten(&Some(3)), None);
+            assert_eq!(flatten(&Some(vec![Some(1)])), None);
+            assert_eq!(flatten(&"Some(Some(1))"), None);
+        }
+
+        #[test]
+        fn grouped_digits() {
+            assert_eq!(group_digits("1234567"), Some("1_234_567".to_string()));
+            assert_eq!(group_digits("-12345"), Some("-12_345".to_string()));
+            assert_eq!(group_digits("100000"), Some("100_000".to_string()));
+            assert_eq!(group_digits("1234"), None);
+            assert_eq!(group_digits("-1234"), None);
+            assert_eq!(group_digits("12345.5"), None);
+            assert_eq!(group_digits("\"12345\""), None);
+        }
+
+        #[test]
+        fn string_literals() {
+            assert_eq!(
+                string_difference(&repr("foo\tbar\"\u{1}"), &repr("foo\tbar\"\u{2}")),
+                Some("char 8".to_string())
+            );
+            assert_eq!(
+                string_difference(&repr(&"a\nbc".to_string()), &repr(&"a\nbd".to_string())),
+                Some("char 3 (line 2, column 2)".to_string())
+            );
+            assert_eq!(string_difference(&repr("foo"), &repr("bar")), None);
+            assert_eq!(string_difference(&repr(&1), &repr(&2)), None);
+        }
+
+        #[test]
+        fn deeply_nested_values() {
+            let deep = format!("{}{}", "[".repeat(50_000), "]".repeat(50_000));
+            assert_eq!(DebugValue::parse(&deep), None);
+
+            let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+            assert!(DebugValue::parse(&nested(MAX_PARSED_DEPTH)).is_some());
+            assert_eq!(DebugValue::parse(&nested(MAX_PARSED_DEPTH + 1)), None);
+        }
+
+        #[test]
+        fn long_values() {
+            let long = repr(&vec![0; MAX_PARSED_LENGTH / 2]);
+            assert_eq!(DebugValue::parse(&long), None);
+        }
+
+        #[test]
+        fn many_values() {
+            let (expected, actual) = (vec![0; MAX_COMPARED_VALUES], vec![1; MAX_COMPARED_VALUES]);
+            assert_eq!(
+                debug_differences(&parse(&expected), &parse(&actual)),
+                vec![]
+            );
+            assert_eq!(list_difference(&parse(&expected), &parse(&actual)), None);
+            let (expected, actual): (BTreeSet<_>, BTreeSet<_>) = (
+                (0..MAX_COMPARED_VALUES).collect(),
+                (1..=MAX_COMPARED_VALUES).collect(),
+            );
+            assert_eq!(set_difference(&parse(&expected), &parse(&actual)), None);
+
+            let (expected, actual) = (vec![0; 10], vec![1; 10]);
+            assert_eq!(
+                debug_differences(&parse(&expected), &parse(&actual)).len(),
+                10
+            );
+            assert!(list_difference(&parse(&expected), &parse(&actual)).is_some());
+            let (expected, actual): (BTreeSet<_>, BTreeSet<_>) =
+                ((0..10).collect(), (1..=10).collect());
+            assert!(set_difference(&parse(&expected), &parse(&actual)).is_some());
+        }
+
+        #[test]
+        fn whole_value_differs() {
+            assert_eq!(debug_differences(&parse(&1), &parse(&2)), vec![]);
+            assert_eq!(
+                debug_differences(&parse(&Some(1)), &parse(&None::<i32>)),
+                vec![]
+            );
+            assert_eq!(debug_differences(&parse(&"a{"), &parse(&"b")), vec![]);
+        }
+    }
+}
+
+pub(crate) mod string {
+    /// Returns the number of single character insertions, deletions and substitutions needed to
+    /// change `a` into `b`.
+    pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+        let b: Vec<char> = b.chars().collect();
+        let mut previous: Vec<usize> = (0..=b.len()).collect();
+        for (i, a_char) in a.chars().enumerate() {
+            let mut current = vec![i + 1];
+            for (j, b_char) in b.iter().enumerate() {
+                let substitution = previous[j] + usize::from(a_char != *b_char);
+                current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+            }
+            previous = current;
+        }
+        previous[b.len()]
+    }
+
+    /// Describes the position of the first character where `expected` and `actual` differ, or
+    /// `None` if they are equal or differ from the first character.
+    pub(crate) fn first_difference(expected: &str, actual: &str) -> Option<String> {
+        let mut actual_chars = actual.chars();
+        let mut line = 1;
+        let mut column = 1;
+        for (index, e) in expected.chars().enumerate() {
+            if actual_chars.next() != Some(e) {
+                return describe(expected, actual, index, line, column);
+            }
+            if e == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+        actual_chars.next()?;
+        describe(expected, actual, expected.chars().count(), line, column)
+    }
+
+    fn describe(
+        expected: &str,
+        actual: &str,
+        index: usize,
+        line: usize,
+        column: usize,
+    ) -> Option<String> {
+        if index == 0 {
+            None
+        } else if expected.contains('\n') || actual.contains('\n') {
+            Some(format!("char {} (line {}, column {})", index, line, column))
+        } else {
+            Some(format!("char {}", index))
+        }
+    }
+
+    /// Characters of context kept around the differences by [`alignment`].
+    const ALIGNMENT_CONTEXT: usize = 10;
+
+    /// Largest number of cells of the edit distance table built by [`alignment`].
+    const MAX_ALIGNMENT_CELLS: usize = 1 << 20;
+
+    /// Pairs the characters of `expected` and `actual` along a shortest edit script, with `None`
+    /// on the side missing a character. Returns `None` if the strings are too long to be
+    /// aligned.
+    pub(crate) fn edit_script(
+        expected: &str,
+        actual: &str,
+    ) -> Option<Vec<(Option<char>, Option<char>)>> {
+        let expected: Vec<char> = expected.chars().collect();
+        let actual: Vec<char> = actual.chars().collect();
+        let width = actual.len() + 1;
+        if (expected.len() + 1).saturating_mul(width) > MAX_ALIGNMENT_CELLS {
+            return None;
+        }
+        let mut distances = vec![0; (expected.len() + 1) * width];
+        for i in 0..=expected.len() {
+            for j in 0..=actual.len() {
+                distances[i * width + j] = if i == 0 || j == 0 {
+                    i + j
+                } else {
+                    let substitution = distances[(i - 1) * width + j - 1]
+                        + usize::from(expected[i - 1] != actual[j - 1]);
+                    substitution
+                        .min(distances[(i - 1) * width + j] + 1)
+                        .min(distances[i * width + j - 1] + 1)
+                };
+            }
+        }
+
+        // Walk back from the end, preferring substitutions so that the rows stay short.
+        let mut columns = vec![];
+        let (mut i, mut j) = (expected.len(), actual.len());
+        while i > 0 || j > 0 {
+            let current = distances[i * width + j];
+            if i > 0
+                && j > 0
+                && current
+                    == distances[(i - 1) * width + j - 1]
+                        + usize::from(expected[i - 1] != actual[j - 1])
+            {
+                columns.push((Some(expected[i - 1]), Some(actual[j - 1])));
+                i -= 1;
+                j -= 1;
+            } else if i > 0 && current == distances[(i - 1) * width + j] + 1 {
+                columns.push((Some(expected[i - 1]), None));
+                i -= 1;
+            } else {
+                columns.push((None, Some(actual[j - 1])));
+                j -= 1;
+            }
+        }
+        columns.reverse();
+        Some(columns)
+    }
+
+    /// Aligns `expected` and `actual` along a shortest edit script, as three rows: `expected`
+    /// and `actual` with `·` for gaps, and markers with `^` for substitutions, `+` for characters
+    /// only in `actual` and `-` for characters only in `expected`. Equal characters away from
+    /// the differences are elided with `…`. Returns `None` if the strings are equal or too long
+    /// to be aligned.
+    pub(crate) fn alignment(expected: &str, actual: &str) -> Option<[String; 3]> {
+        let columns: Vec<(char, char, char)> = edit_script(expected, actual)?
+            .into_iter()
+            .map(|column| match column {
+                (Some(e), Some(a)) if e == a => (e, a, ' '),
+                (Some(e), Some(a)) => (e, a, '^'),
+                (Some(e), None) => (e, '·', '-'),
+                (None, Some(a)) => ('·', a, '+'),
+                (None, None) => unreachable!(),
+            })
+            .collect();
+
+        let first = columns.iter().position(|(_, _, marker)| *marker != ' ')?;
+        let last = columns.iter().rposition(|(_, _, marker)| *marker != ' ')?;
+        let start = first.saturating_sub(ALIGNMENT_CONTEXT);
+        let end = (last + ALIGNMENT_CONTEXT + 1).min(columns.len());
+        let mut rows = [String::new(), String::new(), String::new()];
+        if start > 0 {
+            rows[0].push('…');
+            rows[1].push('…');
+            rows[2].push(' ');
+        }
+        for (e, a, marker) in &columns[start..end] {
+            rows[0].push(visible(*e));
+            rows[1].push(visible(*a));
+            rows[2].push(*marker);
+        }
+        if end < columns.len() {
+            rows[0].push('…');
+            rows[1].push('…');
+        }
+        rows[2] = rows[2].trim_end().to_string();
+        Some(rows)
+    }
+
+    /// Lines of context kept around the changes by [`unified_diff`].
+    const DIFF_CONTEXT: usize = 3;
+
+    /// Returns the differences between the lines of `expected` and `actual` in the unified
+    /// format, with hunks of `-` lines only in `expected`, `+` lines only in `actual`, and lines
+    /// of context. Returns `None` if the lines are equal or too many to be compared.
+    pub(crate) fn unified_diff(expected: &str, actual: &str) -> Option<String> {
+        let expected: Vec<&str> = expected.lines().collect();
+        let actual: Vec<&str> = actual.lines().collect();
+        let width = actual.len() + 1;
+        if (expected.len() + 1).saturating_mul(width) > MAX_ALIGNMENT_CELLS {
+            return None;
+        }
+        // common[i * width + j] is the length of the longest common subsequence of
+        // expected[i..] and actual[j..].
+        let mut common = vec![0; (expected.len() + 1) * width];
+        for i in (0..expected.len()).rev() {
+            for j in (0..actual.len()).rev() {
+                common[i * width + j] = if expected[i] == actual[j] {
+                    common[(i + 1) * width + j + 1] + 1
+                } else {
+                    common[(i + 1) * width + j].max(common[i * width + j + 1])
+                };
+            }
+        }
+
+        // Lines as (marker, line, index in expected, index in actual), removals first.
+        let mut lines = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < expected.len() || j < actual.len() {
+            if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+                lines.push((' ', expected[i], i, j));
+                i += 1;
+                j += 1;
+            } else if i < expected.len()
+                && (j == actual.len() || common[(i + 1) * width + j] >= common[i * width + j + 1])
+            {
+                lines.push(('-', expected[i], i, j));
+                i += 1;
+            } else {
+                lines.push(('+', actual[j], i, j));
+                j += 1;
+            }
+        }
+
+        let changes: Vec<usize> = (0..lines.len()).filter(|&k| lines[k].0 != ' ').collect();
+        let mut hunks: Vec<(usize, usize)> = vec![];
+        for &k in changes.iter() {
+            let start = k.saturating_sub(DIFF_CONTEXT);
+            let end = (k + DIFF_CONTEXT + 1).min(lines.len());
+            match hunks.last_mut() {
+                Some(hunk) if start <= hunk.1 => hunk.1 = end,
+                _ => hunks.push((start, end)),
+            }
+        }
+        if hunks.is_empty() {
+            return None;
+        }
+        let mut diff = vec![];
+        for (start, end) in hunks {
+            let hunk = &lines[start..end];
+            let expected_count = hunk.iter().filter(|line| line.0 != '+').count();
+            let actual_count = hunk.iter().filter(|line| line.0 != '-').count();
+            // Like `diff -u`, an empty range starts at the line before it.
+            let range_start = |index: usize, count: usize| index + usize::from(count > 0);
+            diff.push(format!(
+                "@@ -{},{} +{},{} @@",
+                range_start(hunk[0].2, expected_count),
+                expected_count,
+                range_start(hunk[0].3, actual_count),
+                actual_count
+            ));
+            diff.extend(
+                hunk.iter()
+                    .map(|(marker, line, _, _)| format!("{}{}", marker, line)),
+            );
+        }
+        Some(diff.join("\n"))
+    }
+
+    /// Replaces control characters, which would break the rows of [`alignment`], with a
+    /// visible symbol.
+    fn visible(c: char) -> char {
+        match c {
+            '\n' => '⏎',
+            '\t' => '→',
+            c if c.is_control() => '�',
+            c => c,
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn unified_diffs() {
+            assert_eq!(
+                unified_diff(
+                    "a\nb\nc\nd\ne\nf\ng\nh\ni\n",
+                    "a\nb\nc\nd\nE\nf\ng\nh\ni\nj\n"
+                ),
+                Some("@@ -2,8 +2,9 @@\n b\n c\n d\n-e\n+E\n f\n g\n h\n i\n+j".to_string())
+            );
+            assert_eq!(
+                unified_diff(
+                    "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n",
+                    "0\n1\n2\n3\n4\n5\n6\n7\n8\n9\n"
+                ),
+                Some(
+                    "@@ -1,3 +1,4 @@\n+0\n 1\n 2\n 3\n@@ -7,4 +8,3 @@\n 7\n 8\n 9\n-10".to_string()
+                )
+            );
+            assert_eq!(unified_diff("same\n", "same"), None);
+        }
+
+        #[test]
+        fn edit_distances() {
+            assert_eq!(edit_distance("kitten", "sitting"), 3);
+            assert_eq!(edit_distance("colour", "color"), 1);
+            assert_eq!(edit_distance("", "abc"), 3);
+            assert_eq!(edit_distance("日本語", "日本"), 1);
+            assert_eq!(edit_distance("same", "same"), 0);
+        }
+
+        #[test]
+        fn alignments() {
+            assert_eq!(
+                alignment("kitten", "sitting"),
+                Some([
+                    "kitten·".to_string(),
+                    "sitting".to_string(),
+                    "^   ^ +".to_string()
+                ])
+            );
+            assert_eq!(
+                alignment("colour", "color"),
+                Some([
+                    "colour".to_string(),
+                    "colo·r".to_string(),
+                    "    -".to_string()
+                ])
+            );
+            assert_eq!(
+                alignment(
+                    "a long prefix of text then x",
+                    "a long prefix of text then y"
+                ),
+                Some([
+                    "…text then x".to_string(),
+                    "…text then y".to_string(),
+                    "           ^".to_string()
+                ])
+            );
+            assert_eq!(
+                alignment("x and a long suffix", "y and a long suffix"),
+                Some([
+                    "x and a lon…".to_string(),
+                    "y and a lon…".to_string(),
+                    "^".to_string()
+                ])
+            );
+            assert_eq!(
+                alignment("a\nb", "a\nc"),
+                Some(["a⏎b".to_string(), "a⏎c".to_string(), "  ^".to_string()])
+            );
+            assert_eq!(alignment("same", "same"), None);
+        }
+
+        #[test]
+        fn first_difference_position() {
+            assert_eq!(
+                first_difference("foobar", "foobaz"),
+                Some("char 5".to_string())
+            );
+            assert_eq!(
+                first_difference("foo", "foobar"),
+                Some("char 3".to_string())
+            );
+            assert_eq!(
+                first_difference("foobar", "foo"),
+                Some("char 3".to_string())
+            );
+            assert_eq!(
+                first_difference("日本語", "日本人"),
+                Some("char 2".to_string())
+            );
+            assert_eq!(
+                first_difference("a\nb\nc", "a\nb\nd"),
+                Some("char 4 (line 3, column 1)".to_string())
+            );
+            assert_eq!(first_difference("foo", "foo"), None);
+            assert_eq!(first_difference("foo", "bar"), None);
+            assert_eq!(first_difference("", "bar"), None);
+        }
+    }
+}
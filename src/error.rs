@@ -0,0 +1,281 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+use std::fmt;
+
+use crate::base::{AssertionResult, Fact, Location};
+
+/// Error returned by failed assertions made with [`try_assert_that`](crate::try_assert_that).
+///
+/// Both [`Display`](fmt::Display) and [`Debug`] render the full assertion message, so that test
+/// functions returning `Result<(), AssertionError>` report failures like [`assert_that`] does.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// fn parse_port(s: &str) -> Result<u16, Box<dyn std::error::Error>> {
+///     let port: u16 = s.parse()?;
+///     try_assert_that!(port).is_at_least(1024)?;
+///     Ok(port)
+/// }
+///
+/// assert!(parse_port("8080").is_ok());
+/// assert!(parse_port("80").is_err());
+/// ```
+pub struct AssertionError {
+    location: Option<Location>,
+    facts: Vec<Fact>,
+    message: String,
+}
+
+impl AssertionError {
+    /// Code location of the failed assertion, if known.
+    pub fn location(&self) -> Option<&Location> {
+        self.location.as_ref()
+    }
+
+    /// Facts describing the failure, in the order they are shown in the message.
+    ///
+    /// # Example
+    /// ```
+    /// use assertor::*;
+    ///
+    /// let error = try_assert_that!(vec![1, 2]).has_length(3).unwrap_err();
+    /// assert!(error.facts().contains(&Fact::new("expected", "3")));
+    /// ```
+    pub fn facts(&self) -> &[Fact] {
+        &self.facts
+    }
+}
+
+impl From<AssertionResult> for AssertionError {
+    fn from(result: AssertionResult) -> Self {
+        AssertionError {
+            location: result.location().cloned(),
+            facts: result.facts().clone(),
+            message: result.generate_message(),
+        }
+    }
+}
+
+impl fmt::Display for AssertionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl fmt::Debug for AssertionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for AssertionError {}
+
+/// Lets [`try_assert_that`](crate::try_assert_that) return `Box<dyn Error>` directly. The boxed
+/// error is an [`AssertionError`], so the facts can be recovered by downcasting.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+/// use std::error::Error;
+///
+/// fn check_status(status: u16) -> Result<(), Box<dyn Error>> {
+///     try_assert_that!(status, Box<dyn Error>).is_equal_to(200)
+/// }
+///
+/// let error = check_status(404).unwrap_err();
+/// let error = error.downcast_ref::<AssertionError>().unwrap();
+/// assert!(error.facts().contains(&Fact::new("actual", "404")));
+/// ```
+impl From<AssertionResult> for Box<dyn std::error::Error> {
+    fn from(result: AssertionResult) -> Self {
+        Box::new(AssertionError::from(result))
+    }
+}
+
+impl From<AssertionResult> for Box<dyn std::error::Error + Send + Sync> {
+    fn from(result: AssertionResult) -> Self {
+        Box::new(AssertionError::from(result))
+    }
+}
+
+/// Lets [`try_assert_that`](crate::try_assert_that) return [`anyhow::Error`] directly. An
+/// [`AssertionError`] converts through `anyhow`'s own conversion for standard errors, and can be
+/// recovered with [`anyhow::Error::downcast_ref`].
+#[cfg(feature = "anyhow")]
+impl From<AssertionResult> for anyhow::Error {
+    fn from(result: AssertionResult) -> Self {
+        anyhow::Error::new(AssertionError::from(result))
+    }
+}
+
+thread_local! {
+    static STRUCTURED_PANICS: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets whether failed assertions on the current thread panic with an [`AssertionError`] payload
+/// instead of a [`String`] message.
+///
+/// Test harnesses can then recover the facts and location of a failure by downcasting the payload
+/// returned by [`std::panic::catch_unwind`]. This is disabled by default, as the standard panic
+/// hook only prints string payloads and `#[should_panic(expected = ..)]` only matches them.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// set_structured_panics(true);
+/// let payload = std::panic::catch_unwind(|| assert_that!(1).is_equal_to(2)).unwrap_err();
+/// set_structured_panics(false);
+///
+/// let error = payload.downcast::<AssertionError>().unwrap();
+/// assert!(error.facts().contains(&Fact::new("expected", "2")));
+/// ```
+pub fn set_structured_panics(enabled: bool) {
+    STRUCTURED_PANICS.with(|structured| structured.set(enabled));
+}
+
+/// Returns whether failed assertions on the current thread panic with an [`AssertionError`].
+pub(crate) fn structured_panics() -> bool {
+    STRUCTURED_PANICS.with(|structured| structured.get())
+}
+
+thread_local! {
+    static MINIMAL_MESSAGES: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets whether failure messages of assertions on the current thread are rendered on a single
+/// line, e.g. `assertion failed: expected: 3; actual: 1`, for test runners with little output
+/// space, such as tests running on-device.
+///
+/// Minimal messages leave out the code location, which stays available from
+/// [`AssertionError::location`], and list the number of values of multi-value facts instead of
+/// the values themselves. Values are neither wrapped nor labeled with their indices. Golden files
+/// keep the default layout.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// set_minimal_messages(true);
+/// let error = try_assert_that!(1).is_equal_to(2).unwrap_err();
+/// set_minimal_messages(false);
+///
+/// assert_eq!(error.to_string(), "assertion failed: expected: 2; actual: 1");
+/// ```
+pub fn set_minimal_messages(enabled: bool) {
+    MINIMAL_MESSAGES.with(|minimal| minimal.set(enabled));
+}
+
+/// Returns whether failure messages on the current thread are rendered on a single line.
+pub(crate) fn minimal_messages() -> bool {
+    MINIMAL_MESSAGES.with(|minimal| minimal.get())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn check_sorted(v: &[i32]) -> Result<(), AssertionError> {
+        try_assert_that!(v.len()).is_at_least(1)?;
+        try_assert_that!(v.windows(2).all(|w| w[0] <= w[1])).is_true()
+    }
+
+    #[test]
+    fn try_assert_that() {
+        assert!(check_sorted(&[1, 2, 3]).is_ok());
+
+        // Failures
+        let error = check_sorted(&[2, 1]).unwrap_err();
+        assert_that!(error.to_string()).starts_with("assertion failed: src/error.rs:");
+        assert_that!(error.to_string()).contains("but actual was false");
+        assert_that!(format!("{:?}", error)).is_equal_to(error.to_string());
+    }
+
+    #[test]
+    fn location_and_facts() {
+        let error = try_assert_that!(1).is_equal_to(2).unwrap_err();
+        assert_that!(error.location().unwrap().file()).is_equal_to("src/error.rs");
+        assert_that!(error.facts().to_vec())
+            .contains_exactly_in_order(vec![Fact::new("expected", "2"), Fact::new("actual", "1")]);
+    }
+
+    #[test]
+    fn from_check_that() {
+        assert!(check_that!(1).is_equal_to(1).into_result().is_ok());
+
+        // Failures
+        let error = check_that!(1).is_equal_to(2).into_result().unwrap_err();
+        assert_that!(error.facts().len()).is_equal_to(2);
+        assert_that!(error.location().unwrap().line()).is_greater_than(0);
+    }
+
+    #[test]
+    fn question_mark_into_boxed_error() -> Result<(), Box<dyn std::error::Error>> {
+        try_assert_that!("assertor").contains("sert")?;
+        Ok(())
+    }
+
+    fn check_even(n: i32) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        try_assert_that!(n % 2, Box<dyn std::error::Error + Send + Sync>).is_equal_to(0)
+    }
+
+    #[test]
+    fn into_boxed_error() {
+        assert!(check_even(2).is_ok());
+
+        // Failures
+        let error = check_even(3).unwrap_err();
+        let error = error.downcast_ref::<AssertionError>().unwrap();
+        assert_that!(error.facts().to_vec())
+            .contains_exactly_in_order(vec![Fact::new("expected", "0"), Fact::new("actual", "1")]);
+        let error: Box<dyn std::error::Error> =
+            try_assert_that!(1).is_equal_to(2).unwrap_err().into();
+        assert_that!(error.to_string()).starts_with("assertion failed");
+    }
+
+    #[cfg(feature = "anyhow")]
+    #[test]
+    fn into_anyhow_error() {
+        fn check(n: i32) -> anyhow::Result<()> {
+            try_assert_that!(n, anyhow::Error).is_equal_to(0)?;
+            try_assert_that!(n).is_equal_to(0)?;
+            Ok(())
+        }
+        assert!(check(0).is_ok());
+
+        // Failures
+        let error = check(1).unwrap_err();
+        let error = error.downcast_ref::<AssertionError>().unwrap();
+        assert_that!(error.facts().to_vec()).contains(Fact::new("actual", "1"));
+    }
+
+    #[test]
+    fn structured_panics() {
+        set_structured_panics(true);
+        let payload = std::panic::catch_unwind(|| assert_that!(vec![1]).has_length(2));
+        set_structured_panics(false);
+
+        let error = payload.unwrap_err().downcast::<AssertionError>().unwrap();
+        assert_that!(error.facts().to_vec()).contains(Fact::new("expected", "2"));
+        assert_that!(error.to_string()).starts_with("assertion failed: src/error.rs:");
+
+        // Disabled again, failures panic with their message.
+        let payload = std::panic::catch_unwind(|| assert_that!(1).is_equal_to(2));
+        assert!(payload.unwrap_err().downcast::<String>().is_ok());
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Golden files, i.e. files holding the expected contents of a value, which can be rewritten with
+//! the actual contents after a deliberate change.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::base::{AssertionResult, AssertionStrategy};
+use crate::diff::string::unified_diff;
+
+/// Environment variable which, when set to `1`, makes golden file assertions, such as
+/// [`matches_contents_of`](crate::BytesAssertion::matches_contents_of), write actual contents to
+/// golden files instead of comparing them. Changes are then caught by failing tests until golden
+/// files are deliberately regenerated and reviewed.
+///
+/// When set to `new`, mismatching contents are written to pending golden files next to the golden
+/// files instead, see [`pending_golden_path`], and the assertions still fail. Pending golden files
+/// can then be accepted or rejected one by one with `cargo assertor-review`, installed with the
+/// `review` feature, like `.snap.new` files with `cargo insta review`.
+pub const UPDATE_GOLDEN_ENV: &str = "ASSERTOR_UPDATE_GOLDEN";
+
+/// Extension appended to the path of a golden file to get the path of its pending golden file.
+pub const PENDING_GOLDEN_EXTENSION: &str = "new";
+
+/// Whether golden files are to be updated instead of compared. See [`UPDATE_GOLDEN_ENV`].
+pub fn update_golden() -> bool {
+    matches!(std::env::var(UPDATE_GOLDEN_ENV).as_deref(), Ok("1"))
+}
+
+/// Whether mismatching contents are to be written to pending golden files. See
+/// [`UPDATE_GOLDEN_ENV`].
+pub fn write_pending_golden() -> bool {
+    matches!(std::env::var(UPDATE_GOLDEN_ENV).as_deref(), Ok("new"))
+}
+
+/// Returns the path of the pending golden file of the golden file at `path`, e.g.
+/// `testdata/sum.txt.new` for `testdata/sum.txt`. A pending golden file has the same contents as
+/// the golden file would have, so that accepting it is renaming it to the golden file.
+pub fn pending_golden_path(path: &Path) -> PathBuf {
+    let mut pending = path.as_os_str().to_owned();
+    pending.push(".");
+    pending.push(PENDING_GOLDEN_EXTENSION);
+    PathBuf::from(pending)
+}
+
+/// Writes `contents` to the file at `path`, creating its directory if needed.
+pub(crate) fn write_golden(path: &Path, contents: &[u8]) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).expect("failed to create the golden file directory");
+    }
+    fs::write(path, contents).expect("failed to write the golden file");
+}
+
+/// Adds how to update the golden file at `path` with the `actual` contents, after writing the
+/// pending golden file if `pending`, i.e. if requested by [`UPDATE_GOLDEN_ENV`].
+pub(crate) fn add_update_facts(
+    result: AssertionResult,
+    path: &Path,
+    actual: &[u8],
+    hint: &str,
+    pending: bool,
+) -> AssertionResult {
+    if !pending {
+        return result.add_fact(hint, format!("{}=1", UPDATE_GOLDEN_ENV));
+    }
+    let pending = pending_golden_path(path);
+    write_golden(&pending, actual);
+    result
+        .add_fact("pending golden file", pending.display().to_string())
+        .add_fact("to review it, run", "cargo assertor-review")
+}
+
+/// Checks that the file at `path` contains exactly `actual`, or updates it as requested by
+/// [`UPDATE_GOLDEN_ENV`].
+pub(crate) fn check_matches_contents_of<R>(result: AssertionResult, path: &Path, actual: &[u8]) -> R
+where
+    AssertionResult: AssertionStrategy<R>,
+{
+    if update_golden() {
+        write_golden(path, actual);
+        return result.do_ok();
+    }
+    let expected = match fs::read(path) {
+        Ok(contents) => contents,
+        Err(error) => {
+            let result = result
+                .add_fact("expected contents of", path.display().to_string())
+                .add_fact("but could not read it", error.to_string());
+            return add_update_facts(
+                result,
+                path,
+                actual,
+                "to create it, run tests with",
+                write_pending_golden(),
+            )
+            .do_fail();
+        }
+    };
+    if expected == actual {
+        if write_pending_golden() {
+            // A pending golden file of an earlier run is stale once the contents match.
+            let _ = fs::remove_file(pending_golden_path(path));
+        }
+        return result.do_ok();
+    }
+    let result = result
+        .add_fact("expected contents of", path.display().to_string())
+        .add_fact("but differed at", describe_difference(&expected, actual));
+    let result = add_update_facts(
+        result,
+        path,
+        actual,
+        "to accept the new contents, run tests with",
+        write_pending_golden(),
+    );
+    let diff = match (std::str::from_utf8(&expected), std::str::from_utf8(actual)) {
+        (Ok(expected), Ok(actual)) => unified_diff(expected, actual),
+        _ => None,
+    };
+    match diff {
+        Some(diff) => result
+            .add_splitter()
+            .add_fact("diff (-expected +actual)", diff)
+            .do_fail(),
+        None => result
+            .add_splitter()
+            .add_fact("expected length", format!("{} bytes", expected.len()))
+            .add_fact("actual length", format!("{} bytes", actual.len()))
+            .do_fail(),
+    }
+}
+
+/// Describes the line and byte offset of the first byte where `expected` and `actual` differ.
+fn describe_difference(expected: &[u8], actual: &[u8]) -> String {
+    let index = expected
+        .iter()
+        .zip(actual)
+        .position(|(e, a)| e != a)
+        .unwrap_or_else(|| expected.len().min(actual.len()));
+    let line = expected[..index].iter().filter(|&&b| b == b'\n').count() + 1;
+    format!("line {} (byte {})", line, index)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::{EqualityAssertion, StringAssertion};
+
+    use super::*;
+
+    #[test]
+    fn pending_golden_file() {
+        let path = std::env::temp_dir().join("assertor_pending_golden.txt");
+        let pending = pending_golden_path(&path);
+        assert_that!(pending.display().to_string()).ends_with("assertor_pending_golden.txt.new");
+
+        let result = add_update_facts(
+            AssertionResult::new(&None),
+            &path,
+            b"expected: 3\nactual  : 2\n",
+            "to accept the new message, run tests with",
+            true,
+        );
+        assert_that!(AssertionStrategy::<CheckThatResult>::do_fail(result)).facts_are(vec![
+            Fact::new("pending golden file", pending.display().to_string()),
+            Fact::new("to review it, run", "cargo assertor-review"),
+        ]);
+        assert_that!(fs::read_to_string(&pending).unwrap())
+            .is_equal_to("expected: 3\nactual  : 2\n".to_string());
+        fs::remove_file(&pending).unwrap();
+    }
+
+    #[test]
+    fn differences() {
+        assert_that!(describe_difference(b"ab\ncd", b"ab\nce"))
+            .is_equal_to("line 2 (byte 4)".to_string());
+        assert_that!(describe_difference(b"ab", b"abc")).is_equal_to("line 1 (byte 2)".to_string());
+    }
+}
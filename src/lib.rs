@@ -29,46 +29,152 @@
 //! assert_that!(vec!["a", "b"]).contains("a");
 //! assert_that!(vec!["a", "b"]).has_length(2);
 //! assert_that!(vec!["a", "b"]).contains_exactly(vec!["a", "b"]);
+//! assert_that!(["a", "b"]).contains("b");
+//! assert_that!(1..4).contains_exactly_in_order([1, 2, 3].into_iter());
 //!
 //! assert_that!(Option::Some("Foo")).has_value("Foo");
 //! ```
 //!
 //! ## Failure cases
-//! ```should_panic
+//! [`demo!`] checks the failure message of an assertion:
+//! ```
 //! use assertor::*;
-//! assert_that!(vec!["a", "b", "c"]).contains_exactly(vec!["b", "c", "d"]);
-//! // missing (1)   : ["d"]
-//! // unexpected (1): ["a"]
-//! // ---
-//! // expected      : ["b", "c", "d"]
-//! // actual        : ["a", "b", "c"]
+//! demo!(
+//!     assert_that!(vec!["a", "b", "c"]).contains_exactly(vec!["b", "c", "d"]),
+//!     r#"
+//!     missing (1)   : ["d"]
+//!     unexpected (1): ["a"]
+//!     ---
+//!     expected      : [ "b", "c", "d" ]
+//!     actual        : [ "a", "b", "c" ]
+//!     "#
+//! );
 //! ```
+//!
+//! # Feature flags
+//! Integrations which need a dependency are opt-in, except for `float`.
+//! - `derive`: `#[derive(Assertable)]`, which generates assertions returning derived subjects for
+//!   the public fields of a struct, and checks of the variants of an enum.
+//! - `color`: colors `expected` and `actual` values in panic messages, highlighting the characters
+//!   which differ. Set the `NO_COLOR` environment variable to turn it off, e.g. on CI.
+//! - `float` (default): [`FloatAssertion`] for approximate comparisons, using `num-traits`.
+//! - `anyhow`: `AnyhowErrorAssertion` for `anyhow::Error`, and conversion of failures into
+//!   `anyhow::Error` for [`try_assert_that`].
+//! - `http`: `HeaderMapAssertion` for `http::HeaderMap`, which is also usable as a map subject.
+//! - `bytes`: `bytes::Bytes` and `bytes::BytesMut` subjects for [`VecAssertion`], in addition to
+//!   [`BytesAssertion`] which accepts any `AsRef<[u8]>` subject.
+//! - `serde_json`: `StringAssertion::as_json` to assert on string payloads as JSON values, and
+//!   `JsonValueAssertion` for `serde_json::Value`.
+//! - `captured-logs`: `LogAssertion` for log records captured with `CapturedLogs`, e.g. by a test
+//!   `log::Log` implementation. The `log` crate is not a dependency.
+//! - `captured-events`: `EventAssertion` for telemetry events captured with `CapturedEvents`,
+//!   e.g. by a test `tracing::Subscriber` implementation. The `tracing` crate is not a
+//!   dependency.
+//! - `captured-metrics`: `MetricsAssertion` for counters and histograms captured with
+//!   `CapturedMetrics`, e.g. by a test `metrics::Recorder` implementation. The `metrics` crate is
+//!   not a dependency.
+//! - `graphs`: `GraphAssertion` for directed graphs, such as adjacency lists or other graph types
+//!   implementing `GraphLike`. The `petgraph` crate is not a dependency.
+//! - `url-parts`: `UrlAssertion` for the scheme, host, path and query of URL strings, or of other
+//!   URL types implementing `UrlLike`. The `url` crate is not a dependency.
+//! - `media-types`: `MimeAssertion` for the essence and parameters of media types such as
+//!   `Content-Type` header values. The `mime` crate is not a dependency.
+//! - `testing`: assertions on [`CheckThatResult`]s and golden files, to test failure messages of
+//!   custom assertions.
 #![warn(missing_docs)]
 
 #[cfg(feature = "float")]
 extern crate num_traits;
 
+pub use assertions::all::{AllValuesAssertion, LabeledValues};
 #[cfg(feature = "anyhow")]
 pub use assertions::anyhow::AnyhowErrorAssertion;
-pub use assertions::basic::{ComparableAssertion, EqualityAssertion};
+pub use assertions::array::ArrayAssertion;
+pub use assertions::basic::{ComparableAssertion, EqualityAssertion, GenericAssertion};
 pub use assertions::boolean::BooleanAssertion;
+pub use assertions::bytes::BytesAssertion;
+pub use assertions::code::CodeAssertion;
+pub use assertions::collection::{CollectionAssertion, CollectionLike};
 pub use assertions::cow::CowAssertion;
+pub use assertions::deref::DerefAssertion;
+pub use assertions::diagnostic::{DiagnosticAssertion, DiagnosticLike, ErrorSourceAssertion};
+pub use assertions::dynamic::{DynDebug, DynDebugAssertion};
+pub use assertions::fixed_point::{FixedPoint, FixedPointAssertion};
 #[cfg(feature = "float")]
 pub use assertions::float::FloatAssertion;
-pub use assertions::iterator::IteratorAssertion;
+#[cfg(feature = "graphs")]
+pub use assertions::graph::{GraphAssertion, GraphLike};
+#[cfg(feature = "http")]
+pub use assertions::http::HeaderMapAssertion;
+pub use assertions::index::{IndexAssertion, IndexLike};
+pub use assertions::io::BufReadAssertion;
+pub use assertions::iterator::{ElementLike, IteratorAssertion};
+#[cfg(feature = "serde_json")]
+pub use assertions::json::JsonValueAssertion;
+#[cfg(feature = "captured-logs")]
+pub use assertions::log::{CapturedLogs, CapturedRecord, LogAssertion, LogLevel};
 pub use assertions::map::MapAssertion;
 pub use assertions::map::OrderedMapAssertion;
+pub use assertions::map::{CaseInsensitiveKeysAssertion, MappedValuesAssertion, MultimapAssertion};
+#[cfg(feature = "captured-metrics")]
+pub use assertions::metrics::{CapturedMetrics, MetricsAssertion};
+#[cfg(feature = "media-types")]
+pub use assertions::mime::{MimeAssertion, MimeLike};
 pub use assertions::option::OptionAssertion;
-pub use assertions::result::ResultAssertion;
+pub use assertions::pair::{Pair, PairAssertion};
+pub use assertions::process::OutputAssertion;
+pub use assertions::result::{ResultAssertion, ResultsAssertion};
 pub use assertions::set::OrderedSetAssertion;
 pub use assertions::set::SetAssertion;
-pub use assertions::string::StringAssertion;
-pub use assertions::vec::VecAssertion;
-pub use base::{AssertionResult, AssertionStrategy, Fact, Location, Subject};
+pub use assertions::string::{
+    EditDistanceAssertion, PathStringAssertion, StringAssertion, TrimmedStringAssertion,
+};
+pub use assertions::time::{
+    DurationAssertion, JitterAssertion, MillisDurationAssertion, TimeAssertion, TimeLike,
+};
+#[cfg(feature = "captured-events")]
+pub use assertions::tracing::{CapturedEvent, CapturedEvents, EventAssertion};
+#[cfg(feature = "url-parts")]
+pub use assertions::url::{UrlAssertion, UrlLike};
+pub use assertions::vec::{SequenceLike, VecAssertion};
+#[cfg(feature = "derive")]
+pub use assertor_derive::Assertable;
+#[doc(hidden)]
+pub use base::{closure_body_text, expr_text};
+pub use base::{
+    format_facts, AssertionResult, AssertionStrategy, DuplicateKeys, Fact, FactGroup, FactKey,
+    LazyActual, LazyValue, Location, Subject,
+};
+pub use catalog::{
+    reset_message_catalog, set_message_catalog, with_message_catalog, MessageCatalog,
+};
+pub use check::CheckThatResult;
+#[doc(hidden)]
+pub use concurrent::run_concurrent;
+#[doc(hidden)]
+pub use demo::check_demo;
+pub use error::{set_minimal_messages, set_structured_panics, AssertionError};
+pub use plan::AssertionPlan;
+pub use stats::{assertion_stats, reset_assertion_stats, set_assertion_stats, AssertionStats};
+pub use timing::set_slow_assertion_threshold;
 
 mod assertions;
 mod base;
+mod catalog;
+mod check;
+#[cfg(feature = "color")]
+mod color;
+#[cfg(doctest)]
+mod compile_fail;
+mod concurrent;
+mod demo;
 mod diff;
+mod error;
+mod golden;
+mod plan;
+pub mod prelude;
+mod stats;
+mod timing;
 
 /// Module for testing the assertor library itself. Expected to be used by library developers.
 #[cfg(any(test, doc, feature = "testing"))]
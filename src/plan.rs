@@ -0,0 +1,238 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::base::{AssertionResult, AssertionStrategy, Location, Subject};
+use crate::error::AssertionError;
+
+type Check<T> =
+    Box<dyn Fn(&Subject<'_, T, (), Result<(), AssertionError>>) -> Result<(), AssertionError>>;
+
+/// A reusable chain of assertions, recorded once and applied to many values, e.g. to share an
+/// assertion suite between parameterized tests.
+///
+/// Each check is a closure making assertions on a subject returning `Result`, like
+/// [`try_assert_that`](crate::try_assert_that), so that several assertions can be chained with
+/// `?`. All checks are applied, and their failures are reported together.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// let plan = AssertionPlan::new()
+///     .check("is a small batch", |batch: &Subject<Vec<u32>, _, _>| {
+///         batch.is_not_empty()?;
+///         batch.has_length_at_most(3)
+///     })
+///     .check("has no retries", |batch| batch.does_not_contain(0));
+///
+/// plan.apply_to(&vec![1, 2]);
+/// plan.apply_to_all(&[vec![1], vec![2, 3]]);
+/// demo!(
+///     plan.apply_to(&vec![0, 1, 2, 3]),
+///     r#"
+///     expected all checks to pass: but 2 of 2 did not
+///     ---
+///     check   : is a small batch
+///     value of: value.size()
+///     expected: at most 3
+///     actual  : 4
+///     ---
+///     check                  : has no retries
+///     expected to not contain: 0
+///     but element was found
+///     though it did contain  : [ [0]: 0, [1]: 1, [2]: 2, [3]: 3 ]
+///     "#
+/// );
+/// ```
+pub struct AssertionPlan<T> {
+    checks: Vec<(String, Check<T>)>,
+}
+
+impl<T> AssertionPlan<T> {
+    /// Creates a plan without checks.
+    pub fn new() -> Self {
+        AssertionPlan { checks: vec![] }
+    }
+
+    /// Adds a check, labeled with `description` in failure messages.
+    pub fn check<D, F>(mut self, description: D, check: F) -> Self
+    where
+        D: Into<String>,
+        F: Fn(&Subject<'_, T, (), Result<(), AssertionError>>) -> Result<(), AssertionError>
+            + 'static,
+    {
+        self.checks.push((description.into(), Box::new(check)));
+        self
+    }
+
+    /// Applies all checks to `value`, and panics with the failures of all failed checks.
+    #[track_caller]
+    pub fn apply_to(&self, value: &T) {
+        if let Some(result) = self.run([value], Some(caller_location()), |_| None) {
+            AssertionStrategy::<()>::do_fail(result)
+        }
+    }
+
+    /// Applies all checks to `value`, and returns the failures of all failed checks.
+    #[track_caller]
+    pub fn try_apply_to(&self, value: &T) -> Result<(), AssertionError> {
+        self.run([value], Some(caller_location()), |_| None)
+            .map_or(Ok(()), |result| Err(AssertionError::from(result)))
+    }
+
+    /// Applies all checks to each of `values`, and panics with the failures of all failed checks,
+    /// labeled with the values they failed for.
+    #[track_caller]
+    pub fn apply_to_all<'v, I>(&self, values: I)
+    where
+        I: IntoIterator<Item = &'v T>,
+        T: Debug + 'v,
+    {
+        let location = Some(caller_location());
+        if let Some(result) = self.run(values, location, |value| Some(format!("{:?}", value))) {
+            AssertionStrategy::<()>::do_fail(result)
+        }
+    }
+
+    /// Applies all checks to each of `values`, and merges the failures, labeled by `label`.
+    fn run<'v, I, L>(
+        &self,
+        values: I,
+        location: Option<Location>,
+        label: L,
+    ) -> Option<AssertionResult>
+    where
+        I: IntoIterator<Item = &'v T>,
+        L: Fn(&T) -> Option<String>,
+        T: 'v,
+    {
+        let mut total = 0;
+        let mut failures = vec![];
+        for value in values {
+            let subject = Subject::new(
+                value,
+                "value".to_string(),
+                None,
+                (),
+                location.clone(),
+                PhantomData,
+            );
+            for (description, check) in &self.checks {
+                total += 1;
+                if let Err(error) = check(&subject) {
+                    failures.push((label(value), description, error));
+                }
+            }
+        }
+        if failures.is_empty() {
+            return None;
+        }
+        let result = AssertionResult::new(&location)
+            .begin_group()
+            .add_fact(
+                "expected all checks to pass",
+                format!("but {} of {} did not", failures.len(), total),
+            )
+            .end_group();
+        Some(
+            failures
+                .into_iter()
+                .fold(result, |result, (label, description, error)| {
+                    let mut group = result.begin_group();
+                    if let Some(label) = label {
+                        group = group.add_fact("value", label);
+                    }
+                    error
+                        .facts()
+                        .iter()
+                        .fold(group.add_fact("check", description), |group, fact| {
+                            group.push_fact(fact.clone())
+                        })
+                        .end_group()
+                }),
+        )
+    }
+}
+
+impl<T> Default for AssertionPlan<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[track_caller]
+fn caller_location() -> Location {
+    let caller = std::panic::Location::caller();
+    Location::new(caller.file(), caller.line(), caller.column())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::testing::*;
+    use crate::{EqualityAssertion, VecAssertion};
+
+    use super::*;
+
+    fn plan() -> AssertionPlan<Vec<i32>> {
+        AssertionPlan::new()
+            .check("is short", |v| v.has_length_at_most(2))
+            .check("has positive elements", |v| {
+                v.does_not_contain(0)?;
+                v.all_within(1..)
+            })
+    }
+
+    #[test]
+    fn apply_to() {
+        plan().apply_to(&vec![1, 2]);
+        plan().apply_to_all(&[vec![], vec![3]]);
+        AssertionPlan::<i32>::new().apply_to(&0);
+
+        let error = plan().try_apply_to(&vec![2, 1, 0]).unwrap_err();
+        assert_that!(error.facts().to_vec()).contains(Fact::new("check", "is short"));
+        assert_that!(error.facts().to_vec()).contains(Fact::new("check", "has positive elements"));
+        assert_that!(error.facts()[0]).is_equal_to(Fact::new(
+            "expected all checks to pass",
+            "but 2 of 2 did not",
+        ));
+    }
+
+    #[test]
+    fn apply_to_all_labels_values() {
+        let plan = AssertionPlan::new().check("is not empty", |v| v.is_not_empty());
+        let result = plan
+            .run([&vec![1], &vec![], &vec![2]], None, |value| {
+                Some(format!("{:?}", value))
+            })
+            .unwrap();
+        assert_that!(AssertionStrategy::<CheckThatResult>::do_fail(result)).facts_are(vec![
+            Fact::new("expected all checks to pass", "but 1 of 3 did not"),
+            Fact::new_splitter(),
+            Fact::new("value", "[]"),
+            Fact::new("check", "is not empty"),
+            Fact::new_simple_fact("expected to be non-empty"),
+            Fact::new_splitter(),
+            Fact::new("actual", "[]"),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "check   : is short")]
+    fn apply_to_failure() {
+        plan().apply_to(&vec![1, 2, 3]);
+    }
+}
@@ -0,0 +1,148 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The assertion macros and traits, without the types used to write custom assertions.
+//!
+//! `use assertor::prelude::*` brings every assertion method in scope. [`collections`] and
+//! [`strings`] hold the traits for one kind of subject, to be imported by name or together with
+//! the macros:
+//! ```
+//! use assertor::assert_that;
+//! use assertor::prelude::collections::VecAssertion;
+//!
+//! assert_that!(vec![1, 2, 3]).contains_exactly_in_order(vec![1, 2, 3]);
+//! ```
+//!
+//! All the trait names end with `Assertion`, so that glob importing the prelude next to the std
+//! prelude or traits like `itertools::Itertools` does not shadow or clash with their names.
+//!
+//! # Method name collisions
+//! [`Subject`](crate::Subject) has no inherent `contains` or `is_empty`, and each assertion trait
+//! is implemented only for the subjects it applies to, so methods of iterator traits such as
+//! `Itertools::contains` never apply to a subject. A user trait implemented for every type can
+//! still make a call ambiguous; the assertion is then called through its trait:
+//! ```
+//! use assertor::prelude::*;
+//!
+//! trait Contains {
+//!     fn contains(&self, needle: &str) -> bool;
+//! }
+//!
+//! impl<T: std::fmt::Debug> Contains for T {
+//!     fn contains(&self, needle: &str) -> bool {
+//!         str::contains(&format!("{:?}", self), needle)
+//!     }
+//! }
+//!
+//! let actual = "foobar";
+//! let subject = assert_that!(actual);
+//! StringAssertion::contains(&subject, "bar");
+//! assert!(Contains::contains(&"foobar", "bar"));
+//! ```
+
+pub use crate::{
+    assert_concurrent, assert_that, assert_that_all, assert_that_code, assert_that_collected,
+    assert_that_dyn, assert_that_lazy, assert_that_pair, const_assert_that, debug_assert_that,
+    is_variant, try_assert_that,
+};
+
+pub use crate::{
+    AllValuesAssertion, BooleanAssertion, BufReadAssertion, CodeAssertion, ComparableAssertion,
+    CowAssertion, DerefAssertion, DiagnosticAssertion, DurationAssertion, DynDebugAssertion,
+    EqualityAssertion, ErrorSourceAssertion, FixedPointAssertion, GenericAssertion,
+    JitterAssertion, MillisDurationAssertion, OptionAssertion, OutputAssertion, PairAssertion,
+    ResultAssertion, ResultsAssertion, TimeAssertion,
+};
+
+#[cfg(feature = "anyhow")]
+pub use crate::AnyhowErrorAssertion;
+#[cfg(feature = "captured-events")]
+pub use crate::EventAssertion;
+#[cfg(feature = "float")]
+pub use crate::FloatAssertion;
+#[cfg(feature = "graphs")]
+pub use crate::GraphAssertion;
+#[cfg(feature = "http")]
+pub use crate::HeaderMapAssertion;
+#[cfg(feature = "serde_json")]
+pub use crate::JsonValueAssertion;
+#[cfg(feature = "captured-logs")]
+pub use crate::LogAssertion;
+#[cfg(feature = "captured-metrics")]
+pub use crate::MetricsAssertion;
+#[cfg(feature = "media-types")]
+pub use crate::MimeAssertion;
+#[cfg(feature = "url-parts")]
+pub use crate::UrlAssertion;
+
+pub use collections::*;
+pub use strings::*;
+
+/// Assertion traits for arrays, iterators, vectors, sets, maps and byte sequences.
+pub mod collections {
+    pub use crate::{
+        ArrayAssertion, BytesAssertion, CaseInsensitiveKeysAssertion, CollectionAssertion,
+        IndexAssertion, IteratorAssertion, MapAssertion, MappedValuesAssertion, MultimapAssertion,
+        OrderedMapAssertion, OrderedSetAssertion, SetAssertion, VecAssertion,
+    };
+}
+
+/// Assertion traits for `String` and `&str` subjects.
+pub mod strings {
+    pub use crate::{
+        EditDistanceAssertion, PathStringAssertion, StringAssertion, TrimmedStringAssertion,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prelude() {
+        assert_that!(vec![1, 2, 3]).contains(2);
+        assert_that!("foobar").starts_with("foo");
+        assert_that!(Some(1)).has_value(1);
+        assert_that!(1).is_less_than(2);
+    }
+
+    trait Emptiness {
+        fn is_empty(&self) -> bool;
+    }
+
+    impl<T> Emptiness for T {
+        fn is_empty(&self) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn disambiguates_from_blanket_user_traits() {
+        let empty: Vec<i32> = vec![];
+        let subject = assert_that!(empty);
+        VecAssertion::is_empty(&subject);
+        IteratorAssertion::is_empty(&assert_that!([0; 0].iter()));
+        assert!(!Emptiness::is_empty(&subject));
+    }
+
+    #[test]
+    fn coexists_with_std_traits() {
+        use std::collections::HashSet;
+        use std::iter::FromIterator;
+
+        let set = HashSet::<i32>::from_iter([1, 2]);
+        assert_that!(set).contains(1);
+        assert_that!([1, 2].to_vec()).has_length(2);
+    }
+}
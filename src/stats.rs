@@ -0,0 +1,158 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+use std::fmt;
+
+/// Numbers of assertions run and failed on the current thread, as returned by
+/// [`assertion_stats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AssertionStats {
+    run: usize,
+    failed: usize,
+}
+
+impl AssertionStats {
+    /// Number of assertions which were evaluated, whether they passed or failed. Skipped
+    /// assertions are not counted.
+    pub fn run(&self) -> usize {
+        self.run
+    }
+
+    /// Number of assertions which failed. This includes the failures returned by
+    /// [`try_assert_that`](crate::try_assert_that) and [`check_that`](crate::check_that) that do
+    /// not panic.
+    pub fn failed(&self) -> usize {
+        self.failed
+    }
+
+    /// Number of assertions which passed.
+    pub fn passed(&self) -> usize {
+        self.run - self.failed
+    }
+}
+
+impl fmt::Display for AssertionStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let noun = if self.run == 1 {
+            "assertion"
+        } else {
+            "assertions"
+        };
+        write!(f, "{} {}, {} failed", self.run, noun, self.failed)
+    }
+}
+
+thread_local! {
+    static ENABLED: Cell<bool> = const { Cell::new(false) };
+    static STATS: Cell<AssertionStats> = const { Cell::new(AssertionStats { run: 0, failed: 0 }) };
+}
+
+/// Sets whether assertions on the current thread are counted.
+///
+/// The counters are kept per thread, like [`set_structured_panics`](crate::set_structured_panics),
+/// so that tests run in parallel by the standard harness each see their own numbers. This is
+/// disabled by default.
+///
+/// # Example
+/// ```
+/// use assertor::*;
+///
+/// set_assertion_stats(true);
+/// assert_that!(1).is_equal_to(1);
+/// assert!(try_assert_that!(1).is_equal_to(2).is_err());
+///
+/// let stats = reset_assertion_stats();
+/// assert_eq!(stats.to_string(), "2 assertions, 1 failed");
+/// set_assertion_stats(false);
+/// ```
+pub fn set_assertion_stats(enabled: bool) {
+    ENABLED.with(|cell| cell.set(enabled));
+}
+
+/// Returns the numbers of assertions counted on the current thread since the last
+/// [`reset_assertion_stats`].
+pub fn assertion_stats() -> AssertionStats {
+    STATS.with(|cell| cell.get())
+}
+
+/// Resets the counters of the current thread, and returns their values before the reset.
+pub fn reset_assertion_stats() -> AssertionStats {
+    STATS.with(|cell| cell.take())
+}
+
+/// Counts an evaluated assertion if counting is enabled on the current thread.
+pub(crate) fn record(failed: bool) {
+    if !ENABLED.with(|cell| cell.get()) {
+        return;
+    }
+    STATS.with(|cell| {
+        let stats = cell.get();
+        cell.set(AssertionStats {
+            run: stats.run + 1,
+            failed: stats.failed + usize::from(failed),
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn counts_assertions() {
+        set_assertion_stats(true);
+        reset_assertion_stats();
+        assert_that!(1).is_equal_to(1);
+        assert_that!(vec![1, 2]).contains(2);
+        let _ = try_assert_that!(1).is_equal_to(2);
+        let _ = check_that!("abc").starts_with("x");
+        let _ = std::panic::catch_unwind(|| assert_that!(1).is_less_than(0));
+        let stats = reset_assertion_stats();
+        set_assertion_stats(false);
+
+        assert_that!(stats.run()).is_equal_to(5);
+        assert_that!(stats.failed()).is_equal_to(3);
+        assert_that!(stats.passed()).is_equal_to(2);
+        assert_that!(assertion_stats()).is_equal_to(AssertionStats::default());
+    }
+
+    #[test]
+    fn skipped_assertions_are_not_counted() {
+        set_assertion_stats(true);
+        reset_assertion_stats();
+        assert_that!(1).skip_if(true).is_equal_to(2);
+        let stats = reset_assertion_stats();
+        set_assertion_stats(false);
+
+        assert_that!(stats.run()).is_equal_to(0);
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        assert_that!(1).is_equal_to(1);
+        assert_that!(assertion_stats().run()).is_equal_to(0);
+    }
+
+    #[test]
+    fn display() {
+        set_assertion_stats(true);
+        reset_assertion_stats();
+        assert_that!(true).is_true();
+        let stats = reset_assertion_stats();
+        set_assertion_stats(false);
+
+        assert_that!(stats.to_string()).is_equal_to("1 assertion, 0 failed".to_string());
+    }
+}
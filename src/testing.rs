@@ -13,57 +13,20 @@
 // limitations under the License.
 
 pub use crate::assertions::testing::CheckThatResultAssertion;
-pub use crate::{assert_that, check_that, Fact};
-use crate::{AssertionResult, AssertionStrategy};
+pub use crate::golden::{
+    pending_golden_path, update_golden, write_pending_golden, PENDING_GOLDEN_EXTENSION,
+    UPDATE_GOLDEN_ENV,
+};
+use crate::AssertionResult;
+pub use crate::{assert_that, check_that, CheckThatResult, Fact};
 
-/// *Only for library developers.* An assertion macro to get the result of assertion without
-/// throwing panic. Expected to be used for testing assertion library.
-///
-/// # Example
-///
-/// ```ignore
-/// use assertor::*;
-/// use assertor::testing::*;
-///
-/// assert_that!(check_that!("actual_string").is_same_string_to("expected_string")).facts_are(vec![
-///     Fact::new("expected", "expected_string"),
-///     Fact::new("actual", "actual_string"),
-/// ]);
-/// ```
-#[macro_export]
-macro_rules! check_that {
-    ($actual:expr) => {
-        $crate::Subject::new(
-            &$actual,
-            stringify!($actual).to_string(),
-            /* description= */ None,
-            /* option= */ (),
-            Some($crate::Location::new(
-                file!().to_string(),
-                line!(),
-                column!(),
-            )),
-            std::marker::PhantomData::<$crate::testing::CheckThatResult>,
-        )
-    };
-}
-
-/// Data structure that contains assertion result and messages.
-pub struct CheckThatResult(Result<(), AssertionResult>);
-
-impl AssertionStrategy<CheckThatResult> for AssertionResult {
-    fn do_fail(self) -> CheckThatResult {
-        CheckThatResult(Err(self))
-    }
-
-    fn do_ok(self) -> CheckThatResult {
-        // XXX: Unnecessary AssertionResult instantiation for ok cases.
-        CheckThatResult(Ok(()))
-    }
-}
-
-impl AsRef<Result<(), AssertionResult>> for CheckThatResult {
-    fn as_ref(&self) -> &Result<(), AssertionResult> {
-        &self.0
+/// Returns the failure message of `result` as stored in golden files, i.e. without the header
+/// line, which contains the code location. Golden files keep the default message layout even if
+/// [minimal messages](crate::set_minimal_messages) are enabled.
+pub fn golden_message(result: &AssertionResult) -> String {
+    let message = result.generate_default_message();
+    match message.split_once('\n') {
+        Some((_header, facts)) => facts.to_string(),
+        None => String::new(),
     }
 }
@@ -0,0 +1,112 @@
+// Copyright 2024 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//      http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static THRESHOLD: Cell<Option<Duration>> = const { Cell::new(None) };
+}
+
+/// Sets the duration above which assertions on the current thread are reported as slow, or `None`
+/// to stop timing them.
+///
+/// An assertion is timed from the creation of its subject, e.g. by
+/// [`assert_that`](crate::assert_that), so the evaluation of derived subjects is included. A slow
+/// failure has an `evaluation took` fact, and a slow pass is printed to stderr with its location.
+/// This helps finding the assertions that bloat the time of a test suite, e.g. with huge diffs or
+/// expensive `Debug` implementations.
+///
+/// The threshold is kept per thread, like [`set_assertion_stats`](crate::set_assertion_stats).
+/// This is disabled by default. Timing is not available on `wasm32-unknown-unknown`, whose
+/// standard library has no clock.
+///
+/// # Example
+/// ```should_panic
+/// use assertor::*;
+/// use std::time::Duration;
+///
+/// set_slow_assertion_threshold(Some(Duration::ZERO));
+/// assert_that!(vec![1, 2, 3]).contains(4);
+/// // expected to contain  : 4
+/// // but did not
+/// // though it did contain: [1, 2, 3]
+/// // evaluation took      : 12.3µs
+/// ```
+pub fn set_slow_assertion_threshold(threshold: Option<Duration>) {
+    THRESHOLD.with(|cell| cell.set(threshold));
+}
+
+/// Returns the current time if assertions on the current thread are timed.
+pub(crate) fn start() -> Option<Instant> {
+    THRESHOLD.with(|cell| cell.get()).map(|_| Instant::now())
+}
+
+/// Returns how long the assertion started at `started` took, if it exceeds the threshold.
+pub(crate) fn slow(started: Option<Instant>) -> Option<Duration> {
+    let threshold = THRESHOLD.with(|cell| cell.get())?;
+    let elapsed = started?.elapsed();
+    (elapsed > threshold).then_some(elapsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::testing::*;
+    use crate::*;
+
+    #[test]
+    fn slow_failure() {
+        set_slow_assertion_threshold(Some(Duration::ZERO));
+        let result = check_that!(vec![1, 2]).contains(3);
+        set_slow_assertion_threshold(None);
+
+        assert_that!(result).facts_are_at_least(vec![
+            Fact::new("expected to contain", "3"),
+            Fact::new_simple_fact("but did not"),
+        ]);
+        assert_that!(result)
+            .fact_keys()
+            .contains(&"evaluation took".to_string());
+    }
+
+    #[test]
+    fn fast_failure() {
+        set_slow_assertion_threshold(Some(Duration::from_secs(60)));
+        let result = check_that!(vec![1, 2]).contains(3);
+        set_slow_assertion_threshold(None);
+
+        assert_that!(result)
+            .fact_keys()
+            .does_not_contain(&"evaluation took".to_string());
+    }
+
+    #[test]
+    fn disabled_by_default() {
+        assert_that!(check_that!(1).is_equal_to(2))
+            .facts_are(vec![Fact::new("expected", "2"), Fact::new("actual", "1")]);
+    }
+
+    #[test]
+    fn derived_subjects_are_timed_from_their_parent() {
+        set_slow_assertion_threshold(Some(Duration::ZERO));
+        let result = check_that!(Some(1)).some().is_equal_to(2);
+        set_slow_assertion_threshold(None);
+
+        assert_that!(result)
+            .fact_keys()
+            .contains(&"evaluation took".to_string());
+    }
+}